@@ -0,0 +1,28 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Attribute, Ident, Result};
+
+use crate::util::AsOption;
+
+pub fn parse_group(attrs: &[Attribute]) -> Result<AsOption<Group>> {
+    attrs
+        .iter()
+        .find(|attr| match attr.path.get_ident() {
+            Some(ident) => ident == "group",
+            None => return false,
+        })
+        .map(|a| a.parse_args())
+        .transpose()
+        .map(|g| AsOption(g.map(|group| Group { group })))
+}
+
+pub struct Group {
+    group: Ident,
+}
+
+impl ToTokens for Group {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ident = &self.group;
+        tokens.extend(quote!(crate::core::commands::prefix::PrefixCommandGroup::#ident));
+    }
+}
@@ -6,6 +6,7 @@ use syn::{parse_macro_input, DeriveInput};
 mod bucket;
 mod embed_data;
 mod flags;
+mod group;
 mod has_mods;
 mod has_name;
 mod pagination;
@@ -17,7 +18,12 @@ mod util;
 ///
 /// Make sure there is a function in scope with the signature
 /// `async fn slash_{lowercased_name}(Arc<Context>, Box<ApplicationCommand>) -> Result<()>`
-#[proc_macro_derive(SlashCommand, attributes(bucket, flags))]
+///
+/// Available attributes:
+/// - `bucket`: `BucketName`
+/// - `flags`: list of `CommandFlags`
+/// - `group`: `PrefixCommandGroup`
+#[proc_macro_derive(SlashCommand, attributes(bucket, flags, group))]
 pub fn slash_command(input: TokenStream) -> TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
 
@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::{DeriveInput, Error, Result, Visibility};
 
-use crate::{bucket::parse_bucket, flags::parse_flags};
+use crate::{bucket::parse_bucket, flags::parse_flags, group::parse_group};
 
 pub fn derive(input: DeriveInput) -> Result<TokenStream> {
     match input.vis {
@@ -17,6 +17,7 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream> {
     let exec = format_ident!("{slash_cmd}__");
     let bucket = parse_bucket(&input.attrs)?;
     let flags = parse_flags(&input.attrs)?.into_tokens();
+    let group = parse_group(&input.attrs)?;
     let path = quote!(crate::core::commands::slash::SlashCommand);
 
     let tokens = quote! {
@@ -25,6 +26,7 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream> {
             create: #name::create_command,
             exec: #exec,
             flags: #flags,
+            group: #group,
         };
 
         pub fn #exec(
@@ -0,0 +1 @@
+DROP TABLE osu_user_skills;
\ No newline at end of file
@@ -0,0 +1,12 @@
+CREATE TABLE osu_user_skills (
+    user_id  INT4 NOT NULL,
+    mode     INT2 NOT NULL,
+    date     DATE NOT NULL DEFAULT CURRENT_DATE,
+    acc      FLOAT4 NOT NULL,
+    aim      FLOAT4,
+    speed    FLOAT4,
+    strain   FLOAT4,
+    movement FLOAT4,
+
+    PRIMARY KEY (user_id, mode, date)
+);
\ No newline at end of file
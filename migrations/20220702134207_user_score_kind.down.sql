@@ -0,0 +1 @@
+ALTER TABLE user_configs DROP COLUMN score_kind;
\ No newline at end of file
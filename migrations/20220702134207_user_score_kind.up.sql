@@ -0,0 +1 @@
+ALTER TABLE user_configs ADD COLUMN score_kind INT2;
\ No newline at end of file
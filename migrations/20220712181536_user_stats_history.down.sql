@@ -0,0 +1 @@
+DROP TABLE osu_user_stats_history;
\ No newline at end of file
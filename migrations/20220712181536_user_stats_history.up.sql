@@ -0,0 +1,12 @@
+CREATE TABLE osu_user_stats_history (
+    user_id      INT4        NOT NULL,
+    mode         INT2        NOT NULL,
+    timestamp    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    pp           FLOAT4      NOT NULL,
+    global_rank  INT4,
+    accuracy     FLOAT4      NOT NULL,
+    playcount    INT4        NOT NULL,
+    ranked_score INT8        NOT NULL,
+
+    PRIMARY KEY (user_id, mode, timestamp)
+);
\ No newline at end of file
@@ -0,0 +1,25 @@
+CREATE TABLE scores (
+    score_id   INT8        NOT NULL,
+    user_id    INT4        NOT NULL,
+    map_id     INT4        NOT NULL,
+    mode       INT2        NOT NULL,
+    mods       INT4        NOT NULL,
+    score      INT4        NOT NULL,
+    max_combo  INT4        NOT NULL,
+    accuracy   FLOAT4      NOT NULL,
+    pp         FLOAT4,
+    grade      INT2        NOT NULL,
+    perfect    BOOL        NOT NULL,
+    count_geki INT4        NOT NULL,
+    count_300  INT4        NOT NULL,
+    count_katu INT4        NOT NULL,
+    count_100  INT4        NOT NULL,
+    count_50   INT4        NOT NULL,
+    count_miss INT4        NOT NULL,
+    ended_at   TIMESTAMPTZ NOT NULL,
+
+    PRIMARY KEY (score_id)
+);
+
+CREATE INDEX scores_user_id_mode ON scores (user_id, mode);
+CREATE INDEX scores_user_id_map_id ON scores (user_id, map_id);
\ No newline at end of file
@@ -0,0 +1,2 @@
+DROP TABLE competition_scores;
+DROP TABLE competitions;
\ No newline at end of file
@@ -0,0 +1,36 @@
+CREATE TABLE competitions (
+    competition_id SERIAL      NOT NULL,
+    guild_id       INT8        NOT NULL,
+    channel_id     INT8        NOT NULL,
+    message_id     INT8,
+    map_id         INT4        NOT NULL,
+    mode           INT2        NOT NULL,
+    mods           INT4,
+    started_at     TIMESTAMPTZ NOT NULL,
+    ends_at        TIMESTAMPTZ NOT NULL,
+    finished       BOOL        NOT NULL DEFAULT FALSE,
+    finished_at    TIMESTAMPTZ,
+    announced      BOOL        NOT NULL DEFAULT FALSE,
+
+    PRIMARY KEY (competition_id)
+);
+
+CREATE INDEX competitions_guild_id ON competitions (guild_id);
+
+-- Only one active competition per guild
+CREATE UNIQUE INDEX competitions_active_guild_id ON competitions (guild_id) WHERE NOT finished;
+
+CREATE TABLE competition_scores (
+    competition_id INT4        NOT NULL,
+    user_id        INT4        NOT NULL,
+    score_id       INT8        NOT NULL,
+    score          INT4        NOT NULL,
+    accuracy       FLOAT4      NOT NULL,
+    max_combo      INT4        NOT NULL,
+    mods           INT4        NOT NULL,
+    pp             FLOAT4,
+    ended_at       TIMESTAMPTZ NOT NULL,
+
+    PRIMARY KEY (competition_id, user_id),
+    FOREIGN KEY (competition_id) REFERENCES competitions (competition_id) ON DELETE CASCADE
+);
\ No newline at end of file
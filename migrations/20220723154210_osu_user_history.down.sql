@@ -0,0 +1,2 @@
+DROP TABLE osu_user_country_history;
+DROP TABLE osu_user_name_history;
\ No newline at end of file
@@ -0,0 +1,24 @@
+CREATE TABLE osu_user_name_history (
+    user_id     INT4        NOT NULL,
+    username    VARCHAR(15) NOT NULL,
+    observed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (user_id, observed_at)
+);
+
+CREATE INDEX osu_user_name_history_username ON osu_user_name_history (username);
+
+CREATE TABLE osu_user_country_history (
+    user_id      INT4        NOT NULL,
+    country_code VARCHAR(2)  NOT NULL,
+    observed_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (user_id, observed_at)
+);
+
+-- The actual observation date of existing entries is unknown so they're stored at the unix epoch
+INSERT INTO osu_user_name_history (user_id, username, observed_at)
+SELECT user_id, username, 'epoch' FROM osu_user_names;
+
+INSERT INTO osu_user_country_history (user_id, country_code, observed_at)
+SELECT user_id, country_code, 'epoch' FROM osu_user_stats;
\ No newline at end of file
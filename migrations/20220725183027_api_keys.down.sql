@@ -0,0 +1,3 @@
+DROP TABLE api_keys;
+
+ALTER TABLE guild_configs DROP COLUMN public_api;
\ No newline at end of file
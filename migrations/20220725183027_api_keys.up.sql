@@ -0,0 +1,11 @@
+-- Only the sha256 hash of keys is stored
+CREATE TABLE api_keys (
+    key_id     SERIAL      PRIMARY KEY,
+    key_hash   VARCHAR(64) NOT NULL UNIQUE,
+    owner_id   INT8        NOT NULL,
+    label      VARCHAR(64) NOT NULL,
+    ratelimit  INT4        NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE guild_configs ADD COLUMN public_api BOOL;
\ No newline at end of file
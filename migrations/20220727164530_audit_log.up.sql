@@ -0,0 +1,10 @@
+CREATE TABLE audit_log (
+    entry_id   SERIAL      PRIMARY KEY,
+    guild_id   INT8        NOT NULL,
+    user_id    INT8        NOT NULL,
+    action     INT2        NOT NULL,
+    details    TEXT        NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX audit_log_guild_id_created_at ON audit_log (guild_id, created_at);
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE mapper_stats_history;
\ No newline at end of file
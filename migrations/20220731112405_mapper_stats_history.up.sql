@@ -0,0 +1,8 @@
+CREATE TABLE mapper_stats_history (
+    user_id         INT4        NOT NULL,
+    timestamp       TIMESTAMPTZ NOT NULL,
+    playcount       INT8        NOT NULL,
+    favourite_count INT8        NOT NULL,
+
+    PRIMARY KEY (user_id, timestamp)
+);
\ No newline at end of file
@@ -105,6 +105,7 @@ pub async fn prefix_backgroundgame(ctx: Arc<Context>, msg: &Message, args: Args<
     I will only show members of this server."
 )]
 #[flags(SKIP_DEFER)]
+#[group(Games)]
 /// Start a new background guessing game
 pub struct Bg {
     /// Specify a gamemode
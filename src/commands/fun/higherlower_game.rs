@@ -21,6 +21,7 @@ use crate::{
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "higherlower")]
+#[group(Games)]
 /// Play a game of osu! themed higher lower
 pub enum HigherLower {
     #[command(name = "pp")]
@@ -26,6 +26,7 @@ use crate::{
         In case you don't know how it works: Each number indicates the amount of neighboring bombs."
 )]
 #[flags(SKIP_DEFER)]
+#[group(Games)]
 /// Play a game of minesweeper
 pub struct Minesweeper {
     /// Choose a difficulty
@@ -28,6 +28,7 @@ use super::{failed_message_content, option_fields, parse_select_menu, AUTHORITY_
 
 #[derive(CreateCommand, SlashCommand)]
 #[flags(SKIP_DEFER)]
+#[group(Utility)]
 #[command(name = "help")]
 #[allow(dead_code)]
 /// Display general help or help for a specific command
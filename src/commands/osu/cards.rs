@@ -24,6 +24,7 @@ use twilight_model::id::{marker::UserMarker, Id};
 use crate::{
     commands::GameModeOption,
     core::{commands::CommandOrigin, BotConfig, Context},
+    database::UserSkills,
     embeds::{CardEmbed, EmbedData},
     util::{
         builder::MessageBuilder,
@@ -89,7 +90,9 @@ static HTML_TEMPLATE: Lazy<Handlebars<'static>> = Lazy::new(|| {
     __taiko, catch, and mania:__\n\
     - All skills are roughly the same: `Gamer`\n\
     - High accuracy but low strain: `Rhythm Enjoyer`\n\
-    - High strain but low accuracy: `Masher` / `Droplet Dodger`"
+    - High strain but low accuracy: `Masher` / `Droplet Dodger`\n\n\
+    The skill values are stored whenever a card is created or a profile is viewed \
+    so their history can be checked with `/graph skills`."
 )]
 /// Create a user card
 pub struct Card {
@@ -153,8 +156,8 @@ async fn slash_card(ctx: Arc<Context>, mut command: InteractionCommand) -> Resul
 
     user.mode = mode;
 
-    let render_data = match Skills::calculate(&ctx, mode, &scores).await {
-        Ok(skills) => skills.render_data(&user, &scores, medals_overall),
+    let skills = match Skills::calculate(&ctx, mode, &scores).await {
+        Ok(skills) => skills,
         Err(err) => {
             let _ = orig.error(&ctx, GENERAL_ISSUE).await;
 
@@ -162,6 +165,16 @@ async fn slash_card(ctx: Arc<Context>, mut command: InteractionCommand) -> Resul
         }
     };
 
+    let upsert_fut = ctx
+        .psql()
+        .upsert_user_skills(user.user_id, mode, skills.into());
+
+    if let Err(err) = upsert_fut.await {
+        warn!("{:?}", err.wrap_err("failed to store skills"));
+    }
+
+    let render_data = skills.render_data(&user, &scores, medals_overall);
+
     let html = match HTML_TEMPLATE.render("card", &render_data) {
         Ok(rendered) => rendered,
         Err(err) => {
@@ -192,6 +205,32 @@ async fn slash_card(ctx: Arc<Context>, mut command: InteractionCommand) -> Resul
     Ok(())
 }
 
+/// Calculate the skill values of a user's top scores and store them
+/// unless values of the current day are already stored
+pub(super) async fn store_skills(
+    ctx: Arc<Context>,
+    user_id: u32,
+    mode: GameMode,
+    scores: Vec<Score>,
+) {
+    match ctx.psql().has_current_user_skills(user_id, mode).await {
+        Ok(true) => return,
+        Ok(false) => {}
+        Err(err) => return warn!("{:?}", err),
+    }
+
+    let skills = match Skills::calculate(&ctx, mode, &scores).await {
+        Ok(skills) => skills,
+        Err(err) => return warn!("{:?}", err.wrap_err("failed to calculate skills")),
+    };
+
+    let upsert_fut = ctx.psql().upsert_user_skills(user_id, mode, skills.into());
+
+    if let Err(err) = upsert_fut.await {
+        warn!("{:?}", err.wrap_err("failed to store skills"));
+    }
+}
+
 #[derive(Copy, Clone)]
 enum Skills {
     Osu { acc: f64, aim: f64, speed: f64 },
@@ -591,6 +630,37 @@ impl Skills {
     }
 }
 
+impl From<Skills> for UserSkills {
+    #[inline]
+    fn from(skills: Skills) -> Self {
+        let mut user_skills = Self {
+            acc: 0.0,
+            aim: None,
+            speed: None,
+            strain: None,
+            movement: None,
+        };
+
+        match skills {
+            Skills::Osu { acc, aim, speed } => {
+                user_skills.acc = acc as f32;
+                user_skills.aim = Some(aim as f32);
+                user_skills.speed = Some(speed as f32);
+            }
+            Skills::Taiko { acc, strain } | Skills::Mania { acc, strain } => {
+                user_skills.acc = acc as f32;
+                user_skills.strain = Some(strain as f32);
+            }
+            Skills::Catch { acc, movement } => {
+                user_skills.acc = acc as f32;
+                user_skills.movement = Some(movement as f32);
+            }
+        }
+
+        user_skills
+    }
+}
+
 struct Title {
     prefix: TitlePrefix,
     mods: ModDescriptions,
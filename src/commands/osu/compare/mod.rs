@@ -69,6 +69,14 @@ pub struct CompareScore<'a> {
         - `-ezhd!`: Remove all scores that have either `EZ` or `HD`")]
     /// Filter out scores based on mods (`+mods` for included, `+mods!` for exact, `-mods!` for excluded)
     mods: Option<Cow<'a, str>>,
+    #[command(
+        help = "Filter out scores similarly as you filter maps in osu! itself.\n\
+        You can specify limit values such as acc, pp, combo, miss, grade, mods, or date \
+        like for example `pp>300 grade=s date>=2022-01`.\n\
+        Use `|` between words for alternatives and a leading `-` to exclude a word."
+    )]
+    /// Specify a search query containing acc, pp, grade, date, ...
+    query: Option<String>,
     #[command(
         help = "Instead of specifying an osu! username with the `name` option, \
         you can use this option to choose a discord user.\n\
@@ -76,6 +84,10 @@ pub struct CompareScore<'a> {
     )]
     /// Specify a linked discord user
     discord: Option<Id<UserMarker>>,
+    #[command(help = "Also include older scores that were archived by the bot.\n\
+        Recent scores are only archived for users who have used the `/link` command.")]
+    /// Specify whether archived scores of linked users should be included
+    archive: Option<bool>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -201,12 +213,14 @@ where
 #[derive(CommandModel, CreateCommand, Default)]
 #[command(
     name = "profile",
-    help = "Compare profile stats between two players.\n\
+    help = "Compare profile stats between two to five players.\n\
+        With more than two players, a chart comparing their stats relative \
+        to each other will be shown as well.\n\
         Note:\n\
         - PC peak = Monthly playcount peak\n\
         - PP spread = PP difference between the top score and the 100th score"
 )]
-/// Compare two profiles
+/// Compare up to five profiles
 pub struct CompareProfile<'a> {
     /// Specify a gamemode
     mode: Option<GameModeOption>,
@@ -223,6 +237,18 @@ pub struct CompareProfile<'a> {
     discord1: Option<Id<UserMarker>>,
     /// Specify a linked discord user
     discord2: Option<Id<UserMarker>>,
+    /// Specify a username
+    name3: Option<Cow<'a, str>>,
+    /// Specify a username
+    name4: Option<Cow<'a, str>>,
+    /// Specify a username
+    name5: Option<Cow<'a, str>>,
+    /// Specify a linked discord user
+    discord3: Option<Id<UserMarker>>,
+    /// Specify a linked discord user
+    discord4: Option<Id<UserMarker>>,
+    /// Specify a linked discord user
+    discord5: Option<Id<UserMarker>>,
 }
 
 #[derive(CommandModel, CreateCommand, Default)]
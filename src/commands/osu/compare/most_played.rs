@@ -10,7 +10,7 @@ use rosu_v2::{
 
 use crate::{
     commands::osu::{NameExtraction, UserArgs},
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, OsuFutureExt},
     pagination::MostPlayedCommonPagination,
     util::{
         builder::MessageBuilder,
@@ -183,7 +183,11 @@ pub(super) async fn mostplayed(
 
 async fn get_scores_(ctx: &Context, name: &str) -> OsuResult<Vec<MostPlayedMap>> {
     let user_args = UserArgs::new(name, GameMode::Osu);
-    let scores_fut = ctx.osu().user_most_played(name).limit(100);
+    let scores_fut = ctx
+        .osu()
+        .user_most_played(name)
+        .limit(100)
+        .observe(&ctx.stats, "most_played");
 
     if let Some(alt_name) = user_args.whitespaced_name() {
         match scores_fut.await {
@@ -192,6 +196,7 @@ async fn get_scores_(ctx: &Context, name: &str) -> OsuResult<Vec<MostPlayedMap>>
                 ctx.osu()
                     .user_most_played(alt_name.as_str())
                     .limit(100)
+                    .observe(&ctx.stats, "most_played")
                     .await
             }
             Err(err) => Err(err),
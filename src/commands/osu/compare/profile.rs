@@ -2,13 +2,20 @@ use std::{io::Cursor, sync::Arc};
 
 use command_macros::command;
 use eyre::{Report, Result, WrapErr};
+use futures::stream::{FuturesOrdered, TryStreamExt};
 use image::{
+    codecs::png::PngEncoder,
     imageops::{overlay, FilterType},
-    DynamicImage, ImageBuffer,
+    ColorType, DynamicImage, ImageBuffer, ImageEncoder,
     ImageOutputFormat::Png,
     Rgba,
 };
-use rosu_v2::prelude::{GameMode, GameMods, OsuError, Score, UserStatistics};
+use plotters::{
+    prelude::{BitMapBackend, ChartBuilder, IntoDrawingArea, Rectangle, SeriesLabelPosition},
+    style::{Color, RGBColor, WHITE},
+};
+use plotters_backend::FontStyle;
+use rosu_v2::prelude::{GameMode, GameMods, OsuError, Score, User, UserStatistics};
 
 use crate::{
     commands::{
@@ -16,22 +23,40 @@ use crate::{
         GameModeOption,
     },
     core::commands::{prefix::Args, CommandOrigin},
-    embeds::{EmbedData, ProfileCompareEmbed},
+    embeds::{EmbedData, ProfileCompareEmbed, ProfileCompareMultiEmbed},
     util::{
         builder::MessageBuilder,
         constants::{GENERAL_ISSUE, OSU_API_ISSUE},
         matcher,
         osu::BonusPP,
+        MessageExt,
     },
     Context,
 };
 
 use super::{CompareProfile, AT_LEAST_ONE};
 
+const MAX_USERS: usize = 5;
+
 async fn extract_name(ctx: &Context, args: &mut CompareProfile<'_>) -> NameExtraction {
-    if let Some(name) = args.name1.take().or_else(|| args.name2.take()) {
+    let name = args
+        .name1
+        .take()
+        .or_else(|| args.name2.take())
+        .or_else(|| args.name3.take())
+        .or_else(|| args.name4.take())
+        .or_else(|| args.name5.take());
+
+    if let Some(name) = name {
         NameExtraction::Name(name.as_ref().into())
-    } else if let Some(discord) = args.discord1.take().or_else(|| args.discord2.take()) {
+    } else if let Some(discord) = args
+        .discord1
+        .take()
+        .or_else(|| args.discord2.take())
+        .or_else(|| args.discord3.take())
+        .or_else(|| args.discord4.take())
+        .or_else(|| args.discord5.take())
+    {
         match ctx.psql().get_user_osu(discord).await {
             Ok(Some(osu)) => NameExtraction::Name(osu.into_username()),
             Ok(None) => {
@@ -49,27 +74,29 @@ pub(super) async fn profile(
     orig: CommandOrigin<'_>,
     mut args: CompareProfile<'_>,
 ) -> Result<()> {
-    let name1 = match extract_name(&ctx, &mut args).await {
-        NameExtraction::Name(name) => name,
-        NameExtraction::Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+    let mut names = Vec::with_capacity(MAX_USERS);
+
+    loop {
+        match extract_name(&ctx, &mut args).await {
+            NameExtraction::Name(name) => names.push(name),
+            NameExtraction::Err(err) => {
+                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
 
-            return Err(err);
+                return Err(err);
+            }
+            NameExtraction::Content(content) => return orig.error(&ctx, content).await,
+            NameExtraction::None => break,
         }
-        NameExtraction::Content(content) => return orig.error(&ctx, content).await,
-        NameExtraction::None => return orig.error(&ctx, AT_LEAST_ONE).await,
-    };
+    }
 
-    let name2 = match extract_name(&ctx, &mut args).await {
-        NameExtraction::Name(name) => name,
-        NameExtraction::Err(err) => {
-            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+    if names.len() > MAX_USERS {
+        return orig.error(&ctx, CompareProfile::ERR_TOO_MANY_USERS).await;
+    }
 
-            return Err(err);
-        }
-        NameExtraction::Content(content) => return orig.error(&ctx, content).await,
-        NameExtraction::None => match ctx.psql().get_user_osu(orig.user_id()?).await {
-            Ok(Some(osu)) => osu.into_username(),
+    match names.len() {
+        0 => return orig.error(&ctx, AT_LEAST_ONE).await,
+        1 => match ctx.psql().get_user_osu(orig.user_id()?).await {
+            Ok(Some(osu)) => names.push(osu.into_username()),
             Ok(None) => {
                 let content =
                     "Since you're not linked with the `/link` command, you must specify two names.";
@@ -82,10 +109,15 @@ pub(super) async fn profile(
                 return Err(err);
             }
         },
-    };
+        _ => {}
+    }
 
-    if name1 == name2 {
-        return orig.error(&ctx, "Give two different names").await;
+    if names
+        .iter()
+        .enumerate()
+        .any(|(i, name)| names[..i].contains(name))
+    {
+        return orig.error(&ctx, "Give different names").await;
     }
 
     let mode = match args.mode {
@@ -101,16 +133,16 @@ pub(super) async fn profile(
     };
 
     // Retrieve all users and their scores
-    let user_args1 = UserArgs::new(name1.as_ref(), mode);
-    let user_args2 = UserArgs::new(name2.as_ref(), mode);
     let score_args = ScoreArgs::top(100);
 
-    let fut1 = get_user_and_scores(&ctx, user_args1, &score_args);
-    let fut2 = get_user_and_scores(&ctx, user_args2, &score_args);
+    let user_futs: FuturesOrdered<_> = names
+        .iter()
+        .map(|name| get_user_and_scores(&ctx, UserArgs::new(name.as_str(), mode), &score_args))
+        .collect();
 
     #[allow(unused_mut)]
-    let (user1, user2, mut scores1, mut scores2) = match tokio::try_join!(fut1, fut2) {
-        Ok(((user1, scores1), (user2, scores2))) => (user1, user2, scores1, scores2),
+    let mut users: Vec<(User, Vec<Score>)> = match user_futs.try_collect().await {
+        Ok(users) => users,
         Err(OsuError::NotFound) => {
             let content = "At least one of the players was not found";
 
@@ -124,51 +156,78 @@ pub(super) async fn profile(
         }
     };
 
-    if user1.user_id == user2.user_id {
-        let content = "Give two different users";
+    if users
+        .iter()
+        .enumerate()
+        .any(|(i, (user, _))| users[..i].iter().any(|(u, _)| u.user_id == user.user_id))
+    {
+        let content = "Give different users";
 
         return orig.error(&ctx, content).await;
     }
 
-    let content = if scores1.is_empty() {
-        Some(format!("No scores data for user `{name1}`"))
-    } else if scores2.is_empty() {
-        Some(format!("No scores data for user `{name2}`"))
-    } else {
-        None
-    };
+    if let Some((user, _)) = users.iter().find(|(_, scores)| scores.is_empty()) {
+        let content = format!("No scores data for user `{}`", user.username);
 
-    if let Some(content) = content {
         return orig.error(&ctx, content).await;
     }
 
-    // Process user and their top scores for tracking
+    // Process users and their top scores for tracking
     #[cfg(feature = "osutracking")]
-    tokio::join! {
-        crate::tracking::process_osu_tracking(&ctx, &mut scores1, Some(&user1)),
-        crate::tracking::process_osu_tracking(&ctx, &mut scores2, Some(&user2)),
-    };
+    for (user, scores) in users.iter_mut() {
+        crate::tracking::process_osu_tracking(&ctx, scores, Some(&*user)).await;
+    }
+
+    let results: Vec<_> = users
+        .iter()
+        .map(|(user, scores)| CompareResult::calc(mode, scores, user.statistics.as_ref().unwrap()))
+        .collect();
+
+    let users: Vec<_> = users.into_iter().map(|(user, _)| user).collect();
+
+    if let ([user1, user2], [result1, result2]) = (&users[..], &results[..]) {
+        // Create the thumbnail
+        let thumbnail =
+            match get_combined_thumbnail(&ctx, &user1.avatar_url, &user2.avatar_url).await {
+                Ok(thumbnail) => Some(thumbnail),
+                Err(err) => {
+                    warn!("{:?}", err.wrap_err("Failed to combine avatars"));
+
+                    None
+                }
+            };
+
+        // Creating the embed
+        let embed_data = ProfileCompareEmbed::new(mode, user1, user2, result1, result2);
+        let embed = embed_data.build();
+        let mut builder = MessageBuilder::new().embed(embed);
+
+        if let Some(bytes) = thumbnail {
+            builder = builder.attachment("avatar_fuse.png", bytes);
+        }
 
-    let profile_result1 = CompareResult::calc(mode, &scores1, user1.statistics.as_ref().unwrap());
-    let profile_result2 = CompareResult::calc(mode, &scores2, user2.statistics.as_ref().unwrap());
+        orig.create_message(&ctx, &builder).await?;
 
-    // Create the thumbnail
-    let thumbnail = match get_combined_thumbnail(&ctx, &user1.avatar_url, &user2.avatar_url).await {
-        Ok(thumbnail) => Some(thumbnail),
+        return Ok(());
+    }
+
+    let graph = match compare_graph(&users, &results) {
+        Ok(graph) => Some(graph),
         Err(err) => {
-            warn!("{:?}", err.wrap_err("Failed to combine avatars"));
+            warn!(
+                "{:?}",
+                err.wrap_err("Failed to create profile compare graph")
+            );
 
             None
         }
     };
 
-    // Creating the embed
-    let embed_data = ProfileCompareEmbed::new(mode, user1, user2, profile_result1, profile_result2);
-    let embed = embed_data.build();
+    let embed = ProfileCompareMultiEmbed::new(mode, &users, &results).build();
     let mut builder = MessageBuilder::new().embed(embed);
 
-    if let Some(bytes) = thumbnail {
-        builder = builder.attachment("avatar_fuse.png", bytes);
+    if let Some(bytes) = graph {
+        builder = builder.attachment("profile_compare.png", bytes);
     }
 
     orig.create_message(&ctx, &builder).await?;
@@ -177,32 +236,37 @@ pub(super) async fn profile(
 }
 
 #[command]
-#[desc("Compare profile stats between two players")]
+#[desc("Compare profile stats between players")]
 #[help(
-    "Compare profile stats between two players.\n\
+    "Compare profile stats between two to five players.\n\
     Note:\n \
     - PC peak = Monthly playcount peak\n \
     - PP spread = PP difference between top score and 100th score"
 )]
-#[usage("[username1] [username2]")]
+#[usage("[username1] [username2] [username3] ...")]
 #[example("badewanne3 5joshi")]
 #[aliases("pc", "profilecompareosu", "pco")]
 #[group(Osu)]
 async fn prefix_profilecompare(ctx: Arc<Context>, msg: &Message, args: Args<'_>) -> Result<()> {
-    let args = CompareProfile::args(None, args);
+    match CompareProfile::args(None, args) {
+        Ok(args) => profile(ctx, msg.into(), args).await,
+        Err(content) => {
+            msg.error(&ctx, content).await?;
 
-    profile(ctx, msg.into(), args).await
+            Ok(())
+        }
+    }
 }
 
 #[command]
-#[desc("Compare profile stats between two mania players")]
+#[desc("Compare profile stats between mania players")]
 #[help(
-    "Compare profile stats between two mania players.\n\
+    "Compare profile stats between two to five mania players.\n\
     Note:\n \
     - PC peak = Monthly playcount peak\n \
     - PP spread = PP difference between top score and 100th score"
 )]
-#[usage("[username1] [username2]")]
+#[usage("[username1] [username2] [username3] ...")]
 #[example("badewanne3 5joshi")]
 #[alias("pcm")]
 #[group(Mania)]
@@ -211,20 +275,25 @@ async fn prefix_profilecomparemania(
     msg: &Message,
     args: Args<'_>,
 ) -> Result<()> {
-    let args = CompareProfile::args(Some(GameModeOption::Mania), args);
+    match CompareProfile::args(Some(GameModeOption::Mania), args) {
+        Ok(args) => profile(ctx, msg.into(), args).await,
+        Err(content) => {
+            msg.error(&ctx, content).await?;
 
-    profile(ctx, msg.into(), args).await
+            Ok(())
+        }
+    }
 }
 
 #[command]
-#[desc("Compare profile stats between two taiko players")]
+#[desc("Compare profile stats between taiko players")]
 #[help(
-    "Compare profile stats between two taiko players.\n\
+    "Compare profile stats between two to five taiko players.\n\
     Note:\n \
     - PC peak = Monthly playcount peak\n \
     - PP spread = PP difference between top score and 100th score"
 )]
-#[usage("[username1] [username2]")]
+#[usage("[username1] [username2] [username3] ...")]
 #[example("badewanne3 5joshi")]
 #[alias("pct")]
 #[group(Taiko)]
@@ -233,27 +302,37 @@ async fn prefix_profilecomparetaiko(
     msg: &Message,
     args: Args<'_>,
 ) -> Result<()> {
-    let args = CompareProfile::args(Some(GameModeOption::Taiko), args);
+    match CompareProfile::args(Some(GameModeOption::Taiko), args) {
+        Ok(args) => profile(ctx, msg.into(), args).await,
+        Err(content) => {
+            msg.error(&ctx, content).await?;
 
-    profile(ctx, msg.into(), args).await
+            Ok(())
+        }
+    }
 }
 
 #[command]
-#[desc("Compare profile stats between two ctb players")]
+#[desc("Compare profile stats between ctb players")]
 #[help(
-    "Compare profile stats between two ctb players.\n\
+    "Compare profile stats between two to five ctb players.\n\
     Note:\n \
     - PC peak = Monthly playcount peak\n \
     - PP spread = PP difference between top score and 100th score"
 )]
-#[usage("[username1] [username2]")]
+#[usage("[username1] [username2] [username3] ...")]
 #[example("badewanne3 5joshi")]
 #[aliases("pcc", "profilecomparecatch")]
 #[group(Catch)]
 async fn prefix_profilecomparectb(ctx: Arc<Context>, msg: &Message, args: Args<'_>) -> Result<()> {
-    let args = CompareProfile::args(Some(GameModeOption::Catch), args);
+    match CompareProfile::args(Some(GameModeOption::Catch), args) {
+        Ok(args) => profile(ctx, msg.into(), args).await,
+        Err(content) => {
+            msg.error(&ctx, content).await?;
 
-    profile(ctx, msg.into(), args).await
+            Ok(())
+        }
+    }
 }
 pub struct CompareResult {
     pub mode: GameMode,
@@ -331,33 +410,166 @@ async fn get_combined_thumbnail(
     Ok(cursor.into_inner())
 }
 
+const W: u32 = 1350;
+const H: u32 = 500;
+const LEN: usize = (W * H) as usize;
+
+const USER_COLORS: [RGBColor; MAX_USERS] = [
+    RGBColor(0, 208, 138),
+    RGBColor(2, 186, 213),
+    RGBColor(255, 102, 170),
+    RGBColor(255, 204, 34),
+    RGBColor(170, 136, 255),
+];
+
+/// Bar chart of various stats, each relative to the highest value among the users
+fn compare_graph(users: &[User], results: &[CompareResult]) -> Result<Vec<u8>> {
+    type StatFn = fn(&User, &CompareResult) -> f64;
+
+    let stats: [(&str, StatFn); 10] = [
+        ("PP", |user, _| user_stats(user).pp as f64),
+        ("Accuracy", |user, _| user_stats(user).accuracy as f64),
+        ("Playcount", |user, _| user_stats(user).playcount as f64),
+        ("Playtime", |user, _| user_stats(user).playtime as f64),
+        ("Ranked score", |user, _| {
+            user_stats(user).ranked_score as f64
+        }),
+        ("Total hits", |user, _| user_stats(user).total_hits as f64),
+        ("Max combo", |user, _| user_stats(user).max_combo as f64),
+        ("Avg PP", |_, result| result.pp.avg() as f64),
+        ("Bonus PP", |_, result| result.bonus_pp as f64),
+        ("Medals", |user, _| {
+            user.medals.as_ref().map_or(0, Vec::len) as f64
+        }),
+    ];
+
+    let mut buf = vec![0; LEN * 3]; // PIXEL_SIZE = 3
+
+    {
+        let root = BitMapBackend::with_buffer(&mut buf, (W, H)).into_drawing_area();
+        let background = RGBColor(19, 43, 33);
+        root.fill(&background)
+            .wrap_err("failed to fill background")?;
+
+        let len = stats.len() as f64;
+
+        let mut chart = ChartBuilder::on(&root)
+            .margin(10_i32)
+            .x_label_area_size(30_i32)
+            .y_label_area_size(50_i32)
+            .build_cartesian_2d(-0.5..len - 0.5, 0.0..110.0)
+            .wrap_err("failed to build chart")?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .x_labels(stats.len())
+            .x_label_formatter(&|x| {
+                let idx = x.round();
+
+                if (x - idx).abs() > f64::EPSILON || idx < 0.0 {
+                    return String::new();
+                }
+
+                stats
+                    .get(idx as usize)
+                    .map_or_else(String::new, |(name, _)| (*name).to_owned())
+            })
+            .y_labels(6)
+            .y_label_formatter(&|percent| format!("{percent:.0}%"))
+            .label_style(("sans-serif", 16_i32, &WHITE))
+            .bold_line_style(&WHITE.mix(0.3))
+            .axis_style(RGBColor(7, 18, 14))
+            .draw()
+            .wrap_err("failed to draw mesh")?;
+
+        let width = 0.8 / users.len() as f64;
+
+        for (i, (user, color)) in users.iter().zip(USER_COLORS).enumerate() {
+            let bars = stats.iter().enumerate().map(|(j, (_, stat))| {
+                let max = users
+                    .iter()
+                    .zip(results)
+                    .map(|(user, result)| stat(user, result))
+                    .fold(0.0, f64::max);
+
+                let value = stat(user, &results[i]);
+                let percent = if max > 0.0 { 100.0 * value / max } else { 0.0 };
+
+                let left = j as f64 - 0.4 + i as f64 * width;
+                let right = left + width;
+
+                Rectangle::new([(left, 0.0), (right, percent)], color.filled())
+            });
+
+            chart
+                .draw_series(bars)
+                .wrap_err("failed to draw bars")?
+                .label(user.username.as_str())
+                .legend(move |(x, y)| {
+                    Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled())
+                });
+        }
+
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::UpperRight)
+            .border_style(WHITE.mix(0.6).stroke_width(1))
+            .background_style(RGBColor(7, 23, 17))
+            .label_font(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("failed to draw legend")?;
+    }
+
+    // Encode buf to png
+    let mut png_bytes: Vec<u8> = Vec::with_capacity(LEN);
+    let png_encoder = PngEncoder::new(&mut png_bytes);
+
+    png_encoder
+        .write_image(&buf, W, H, ColorType::Rgb8)
+        .wrap_err("failed to encode image")?;
+
+    Ok(png_bytes)
+}
+
+fn user_stats(user: &User) -> &UserStatistics {
+    user.statistics.as_ref().expect("missing user statistics")
+}
+
 impl<'m> CompareProfile<'m> {
-    fn args(mode: Option<GameModeOption>, args: Args<'m>) -> Self {
-        let mut name1 = None;
-        let mut name2 = None;
-        let mut discord1 = None;
-        let mut discord2 = None;
+    const ERR_TOO_MANY_USERS: &'static str = "You can compare at most 5 users";
+
+    fn args(mode: Option<GameModeOption>, args: Args<'m>) -> Result<Self, &'static str> {
+        let mut names = Vec::with_capacity(MAX_USERS);
+        let mut discords = Vec::with_capacity(MAX_USERS);
+
+        for arg in args {
+            if names.len() + discords.len() == MAX_USERS {
+                return Err(Self::ERR_TOO_MANY_USERS);
+            }
 
-        for arg in args.take(2) {
             if let Some(id) = matcher::get_mention_user(arg) {
-                if discord1.is_none() {
-                    discord1 = Some(id);
-                } else {
-                    discord2 = Some(id);
-                }
-            } else if name1.is_none() {
-                name1 = Some(arg.into());
+                discords.push(id);
             } else {
-                name2 = Some(arg.into());
+                names.push(arg.into());
             }
         }
 
-        Self {
+        let mut names = names.into_iter();
+        let mut discords = discords.into_iter();
+
+        Ok(Self {
             mode,
-            name1,
-            name2,
-            discord1,
-            discord2,
-        }
+            name1: names.next(),
+            name2: names.next(),
+            discord1: discords.next(),
+            discord2: discords.next(),
+            name3: names.next(),
+            name4: names.next(),
+            name5: names.next(),
+            discord3: discords.next(),
+            discord4: discords.next(),
+            discord5: discords.next(),
+        })
     }
 }
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, cmp::Ordering, sync::Arc};
+use std::{borrow::Cow, cmp::Ordering, collections::HashSet, sync::Arc};
 
 use command_macros::{command, HasMods, HasName, SlashCommand};
 use eyre::{Report, Result};
@@ -16,7 +16,10 @@ use twilight_model::{
 
 use crate::{
     commands::osu::{get_user, require_link, HasMods, ModsResult, UserArgs},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        OsuFutureExt,
+    },
     database::{EmbedsSize, MinimizedPp},
     embeds::{CompareEmbed, EmbedData, NoScoresEmbed},
     pagination::ScoresPagination,
@@ -26,6 +29,7 @@ use crate::{
         interaction::InteractionCommand,
         matcher,
         osu::{MapIdType, ModSelection},
+        query::{FilterCriteria, Searchable},
         InteractionCommandExt, MessageExt,
     },
     Context,
@@ -60,6 +64,14 @@ pub struct Cs<'a> {
         - `-ezhd!`: Remove all scores that have either `EZ` or `HD`")]
     /// Filter out scores based on mods (`+mods` for included, `+mods!` for exact, `-mods!` for excluded)
     mods: Option<Cow<'a, str>>,
+    #[command(
+        help = "Filter out scores similarly as you filter maps in osu! itself.\n\
+        You can specify limit values such as acc, pp, combo, miss, grade, mods, or date \
+        like for example `pp>300 grade=s date>=2022-01`.\n\
+        Use `|` between words for alternatives and a leading `-` to exclude a word."
+    )]
+    /// Specify a search query containing acc, pp, grade, date, ...
+    query: Option<String>,
     #[command(
         help = "Instead of specifying an osu! username with the `name` option, \
         you can use this option to choose a discord user.\n\
@@ -67,6 +79,10 @@ pub struct Cs<'a> {
     )]
     /// Specify a linked discord user
     discord: Option<Id<UserMarker>>,
+    #[command(help = "Also include older scores that were archived by the bot.\n\
+        Recent scores are only archived for users who have used the `/link` command.")]
+    /// Specify whether archived scores of linked users should be included
+    archive: Option<bool>,
 }
 
 enum MapOrScore {
@@ -80,8 +96,10 @@ pub(super) struct CompareScoreArgs<'a> {
     map: Option<MapOrScore>,
     sort: Option<CompareScoreOrder>,
     mods: Option<Cow<'a, str>>,
+    query: Option<String>,
     discord: Option<Id<UserMarker>>,
     index: Option<u64>,
+    archive: bool,
 }
 
 impl<'m> CompareScoreArgs<'m> {
@@ -114,8 +132,10 @@ impl<'m> CompareScoreArgs<'m> {
             map,
             sort: None,
             mods,
+            query: None,
             discord,
             index,
+            archive: false,
         }
     }
 }
@@ -149,8 +169,10 @@ macro_rules! impl_try_from {
                         map,
                         sort: args.sort,
                         mods: args.mods,
+                        query: args.query,
                         discord: args.discord,
                         index: None,
+                        archive: args.archive.unwrap_or(false),
                     })
                 }
             }
@@ -256,7 +278,12 @@ pub(super) async fn score(
     };
 
     let CompareScoreArgs {
-        sort, map, index, ..
+        sort,
+        map,
+        query,
+        index,
+        archive,
+        ..
     } = args;
 
     let map_id = match map {
@@ -267,7 +294,7 @@ pub(super) async fn score(
             return orig.error(&ctx, content).await;
         }
         Some(MapOrScore::Score { id, mode }) => {
-            let mut score = match ctx.osu().score(id, mode).await {
+            let mut score = match ctx.osu().score(id, mode).observe(&ctx.stats, "score").await {
                 Ok(score) => score,
                 Err(err) => {
                     let _ = orig.error(&ctx, OSU_API_ISSUE).await;
@@ -277,14 +304,19 @@ pub(super) async fn score(
                 }
             };
 
-            let user_fut = ctx.osu().user(score.user_id).mode(mode);
+            let user_fut = ctx
+                .osu()
+                .user(score.user_id)
+                .mode(mode)
+                .observe(&ctx.stats, "user");
 
             let pinned_fut = ctx
                 .osu()
                 .user_scores(score.user_id)
                 .pinned()
                 .limit(100)
-                .mode(mode);
+                .mode(mode)
+                .observe(&ctx.stats, "user_scores");
 
             let (user_result, pinned_result) = tokio::join!(user_fut, pinned_fut);
 
@@ -311,7 +343,13 @@ pub(super) async fn score(
             let map = score.map.as_ref().unwrap();
 
             let global_idx = if matches!(map.status, Ranked | Loved | Approved) {
-                match ctx.osu().beatmap_scores(map.map_id).mode(mode).await {
+                match ctx
+                    .osu()
+                    .beatmap_scores(map.map_id)
+                    .mode(mode)
+                    .observe(&ctx.stats, "beatmap_scores")
+                    .await
+                {
                     Ok(scores) => scores.iter().position(|s| s == &score),
                     Err(err) => {
                         let report = Report::new(err).wrap_err("Failed to get global scores");
@@ -333,7 +371,8 @@ pub(super) async fn score(
                     .user_scores(score.user_id)
                     .best()
                     .limit(100)
-                    .mode(mode);
+                    .mode(mode)
+                    .observe(&ctx.stats, "user_scores");
 
                 match fut.await {
                     Ok(scores) => Some(scores),
@@ -407,7 +446,13 @@ pub(super) async fn score(
     // Retrieving the beatmap
     let mut map = match ctx.psql().get_beatmap(map_id, true).await {
         Ok(map) => map,
-        Err(_) => match ctx.osu().beatmap().map_id(map_id).await {
+        Err(_) => match ctx
+            .osu()
+            .beatmap()
+            .map_id(map_id)
+            .observe(&ctx.stats, "beatmap")
+            .await
+        {
             Ok(map) => {
                 // Store map in DB
                 if let Err(err) = ctx.psql().insert_beatmap(&map).await {
@@ -433,7 +478,8 @@ pub(super) async fn score(
                 let scores_fut = ctx
                     .osu()
                     .beatmap_user_scores(map_id, user.user_id)
-                    .mode(map.mode);
+                    .mode(map.mode)
+                    .observe(&ctx.stats, "beatmap_user_scores");
 
                 match scores_fut.await {
                     Ok(scores) => (user, scores),
@@ -457,7 +503,8 @@ pub(super) async fn score(
                 let scores_fut = ctx
                     .osu()
                     .beatmap_user_scores(map_id, alt_name.as_str())
-                    .mode(map.mode);
+                    .mode(map.mode)
+                    .observe(&ctx.stats, "beatmap_user_scores");
 
                 match tokio::join!(redis.osu_user(&user_args), scores_fut) {
                     (Err(OsuError::NotFound), _) => {
@@ -490,7 +537,8 @@ pub(super) async fn score(
         let scores_fut = ctx
             .osu()
             .beatmap_user_scores(map_id, name.as_str())
-            .mode(map.mode);
+            .mode(map.mode)
+            .observe(&ctx.stats, "beatmap_user_scores");
 
         let redis = ctx.redis();
 
@@ -515,6 +563,24 @@ pub(super) async fn score(
         }
     };
 
+    if archive {
+        match ctx
+            .psql()
+            .get_archived_map_scores(user.user_id, map_id)
+            .await
+        {
+            Ok(archived) => {
+                let ids: HashSet<_> = scores.iter().map(|score| score.score_id).collect();
+                let archived = archived
+                    .into_iter()
+                    .filter(|score| score.mode == map.mode && !ids.contains(&score.score_id));
+
+                scores.extend(archived);
+            }
+            Err(err) => warn!("{:?}", err.wrap_err("failed to get archived scores")),
+        }
+    }
+
     match mods {
         Some(ModSelection::Include(mods)) if mods.is_empty() => {
             scores.retain(|s| s.mods.is_empty())
@@ -527,6 +593,11 @@ pub(super) async fn score(
         None => {}
     }
 
+    if let Some(query) = query.as_deref() {
+        let criteria = FilterCriteria::new(query);
+        scores.retain(|score| score.matches(&criteria));
+    }
+
     if scores.is_empty() {
         return no_scores(&ctx, &orig, name.as_str(), map_id, mods).await;
     }
@@ -536,7 +607,8 @@ pub(super) async fn score(
         .user_scores(user.user_id)
         .pinned()
         .mode(map.mode)
-        .limit(100);
+        .limit(100)
+        .observe(&ctx.stats, "user_scores");
 
     let sort_fut = sort
         .unwrap_or_default()
@@ -547,7 +619,11 @@ pub(super) async fn score(
             map.status,
             RankStatus::Ranked | RankStatus::Loved | RankStatus::Approved
         ) {
-            let fut = ctx.osu().beatmap_scores(map.map_id).mode(map.mode);
+            let fut = ctx
+                .osu()
+                .beatmap_scores(map.map_id)
+                .mode(map.mode)
+                .observe(&ctx.stats, "beatmap_scores");
 
             Some(fut.await)
         } else {
@@ -562,7 +638,8 @@ pub(super) async fn score(
                 .user_scores(user.user_id)
                 .mode(map.mode)
                 .best()
-                .limit(100);
+                .limit(100)
+                .observe(&ctx.stats, "user_scores");
 
             Some(fut.await)
         } else {
@@ -741,7 +818,13 @@ async fn no_scores(
 ) -> Result<()> {
     let map = match ctx.psql().get_beatmap(map_id, true).await {
         Ok(map) => map,
-        Err(_) => match ctx.osu().beatmap().map_id(map_id).await {
+        Err(_) => match ctx
+            .osu()
+            .beatmap()
+            .map_id(map_id)
+            .observe(&ctx.stats, "beatmap")
+            .await
+        {
             Ok(map) => {
                 if let Err(err) = ctx.psql().insert_beatmap(&map).await {
                     warn!("{:?}", err.wrap_err("Failed to insert map in database"));
@@ -0,0 +1,384 @@
+use std::{str::FromStr, sync::Arc};
+
+use command_macros::SlashCommand;
+use eyre::{Report, Result};
+use rosu_v2::prelude::{
+    GameMode, GameMods, OsuError,
+    RankStatus::{Approved, Loved, Qualified, Ranked},
+};
+use time::{Duration, OffsetDateTime};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    commands::GameModeOption,
+    core::{commands::checks::check_authority, OsuFutureExt},
+    embeds::{CompetitionEmbed, CompetitionWinnersEmbed, EmbedData},
+    util::{
+        builder::MessageBuilder,
+        constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+        interaction::InteractionCommand,
+        matcher, Authored, InteractionCommandExt,
+    },
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "competition",
+    help = "Compete with other members of this server on a map.\n\
+    Server authorities can start a competition on a map with an optional mod requirement \
+    and a deadline. Until the deadline, the scores of all linked members on that map \
+    are checked every few minutes and a leaderboard in the competition's channel is kept up to date.\n\
+    Only scores that were set after the competition started count. Since osu! only keeps \
+    a user's best score for each mod combination on a map, a new score only shows up \
+    if it improves the user's previous best with the same mods.\n\
+    Once the deadline passes, the winner will be announced and the competition \
+    becomes part of the server's hall of fame."
+)]
+#[flags(ONLY_GUILDS)]
+/// Compete with other server members on a map
+pub enum Competition {
+    #[command(name = "start")]
+    Start(CompetitionStart),
+    #[command(name = "end")]
+    End(CompetitionEnd),
+    #[command(name = "leaderboard")]
+    Leaderboard(CompetitionLeaderboard),
+    #[command(name = "halloffame")]
+    HallOfFame(CompetitionHallOfFame),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "start",
+    help = "Start a competition on a map in this channel.\n\
+    Only one competition can be active per server at a time.\n\
+    Requires authority status."
+)]
+/// Start a competition in this channel
+pub struct CompetitionStart {
+    /// Specify a map url or map id
+    map: String,
+    #[command(min_value = 1, max_value = 30)]
+    /// Specify after how many days the competition ends
+    days: i64,
+    #[command(
+        help = "Specify mods that scores must have exactly, e.g. `hdhr` or `nm`.\n\
+    If not specified, scores with any mods count."
+    )]
+    /// Specify required mods e.g. hdhr or nm
+    mods: Option<String>,
+    /// Specify a gamemode
+    mode: Option<GameModeOption>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "end",
+    help = "End the current competition early and announce its winner.\n\
+    Requires authority status."
+)]
+/// End the current competition
+pub struct CompetitionEnd;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "leaderboard")]
+/// Show the leaderboard of the current competition
+pub struct CompetitionLeaderboard;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "halloffame")]
+/// Show the winners of past competitions
+pub struct CompetitionHallOfFame;
+
+async fn slash_competition(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
+    match Competition::from_interaction(command.input_data())? {
+        Competition::Start(args) => start(ctx, command, args).await,
+        Competition::End(_) => end(ctx, command).await,
+        Competition::Leaderboard(_) => leaderboard(ctx, command).await,
+        Competition::HallOfFame(_) => hall_of_fame(ctx, command).await,
+    }
+}
+
+/// Respond with an error and return `false` if the author is not an authority
+async fn is_authority(ctx: &Context, command: &InteractionCommand) -> Result<bool> {
+    match check_authority(ctx, command.user_id()?, command.guild_id).await {
+        Ok(None) => Ok(true),
+        Ok(Some(content)) => {
+            command.error(ctx, content).await?;
+
+            Ok(false)
+        }
+        Err(err) => {
+            let _ = command.error(ctx, GENERAL_ISSUE).await;
+
+            Err(err.wrap_err("failed to check authority status"))
+        }
+    }
+}
+
+async fn start(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: CompetitionStart,
+) -> Result<()> {
+    if !is_authority(&ctx, &command).await? {
+        return Ok(());
+    }
+
+    let guild_id = command.guild_id.unwrap(); // command is only processed in guilds
+
+    let map_id = match matcher::get_osu_map_id(&args.map).or_else(|| args.map.parse().ok()) {
+        Some(map_id) => map_id,
+        None => {
+            let content = "Failed to parse map url or id. Be sure to specify a valid map.";
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+    };
+
+    let mods = match args.mods.as_deref() {
+        Some(mods) => {
+            let mods = mods.trim_start_matches('+').trim_end_matches('!');
+
+            match GameMods::from_str(mods) {
+                Ok(mods) => Some(mods),
+                Err(_) => {
+                    let content = "Failed to parse mods. Be sure to specify them e.g. as `hdhr`.";
+                    command.error(&ctx, content).await?;
+
+                    return Ok(());
+                }
+            }
+        }
+        None => None,
+    };
+
+    match ctx.psql().get_guild_competition(guild_id).await {
+        Ok(None) => {}
+        Ok(Some(competition)) => {
+            let content = format!(
+                "There is already an active competition in this server, \
+                ending <t:{}:R>.\nUse `/competition end` to end it early.",
+                competition.ends_at.unix_timestamp()
+            );
+
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get guild competition"));
+        }
+    }
+
+    let map = match ctx
+        .osu()
+        .beatmap()
+        .map_id(map_id)
+        .observe(&ctx.stats, "beatmap")
+        .await
+    {
+        Ok(map) => map,
+        Err(OsuError::NotFound) => {
+            let content = format!("Beatmap with id {map_id} was not found");
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error(&ctx, OSU_API_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("failed to get beatmap"));
+        }
+    };
+
+    if !matches!(map.status, Ranked | Loved | Qualified | Approved) {
+        let content = "Competitions are only possible on maps with a leaderboard";
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let mode = args.mode.map_or(map.mode, GameMode::from);
+
+    if map.mode != GameMode::Osu && mode != map.mode {
+        let content = format!("Can't convert a {} map to {mode}", map.mode);
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    if let Err(err) = ctx.psql().insert_beatmap(&map).await {
+        warn!("{:?}", err.wrap_err("failed to insert map in database"));
+    }
+
+    let ends_at = OffsetDateTime::now_utc() + Duration::days(args.days);
+
+    let insert_fut =
+        ctx.psql()
+            .insert_competition(guild_id, command.channel_id, map_id, mode, mods, ends_at);
+
+    match insert_fut.await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            let content = "There is already an active competition in this server";
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to insert competition"));
+        }
+    }
+
+    let content = format!(
+        "The competition has started and ends <t:{}:R>!\n\
+        The leaderboard will be posted in this channel and updated every few minutes.",
+        ends_at.unix_timestamp()
+    );
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(&ctx, &builder).await?;
+
+    Ok(())
+}
+
+async fn end(ctx: Arc<Context>, command: InteractionCommand) -> Result<()> {
+    if !is_authority(&ctx, &command).await? {
+        return Ok(());
+    }
+
+    let guild_id = command.guild_id.unwrap(); // command is only processed in guilds
+
+    let competition = match ctx.psql().get_guild_competition(guild_id).await {
+        Ok(Some(competition)) => competition,
+        Ok(None) => {
+            command
+                .error(&ctx, "There is no active competition in this server")
+                .await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get guild competition"));
+        }
+    };
+
+    let builder = MessageBuilder::new().embed("Ending the competition...");
+    command.update(&ctx, &builder).await?;
+
+    if let Err(err) = ctx.update_competition(&competition, true).await {
+        let _ = command.error(&ctx, GENERAL_ISSUE).await;
+
+        return Err(err.wrap_err("failed to end competition"));
+    }
+
+    let builder = MessageBuilder::new().embed("The competition has ended");
+    command.update(&ctx, &builder).await?;
+
+    Ok(())
+}
+
+async fn leaderboard(ctx: Arc<Context>, command: InteractionCommand) -> Result<()> {
+    let guild_id = command.guild_id.unwrap(); // command is only processed in guilds
+
+    let competition = match ctx.psql().get_guild_competition(guild_id).await {
+        Ok(Some(competition)) => competition,
+        Ok(None) => {
+            let content = "There is no active competition in this server.\n\
+                Check out `/competition halloffame` for past competitions.";
+
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get guild competition"));
+        }
+    };
+
+    let scores_fut = ctx
+        .psql()
+        .get_competition_scores(competition.competition_id);
+    let map_fut = ctx.psql().get_beatmap(competition.map_id, true);
+
+    let (scores, map) = match tokio::try_join!(scores_fut, map_fut) {
+        Ok(tuple) => tuple,
+        Err(err) => {
+            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get competition scores or map"));
+        }
+    };
+
+    let user_ids: Vec<_> = scores.iter().map(|score| score.user_id as i32).collect();
+
+    let names = match ctx.psql().get_names_by_ids(&user_ids).await {
+        Ok(names) => names,
+        Err(err) => {
+            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get usernames"));
+        }
+    };
+
+    let embed = CompetitionEmbed::new(&competition, &map, &scores, &names, false).build();
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(&ctx, &builder).await?;
+
+    Ok(())
+}
+
+async fn hall_of_fame(ctx: Arc<Context>, command: InteractionCommand) -> Result<()> {
+    let guild_id = command.guild_id.unwrap(); // command is only processed in guilds
+
+    let winners = match ctx.psql().get_competition_winners(guild_id).await {
+        Ok(winners) => winners,
+        Err(err) => {
+            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get competition winners"));
+        }
+    };
+
+    if winners.is_empty() {
+        let content = "No competition in this server has been won yet";
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let mut user_ids: Vec<_> = winners.iter().map(|winner| winner.user_id as i32).collect();
+    user_ids.sort_unstable();
+    user_ids.dedup();
+
+    let names = match ctx.psql().get_names_by_ids(&user_ids).await {
+        Ok(names) => names,
+        Err(err) => {
+            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get usernames"));
+        }
+    };
+
+    let guild_name = ctx
+        .cache
+        .guild(guild_id, |g| g.name().to_owned())
+        .unwrap_or_else(|_| "this server".to_owned());
+
+    let embed = CompetitionWinnersEmbed::new(&guild_name, &winners, &names).build();
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(&ctx, &builder).await?;
+
+    Ok(())
+}
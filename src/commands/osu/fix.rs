@@ -13,7 +13,10 @@ use twilight_model::{
 };
 
 use crate::{
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        OsuFutureExt,
+    },
     embeds::{EmbedData, FixScoreEmbed},
     util::{
         constants::{GENERAL_ISSUE, OSU_API_ISSUE},
@@ -316,21 +319,28 @@ async fn request_by_map(
 ) -> ScoreResult {
     let user_args = UserArgs::new(name, GameMode::Osu);
 
-    match get_beatmap_user_score(ctx.osu(), map_id, &user_args, mods).await {
+    let score_fut = get_beatmap_user_score(ctx.osu(), map_id, &user_args, mods);
+
+    match score_fut.observe(&ctx.stats, "beatmap_user_score").await {
         Ok(mut score) => match super::prepare_score(ctx, &mut score.score).await {
             Ok(_) => {
                 let mut map = score.score.map.take().unwrap();
 
                 // First try to just get the mapset from the DB
                 let mapset_fut = ctx.psql().get_beatmapset(map.mapset_id);
-                let user_fut = ctx.osu().user(score.score.user_id).mode(score.score.mode);
+                let user_fut = ctx
+                    .osu()
+                    .user(score.score.user_id)
+                    .mode(score.score.mode)
+                    .observe(&ctx.stats, "user");
 
                 let best_fut = ctx
                     .osu()
                     .user_scores(score.score.user_id)
                     .mode(score.score.mode)
                     .limit(100)
-                    .best();
+                    .best()
+                    .observe(&ctx.stats, "user_scores");
 
                 let (user, best) = match tokio::join!(mapset_fut, user_fut, best_fut) {
                     (_, Err(err), _) | (_, _, Err(err)) => {
@@ -345,7 +355,12 @@ async fn request_by_map(
                         (user, best)
                     }
                     (Err(_), Ok(user), Ok(best)) => {
-                        let mapset = match ctx.osu().beatmapset(map.mapset_id).await {
+                        let mapset = match ctx
+                            .osu()
+                            .beatmapset(map.mapset_id)
+                            .observe(&ctx.stats, "beatmapset")
+                            .await
+                        {
                             Ok(mapset) => mapset,
                             Err(err) => {
                                 let _ = orig.error(ctx, OSU_API_ISSUE).await;
@@ -380,7 +395,13 @@ async fn request_by_map(
         Err(OsuError::NotFound) => {
             let map = match ctx.psql().get_beatmap(map_id, true).await {
                 Ok(map) => map,
-                Err(_) => match ctx.osu().beatmap().map_id(map_id).await {
+                Err(_) => match ctx
+                    .osu()
+                    .beatmap()
+                    .map_id(map_id)
+                    .observe(&ctx.stats, "beatmap")
+                    .await
+                {
                     Ok(map) => {
                         if let Err(err) = ctx.psql().insert_beatmap(&map).await {
                             warn!("{:?}", err.wrap_err("Failed to insert map in database"));
@@ -449,8 +470,8 @@ async fn request_by_score(
     mode: GameMode,
     name: &str,
 ) -> ScoreResult {
-    let score_fut = ctx.osu().score(score_id, mode);
-    let user_fut = ctx.osu().user(name).mode(mode);
+    let score_fut = ctx.osu().score(score_id, mode).observe(&ctx.stats, "score");
+    let user_fut = ctx.osu().user(name).mode(mode).observe(&ctx.stats, "user");
 
     let (user, mut score) = match tokio::try_join!(user_fut, score_fut) {
         Ok((user, score)) => (user, score),
@@ -472,7 +493,8 @@ async fn request_by_score(
         .user_scores(score.user_id)
         .mode(score.mode)
         .limit(100)
-        .best();
+        .best()
+        .observe(&ctx.stats, "user_scores");
 
     let best = match tokio::join!(mapset_fut, best_fut) {
         (_, Err(err)) => {
@@ -487,7 +509,12 @@ async fn request_by_score(
             best
         }
         (Err(_), Ok(best)) => {
-            let mapset = match ctx.osu().beatmapset(map.mapset_id).await {
+            let mapset = match ctx
+                .osu()
+                .beatmapset(map.mapset_id)
+                .observe(&ctx.stats, "beatmapset")
+                .await
+            {
                 Ok(mapset) => mapset,
                 Err(err) => {
                     let _ = orig.error(ctx, OSU_API_ISSUE).await;
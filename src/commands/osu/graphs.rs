@@ -6,7 +6,8 @@ use image::{codecs::png::PngEncoder, ColorType, ImageEncoder};
 use plotters::{
     prelude::{
         AreaSeries, BitMapBackend, ChartBuilder, Circle, EmptyElement, IntoDrawingArea,
-        IntoSegmentedCoord, PointSeries, Rectangle, SegmentValue, SeriesLabelPosition,
+        IntoSegmentedCoord, LineSeries, PathElement, PointSeries, Rectangle, SegmentValue,
+        SeriesLabelPosition,
     },
     style::{Color, RGBColor, ShapeStyle, BLACK, GREEN, RED, WHITE},
 };
@@ -22,6 +23,7 @@ use crate::{
         GameModeOption, ShowHideOption,
     },
     core::{commands::CommandOrigin, Context},
+    database::{UserSkills, UserSkillsEntry},
     embeds::{EmbedData, GraphEmbed},
     util::{
         builder::MessageBuilder,
@@ -44,6 +46,8 @@ pub enum Graph {
     PlaycountReplays(GraphPlaycountReplays),
     #[command(name = "rank")]
     Rank(GraphRank),
+    #[command(name = "skills")]
+    Skills(GraphSkills),
     #[command(name = "sniped")]
     Sniped(GraphSniped),
     #[command(name = "snipe_count")]
@@ -105,6 +109,28 @@ pub struct GraphRank {
     discord: Option<Id<UserMarker>>,
 }
 
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(
+    name = "skills",
+    help = "Display how a user's skill values of the `/card` command evolved.\n\
+    Skill values are stored whenever a card is created and on the first profile view of a day \
+    so there is at most one value per day on which that happened."
+)]
+/// Display a user's card skill values over time
+pub struct GraphSkills {
+    /// Specify a gamemode
+    mode: Option<GameModeOption>,
+    /// Specify a username
+    name: Option<String>,
+    #[command(
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    /// Specify a linked discord user
+    discord: Option<Id<UserMarker>>,
+}
+
 #[derive(CommandModel, CreateCommand, HasName)]
 #[command(name = "sniped")]
 /// Display sniped users of the past 8 weeks
@@ -332,6 +358,14 @@ async fn graph(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Graph) -> Resul
                 .await
                 .wrap_err("failed to create rank graph")?
         }
+        Graph::Skills(args) => {
+            let (name, mode) = name_mode!(ctx, orig, args);
+            let user_args = UserArgs::new(name.as_str(), mode);
+
+            skills_graph(&ctx, &orig, &name, &user_args)
+                .await
+                .wrap_err("failed to create skills graph")?
+        }
         Graph::Sniped(args) => {
             let name = match username!(ctx, orig, args) {
                 Some(name) => name,
@@ -670,6 +704,153 @@ async fn rank_graph(
     Ok(Some((user, bytes)))
 }
 
+async fn skills_graph(
+    ctx: &Context,
+    orig: &CommandOrigin<'_>,
+    name: &str,
+    user_args: &UserArgs<'_>,
+) -> Result<Option<(User, Vec<u8>)>> {
+    let user = match get_user(ctx, user_args).await {
+        Ok(user) => user,
+        Err(OsuError::NotFound) => {
+            let content = format!("Could not find user `{name}`");
+            orig.error(ctx, content).await?;
+
+            return Ok(None);
+        }
+        Err(err) => {
+            let _ = orig.error(ctx, OSU_API_ISSUE).await;
+            let report = Report::new(err).wrap_err("failed to get user");
+
+            return Err(report);
+        }
+    };
+
+    let history = match ctx
+        .psql()
+        .get_user_skills(user.user_id, user_args.mode)
+        .await
+    {
+        Ok(history) => history,
+        Err(err) => {
+            let _ = orig.error(ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get skill history"));
+        }
+    };
+
+    if history.len() < 2 {
+        let content = format!(
+            "There are not enough skill values stored for `{name}`.\n\
+            Skill values are stored whenever a `/card` is created or a profile is viewed \
+            so that needs to happen on at least two different days."
+        );
+
+        orig.error(ctx, content).await?;
+
+        return Ok(None);
+    }
+
+    fn draw_graph(history: &[UserSkillsEntry]) -> Result<Vec<u8>> {
+        let mut buf = vec![0; LEN * 3];
+
+        let first = history[0].date;
+        let last = history[history.len() - 1].date;
+
+        {
+            let root = BitMapBackend::with_buffer(&mut buf, (W, H)).into_drawing_area();
+            let background = RGBColor(19, 43, 33);
+            root.fill(&background)
+                .wrap_err("failed to fill background")?;
+
+            let caption_style = ("sans-serif", 25_i32, FontStyle::Bold, &WHITE);
+
+            let mut chart = ChartBuilder::on(&root)
+                .x_label_area_size(40_i32)
+                .y_label_area_size(45_i32)
+                .margin_top(5_i32)
+                .margin_right(15_i32)
+                .caption("Skill history", caption_style)
+                .build_cartesian_2d(Monthly(first..last), 0.0_f32..101.0_f32)
+                .wrap_err("failed to build chart")?;
+
+            chart
+                .configure_mesh()
+                .disable_x_mesh()
+                .x_label_formatter(&|date| date.to_string())
+                .y_label_formatter(&|value| format!("{value:.0}"))
+                .label_style(("sans-serif", 16_i32, &WHITE))
+                .bold_line_style(&WHITE.mix(0.3))
+                .axis_style(RGBColor(7, 18, 14))
+                .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+                .draw()
+                .wrap_err("failed to draw mesh")?;
+
+            type SkillFn = fn(&UserSkills) -> Option<f32>;
+
+            let skills: [(&str, RGBColor, SkillFn); 5] = [
+                ("Accuracy", RGBColor(2, 186, 213), |skills| Some(skills.acc)),
+                ("Aim", RGBColor(0, 208, 138), |skills| skills.aim),
+                ("Speed", RGBColor(246, 111, 162), |skills| skills.speed),
+                ("Strain", RGBColor(246, 111, 162), |skills| skills.strain),
+                ("Movement", RGBColor(0, 208, 138), |skills| skills.movement),
+            ];
+
+            for (label, color, skill_fn) in skills {
+                let data: Vec<_> = history
+                    .iter()
+                    .filter_map(|entry| Some((entry.date, skill_fn(&entry.skills)?)))
+                    .collect();
+
+                if data.is_empty() {
+                    continue;
+                }
+
+                let style = color.stroke_width(3);
+
+                chart
+                    .draw_series(LineSeries::new(data, style))
+                    .wrap_err("failed to draw skill line")?
+                    .label(label)
+                    .legend(move |(x, y)| {
+                        PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(3))
+                    });
+            }
+
+            chart
+                .configure_series_labels()
+                .border_style(WHITE.mix(0.6).stroke_width(1))
+                .background_style(RGBColor(7, 23, 17))
+                .position(SeriesLabelPosition::LowerRight)
+                .label_font(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+                .draw()
+                .wrap_err("failed to draw legend")?;
+        }
+
+        // Encode buf to png
+        let mut png_bytes: Vec<u8> = Vec::with_capacity(LEN);
+        let png_encoder = PngEncoder::new(&mut png_bytes);
+
+        png_encoder
+            .write_image(&buf, W, H, ColorType::Rgb8)
+            .wrap_err("failed to encode image")?;
+
+        Ok(png_bytes)
+    }
+
+    let bytes = match draw_graph(&history) {
+        Ok(graph) => graph,
+        Err(err) => {
+            let _ = orig.error(ctx, GENERAL_ISSUE).await;
+            warn!("{:?}", err.wrap_err("Failed to draw skills graph"));
+
+            return Ok(None);
+        }
+    };
+
+    Ok(Some((user, bytes)))
+}
+
 async fn sniped_graph(
     ctx: &Context,
     orig: &CommandOrigin<'_>,
@@ -1065,6 +1246,17 @@ async fn top_graph_index(caption: String, scores: &[Score]) -> Result<Vec<u8>> {
     Ok(png_bytes)
 }
 
+/// Count how many scores were set in each hour of the day
+pub(super) fn score_hours(scores: &[Score], tz: UtcOffset) -> [u32; 24] {
+    let mut hours = [0_u32; 24];
+
+    for score in scores {
+        hours[score.ended_at.to_offset(tz).hour() as usize] += 1;
+    }
+
+    hours
+}
+
 async fn top_graph_time(
     mut caption: String,
     scores: &mut [Score],
@@ -1076,8 +1268,6 @@ async fn top_graph_time(
 
     let _ = write!(caption, " (UTC{tz})");
 
-    let mut hours = [0_u32; 24];
-
     let max = scores.first().and_then(|s| s.pp).unwrap_or(0.0);
     let max_adj = max + 5.0;
 
@@ -1086,9 +1276,10 @@ async fn top_graph_time(
 
     for score in scores.iter_mut() {
         score.ended_at = score.ended_at.to_offset(tz);
-        hours[score.ended_at.hour() as usize] += 1;
     }
 
+    let hours = score_hours(scores, tz);
+
     scores.sort_unstable_by_key(|s| s.ended_at.time());
 
     let max_hours = hours.iter().max().copied().unwrap_or(0);
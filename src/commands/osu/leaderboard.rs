@@ -7,7 +7,10 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::channel::{message::MessageType, Message};
 
 use crate::{
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        OsuFutureExt,
+    },
     database::OsuData,
     pagination::LeaderboardPagination,
     pp::PpCalculator,
@@ -206,7 +209,13 @@ async fn leaderboard(
     // Retrieving the beatmap
     let mut map = match ctx.psql().get_beatmap(map_id, true).await {
         Ok(map) => map,
-        Err(_) => match ctx.osu().beatmap().map_id(map_id).await {
+        Err(_) => match ctx
+            .osu()
+            .beatmap()
+            .map_id(map_id)
+            .observe(&ctx.stats, "beatmap")
+            .await
+        {
             Ok(map) => {
                 // Add map to database if its not in already
                 if let Err(err) = ctx.psql().insert_beatmap(&map).await {
@@ -17,7 +17,10 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::channel::{message::MessageType, Message};
 
 use crate::{
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        OsuFutureExt,
+    },
     pagination::MapPagination,
     util::{
         constants::{GENERAL_ISSUE, OSU_API_ISSUE},
@@ -289,7 +292,13 @@ async fn map(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: MapArgs<'_>) -> R
                 Ok(map) => (map.mapset_id, Some(id)),
                 Err(_) => {
                     // If not in DB, request through API
-                    match ctx.osu().beatmap().map_id(id).await {
+                    match ctx
+                        .osu()
+                        .beatmap()
+                        .map_id(id)
+                        .observe(&ctx.stats, "beatmap")
+                        .await
+                    {
                         Ok(map) => {
                             // Store map in DB
                             if let Err(err) = ctx.psql().insert_beatmap(&map).await {
@@ -315,7 +324,12 @@ async fn map(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: MapArgs<'_>) -> R
     };
 
     // Request mapset through API for all maps + genre & language
-    let (mapset, maps) = match ctx.osu().beatmapset(mapset_id).await {
+    let (mapset, maps) = match ctx
+        .osu()
+        .beatmapset(mapset_id)
+        .observe(&ctx.stats, "beatmapset")
+        .await
+    {
         Ok(mut mapset) => {
             if let Err(err) = ctx.psql().insert_beatmapset(&mapset).await {
                 warn!("{:?}", err.wrap_err("Failed to insert mapset in database"));
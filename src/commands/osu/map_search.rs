@@ -10,7 +10,10 @@ use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand,
 
 use crate::{
     commands::GameModeOption,
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        OsuFutureExt,
+    },
     pagination::MapSearchPagination,
     util::{
         constants::OSU_API_ISSUE, interaction::InteractionCommand, ChannelExt,
@@ -628,7 +631,9 @@ async fn prefix_search(ctx: Arc<Context>, msg: &Message, args: Args<'_>) -> Resu
 }
 
 async fn search(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Search) -> Result<()> {
-    let mut search_result = match args.request(ctx.osu()).await {
+    let search_fut = args.request(ctx.osu());
+
+    let mut search_result = match search_fut.observe(&ctx.stats, "map_search").await {
         Ok(response) => response,
         Err(err) => {
             let _ = orig.error(&ctx, OSU_API_ISSUE);
@@ -354,7 +354,9 @@ async fn mapper(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Mapper<'_>) ->
                 (None, None) => MinimizedPp::default(),
             };
 
-            TopSinglePagination::builder(user, scores, minimized_pp)
+            let score_kind = config.score_kind();
+
+            TopSinglePagination::builder(user, scores, minimized_pp, score_kind)
                 .content(content)
                 .start_by_update()
                 .defer_components()
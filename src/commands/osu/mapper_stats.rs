@@ -0,0 +1,516 @@
+use std::{
+    borrow::Cow,
+    cmp::{Ordering, Reverse},
+    sync::Arc,
+};
+
+use command_macros::{HasName, SlashCommand};
+use eyre::{Report, Result, WrapErr};
+use hashbrown::HashMap;
+use image::{codecs::png::PngEncoder, ColorType, ImageEncoder};
+use plotters::{
+    prelude::{
+        AreaSeries, BitMapBackend, ChartBuilder, IntoDrawingArea, LineSeries, PathElement,
+        SeriesLabelPosition,
+    },
+    style::{Color, RGBColor, BLACK, WHITE},
+};
+use plotters_backend::FontStyle;
+use rkyv::{Deserialize, Infallible};
+use rosu_v2::prelude::{Beatmapset, GameMode, OsuError, OsuResult, Username};
+use time::{Date, OffsetDateTime};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{
+    commands::osu::UserArgs,
+    core::{commands::CommandOrigin, OsuFutureExt},
+    custom_client::OsuTrackerMapsetEntry,
+    database::MapperSnapshot,
+    pagination::MapperStatsPagination,
+    util::{
+        constants::{GENERAL_ISSUE, OSUTRACKER_ISSUE, OSU_API_ISSUE},
+        hasher::IntHasher,
+        interaction::InteractionCommand,
+        InteractionCommandExt, RangedDate,
+    },
+    Context,
+};
+
+use super::{get_user, require_link};
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "mapperstats",
+    help = "Show a dashboard about all ranked mapsets hosted by a mapper.\n\
+    For each mapset it shows the playcount, favourites, and pass rate, \
+    how often the mapset appears in top plays according to [osutracker](https://osutracker.com/), \
+    and the pp record on its most played difficulty.\n\
+    The graph shows how the total playcount and favourites of the mapsets grew over time. \
+    Those totals are stored daily for every mapper that was looked up with this command \
+    so the graph only appears once totals from at least two days are available."
+)]
+/// Show statistics about a mapper's ranked mapsets
+pub struct MapperStats<'a> {
+    /// Specify a mapper username
+    name: Option<Cow<'a, str>>,
+    /// Specify the order of mapsets
+    sort: Option<MapperStatsOrder>,
+    #[command(
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    /// Specify a linked discord user
+    discord: Option<Id<UserMarker>>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum MapperStatsOrder {
+    #[option(name = "Favourites", value = "favourites")]
+    Favourites,
+    #[option(name = "Pass rate", value = "pass_rate")]
+    PassRate,
+    #[option(name = "Playcount", value = "playcount")]
+    Playcount,
+    #[option(name = "Ranked date", value = "ranked_date")]
+    RankedDate,
+    #[option(name = "Top plays", value = "top_plays")]
+    TopPlays,
+}
+
+async fn slash_mapperstats(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
+    let args = MapperStats::from_interaction(command.input_data())?;
+
+    mapper_stats(ctx, (&mut command).into(), args).await
+}
+
+/// Only consider this many mapsets to keep the amount of requests reasonable
+const MAX_MAPSETS: usize = 500;
+
+/// Amount of mapsets per request
+const MAPSETS_PER_REQUEST: usize = 50;
+
+async fn mapper_stats(
+    ctx: Arc<Context>,
+    orig: CommandOrigin<'_>,
+    args: MapperStats<'_>,
+) -> Result<()> {
+    let name = match username!(ctx, orig, args) {
+        Some(name) => name,
+        None => match ctx.psql().get_user_osu(orig.user_id()?).await {
+            Ok(Some(osu)) => osu.into_username(),
+            Ok(None) => return require_link(&ctx, &orig).await,
+            Err(err) => {
+                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("failed to get username"));
+            }
+        },
+    };
+
+    let user_args = UserArgs::new(name.as_str(), GameMode::Osu);
+
+    let user = match get_user(&ctx, &user_args).await {
+        Ok(user) => user,
+        Err(OsuError::NotFound) => {
+            let content = format!("User `{name}` was not found");
+
+            return orig.error(&ctx, content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let report = Report::new(err).wrap_err("failed to get user");
+
+            return Err(report);
+        }
+    };
+
+    let ranked_count = user.ranked_mapset_count.unwrap_or(0) as usize;
+
+    if ranked_count == 0 {
+        let content = format!("`{}` has no ranked mapsets", user.username);
+
+        return orig.error(&ctx, content).await;
+    }
+
+    let mapsets = match ranked_mapsets(&ctx, user.user_id, ranked_count).await {
+        Ok(mapsets) => mapsets,
+        Err(err) => {
+            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let report = Report::new(err).wrap_err("failed to get user mapsets");
+
+            return Err(report);
+        }
+    };
+
+    let counts: Vec<OsuTrackerMapsetEntry> = match ctx.redis().osutracker_stats().await {
+        Ok(stats) => stats
+            .get()
+            .mapset_count
+            .deserialize(&mut Infallible)
+            .unwrap(),
+        Err(err) => {
+            let _ = orig.error(&ctx, OSUTRACKER_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get cached osutracker stats"));
+        }
+    };
+
+    let counts: HashMap<_, _, IntHasher> = counts
+        .into_iter()
+        .map(|entry| (entry.mapset_id, entry.count))
+        .collect();
+
+    let mut entries: Vec<_> = mapsets
+        .into_iter()
+        .map(|mapset| MapperMapsetEntry::new(mapset, &counts))
+        .collect();
+
+    let summary = MapperSummary::new(&entries);
+
+    let snapshot_fut =
+        ctx.psql()
+            .insert_mapper_snapshot(user.user_id, summary.playcount, summary.favourite_count);
+
+    if let Err(err) = snapshot_fut.await {
+        warn!("{:?}", err.wrap_err("Failed to store mapper snapshot"));
+    }
+
+    let graph = match ctx.psql().get_mapper_history(user.user_id).await {
+        Ok(history) => match popularity_graph(&history) {
+            Ok(graph) => graph,
+            Err(err) => {
+                warn!("{:?}", err.wrap_err("Failed to create popularity graph"));
+
+                None
+            }
+        },
+        Err(err) => {
+            warn!("{:?}", err.wrap_err("Failed to get mapper history"));
+
+            None
+        }
+    };
+
+    match args.sort.unwrap_or(MapperStatsOrder::Playcount) {
+        MapperStatsOrder::Favourites => {
+            entries.sort_unstable_by_key(|entry| Reverse(entry.favourite_count))
+        }
+        MapperStatsOrder::PassRate => entries.sort_unstable_by(|a, b| {
+            b.pass_rate()
+                .partial_cmp(&a.pass_rate())
+                .unwrap_or(Ordering::Equal)
+        }),
+        MapperStatsOrder::Playcount => {
+            entries.sort_unstable_by_key(|entry| Reverse(entry.playcount))
+        }
+        MapperStatsOrder::RankedDate => {
+            entries.sort_unstable_by_key(|entry| Reverse(entry.ranked_date))
+        }
+        MapperStatsOrder::TopPlays => {
+            entries.sort_unstable_by_key(|entry| Reverse(entry.top_plays))
+        }
+    }
+
+    let records = HashMap::with_hasher(IntHasher);
+
+    let mut builder =
+        MapperStatsPagination::builder(user, summary, entries, records, graph.is_some());
+
+    if let Some(bytes) = graph {
+        builder = builder.attachment("popularity_graph.png", bytes);
+    }
+
+    builder
+        .start_by_update()
+        .defer_components()
+        .start(ctx, orig)
+        .await
+}
+
+/// Ranked mapsets of a mapper, at most [`MAX_MAPSETS`] many
+pub(crate) async fn ranked_mapsets(
+    ctx: &Context,
+    user_id: u32,
+    ranked_count: usize,
+) -> OsuResult<Vec<Beatmapset>> {
+    let mut mapsets = Vec::with_capacity(ranked_count.min(MAX_MAPSETS));
+
+    for offset in (0..ranked_count.min(MAX_MAPSETS)).step_by(MAPSETS_PER_REQUEST) {
+        let chunk = ctx
+            .osu()
+            .user_beatmapsets(user_id)
+            .ranked()
+            .limit(MAPSETS_PER_REQUEST)
+            .offset(offset)
+            .observe(&ctx.stats, "user_mapsets")
+            .await?;
+
+        let last_chunk = chunk.len() < MAPSETS_PER_REQUEST;
+        mapsets.extend(chunk);
+
+        if last_chunk {
+            break;
+        }
+    }
+
+    Ok(mapsets)
+}
+
+/// Fetch the ranked mapsets of a mapper and store their current popularity
+pub(crate) async fn store_mapper_snapshot(ctx: &Context, user_id: u32) -> Result<()> {
+    let mapsets = ranked_mapsets(ctx, user_id, MAX_MAPSETS)
+        .await
+        .wrap_err("failed to get user mapsets")?;
+
+    let counts = HashMap::default();
+
+    let entries: Vec<_> = mapsets
+        .into_iter()
+        .map(|mapset| MapperMapsetEntry::new(mapset, &counts))
+        .collect();
+
+    let summary = MapperSummary::new(&entries);
+
+    ctx.psql()
+        .insert_mapper_snapshot(user_id, summary.playcount, summary.favourite_count)
+        .await
+}
+
+pub struct MapperMapsetEntry {
+    pub mapset_id: u32,
+    pub name: String,
+    pub ranked_date: OffsetDateTime,
+    pub playcount: u32,
+    pub passcount: u32,
+    pub favourite_count: u32,
+    /// Amount of top plays on the mapset according to osutracker
+    pub top_plays: usize,
+    pub map_count: usize,
+    /// Most played difficulty of the mapset
+    pub top_map: Option<MapperTopMap>,
+}
+
+impl MapperMapsetEntry {
+    fn new(mapset: Beatmapset, counts: &HashMap<u32, usize, IntHasher>) -> Self {
+        let maps = mapset.maps.unwrap_or_default();
+
+        // The mapset's playcount is not necessarily the sum of its difficulties'
+        // playcounts so both are gathered from the difficulties to get a proper pass rate
+        let playcount = maps.iter().map(|map| map.playcount).sum();
+        let passcount = maps.iter().map(|map| map.passcount).sum();
+
+        let top_map = maps
+            .iter()
+            .max_by_key(|map| map.playcount)
+            .map(|map| MapperTopMap {
+                map_id: map.map_id,
+                mode: map.mode,
+                version: map.version.clone(),
+            });
+
+        Self {
+            mapset_id: mapset.mapset_id,
+            name: format!("{} - {}", mapset.artist, mapset.title),
+            ranked_date: mapset.ranked_date.unwrap_or_else(OffsetDateTime::now_utc),
+            playcount,
+            passcount,
+            favourite_count: mapset.favourite_count,
+            top_plays: counts.get(&mapset.mapset_id).copied().unwrap_or(0),
+            map_count: maps.len(),
+            top_map,
+        }
+    }
+
+    pub fn pass_rate(&self) -> f32 {
+        if self.playcount == 0 {
+            0.0
+        } else {
+            100.0 * self.passcount as f32 / self.playcount as f32
+        }
+    }
+}
+
+pub struct MapperTopMap {
+    pub map_id: u32,
+    pub mode: GameMode,
+    pub version: String,
+}
+
+pub struct MapperSummary {
+    pub mapsets: usize,
+    pub maps: usize,
+    pub playcount: u64,
+    pub passcount: u64,
+    pub favourite_count: u64,
+    pub top_plays: usize,
+    /// Amount of mapsets that appear in osutracker's top plays data
+    pub popular_mapsets: usize,
+}
+
+impl MapperSummary {
+    fn new(entries: &[MapperMapsetEntry]) -> Self {
+        let mut summary = Self {
+            mapsets: entries.len(),
+            maps: 0,
+            playcount: 0,
+            passcount: 0,
+            favourite_count: 0,
+            top_plays: 0,
+            popular_mapsets: 0,
+        };
+
+        for entry in entries {
+            summary.maps += entry.map_count;
+            summary.playcount += entry.playcount as u64;
+            summary.passcount += entry.passcount as u64;
+            summary.favourite_count += entry.favourite_count as u64;
+            summary.top_plays += entry.top_plays;
+            summary.popular_mapsets += (entry.top_plays > 0) as usize;
+        }
+
+        summary
+    }
+
+    pub fn pass_rate(&self) -> f32 {
+        if self.playcount == 0 {
+            0.0
+        } else {
+            100.0 * self.passcount as f32 / self.playcount as f32
+        }
+    }
+}
+
+/// Highest pp score on a map's global leaderboard
+pub struct MapperPpRecord {
+    pub user_id: u32,
+    pub username: Username,
+    pub pp: f32,
+    pub version: String,
+}
+
+/// Total playcount and favourites of the mapper's ranked mapsets over time.
+///
+/// Requires snapshots of at least two different days.
+fn popularity_graph(history: &[MapperSnapshot]) -> Result<Option<Vec<u8>>> {
+    let (first, last) = match (history.first(), history.last()) {
+        (Some(first), Some(last)) if first.timestamp.date() < last.timestamp.date() => {
+            (first.timestamp.date(), last.timestamp.date())
+        }
+        _ => return Ok(None),
+    };
+
+    let playcounts: Vec<(Date, u64)> = history
+        .iter()
+        .map(|snapshot| (snapshot.timestamp.date(), snapshot.playcount))
+        .collect();
+
+    let favourites: Vec<(Date, u64)> = history
+        .iter()
+        .map(|snapshot| (snapshot.timestamp.date(), snapshot.favourite_count))
+        .collect();
+
+    let (min_playcount, max_playcount) = min_max(playcounts.iter().map(|(_, count)| *count));
+    let (min_favourites, max_favourites) = min_max(favourites.iter().map(|(_, count)| *count));
+
+    const W: u32 = 1350;
+    const H: u32 = 500;
+    const LEN: usize = (W * H) as usize;
+
+    let mut buf = vec![0; LEN * 3];
+
+    {
+        let root = BitMapBackend::with_buffer(&mut buf, (W, H)).into_drawing_area();
+        let background = RGBColor(19, 43, 33);
+        root.fill(&background)
+            .wrap_err("failed to fill background")?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .margin(9_i32)
+            .x_label_area_size(20_i32)
+            .y_label_area_size(75_i32)
+            .right_y_label_area_size(60_i32)
+            .build_cartesian_2d(RangedDate::from(first..last), min_playcount..max_playcount)
+            .wrap_err("failed to build chart")?
+            .set_secondary_coord(
+                RangedDate::from(first..last),
+                min_favourites..max_favourites,
+            );
+
+        chart
+            .configure_mesh()
+            .light_line_style(&BLACK.mix(0.0))
+            .disable_x_mesh()
+            .x_labels(10)
+            .x_label_formatter(&|d| d.to_string())
+            .y_desc("Playcount")
+            .label_style(("sans-serif", 20_i32, &WHITE))
+            .bold_line_style(&WHITE.mix(0.3))
+            .axis_style(RGBColor(7, 18, 14))
+            .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("failed to draw primary mesh")?;
+
+        chart
+            .configure_secondary_axes()
+            .y_desc("Favourites")
+            .label_style(("sans-serif", 20_i32, &WHITE))
+            .axis_style(RGBColor(7, 18, 14))
+            .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("failed to draw secondary mesh")?;
+
+        let area_color = RGBColor(0, 116, 193);
+        let border_color = RGBColor(102, 174, 222);
+        let series = AreaSeries::new(playcounts, min_playcount, area_color.mix(0.5).filled());
+
+        chart
+            .draw_series(series.border_style(border_color.stroke_width(1)))
+            .wrap_err("failed to draw playcount area")?
+            .label("Playcount")
+            .legend(move |(x, y)| {
+                PathElement::new(vec![(x, y), (x + 20, y)], border_color.stroke_width(2))
+            });
+
+        let line_color = RGBColor(246, 111, 162);
+
+        chart
+            .draw_secondary_series(LineSeries::new(favourites, line_color.stroke_width(2)))
+            .wrap_err("failed to draw favourites line")?
+            .label("Favourites")
+            .legend(move |(x, y)| {
+                PathElement::new(vec![(x, y), (x + 20, y)], line_color.stroke_width(2))
+            });
+
+        chart
+            .configure_series_labels()
+            .background_style(&RGBColor(7, 23, 17))
+            .position(SeriesLabelPosition::UpperLeft)
+            .legend_area_size(45_i32)
+            .label_font(("sans-serif", 20_i32, &WHITE))
+            .draw()
+            .wrap_err("failed to draw legend")?;
+    }
+
+    // Encode buf to png
+    let mut png_bytes: Vec<u8> = Vec::with_capacity(LEN);
+    let png_encoder = PngEncoder::new(&mut png_bytes);
+
+    png_encoder
+        .write_image(&buf, W, H, ColorType::Rgb8)
+        .wrap_err("failed to encode image")?;
+
+    Ok(Some(png_bytes))
+}
+
+/// Minimum and maximum of the values with some padding so that the graph is never flat
+fn min_max(values: impl Iterator<Item = u64>) -> (u64, u64) {
+    let (min, max) = values.fold((u64::MAX, 0), |(min, max), value| {
+        (min.min(value), max.max(value))
+    });
+
+    let padding = ((max - min.min(max)) / 10).max(1);
+
+    (min.saturating_sub(padding), max + padding)
+}
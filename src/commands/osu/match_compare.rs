@@ -12,7 +12,7 @@ use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand,
 use twilight_model::channel::embed::Embed;
 
 use crate::{
-    core::Context,
+    core::{Context, OsuFutureExt},
     embeds::{EmbedData, MatchCompareMapEmbed, MatchCompareSummaryEmbed},
     pagination::MatchComparePagination,
     util::{
@@ -115,16 +115,18 @@ async fn matchcompare(
         return Ok(());
     }
 
-    let match_fut_1 = ctx.osu().osu_match(match_id_1);
-    let match_fut_2 = ctx.osu().osu_match(match_id_2);
+    let match_fut_1 = ctx.osu().osu_match(match_id_1).observe(&ctx.stats, "match");
+    let match_fut_2 = ctx.osu().osu_match(match_id_2).observe(&ctx.stats, "match");
 
     let output = output.unwrap_or_default();
     let comparison = comparison.unwrap_or_default();
 
     let embeds = match tokio::try_join!(match_fut_1, match_fut_2) {
         Ok((mut match_1, mut match_2)) => {
-            let previous_fut_1 = retrieve_previous(&mut match_1, ctx.osu());
-            let previous_fut_2 = retrieve_previous(&mut match_2, ctx.osu());
+            let previous_fut_1 =
+                retrieve_previous(&mut match_1, ctx.osu()).observe(&ctx.stats, "match");
+            let previous_fut_2 =
+                retrieve_previous(&mut match_2, ctx.osu()).observe(&ctx.stats, "match");
 
             if let Err(err) = tokio::try_join!(previous_fut_1, previous_fut_2) {
                 let _ = command.error(&ctx, OSU_API_ISSUE).await;
@@ -11,7 +11,10 @@ use rosu_v2::prelude::{
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        OsuFutureExt,
+    },
     embeds::{EmbedData, MatchCostEmbed},
     util::{
         builder::MessageBuilder, constants::OSU_API_ISSUE, hasher::IntHasher,
@@ -143,9 +146,16 @@ async fn matchcosts(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: MatchCost<
     let skip_last = skip_last.unwrap_or(0);
 
     // Retrieve the match
-    let (mut osu_match, games) = match ctx.osu().osu_match(match_id).await {
+    let (mut osu_match, games) = match ctx
+        .osu()
+        .osu_match(match_id)
+        .observe(&ctx.stats, "match")
+        .await
+    {
         Ok(mut osu_match) => {
-            retrieve_previous(&mut osu_match, ctx.osu()).await?;
+            retrieve_previous(&mut osu_match, ctx.osu())
+                .observe(&ctx.stats, "match")
+                .await?;
             let games_iter = osu_match.drain_games().skip(warmups);
 
             let mut games: Vec<_> = if ez_mult != 1.0 {
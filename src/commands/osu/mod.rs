@@ -111,7 +111,7 @@ use twilight_interactions::command::{CommandOption, CreateOption};
 use twilight_model::id::{marker::UserMarker, Id};
 
 use crate::{
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, OsuFutureExt},
     custom_client::OsuStatsParams,
     pp::PpCalculator,
     util::{
@@ -125,10 +125,11 @@ use crate::{
 
 pub use self::{
     attributes::*, avatar::*, badges::*, bws::*, cards::*, claim_name::*, compare::*,
-    country_top::*, fix::*, graphs::*, leaderboard::*, map::*, map_search::*, mapper::*,
-    match_compare::*, match_costs::*, medals::*, most_played::*, nochoke::*, osekai::*,
-    osustats::*, pinned::*, popular::*, pp::*, profile::*, rank::*, ranking::*, ratios::*,
-    recent::*, serverleaderboard::*, simulate::*, snipe::*, top::*, whatif::*,
+    competition::*, country_top::*, fix::*, graphs::*, leaderboard::*, map::*, map_search::*,
+    mapper::*, mapper_stats::*, match_compare::*, match_costs::*, medals::*, most_played::*,
+    name_history::*, nochoke::*, osekai::*, osustats::*, pinned::*, popular::*, pp::*, profile::*,
+    progress::*, rank::*, ranking::*, ratios::*, recent::*, serverleaderboard::*, session::*,
+    simulate::*, snipe::*, top::*, whatif::*, wrapped::*,
 };
 
 #[cfg(feature = "matchlive")]
@@ -144,6 +145,7 @@ mod bws;
 mod cards;
 mod claim_name;
 mod compare;
+mod competition;
 mod country_top;
 mod fix;
 mod graphs;
@@ -152,11 +154,13 @@ mod link;
 mod map;
 mod map_search;
 mod mapper;
+mod mapper_stats;
 mod match_compare;
 mod match_costs;
 mod match_live;
 mod medals;
 mod most_played;
+mod name_history;
 mod nochoke;
 mod osekai;
 mod osustats;
@@ -164,15 +168,18 @@ mod pinned;
 mod popular;
 mod pp;
 mod profile;
+mod progress;
 mod rank;
 mod ranking;
 mod ratios;
 mod recent;
 mod serverleaderboard;
+mod session;
 mod simulate;
 mod snipe;
 mod top;
 mod whatif;
+mod wrapped;
 
 pub trait HasMods {
     fn mods(&self) -> ModsResult;
@@ -311,6 +318,8 @@ async fn get_scores<'c>(
 
     let scores_fut = (scores.fun)(fut);
 
+    let scores_fut = ctx.stats.observe_osu("user_scores", scores_fut);
+
     let result = if scores.with_combo {
         prepare_scores(ctx, scores_fut).await
     } else {
@@ -404,7 +413,12 @@ pub async fn prepare_score(ctx: &Context, score: &mut Score) -> OsuResult<()> {
         if let Ok(Some(combo)) = ctx.psql().get_beatmap_combo(map.map_id).await {
             map.max_combo = Some(combo);
         } else {
-            let beatmap = ctx.osu().beatmap().map_id(map.map_id).await?;
+            let beatmap = ctx
+                .osu()
+                .beatmap()
+                .map_id(map.map_id)
+                .observe(&ctx.stats, "beatmap")
+                .await?;
 
             if let Err(err) = ctx.psql().insert_beatmap(&beatmap).await {
                 warn!("{:?}", err.wrap_err("failed to insert map in database"));
@@ -475,7 +489,12 @@ where
         for chunk in map_ids.chunks(50) {
             let ids = chunk.iter().copied();
 
-            for map in ctx.osu().beatmaps(ids).await? {
+            for map in ctx
+                .osu()
+                .beatmaps(ids)
+                .observe(&ctx.stats, "beatmaps")
+                .await?
+            {
                 if let Some(combo) = map.max_combo {
                     let map_opt = scores
                         .iter_mut()
@@ -731,7 +750,12 @@ impl ScoreOrder {
                         Err(err) => {
                             warn!("{:?}", err.wrap_err("Failed to get mapset from database"));
 
-                            match ctx.osu().beatmapset(mapset_id).await {
+                            match ctx
+                                .osu()
+                                .beatmapset(mapset_id)
+                                .observe(&ctx.stats, "beatmapset")
+                                .await
+                            {
                                 Ok(mapset) => {
                                     new_mapsets.insert(mapset_id, mapset);
                                 }
@@ -7,7 +7,7 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{marker::UserMarker, Id};
 
 use crate::{
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, OsuFutureExt},
     pagination::MostPlayedPagination,
     util::{
         constants::{GENERAL_ISSUE, OSU_API_ISSUE},
@@ -93,6 +93,7 @@ async fn mostplayed(
                 .osu()
                 .user_most_played(user_args.name)
                 .limit(100)
+                .observe(&ctx.stats, "most_played")
                 .await
                 .map(|maps| (user, maps)),
             Err(OsuError::NotFound) => {
@@ -100,7 +101,11 @@ async fn mostplayed(
                 let redis = ctx.redis();
 
                 let user_fut = redis.osu_user(&user_args);
-                let maps_fut = ctx.osu().user_most_played(user_args.name).limit(100);
+                let maps_fut = ctx
+                    .osu()
+                    .user_most_played(user_args.name)
+                    .limit(100)
+                    .observe(&ctx.stats, "most_played");
 
                 tokio::try_join!(user_fut, maps_fut)
             }
@@ -108,7 +113,11 @@ async fn mostplayed(
         }
     } else {
         let redis = ctx.redis();
-        let maps_fut = ctx.osu().user_most_played(user_args.name).limit(100);
+        let maps_fut = ctx
+            .osu()
+            .user_most_played(user_args.name)
+            .limit(100)
+            .observe(&ctx.stats, "most_played");
 
         tokio::try_join!(redis.osu_user(&user_args), maps_fut)
     };
@@ -0,0 +1,101 @@
+use std::{borrow::Cow, sync::Arc};
+
+use command_macros::{HasName, SlashCommand};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameMode, OsuError};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{
+    commands::osu::UserArgs,
+    core::commands::CommandOrigin,
+    embeds::{EmbedData, NameHistoryEmbed},
+    util::{
+        builder::MessageBuilder,
+        constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+        interaction::InteractionCommand,
+        InteractionCommandExt,
+    },
+    Context,
+};
+
+use super::{get_user, require_link};
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "namehistory",
+    help = "Show all usernames and countries of a user that have been observed so far.\n\
+    Changes are only observed whenever the user is requested through some command \
+    so the dates might be later than the actual change.\n\
+    Additionally, the previous usernames known to osu! are shown."
+)]
+/// Show a user's previous usernames and countries
+pub struct NameHistory<'a> {
+    /// Specify a username
+    name: Option<Cow<'a, str>>,
+    #[command(
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    /// Specify a linked discord user
+    discord: Option<Id<UserMarker>>,
+}
+
+async fn slash_namehistory(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
+    let args = NameHistory::from_interaction(command.input_data())?;
+
+    name_history(ctx, (&mut command).into(), args).await
+}
+
+async fn name_history(
+    ctx: Arc<Context>,
+    orig: CommandOrigin<'_>,
+    args: NameHistory<'_>,
+) -> Result<()> {
+    let name = match username!(ctx, orig, args) {
+        Some(name) => name,
+        None => match ctx.psql().get_user_osu(orig.user_id()?).await {
+            Ok(Some(osu)) => osu.into_username(),
+            Ok(None) => return require_link(&ctx, &orig).await,
+            Err(err) => {
+                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("failed to get username"));
+            }
+        },
+    };
+
+    // Requesting the user also stores its current name and country
+    let user_args = UserArgs::new(name.as_str(), GameMode::Osu);
+
+    let user = match get_user(&ctx, &user_args).await {
+        Ok(user) => user,
+        Err(OsuError::NotFound) => {
+            let content = format!("User `{name}` was not found");
+
+            return orig.error(&ctx, content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let report = Report::new(err).wrap_err("failed to get user");
+
+            return Err(report);
+        }
+    };
+
+    let history = match ctx.psql().get_osu_user_history(user.user_id).await {
+        Ok(history) => history,
+        Err(err) => {
+            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get name and country history"));
+        }
+    };
+
+    let embed = NameHistoryEmbed::new(&user, &history).build();
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(&ctx, &builder).await?;
+
+    Ok(())
+}
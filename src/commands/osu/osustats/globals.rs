@@ -2,6 +2,7 @@ use std::{borrow::Cow, collections::BTreeMap, fmt::Write, sync::Arc};
 
 use command_macros::command;
 use eyre::{Report, Result};
+use futures::stream::{self, StreamExt, TryStreamExt};
 use rosu_v2::prelude::{GameMode, OsuError, Username};
 
 use crate::{
@@ -16,6 +17,7 @@ use crate::{
         constants::{OSUSTATS_API_ISSUE, OSU_API_ISSUE},
         matcher,
         osu::ModSelection,
+        query::{FilterCriteria, Searchable},
         ChannelExt, CowUtils,
     },
     Context,
@@ -170,7 +172,7 @@ async fn prefix_osustatsglobalsctb(ctx: Arc<Context>, msg: &Message, args: Args<
 pub(super) async fn scores(
     ctx: Arc<Context>,
     orig: CommandOrigin<'_>,
-    args: OsuStatsScores<'_>,
+    mut args: OsuStatsScores<'_>,
 ) -> Result<()> {
     let mods = match args.mods() {
         ModsResult::Mods(mods) => Some(mods),
@@ -200,10 +202,11 @@ pub(super) async fn scores(
     // Overwrite default mode
     user.mode = mode;
 
-    let params = args.into_params(user.username.as_str().into(), mode, mods);
+    let query = args.query.take();
+    let mut params = args.into_params(user.username.as_str().into(), mode, mods);
 
     // Retrieve their top global scores
-    let (scores, amount) = match ctx.client().get_global_scores(&params).await {
+    let (mut scores, mut amount) = match ctx.client().get_global_scores(&params).await {
         Ok((scores, amount)) => (
             scores
                 .into_iter()
@@ -218,6 +221,58 @@ pub(super) async fn scores(
         }
     };
 
+    // Filter scores according to query which requires all scores upfront
+    if let Some(query) = query.as_deref() {
+        let last_page = (amount.min(OsuStatsScores::MAX_QUERY_SCORES) + 23) / 24;
+
+        let page_futs = (2..=last_page).map(|page| {
+            let mut params = params.clone();
+            params.page = page;
+            let ctx = &ctx;
+
+            async move {
+                ctx.client()
+                    .get_global_scores(&params)
+                    .await
+                    .map(|(scores, _)| (page, scores))
+            }
+        });
+
+        let pages_result: Result<Vec<_>> = stream::iter(page_futs)
+            .buffer_unordered(OsuStatsScores::CONCURRENT_PAGES)
+            .try_collect()
+            .await;
+
+        let pages = match pages_result {
+            Ok(pages) => pages,
+            Err(err) => {
+                let _ = orig.error(&ctx, OSUSTATS_API_ISSUE).await;
+
+                return Err(err.wrap_err("failed to get global scores"));
+            }
+        };
+
+        for (page, page_scores) in pages {
+            let iter = page_scores
+                .into_iter()
+                .enumerate()
+                .map(|(i, score)| ((page - 1) * 24 + i, score));
+
+            scores.extend(iter);
+        }
+
+        params.page = 1;
+        let criteria = FilterCriteria::new(query);
+
+        scores = scores
+            .into_values()
+            .filter(|score| score.matches(&criteria))
+            .enumerate()
+            .collect();
+
+        amount = scores.len();
+    }
+
     let mut content = format!(
         "`Rank: {rank_min} - {rank_max}` ~ \
         `Acc: {acc_min}% - {acc_max}%` ~ \
@@ -242,6 +297,10 @@ pub(super) async fn scores(
         );
     }
 
+    if let Some(query) = query.as_deref() {
+        let _ = write!(content, " ~ `Query: {query}`");
+    }
+
     OsuStatsGlobalsPagination::builder(user, scores, amount, params)
         .content(content)
         .start_by_update()
@@ -254,6 +313,12 @@ impl<'m> OsuStatsScores<'m> {
     const MIN_RANK: u32 = 1;
     const MAX_RANK: u32 = 100;
 
+    /// Only this many scores are considered when filtering by query
+    const MAX_QUERY_SCORES: usize = 480;
+
+    /// Amount of pages that are requested at the same time when filtering by query
+    const CONCURRENT_PAGES: usize = 4;
+
     const ERR_PARSE_ACC: &'static str = "Failed to parse `accuracy`.\n\
         Must be either decimal number \
         or two decimal numbers of the form `a..b` e.g. `97.5..98.5`.";
@@ -418,6 +483,7 @@ impl<'m> OsuStatsScores<'m> {
             max_rank,
             min_acc,
             max_acc,
+            query: None,
             reverse,
             discord,
         })
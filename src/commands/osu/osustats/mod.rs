@@ -99,6 +99,17 @@ pub struct OsuStatsScores<'a> {
     #[command(min_value = 0.0, max_value = 100.0)]
     /// Specify a max accuracy
     max_acc: Option<f32>,
+    #[command(
+        help = "Filter out scores similarly as you filter maps in osu! itself.\n\
+        You can specify the artist, creator, difficulty, title, or limit values such as \
+        ar, cs, hp, od, bpm, length, stars, acc, pp, combo, miss, grade, mods, date, or rank \
+        like for example `fdfd ar>10 pp>300 date>=2022-01`.\n\
+        Use `|` between words for alternatives, a leading `-` to exclude a word, \
+        and quotes for phrases e.g. `\"big black\" | -remix`.\n\
+        Note that only the first 480 scores are considered when a query is specified."
+    )]
+    /// Specify a search query containing artist, difficulty, AR, BPM, ...
+    query: Option<String>,
     /// Reverse the resulting score list
     reverse: Option<bool>,
     #[command(
@@ -14,8 +14,8 @@ use twilight_model::id::{marker::UserMarker, Id};
 
 use crate::{
     commands::{osu::UserArgs, GameModeOption},
-    core::commands::CommandOrigin,
-    database::{EmbedsSize, ListSize, MinimizedPp},
+    core::{commands::CommandOrigin, OsuFutureExt},
+    database::{EmbedsSize, ListSize, MinimizedPp, ScoreKind},
     embeds::TopSingleEmbed,
     pagination::{TopCondensedPagination, TopPagination, TopSinglePagination},
     util::{
@@ -45,8 +45,12 @@ pub struct Pinned {
     #[command(
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
-    ar, cs, hp, od, bpm, length, or stars like for example `fdfd ar>10 od>=9`.\n\
-    While ar & co will be adjusted to mods, stars will not."
+        ar, cs, hp, od, bpm, length, or stars like for example `fdfd ar>10 od>=9`.\n\
+        Scores can also be filtered by acc, pp, combo, miss, grade, mods, date, or rank \
+        e.g. `pp>500 mods>=hd date>=2022-01 grade=s`.\n\
+        Use `|` between words for alternatives, a leading `-` to exclude a word, \
+        and quotes for phrases e.g. `\"big black\" | -remix`.\n\
+        While ar & co will be adjusted to mods, stars will not."
     )]
     /// Specify a search query containing artist, difficulty, AR, BPM, ...
     query: Option<String>,
@@ -128,7 +132,8 @@ async fn pinned(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Pinned) -> Res
                     .user_scores(user_args.name)
                     .pinned()
                     .mode(mode)
-                    .limit(100);
+                    .limit(100)
+                    .observe(&ctx.stats, "user_scores");
 
                 prepare_scores(&ctx, scores_fut)
                     .await
@@ -145,7 +150,8 @@ async fn pinned(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Pinned) -> Res
                     .user_scores(user_args.name)
                     .pinned()
                     .mode(mode)
-                    .limit(100);
+                    .limit(100)
+                    .observe(&ctx.stats, "user_scores");
 
                 tokio::try_join!(user_fut, prepare_scores(&ctx, scores_fut))
             }
@@ -160,7 +166,8 @@ async fn pinned(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Pinned) -> Res
             .user_scores(user_args.name)
             .pinned()
             .mode(mode)
-            .limit(100);
+            .limit(100)
+            .observe(&ctx.stats, "user_scores");
 
         tokio::try_join!(user_fut, prepare_scores(&ctx, scores_fut))
     };
@@ -199,8 +206,20 @@ async fn pinned(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Pinned) -> Res
             (None, None) => MinimizedPp::default(),
         };
 
+        let score_kind = config.score_kind();
         let content = write_content(&name, &args, 1, mods);
-        single_embed(ctx, orig, user, score, embeds_size, minimized_pp, content).await?;
+
+        single_embed(
+            ctx,
+            orig,
+            user,
+            score,
+            embeds_size,
+            minimized_pp,
+            score_kind,
+            content,
+        )
+        .await?;
     } else {
         let list_size = match args.size {
             Some(size) => size,
@@ -240,7 +259,9 @@ async fn pinned(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Pinned) -> Res
                     (None, None) => MinimizedPp::default(),
                 };
 
-                TopSinglePagination::builder(user, scores, minimized_pp)
+                let score_kind = config.score_kind();
+
+                TopSinglePagination::builder(user, scores, minimized_pp, score_kind)
                     .content(content.unwrap_or_default())
                     .start_by_update()
                     .defer_components()
@@ -287,6 +308,7 @@ async fn filter_scores(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn single_embed(
     ctx: Arc<Context>,
     orig: CommandOrigin<'_>,
@@ -294,6 +316,7 @@ async fn single_embed(
     score: &Score,
     embeds_size: EmbedsSize,
     minimized_pp: MinimizedPp,
+    score_kind: ScoreKind,
     content: Option<String>,
 ) -> Result<()> {
     let map = score.map.as_ref().unwrap();
@@ -305,9 +328,15 @@ async fn single_embed(
                 .osu()
                 .user_scores(user.user_id)
                 .mode(score.mode)
-                .limit(100);
+                .limit(100)
+                .observe(&ctx.stats, "user_scores");
+
+            // TODO: Add .limit(50) when supported by osu!api
+            let global_fut = ctx
+                .osu()
+                .beatmap_scores(map.map_id)
+                .observe(&ctx.stats, "beatmap_scores");
 
-            let global_fut = ctx.osu().beatmap_scores(map.map_id); // TODO: Add .limit(50) when supported by osu!api
             let (best_result, global_result) = tokio::join!(best_fut, global_fut);
 
             let personal_idx = match best_result {
@@ -335,8 +364,17 @@ async fn single_embed(
         _ => (None, None),
     };
 
-    let embed_data =
-        TopSingleEmbed::new(&user, score, personal_idx, global_idx, minimized_pp, &ctx).await?;
+    let embed_fut = TopSingleEmbed::new(
+        &user,
+        score,
+        personal_idx,
+        global_idx,
+        minimized_pp,
+        score_kind,
+        &ctx,
+    );
+
+    let embed_data = embed_fut.await?;
 
     // Only maximize if config allows it
     match embeds_size {
@@ -7,7 +7,7 @@ use rosu_v2::prelude::{Beatmapset, Username};
 use time::OffsetDateTime;
 
 use crate::{
-    core::Context,
+    core::{Context, OsuFutureExt},
     custom_client::OsuTrackerMapsetEntry,
     pagination::OsuTrackerMapsetsPagination,
     util::{
@@ -41,7 +41,12 @@ pub(super) async fn mapsets(ctx: Arc<Context>, mut command: InteractionCommand)
 
         let mapset = match ctx.psql().get_beatmapset::<Beatmapset>(mapset_id).await {
             Ok(mapset) => mapset,
-            Err(_) => match ctx.osu().beatmapset(mapset_id).await {
+            Err(_) => match ctx
+                .osu()
+                .beatmapset(mapset_id)
+                .observe(&ctx.stats, "beatmapset")
+                .await
+            {
                 Ok(mapset) => {
                     if let Err(err) = ctx.psql().insert_beatmapset(&mapset).await {
                         warn!("{:?}", err.wrap_err("Failed to insert mapset in database"));
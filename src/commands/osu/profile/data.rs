@@ -10,6 +10,7 @@ use twilight_model::id::{marker::UserMarker, Id};
 
 use crate::{
     commands::osu::MinMaxAvg,
+    database::OsuUserHistory,
     util::{hasher::IntHasher, osu::BonusPP},
 };
 
@@ -22,10 +23,16 @@ pub struct ProfileData {
     pub discord_id: Option<Id<UserMarker>>,
     pub profile_result: Option<ProfileResult>,
     pub globals_count: Option<BTreeMap<usize, Cow<'static, str>>>,
+    pub history: OsuUserHistory,
 }
 
 impl ProfileData {
-    pub(super) fn new(user: User, scores: Vec<Score>, discord_id: Option<Id<UserMarker>>) -> Self {
+    pub(super) fn new(
+        user: User,
+        scores: Vec<Score>,
+        discord_id: Option<Id<UserMarker>>,
+        history: OsuUserHistory,
+    ) -> Self {
         Self {
             user,
             scores,
@@ -33,6 +40,7 @@ impl ProfileData {
             discord_id,
             profile_result: None,
             globals_count: None,
+            history,
         }
     }
 
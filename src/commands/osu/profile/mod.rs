@@ -9,7 +9,10 @@ use twilight_model::id::{marker::UserMarker, Id};
 
 use crate::{
     commands::GameModeOption,
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        OsuFutureExt,
+    },
     embeds::ProfileEmbed,
     pagination::ProfilePagination,
     util::{
@@ -28,7 +31,7 @@ pub use self::{
     size::ProfileEmbedMap,
 };
 
-use super::{get_user_and_scores, require_link, ScoreArgs, UserArgs};
+use super::{cards::store_skills, get_user_and_scores, require_link, ScoreArgs, UserArgs};
 
 mod data;
 mod graph;
@@ -286,13 +289,16 @@ async fn profile(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Profile<'_>)
 
     // Try to get the user discord id that is linked to the osu!user
     let discord_id_fut = ctx.psql().get_discord_from_osu_id(user.user_id);
+    let history_fut = ctx.psql().get_osu_user_history(user.user_id);
+
+    let (discord_id_res, history_res, _) = tokio::join!(discord_id_fut, history_fut, tracking_fut);
 
-    let discord_id = match tokio::join!(discord_id_fut, tracking_fut) {
-        (Ok(user), _) => guild
+    let discord_id = match discord_id_res {
+        Ok(user) => guild
             .zip(user)
             .filter(|&(guild, user)| ctx.cache.member(guild, user, |_| ()).is_ok())
             .map(|(_, user)| user),
-        (Err(err), _) => {
+        Err(err) => {
             warn!(
                 "{:?}",
                 err.wrap_err("Failed to get discord id from osu user id")
@@ -302,7 +308,26 @@ async fn profile(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Profile<'_>)
         }
     };
 
-    let mut profile_data = ProfileData::new(user, scores, discord_id);
+    let history = match history_res {
+        Ok(history) => history,
+        Err(err) => {
+            warn!(
+                "{:?}",
+                err.wrap_err("Failed to get name and country history")
+            );
+
+            Default::default()
+        }
+    };
+
+    // Store the skill values for `/graph skills` in the background
+    // unless they were already stored today
+    if !scores.is_empty() {
+        let skills_fut = store_skills(Arc::clone(&ctx), user.user_id, mode, scores.clone());
+        tokio::spawn(skills_fut);
+    }
+
+    let mut profile_data = ProfileData::new(user, scores, discord_id, history);
 
     // Draw the graph
     let params = ProfileGraphParams::new(&ctx, &mut profile_data.user);
@@ -428,7 +453,13 @@ impl ProfileEmbed {
                                     continue;
                                 }
 
-                                let user_ = match ctx.osu().user(*id).mode(mode).await {
+                                let user_ = match ctx
+                                    .osu()
+                                    .user(*id)
+                                    .mode(mode)
+                                    .observe(&ctx.stats, "user")
+                                    .await
+                                {
                                     Ok(user) => user,
                                     Err(err) => {
                                         let report =
@@ -466,6 +497,7 @@ impl ProfileEmbed {
                             own_top_scores,
                             profile_data.discord_id,
                             &mapper_names,
+                            &profile_data.history,
                         )
                     }
                 };
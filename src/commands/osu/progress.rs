@@ -0,0 +1,474 @@
+use std::{borrow::Cow, sync::Arc};
+
+use command_macros::{command, HasName, SlashCommand};
+use eyre::{Report, Result, WrapErr};
+use image::{codecs::png::PngEncoder, ColorType, ImageEncoder};
+use plotters::{
+    prelude::{BitMapBackend, ChartBuilder, IntoDrawingArea, LineSeries, PathElement},
+    style::{Color, RGBColor, WHITE},
+};
+use plotters_backend::FontStyle;
+use rosu_v2::prelude::OsuError;
+use time::{Duration, OffsetDateTime};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{
+    commands::GameModeOption,
+    core::commands::{prefix::Args, CommandOrigin},
+    database::UserStatsSnapshot,
+    embeds::{EmbedData, ProgressEmbed},
+    util::{
+        builder::MessageBuilder,
+        constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+        datetime::{DATE_FORMAT, TIME_FORMAT},
+        interaction::InteractionCommand,
+        matcher, ChannelExt, InteractionCommandExt,
+    },
+    Context,
+};
+
+use super::{get_user, UserArgs};
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "progress",
+    help = "Display how a user's pp, rank, accuracy, playcount, and ranked score \
+    changed over a period of time.\n\
+    Stats are only stored whenever the user is requested through a command \
+    or whenever they are tracked so the very first and last stored values \
+    of the period will be compared."
+)]
+/// Display how a user's stats changed over a period of time
+pub struct Progress<'a> {
+    /// Specify a gamemode
+    mode: Option<GameModeOption>,
+    /// Specify a username
+    name: Option<Cow<'a, str>>,
+    /// Specify the period, defaults to a week
+    period: Option<ProgressPeriod>,
+    #[command(min_value = 1, max_value = 3650)]
+    /// Specify a custom amount of days, overwrites the period
+    days: Option<u32>,
+    #[command(
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    /// Specify a linked discord user
+    discord: Option<Id<UserMarker>>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum ProgressPeriod {
+    #[option(name = "Day", value = "day")]
+    Day,
+    #[option(name = "Week", value = "week")]
+    Week,
+    #[option(name = "Month", value = "month")]
+    Month,
+    #[option(name = "Year", value = "year")]
+    Year,
+}
+
+impl Default for ProgressPeriod {
+    fn default() -> Self {
+        Self::Week
+    }
+}
+
+impl ProgressPeriod {
+    fn days(self) -> u32 {
+        match self {
+            Self::Day => 1,
+            Self::Week => 7,
+            Self::Month => 30,
+            Self::Year => 365,
+        }
+    }
+}
+
+impl<'m> Progress<'m> {
+    const ERR_PARSE_PERIOD: &'static str = "Failed to parse period.\n\
+        Must be either `day`, `week`, `month`, `year`, or a number of days like `14d`.";
+
+    fn args(mode: Option<GameModeOption>, args: Args<'m>) -> Result<Self, &'static str> {
+        let mut name = None;
+        let mut discord = None;
+        let mut period = None;
+        let mut days = None;
+
+        for arg in args.take(2) {
+            match arg {
+                "day" | "d" | "daily" => period = Some(ProgressPeriod::Day),
+                "week" | "w" | "weekly" => period = Some(ProgressPeriod::Week),
+                "month" | "m" | "monthly" => period = Some(ProgressPeriod::Month),
+                "year" | "y" | "yearly" => period = Some(ProgressPeriod::Year),
+                _ => {
+                    if let Some(n) = arg.strip_suffix('d').filter(|n| !n.is_empty()) {
+                        match n.parse::<u32>() {
+                            Ok(n @ 1..=3650) => days = Some(n),
+                            _ => return Err(Self::ERR_PARSE_PERIOD),
+                        }
+                    } else if let Some(id) = matcher::get_mention_user(arg) {
+                        discord = Some(id);
+                    } else {
+                        name = Some(arg.into());
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            mode,
+            name,
+            period,
+            days,
+            discord,
+        })
+    }
+}
+
+#[command]
+#[desc("Display how a user's stats changed over a period of time")]
+#[help(
+    "Display how a user's pp, rank, accuracy, playcount, and ranked score \
+    changed over a period of time.\n\
+    The period can be `day`, `week` (default), `month`, `year`, \
+    or a custom amount of days like `14d`.\n\
+    Stats are only stored whenever the user is requested through a command \
+    or whenever they are tracked."
+)]
+#[usage("[username] [day/week/month/year/<number>d]")]
+#[examples("badewanne3 month", "peppy 14d")]
+#[alias("gains")]
+#[group(Osu)]
+async fn prefix_progress(ctx: Arc<Context>, msg: &Message, args: Args<'_>) -> Result<()> {
+    match Progress::args(None, args) {
+        Ok(args) => progress(ctx, msg.into(), args).await,
+        Err(content) => {
+            msg.error(&ctx, content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+#[command]
+#[desc("Display how a user's mania stats changed over a period of time")]
+#[help(
+    "Display how a user's mania pp, rank, accuracy, playcount, and ranked score \
+    changed over a period of time.\n\
+    The period can be `day`, `week` (default), `month`, `year`, \
+    or a custom amount of days like `14d`.\n\
+    Stats are only stored whenever the user is requested through a command \
+    or whenever they are tracked."
+)]
+#[usage("[username] [day/week/month/year/<number>d]")]
+#[examples("badewanne3 month", "peppy 14d")]
+#[alias("gainsmania")]
+#[group(Mania)]
+async fn prefix_progressmania(ctx: Arc<Context>, msg: &Message, args: Args<'_>) -> Result<()> {
+    match Progress::args(Some(GameModeOption::Mania), args) {
+        Ok(args) => progress(ctx, msg.into(), args).await,
+        Err(content) => {
+            msg.error(&ctx, content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+#[command]
+#[desc("Display how a user's taiko stats changed over a period of time")]
+#[help(
+    "Display how a user's taiko pp, rank, accuracy, playcount, and ranked score \
+    changed over a period of time.\n\
+    The period can be `day`, `week` (default), `month`, `year`, \
+    or a custom amount of days like `14d`.\n\
+    Stats are only stored whenever the user is requested through a command \
+    or whenever they are tracked."
+)]
+#[usage("[username] [day/week/month/year/<number>d]")]
+#[examples("badewanne3 month", "peppy 14d")]
+#[alias("gainstaiko")]
+#[group(Taiko)]
+async fn prefix_progresstaiko(ctx: Arc<Context>, msg: &Message, args: Args<'_>) -> Result<()> {
+    match Progress::args(Some(GameModeOption::Taiko), args) {
+        Ok(args) => progress(ctx, msg.into(), args).await,
+        Err(content) => {
+            msg.error(&ctx, content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+#[command]
+#[desc("Display how a user's ctb stats changed over a period of time")]
+#[help(
+    "Display how a user's ctb pp, rank, accuracy, playcount, and ranked score \
+    changed over a period of time.\n\
+    The period can be `day`, `week` (default), `month`, `year`, \
+    or a custom amount of days like `14d`.\n\
+    Stats are only stored whenever the user is requested through a command \
+    or whenever they are tracked."
+)]
+#[usage("[username] [day/week/month/year/<number>d]")]
+#[examples("badewanne3 month", "peppy 14d")]
+#[aliases("progresscatch", "gainsctb")]
+#[group(Catch)]
+async fn prefix_progressctb(ctx: Arc<Context>, msg: &Message, args: Args<'_>) -> Result<()> {
+    match Progress::args(Some(GameModeOption::Catch), args) {
+        Ok(args) => progress(ctx, msg.into(), args).await,
+        Err(content) => {
+            msg.error(&ctx, content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn slash_progress(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
+    let args = Progress::from_interaction(command.input_data())?;
+
+    progress(ctx, (&mut command).into(), args).await
+}
+
+async fn progress(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Progress<'_>) -> Result<()> {
+    let (name, mode) = name_mode!(ctx, orig, args);
+
+    let days = args
+        .days
+        .unwrap_or_else(|| args.period.unwrap_or_default().days());
+
+    // Retrieving the user also stores a snapshot of their current stats
+    let user_args = UserArgs::new(name.as_str(), mode);
+    let fetched_at = OffsetDateTime::now_utc();
+
+    let mut user = match get_user(&ctx, &user_args).await {
+        Ok(user) => user,
+        Err(OsuError::NotFound) => {
+            let content = format!("User `{name}` was not found");
+
+            return orig.error(&ctx, content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let report = Report::new(err).wrap_err("failed to get user");
+
+            return Err(report);
+        }
+    };
+
+    // Overwrite default mode
+    user.mode = mode;
+
+    let since = OffsetDateTime::now_utc() - Duration::days(days as i64);
+
+    let mut history = match ctx
+        .psql()
+        .get_osu_user_stats_history(user.user_id, mode, since)
+        .await
+    {
+        Ok(history) => history,
+        Err(err) => {
+            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get stats history"));
+        }
+    };
+
+    // Only compare against stats that were stored before the current ones
+    history.retain(|snapshot| snapshot.timestamp < fetched_at);
+
+    if let Some(ref stats) = user.statistics {
+        let current = UserStatsSnapshot {
+            timestamp: OffsetDateTime::now_utc(),
+            pp: stats.pp,
+            global_rank: stats.global_rank,
+            accuracy: stats.accuracy,
+            playcount: stats.playcount,
+            ranked_score: stats.ranked_score,
+        };
+
+        history.push(current);
+    }
+
+    if history.len() < 2 {
+        let content = format!(
+            "There are no stats stored for `{name}` yet.\n\
+            Stats are stored whenever a user is requested \
+            so check back later to see their progress."
+        );
+
+        return orig.error(&ctx, content).await;
+    }
+
+    let graph = match draw_graph(&history) {
+        Ok(graph) => Some(graph),
+        Err(err) => {
+            warn!("{:?}", err.wrap_err("failed to draw progress graph"));
+
+            None
+        }
+    };
+
+    let embed = ProgressEmbed::new(&user, &history[0], &history[history.len() - 1]).build();
+    let mut builder = MessageBuilder::new().embed(embed);
+
+    if let Some(graph) = graph {
+        builder = builder.attachment("progress_graph.png", graph);
+    }
+
+    orig.create_message(&ctx, &builder).await?;
+
+    Ok(())
+}
+
+const W: u32 = 1350;
+const H: u32 = 711;
+const LEN: usize = (W * H) as usize;
+
+fn draw_graph(history: &[UserStatsSnapshot]) -> Result<Vec<u8>> {
+    let mut buf = vec![0; LEN * 3];
+
+    let first = history[0].timestamp.unix_timestamp();
+    let last = history[history.len() - 1].timestamp.unix_timestamp();
+
+    let (pp_min, pp_max) = history
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(min, max), snapshot| {
+            (min.min(snapshot.pp), max.max(snapshot.pp))
+        });
+
+    // Negative so that better ranks are higher up
+    let (mut rank_min, mut rank_max) = history
+        .iter()
+        .filter_map(|snapshot| snapshot.global_rank)
+        .fold((i64::MAX, i64::MIN), |(min, max), rank| {
+            let rank = -(rank as i64);
+
+            (min.min(rank), max.max(rank))
+        });
+
+    if rank_min > rank_max {
+        (rank_min, rank_max) = (-1, 0);
+    }
+
+    let pp_margin = ((pp_max - pp_min) * 0.1).max(1.0);
+    let rank_margin = ((rank_max - rank_min) / 10).max(1);
+    let show_time = last - first <= 2 * 86_400;
+
+    {
+        let root = BitMapBackend::with_buffer(&mut buf, (W, H)).into_drawing_area();
+        let background = RGBColor(19, 43, 33);
+        root.fill(&background)
+            .wrap_err("failed to fill background")?;
+
+        let caption_style = ("sans-serif", 25_i32, FontStyle::Bold, &WHITE);
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(40_i32)
+            .y_label_area_size(75_i32)
+            .right_y_label_area_size(75_i32)
+            .margin_top(5_i32)
+            .margin_right(15_i32)
+            .caption("Progress", caption_style)
+            .build_cartesian_2d(
+                first..last.max(first + 1),
+                pp_min - pp_margin..pp_max + pp_margin,
+            )
+            .wrap_err("failed to build chart")?
+            .set_secondary_coord(
+                first..last.max(first + 1),
+                rank_min - rank_margin..(rank_max + rank_margin).min(0),
+            );
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .x_labels(8)
+            .x_label_formatter(&|timestamp| {
+                let datetime = match OffsetDateTime::from_unix_timestamp(*timestamp) {
+                    Ok(datetime) => datetime,
+                    Err(_) => return String::new(),
+                };
+
+                let format = if show_time { TIME_FORMAT } else { DATE_FORMAT };
+
+                datetime.format(format).unwrap_or_default()
+            })
+            .y_desc("PP")
+            .y_label_formatter(&|pp| format!("{pp:.0}"))
+            .label_style(("sans-serif", 16_i32, &WHITE))
+            .bold_line_style(&WHITE.mix(0.3))
+            .axis_style(RGBColor(7, 18, 14))
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("failed to draw mesh")?;
+
+        chart
+            .configure_secondary_axes()
+            .y_desc("Rank")
+            .y_label_formatter(&|rank| format!("#{}", -*rank))
+            .label_style(("sans-serif", 16_i32, &WHITE))
+            .axis_style(RGBColor(7, 18, 14))
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("failed to draw secondary mesh")?;
+
+        let pp_color = RGBColor(0, 208, 138);
+
+        let pp_data = history
+            .iter()
+            .map(|snapshot| (snapshot.timestamp.unix_timestamp(), snapshot.pp));
+
+        chart
+            .draw_series(LineSeries::new(pp_data, pp_color.stroke_width(3)))
+            .wrap_err("failed to draw pp line")?
+            .label("PP")
+            .legend(move |(x, y)| {
+                PathElement::new(vec![(x, y), (x + 20, y)], pp_color.stroke_width(3))
+            });
+
+        let rank_color = RGBColor(2, 186, 213);
+
+        let rank_data: Vec<_> = history
+            .iter()
+            .filter_map(|snapshot| {
+                let rank = -(snapshot.global_rank? as i64);
+
+                Some((snapshot.timestamp.unix_timestamp(), rank))
+            })
+            .collect();
+
+        if !rank_data.is_empty() {
+            chart
+                .draw_secondary_series(LineSeries::new(rank_data, rank_color.stroke_width(3)))
+                .wrap_err("failed to draw rank line")?
+                .label("Rank")
+                .legend(move |(x, y)| {
+                    PathElement::new(vec![(x, y), (x + 20, y)], rank_color.stroke_width(3))
+                });
+        }
+
+        chart
+            .configure_series_labels()
+            .border_style(WHITE.mix(0.6).stroke_width(1))
+            .background_style(RGBColor(7, 23, 17))
+            .label_font(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("failed to draw legend")?;
+    }
+
+    // Encode buf to png
+    let mut png_bytes: Vec<u8> = Vec::with_capacity(LEN);
+    let png_encoder = PngEncoder::new(&mut png_bytes);
+
+    png_encoder
+        .write_image(&buf, W, H, ColorType::Rgb8)
+        .wrap_err("failed to encode image")?;
+
+    Ok(png_bytes)
+}
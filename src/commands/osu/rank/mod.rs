@@ -49,6 +49,12 @@ pub struct RankPp<'a> {
     )]
     /// Specify a linked discord user
     discord: Option<Id<UserMarker>>,
+    #[command(help = "Estimate when the target rank will be reached.\n\
+        The user's pp gain rate is extrapolated from their stored stats of the last 90 days, \
+        assuming the pp required for the target rank stay the same.\n\
+        Stats are only stored for users that have been requested through some command beforehand.")]
+    /// Estimate when the rank will be reached at the current pp gain rate
+    projection: Option<bool>,
 }
 
 #[derive(CommandModel, CreateCommand, HasName)]
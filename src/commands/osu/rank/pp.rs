@@ -4,13 +4,17 @@ use command_macros::command;
 use eyre::{Report, Result};
 use rkyv::{Deserialize, Infallible};
 use rosu_v2::prelude::{OsuError, User, UserCompact};
+use time::{Duration, OffsetDateTime};
 
 use crate::{
     commands::{
         osu::{get_user, UserArgs},
         GameModeOption,
     },
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        OsuFutureExt,
+    },
     embeds::{EmbedData, RankEmbed},
     util::{
         builder::MessageBuilder,
@@ -29,6 +33,7 @@ pub(super) async fn pp(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: RankPp<
         country,
         rank,
         each,
+        projection,
         ..
     } = args;
 
@@ -152,7 +157,8 @@ pub(super) async fn pp(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: RankPp<
             .user_scores(user.user_id)
             .limit(100)
             .best()
-            .mode(mode);
+            .mode(mode)
+            .observe(&ctx.stats, "user_scores");
 
         match scores_fut.await {
             Ok(scores) => (!scores.is_empty()).then_some(scores),
@@ -173,8 +179,23 @@ pub(super) async fn pp(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: RankPp<
         crate::tracking::process_osu_tracking(&ctx, scores, Some(rank_data.borrow_user())).await;
     }
 
+    let projection = if projection == Some(true) && rank_data.with_scores() {
+        let user = rank_data.borrow_user();
+
+        match RankProjection::new(&ctx, user, rank_data.target_pp()).await {
+            Ok(projection) => Some(projection),
+            Err(err) => {
+                let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("failed to project rank"));
+            }
+        }
+    } else {
+        None
+    };
+
     // Creating the embed
-    let embed = RankEmbed::new(rank_data, scores, each).build();
+    let embed = RankEmbed::new(rank_data, scores, each, projection).build();
     let builder = MessageBuilder::new().embed(embed);
     orig.create_message(&ctx, &builder).await?;
 
@@ -297,6 +318,86 @@ impl RankData {
             Self::Over10k { user, .. } => user,
         }
     }
+
+    fn target_pp(&self) -> f32 {
+        match self {
+            Self::Sub10k { rank_holder, .. } => rank_holder.statistics.as_ref().unwrap().pp,
+            Self::Over10k { required_pp, .. } => *required_pp,
+        }
+    }
+}
+
+pub enum RankProjection {
+    /// Not enough stats history stored for the user
+    MissingHistory,
+    /// The user did not gain enough pp in the considered time frame
+    NoGain { pp_per_day: f32 },
+    /// At the current rate the target would take too many years to reach
+    TooFarOut { pp_per_day: f32, years: u32 },
+    Reach {
+        pp_per_day: f32,
+        date: OffsetDateTime,
+        /// Approximated rank in 30 days at the current rate
+        month_rank: u32,
+    },
+}
+
+impl RankProjection {
+    const DAYS: i64 = 90;
+    const MAX_DAYS_LEFT: f64 = 365.0 * 20.0;
+
+    async fn new(ctx: &Context, user: &User, target_pp: f32) -> Result<Self> {
+        let now = OffsetDateTime::now_utc();
+        let since = now - Duration::days(Self::DAYS);
+
+        let history = ctx
+            .psql()
+            .get_osu_user_stats_history(user.user_id, user.mode, since)
+            .await?;
+
+        // The history may start with an arbitrarily old snapshot from before `since`
+        // which would no longer represent the recent rate
+        let first = match history.iter().find(|snapshot| snapshot.timestamp >= since) {
+            Some(first) => first,
+            None => return Ok(Self::MissingHistory),
+        };
+
+        let days = (now - first.timestamp).as_seconds_f32() / (60.0 * 60.0 * 24.0);
+
+        // Too little time has passed to extrapolate anything
+        if days < 1.0 {
+            return Ok(Self::MissingHistory);
+        }
+
+        let user_pp = user.statistics.as_ref().map_or(0.0, |stats| stats.pp);
+        let pp_per_day = (user_pp - first.pp) / days;
+
+        if pp_per_day <= 0.0 {
+            return Ok(Self::NoGain { pp_per_day });
+        }
+
+        let days_left = ((target_pp - user_pp) / pp_per_day) as f64;
+
+        // Projections this far into the future are meaningless anyway
+        if days_left > Self::MAX_DAYS_LEFT {
+            let years = (days_left / 365.0) as u32;
+
+            return Ok(Self::TooFarOut { pp_per_day, years });
+        }
+
+        let date = now + Duration::seconds_f64(days_left * 60.0 * 60.0 * 24.0);
+
+        let month_rank = ctx
+            .psql()
+            .approx_rank_from_pp(user_pp + pp_per_day * 30.0, user.mode)
+            .await?;
+
+        Ok(Self::Reach {
+            pp_per_day,
+            date,
+            month_rank,
+        })
+    }
 }
 
 impl<'m> RankPp<'m> {
@@ -343,6 +444,7 @@ impl<'m> RankPp<'m> {
             each: None,
             country,
             discord,
+            projection: None,
         })
     }
 }
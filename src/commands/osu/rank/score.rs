@@ -9,7 +9,10 @@ use crate::{
         osu::{get_user, UserArgs},
         GameModeOption,
     },
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        OsuFutureExt,
+    },
     embeds::{EmbedData, RankRankedScoreEmbed},
     util::{builder::MessageBuilder, constants::OSU_API_ISSUE, matcher, ChannelExt},
     Context,
@@ -160,7 +163,11 @@ pub(super) async fn score(
 
     // Retrieve the user and the user thats holding the given rank
     let page = (rank / 50) + (rank % 50 != 0) as usize;
-    let rank_holder_fut = ctx.osu().score_rankings(mode).page(page as u32);
+    let rank_holder_fut = ctx
+        .osu()
+        .score_rankings(mode)
+        .page(page as u32)
+        .observe(&ctx.stats, "score_ranking");
     let user_args = UserArgs::new(name.as_str(), mode);
     let user_fut = get_user(&ctx, &user_args);
 
@@ -6,7 +6,7 @@ use rosu_v2::prelude::GameMode;
 
 use crate::{
     commands::GameModeOption,
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, OsuFutureExt},
     pagination::RankingCountriesPagination,
     util::constants::{GENERAL_ISSUE, OSU_API_ISSUE},
     Context,
@@ -65,7 +65,12 @@ pub(super) async fn country(
         },
     };
 
-    let mut ranking = match ctx.osu().country_rankings(mode).await {
+    let mut ranking = match ctx
+        .osu()
+        .country_rankings(mode)
+        .observe(&ctx.stats, "country_ranking")
+        .await
+    {
         Ok(ranking) => ranking,
         Err(err) => {
             let _ = orig.error(&ctx, OSU_API_ISSUE).await;
@@ -3,13 +3,13 @@ use std::{collections::BTreeMap, fmt, mem, ops::Deref, sync::Arc};
 use command_macros::command;
 use eyre::{Report, Result};
 use rkyv::{Deserialize, Infallible};
-use rosu_v2::prelude::{GameMode, OsuResult, Rankings};
+use rosu_v2::prelude::{GameMode, GameMods, OsuResult, Rankings};
 use time::OffsetDateTime;
 use twilight_model::id::{marker::UserMarker, Id};
 
 use crate::{
     commands::{osu::UserArgs, GameModeOption},
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, OsuFutureExt},
     database::OsuData,
     embeds::{RankingEntry, RankingKindData},
     pagination::RankingPagination,
@@ -152,7 +152,10 @@ pub(super) async fn score(
         },
     };
 
-    let ranking_fut = ctx.osu().score_rankings(mode);
+    let ranking_fut = ctx
+        .osu()
+        .score_rankings(mode)
+        .observe(&ctx.stats, "score_ranking");
 
     let author_idx_fut = async {
         match ctx.psql().get_user_osu(author_id).await {
@@ -439,6 +442,13 @@ pub enum UserValue {
     AmountWithNegative(i64),
     Date(OffsetDateTime),
     Float(f32),
+    /// A score on a map, optionally with its rank on the map's global leaderboard
+    MapScore {
+        rank: Option<u32>,
+        score: u32,
+        acc: f32,
+        mods: GameMods,
+    },
     Playtime(u32),
     PpF32(f32),
     PpU32(u32),
@@ -461,6 +471,24 @@ impl fmt::Display for UserValue {
             }
             Self::Date(datetime) => write!(f, "{}", datetime.date()),
             Self::Float(v) => write!(f, "{:.2}", numbers::round(v)),
+            Self::MapScore {
+                rank,
+                score,
+                acc,
+                mods,
+            } => {
+                if let Some(rank) = rank {
+                    write!(f, "#{rank} ")?;
+                }
+
+                write!(f, "{} ", numbers::with_comma_int(score))?;
+
+                if rank.is_none() {
+                    write!(f, "{:.2}% ", numbers::round(acc))?;
+                }
+
+                write!(f, "+{mods}")
+            }
             Self::Playtime(seconds) => {
                 write!(f, "{} hrs", numbers::with_comma_int(seconds / 60 / 60))
             }
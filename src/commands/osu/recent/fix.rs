@@ -5,7 +5,7 @@ use rosu_v2::prelude::{Beatmap, GameMode, OsuError, Score};
 
 use crate::{
     commands::osu::{get_user_and_scores, prepare_score, unchoke_pp, ScoreArgs, UserArgs},
-    core::{commands::CommandOrigin, Context},
+    core::{commands::CommandOrigin, Context, OsuFutureExt},
     embeds::{EmbedData, FixScoreEmbed},
     util::{
         builder::MessageBuilder,
@@ -72,9 +72,14 @@ pub(super) async fn fix(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Recent
                 .user_scores(score.user_id)
                 .mode(mode)
                 .limit(100)
-                .best();
+                .best()
+                .observe(&ctx.stats, "user_scores");
 
-            let user_fut = ctx.osu().user(score.user_id).mode(mode);
+            let user_fut = ctx
+                .osu()
+                .user(score.user_id)
+                .mode(mode)
+                .observe(&ctx.stats, "user");
             let score_fut = prepare_score(&ctx, &mut score);
 
             match tokio::join!(mapset_fut, score_fut, user_fut, best_fut) {
@@ -93,7 +98,12 @@ pub(super) async fn fix(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Recent
                 (Err(_), Ok(_), Ok(user), Ok(best)) => {
                     let mut map = score.map.take().unwrap();
 
-                    let mapset = match ctx.osu().beatmapset(map.mapset_id).await {
+                    let mapset = match ctx
+                        .osu()
+                        .beatmapset(map.mapset_id)
+                        .observe(&ctx.stats, "beatmapset")
+                        .await
+                    {
                         Ok(mapset) => mapset,
                         Err(err) => {
                             let _ = orig.error(&ctx, OSU_API_ISSUE).await;
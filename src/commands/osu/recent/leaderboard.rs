@@ -9,7 +9,10 @@ use crate::{
         osu::{HasMods, ModsResult},
         GameModeOption,
     },
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        OsuFutureExt,
+    },
     pagination::LeaderboardPagination,
     pp::PpCalculator,
     util::{
@@ -187,7 +190,8 @@ pub(super) async fn leaderboard(
         .recent()
         .include_fails(true)
         .mode(mode)
-        .limit(limit);
+        .limit(limit)
+        .observe(&ctx.stats, "user_scores");
 
     let (map_id, user) = match scores_fut.await {
         Ok(scores) if scores.len() < limit => {
@@ -246,7 +250,13 @@ pub(super) async fn leaderboard(
     // Retrieving the beatmap
     let mut map = match map_result {
         Ok(map) => map,
-        Err(_) => match ctx.osu().beatmap().map_id(map_id).await {
+        Err(_) => match ctx
+            .osu()
+            .beatmap()
+            .map_id(map_id)
+            .observe(&ctx.stats, "beatmap")
+            .await
+        {
             Ok(map) => {
                 // Add map to database if its not in already
                 if let Err(err) = ctx.psql().insert_beatmap(&map).await {
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, fmt::Write, sync::Arc};
+use std::{borrow::Cow, collections::HashSet, fmt::Write, sync::Arc};
 
 use command_macros::command;
 use eyre::{Report, Result};
@@ -23,13 +23,19 @@ use crate::{
 
 use super::RecentList;
 
+/// Maximum amount of archived scores that are added to the recent scores
+const ARCHIVE_LIMIT: usize = 1000;
+
 #[command]
 #[desc("Display a list of a user's most recent plays")]
 #[help(
     "Display a list of a user's most recent plays.\n\
     To filter all fails, you can specify `pass=true`.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To include older scores archived by the bot, specify `archive=true`. \
+    Scores are only archived for users who used the `/link` command \
+    and only the 1000 most recent archived scores are included."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -52,7 +58,10 @@ async fn prefix_recentlist(ctx: Arc<Context>, msg: &Message, args: Args<'_>) ->
     "Display a list of a user's most recent mania plays.\n\
     To filter all fails, you can specify `pass=true`.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To include older scores archived by the bot, specify `archive=true`. \
+    Scores are only archived for users who used the `/link` command \
+    and only the 1000 most recent archived scores are included."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -75,7 +84,10 @@ async fn prefix_recentlistmania(ctx: Arc<Context>, msg: &Message, args: Args<'_>
     "Display a list of a user's most recent taiko plays.\n\
     To filter all fails, you can specify `pass=true`.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To include older scores archived by the bot, specify `archive=true`. \
+    Scores are only archived for users who used the `/link` command \
+    and only the 1000 most recent archived scores are included."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -98,7 +110,10 @@ async fn prefix_recentlisttaiko(ctx: Arc<Context>, msg: &Message, args: Args<'_>
     "Display a list of a user's most recent ctb plays.\n\
     To filter all fails, you can specify `pass=true`.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To include older scores archived by the bot, specify `archive=true`. \
+    Scores are only archived for users who used the `/link` command \
+    and only the 1000 most recent archived scores are included."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -121,8 +136,9 @@ impl<'m> RecentList<'m> {
         let mut discord = None;
         let mut grade = None;
         let mut passes = None;
+        let mut archive = None;
 
-        for arg in args.take(3).map(|arg| arg.cow_to_ascii_lowercase()) {
+        for arg in args.take(4).map(|arg| arg.cow_to_ascii_lowercase()) {
             if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
                 let key = &arg[..idx];
                 let value = arg[idx + 1..].trim_end();
@@ -152,10 +168,20 @@ impl<'m> RecentList<'m> {
                         Ok(grade_) => grade = Some(grade_),
                         Err(content) => return Err(content.into()),
                     },
+                    "archive" | "a" => match value {
+                        "true" | "t" | "1" => archive = Some(true),
+                        "false" | "f" | "0" => archive = Some(false),
+                        _ => {
+                            let content =
+                                "Failed to parse `archive`. Must be either `true` or `false`.";
+
+                            return Err(content.into());
+                        }
+                    },
                     _ => {
                         let content = format!(
                             "Unrecognized option `{key}`.\n\
-                            Available options are: `grade` or `pass`."
+                            Available options are: `grade`, `pass`, or `archive`."
                         );
 
                         return Err(content.into());
@@ -180,6 +206,7 @@ impl<'m> RecentList<'m> {
             passes,
             mods: None,
             discord,
+            archive,
         })
     }
 }
@@ -208,9 +235,12 @@ pub(super) async fn list(
         query,
         grade,
         passes,
+        archive,
         ..
     } = args;
 
+    let archive = archive.unwrap_or(false);
+
     let grade = grade.map(Grade::from);
 
     // Retrieve the user and their recent scores
@@ -257,6 +287,25 @@ pub(super) async fn list(
     // Overwrite default mode
     user.mode = mode;
 
+    if archive {
+        let archived_fut =
+            ctx.psql()
+                .get_archived_scores(user.user_id, mode, None, None, ARCHIVE_LIMIT);
+
+        match archived_fut.await {
+            Ok(archived) => {
+                let ids: HashSet<_> = scores.iter().map(|score| score.score_id).collect();
+                let archived = archived
+                    .into_iter()
+                    .filter(|score| !ids.contains(&score.score_id));
+
+                scores.extend(archived);
+                scores.sort_by(|a, b| b.ended_at.cmp(&a.ended_at));
+            }
+            Err(err) => warn!("{:?}", err.wrap_err("failed to get archived scores")),
+        }
+    }
+
     if let Some(grade) = grade {
         scores.retain(|score| score.grade.eq_letter(grade));
     } else if let Some(true) = passes {
@@ -285,7 +334,7 @@ pub(super) async fn list(
         scores.retain(|score| score.matches(&criteria));
     }
 
-    let content = message_content(grade, mods, query);
+    let content = message_content(grade, mods, query, archive);
 
     RecentListPagination::builder(user, scores)
         .content(content.unwrap_or_default())
@@ -299,6 +348,7 @@ fn message_content(
     grade: Option<Grade>,
     mods: Option<ModSelection>,
     query: Option<String>,
+    archive: bool,
 ) -> Option<String> {
     let mut content = String::new();
 
@@ -328,5 +378,13 @@ fn message_content(
         let _ = write!(content, "`Query: {query}`");
     }
 
+    if archive {
+        if !content.is_empty() {
+            content.push_str(" ~ ");
+        }
+
+        content.push_str("`Including archived scores`");
+    }
+
     (!content.is_empty()).then_some(content)
 }
@@ -124,6 +124,10 @@ pub struct RecentBest {
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
         ar, cs, hp, od, bpm, length, or stars like for example `fdfd ar>10 od>=9`.\n\
+        Scores can also be filtered by acc, pp, combo, miss, grade, mods, date, or rank \
+        e.g. `pp>500 mods>=hd date>=2022-01 grade=s`.\n\
+        Use `|` between words for alternatives, a leading `-` to exclude a word, \
+        and quotes for phrases e.g. `\"big black\" | -remix`.\n\
         While ar & co will be adjusted to mods, stars will not."
     )]
     /// Specify a search query containing artist, difficulty, AR, BPM, ...
@@ -173,6 +177,7 @@ impl<'a> TryFrom<RecentBest> for TopArgs<'a> {
             query: args.query,
             farm: args.farm,
             size: args.size,
+            archive: false,
             has_dash_r: false,
             has_dash_p_or_i: false,
         })
@@ -223,6 +228,10 @@ pub struct RecentList<'a> {
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
         ar, cs, hp, od, bpm, length, or stars like for example `fdfd ar>10 od>=9`.\n\
+        Scores can also be filtered by acc, pp, combo, miss, grade, mods, date, or rank \
+        e.g. `pp>500 mods>=hd date>=2022-01 grade=s`.\n\
+        Use `|` between words for alternatives, a leading `-` to exclude a word, \
+        and quotes for phrases e.g. `\"big black\" | -remix`.\n\
         While ar & co will be adjusted to mods, stars will not."
     )]
     /// Specify a search query containing artist, difficulty, AR, BPM, ...
@@ -248,6 +257,11 @@ pub struct RecentList<'a> {
     )]
     /// Specify a linked discord user
     discord: Option<Id<UserMarker>>,
+    #[command(help = "Include older scores that were archived by the bot.\n\
+        Recent scores are only archived for users who have used the `/link` command.\n\
+        Only the 1000 most recent archived scores are considered.")]
+    /// Specify whether archived scores of linked users should be included
+    archive: Option<bool>,
 }
 
 #[derive(CommandModel, CreateCommand, HasName)]
@@ -549,6 +563,10 @@ pub struct Rb {
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
         ar, cs, hp, od, bpm, length, or stars like for example `fdfd ar>10 od>=9`.\n\
+        Scores can also be filtered by acc, pp, combo, miss, grade, mods, date, or rank \
+        e.g. `pp>500 mods>=hd date>=2022-01 grade=s`.\n\
+        Use `|` between words for alternatives, a leading `-` to exclude a word, \
+        and quotes for phrases e.g. `\"big black\" | -remix`.\n\
         While ar & co will be adjusted to mods, stars will not."
     )]
     /// Specify a search query containing artist, difficulty, AR, BPM, ...
@@ -15,7 +15,10 @@ use crate::{
         osu::{get_user_and_scores, prepare_score, require_link, ScoreArgs, UserArgs},
         GameModeOption, GradeOption,
     },
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        OsuFutureExt,
+    },
     database::{EmbedsSize, MinimizedPp},
     embeds::RecentEmbed,
     util::{
@@ -354,7 +357,8 @@ pub(super) async fn score(
             let fut = ctx
                 .osu()
                 .beatmap_user_score(map.map_id, user.user_id)
-                .mode(mode);
+                .mode(mode)
+                .observe(&ctx.stats, "beatmap_user_score");
 
             Some(fut.await)
         } else {
@@ -369,7 +373,8 @@ pub(super) async fn score(
                 .user_scores(user.user_id)
                 .best()
                 .limit(100)
-                .mode(mode);
+                .mode(mode)
+                .observe(&ctx.stats, "user_scores");
 
             Some(fut.await)
         } else {
@@ -438,6 +443,8 @@ pub(super) async fn score(
         (None, None) => MinimizedPp::default(),
     };
 
+    let score_kind = config.score_kind();
+
     #[cfg(feature = "twitch")]
     let data_fut = RecentEmbed::new(
         &user,
@@ -446,6 +453,7 @@ pub(super) async fn score(
         map_score.as_ref(),
         twitch_vod,
         minimized_pp,
+        score_kind,
         &ctx,
     );
 
@@ -456,6 +464,7 @@ pub(super) async fn score(
         best.as_deref(),
         map_score.as_ref(),
         minimized_pp,
+        score_kind,
         &ctx,
     );
 
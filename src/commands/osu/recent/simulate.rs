@@ -10,7 +10,10 @@ use crate::{
         osu::{prepare_score, require_link},
         GameModeOption,
     },
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        OsuFutureExt,
+    },
     database::EmbedsSize,
     embeds::{SimulateArgs, SimulateEmbed},
     util::{
@@ -204,7 +207,8 @@ pub(super) async fn simulate(
         .recent()
         .mode(mode)
         .include_fails(true)
-        .limit(limit);
+        .limit(limit)
+        .observe(&ctx.stats, "user_scores");
 
     let mut score = match scores_fut.await {
         Ok(scores) if scores.is_empty() => {
@@ -268,7 +272,8 @@ pub(super) async fn simulate(
 
     // Accumulate all necessary data
     let embed_data = match SimulateEmbed::new(Some(score), &map, &mapset, args, &ctx).await {
-        Ok(data) => data,
+        Ok(Ok(data)) => data,
+        Ok(Err(content)) => return orig.error(&ctx, content).await,
         Err(err) => {
             let _ = orig.error(&ctx, GENERAL_ISSUE).await;
 
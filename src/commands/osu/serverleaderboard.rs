@@ -1,20 +1,40 @@
-use std::sync::Arc;
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+};
 
 use command_macros::SlashCommand;
-use eyre::Result;
-use rosu_v2::prelude::GameMode;
+use eyre::{Report, Result};
+use hashbrown::HashMap;
+use rosu_v2::prelude::{
+    GameMode, GameMods,
+    RankStatus::{Approved, Ranked},
+    Score,
+};
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::{
+    id::{marker::GuildMarker, Id},
+    util::ImageHash,
+};
 
 use crate::{
+    commands::GameModeOption,
+    core::OsuFutureExt,
     database::UserStatsColumn,
-    embeds::RankingKindData,
+    embeds::{RankingEntry, RankingKindData},
     pagination::RankingPagination,
     util::{
-        constants::GENERAL_ISSUE, interaction::InteractionCommand, Authored, InteractionCommandExt,
+        constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+        hasher::IntHasher,
+        interaction::InteractionCommand,
+        matcher, Authored, InteractionCommandExt,
     },
     Context,
 };
 
+use super::UserValue;
+
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(
     name = "serverleaderboard",
@@ -28,7 +48,9 @@ use crate::{
     - They are not linked through the `/link` command\n\
     - Their osu! user stats have not been cached yet. \
     Try using any command that retrieves the user, e.g. `/profile`, in order to cache them.\n\
-    - Members of this server are not stored as such. Maybe let bade know :eyes:"
+    - Members of this server are not stored as such. Maybe let bade know :eyes:\n\n\
+    The `scores` leaderboards are computed from the stored top plays and recent passes \
+    of linked members instead."
 )]
 #[flags(ONLY_GUILDS)]
 /// Various osu! leaderboards for linked server members
@@ -43,6 +65,8 @@ pub enum ServerLeaderboard {
     Catch(ServerLeaderboardCatch),
     #[command(name = "mania")]
     Mania(ServerLeaderboardMania),
+    #[command(name = "scores")]
+    Scores(ServerLeaderboardScores),
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -210,6 +234,41 @@ impl ServerLeaderboardModeKind {
     }
 }
 
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "scores")]
+/// Leaderboards over stored scores of linked server members
+pub struct ServerLeaderboardScores {
+    #[command(help = "Specify what kind of leaderboard to show.\n\
+    - `HD pp`: Sum of the pp of each member's best HD play per map\n\
+    - `FC count`: Amount of ranked maps with a full combo, optionally above some star rating\n\
+    - `Map top50`: Global rank of each member's best stored score on a map, \
+    only including members whose score would be within the map's global top50\n\
+    - `Map best`: Best stored score of each member on a map with its accuracy and mods")]
+    /// Specify what kind of leaderboard to show
+    kind: ServerLeaderboardScoresKind,
+    /// Specify a gamemode
+    mode: Option<GameModeOption>,
+    #[command(help = "Specify a map url or map id.\n\
+    Required for the `Map top50` and `Map best` leaderboards.")]
+    /// Specify a map url or map id
+    map: Option<String>,
+    #[command(min_value = 0.0, max_value = 15.0)]
+    /// Only count FCs on maps with at least this many stars
+    stars: Option<f64>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum ServerLeaderboardScoresKind {
+    #[option(name = "HD pp", value = "hd_pp")]
+    HdPp,
+    #[option(name = "FC count", value = "fc_count")]
+    FcCount,
+    #[option(name = "Map top50", value = "map_top50")]
+    MapTop50,
+    #[option(name = "Map best", value = "map_best")]
+    MapBest,
+}
+
 async fn slash_serverleaderboard(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
     let args = ServerLeaderboard::from_interaction(command.input_data())?;
 
@@ -219,19 +278,14 @@ async fn slash_serverleaderboard(ctx: Arc<Context>, mut command: InteractionComm
         ServerLeaderboard::Taiko(args) => args.kind.column(GameMode::Taiko),
         ServerLeaderboard::Catch(args) => args.kind.column(GameMode::Catch),
         ServerLeaderboard::Mania(args) => args.kind.column(GameMode::Mania),
+        ServerLeaderboard::Scores(args) => return score_leaderboard(ctx, command, args).await,
     };
 
     let owner = command.user_id()?;
     let guild_id = command.guild_id.unwrap(); // command is only processed in guilds
 
     let members: Vec<_> = ctx.cache.members(guild_id, |id| id.get() as i64);
-
-    let guild_icon = ctx
-        .cache
-        .guild(guild_id, |g| g.icon().copied())
-        .ok()
-        .flatten()
-        .map(|icon| (guild_id, icon));
+    let guild_icon = guild_icon(&ctx, guild_id);
 
     let name = match ctx.user_config(owner).await {
         Ok(config) => config.into_username(),
@@ -281,3 +335,314 @@ async fn slash_serverleaderboard(ctx: Arc<Context>, mut command: InteractionComm
         .start(ctx, (&mut command).into())
         .await
 }
+
+async fn score_leaderboard(
+    ctx: Arc<Context>,
+    mut command: InteractionCommand,
+    args: ServerLeaderboardScores,
+) -> Result<()> {
+    let owner = command.user_id()?;
+    let guild_id = command.guild_id.unwrap(); // command is only processed in guilds
+
+    let config = match ctx.user_config(owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get user config"));
+        }
+    };
+
+    let map_id = match (args.kind, args.map.as_deref()) {
+        (ServerLeaderboardScoresKind::HdPp | ServerLeaderboardScoresKind::FcCount, _) => None,
+        (_, Some(map)) => match matcher::get_osu_map_id(map).or_else(|| map.parse().ok()) {
+            Some(map_id) => Some(map_id),
+            None => {
+                let content = "Failed to parse map url or id. Be sure to specify a valid map.";
+
+                command.error(&ctx, content).await?;
+
+                return Ok(());
+            }
+        },
+        (_, None) => {
+            let content = "This leaderboard requires the `map` option to be specified";
+
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+    };
+
+    // Only stored maps provide a title so the leaderboard can do without
+    let map = match map_id {
+        Some(map_id) => ctx.psql().get_beatmap(map_id, true).await.ok(),
+        None => None,
+    };
+
+    let mode = args
+        .mode
+        .map(GameMode::from)
+        .or_else(|| map.as_ref().map(|map| map.mode))
+        .or(config.mode)
+        .unwrap_or(GameMode::Osu);
+
+    let members: Vec<_> = ctx.cache.members(guild_id, |id| id.get() as i64);
+
+    let scores = match ctx
+        .psql()
+        .get_archived_member_scores(&members, mode, map_id)
+        .await
+    {
+        Ok(scores) => scores,
+        Err(err) => {
+            let _ = command.error(&ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get archived member scores"));
+        }
+    };
+
+    let values = match (args.kind, map_id) {
+        (ServerLeaderboardScoresKind::HdPp, _) => hd_pp(&scores),
+        (ServerLeaderboardScoresKind::FcCount, _) => fc_count(&scores, args.stars),
+        (ServerLeaderboardScoresKind::MapTop50, Some(map_id)) => {
+            match ctx
+                .osu()
+                .beatmap_scores(map_id)
+                .mode(mode)
+                .observe(&ctx.stats, "beatmap_scores")
+                .await
+            {
+                Ok(leaderboard) => map_top50(&scores, &leaderboard),
+                Err(err) => {
+                    let _ = command.error(&ctx, OSU_API_ISSUE).await;
+                    let report = Report::new(err).wrap_err("failed to get map leaderboard");
+
+                    return Err(report);
+                }
+            }
+        }
+        (ServerLeaderboardScoresKind::MapTop50 | ServerLeaderboardScoresKind::MapBest, _) => {
+            map_best(&scores)
+        }
+    };
+
+    if values.is_empty() {
+        let content = "No scores found for linked members of this server :(\n\
+            Scores are only considered if the member is linked through the `/link` command \
+            and their top plays have been stored already.";
+
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let user_ids: Vec<_> = values.iter().map(|(user_id, _)| *user_id as i32).collect();
+
+    let mut names = match ctx.psql().get_names_by_ids(&user_ids).await {
+        Ok(names) => names,
+        Err(err) => {
+            warn!("{:?}", err.wrap_err("failed to get usernames"));
+
+            HashMap::default()
+        }
+    };
+
+    let author_name = config.into_username();
+    let mut author_idx = None;
+
+    let leaderboard: BTreeMap<_, _> = values
+        .into_iter()
+        .enumerate()
+        .map(|(i, (user_id, value))| {
+            let name = names
+                .remove(&user_id)
+                .unwrap_or_else(|| user_id.to_string().into());
+
+            if author_name.as_ref() == Some(&name) {
+                author_idx = Some(i);
+            }
+
+            let entry = RankingEntry {
+                value,
+                name,
+                country: None,
+            };
+
+            (i, entry)
+        })
+        .collect();
+
+    let map_name = match (&map, map_id) {
+        (Some(map), _) => match map.mapset {
+            Some(ref mapset) => format!("{} - {} [{}]", mapset.artist, mapset.title, map.version),
+            None => format!("[{}]", map.version),
+        },
+        (None, Some(map_id)) => format!("map {map_id}"),
+        (None, None) => String::new(),
+    };
+
+    let title = match args.kind {
+        ServerLeaderboardScoresKind::HdPp => "HD pp".to_owned(),
+        ServerLeaderboardScoresKind::FcCount => match args.stars {
+            Some(stars) => format!("FCs on ranked maps over {stars:.2}★"),
+            None => "FCs on ranked maps".to_owned(),
+        },
+        ServerLeaderboardScoresKind::MapTop50 => format!("Global top50 ranks on {map_name}"),
+        ServerLeaderboardScoresKind::MapBest => format!("Best scores on {map_name}"),
+    };
+
+    let data = RankingKindData::ScoreStats {
+        guild_icon: guild_icon(&ctx, guild_id),
+        mode,
+        title,
+    };
+
+    let total = leaderboard.len();
+
+    let builder = RankingPagination::builder(leaderboard, total, author_idx, data);
+
+    builder
+        .start_by_update()
+        .start(ctx, (&mut command).into())
+        .await
+}
+
+fn guild_icon(ctx: &Context, guild_id: Id<GuildMarker>) -> Option<(Id<GuildMarker>, ImageHash)> {
+    ctx.cache
+        .guild(guild_id, |g| g.icon().copied())
+        .ok()
+        .flatten()
+        .map(|icon| (guild_id, icon))
+}
+
+/// Sum of the pp of each user's best HD score per map
+fn hd_pp(scores: &[Score]) -> Vec<(u32, UserValue)> {
+    let mut best = HashMap::<_, f32>::new();
+
+    let hd_scores = scores
+        .iter()
+        .filter(|score| score.mods.contains(GameMods::Hidden));
+
+    for score in hd_scores {
+        let map_id = score.map.as_ref().map_or(0, |map| map.map_id);
+        let pp = best.entry((score.user_id, map_id)).or_default();
+        *pp = pp.max(score.pp.unwrap_or(0.0));
+    }
+
+    let mut sums = HashMap::with_hasher(IntHasher);
+
+    for ((user_id, _), pp) in best {
+        *sums.entry(user_id).or_insert(0.0) += pp;
+    }
+
+    let mut values: Vec<_> = sums.into_iter().collect();
+    values.sort_unstable_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+
+    values
+        .into_iter()
+        .map(|(user_id, pp)| (user_id, UserValue::PpF32(pp)))
+        .collect()
+}
+
+/// Amount of distinct ranked maps on which each user has a full combo
+fn fc_count(scores: &[Score], min_stars: Option<f64>) -> Vec<(u32, UserValue)> {
+    let min_stars = min_stars.unwrap_or(0.0) as f32;
+    let mut fcs = HashSet::new();
+
+    for score in scores {
+        let map = match score.map {
+            Some(ref map) => map,
+            None => continue,
+        };
+
+        if !matches!(map.status, Ranked | Approved) || map.stars < min_stars {
+            continue;
+        }
+
+        let is_fc = score.perfect
+            || (score.statistics.count_miss == 0 && map.max_combo == Some(score.max_combo));
+
+        if is_fc {
+            fcs.insert((score.user_id, map.map_id));
+        }
+    }
+
+    let mut counts = HashMap::with_hasher(IntHasher);
+
+    for (user_id, _) in fcs {
+        *counts.entry(user_id).or_insert(0) += 1;
+    }
+
+    let mut values: Vec<_> = counts.into_iter().collect();
+    values.sort_unstable_by(|(_, a), (_, b)| b.cmp(a));
+
+    values
+        .into_iter()
+        .map(|(user_id, count)| (user_id, UserValue::Amount(count)))
+        .collect()
+}
+
+/// Best score of each user
+fn best_scores(scores: &[Score]) -> Vec<&Score> {
+    let mut best = HashMap::<_, &Score, _>::with_hasher(IntHasher);
+
+    for score in scores {
+        best.entry(score.user_id)
+            .and_modify(|best| {
+                if score.score > best.score {
+                    *best = score;
+                }
+            })
+            .or_insert(score);
+    }
+
+    let mut best: Vec<_> = best.into_values().collect();
+    best.sort_unstable_by(|a, b| b.score.cmp(&a.score));
+
+    best
+}
+
+/// Global rank of each user's best score if it is within the map's top50.
+///
+/// The rank is the position that the stored score would have among the scores
+/// of all other users on the global leaderboard.
+fn map_top50(scores: &[Score], leaderboard: &[Score]) -> Vec<(u32, UserValue)> {
+    best_scores(scores)
+        .into_iter()
+        .filter_map(|score| {
+            let better = leaderboard
+                .iter()
+                .filter(|s| s.user_id != score.user_id && s.score > score.score)
+                .count();
+
+            let rank = better as u32 + 1;
+
+            let value = UserValue::MapScore {
+                rank: Some(rank),
+                score: score.score,
+                acc: score.accuracy,
+                mods: score.mods,
+            };
+
+            (rank <= 50).then_some((score.user_id, value))
+        })
+        .collect()
+}
+
+/// Best score of each user with its accuracy and mods
+fn map_best(scores: &[Score]) -> Vec<(u32, UserValue)> {
+    best_scores(scores)
+        .into_iter()
+        .map(|score| {
+            let value = UserValue::MapScore {
+                rank: None,
+                score: score.score,
+                acc: score.accuracy,
+                mods: score.mods,
+            };
+
+            (score.user_id, value)
+        })
+        .collect()
+}
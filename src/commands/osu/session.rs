@@ -0,0 +1,205 @@
+use std::{borrow::Cow, sync::Arc};
+
+use command_macros::{command, HasName, SlashCommand};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameMode, OsuError};
+use time::Duration;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{
+    commands::GameModeOption,
+    core::commands::{prefix::Args, CommandOrigin},
+    database::UserStatsSnapshot,
+    embeds::{EmbedData, SessionEmbed},
+    util::{
+        builder::MessageBuilder, constants::OSU_API_ISSUE, interaction::InteractionCommand,
+        matcher, InteractionCommandExt,
+    },
+    Context,
+};
+
+use super::{get_scores, get_user_and_scores, ScoreArgs, UserArgs};
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "session",
+    help = "Summarize a user's latest play session.\n\
+    Recent scores are grouped into sessions whenever there is a long enough \
+    gap between two scores.\n\
+    Note that the osu!api only provides the last 100 recent scores of the last 24 hours.\n\
+    PP and rank changes are only available if the user's stats have been \
+    stored before the session started."
+)]
+/// Summarize a user's latest play session
+pub struct Session<'a> {
+    /// Specify a gamemode
+    mode: Option<GameModeOption>,
+    /// Specify a username
+    name: Option<Cow<'a, str>>,
+    #[command(min_value = 5, max_value = 360)]
+    /// Minutes of inactivity after which a new session starts, defaults to 30
+    gap: Option<u32>,
+    #[command(
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    /// Specify a linked discord user
+    discord: Option<Id<UserMarker>>,
+}
+
+impl<'m> Session<'m> {
+    const DEFAULT_GAP: u32 = 30;
+
+    fn args(args: Args<'m>) -> Self {
+        let mut name = None;
+        let mut discord = None;
+        let mut gap = None;
+
+        for arg in args.take(2) {
+            if let Some(minutes) = arg.strip_suffix('m').and_then(|n| n.parse().ok()) {
+                gap = Some(minutes);
+            } else if let Some(id) = matcher::get_mention_user(arg) {
+                discord = Some(id);
+            } else {
+                name = Some(arg.into());
+            }
+        }
+
+        Self {
+            mode: None,
+            name,
+            gap,
+            discord,
+        }
+    }
+}
+
+#[command]
+#[desc("Summarize a user's latest play session")]
+#[help(
+    "Summarize a user's latest play session.\n\
+    Recent scores are grouped into sessions whenever there are 30 minutes \
+    without a score, or however many minutes are specified e.g. `45m`.\n\
+    The mode is taken from your `/config`.\n\
+    Note that the osu!api only provides the last 100 recent scores of the last 24 hours."
+)]
+#[usage("[username] [minutes of inactivity e.g. 45m]")]
+#[examples("badewanne3", "badewanne3 60m")]
+#[group(AllModes)]
+async fn prefix_session(ctx: Arc<Context>, msg: &Message, args: Args<'_>) -> Result<()> {
+    session(ctx, msg.into(), Session::args(args)).await
+}
+
+async fn slash_session(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
+    let args = Session::from_interaction(command.input_data())?;
+
+    session(ctx, (&mut command).into(), args).await
+}
+
+async fn session(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Session<'_>) -> Result<()> {
+    let (name, mode) = name_mode!(ctx, orig, args);
+    let gap = args.gap.unwrap_or(Session::DEFAULT_GAP).clamp(5, 360);
+
+    let user_args = UserArgs::new(name.as_str(), mode);
+    let recent_args = ScoreArgs::recent(100).include_fails(true);
+
+    let (mut user, mut scores) = match get_user_and_scores(&ctx, user_args, &recent_args).await {
+        Ok((_, scores)) if scores.is_empty() => {
+            let content = format!(
+                "No recent {}plays found for user `{name}`",
+                match mode {
+                    GameMode::Osu => "",
+                    GameMode::Taiko => "taiko ",
+                    GameMode::Catch => "ctb ",
+                    GameMode::Mania => "mania ",
+                },
+            );
+
+            return orig.error(&ctx, content).await;
+        }
+        Ok((user, scores)) => (user, scores),
+        Err(OsuError::NotFound) => {
+            let content = format!("User `{name}` was not found");
+
+            return orig.error(&ctx, content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let report = Report::new(err).wrap_err("failed to get user or scores");
+
+            return Err(report);
+        }
+    };
+
+    // Overwrite default mode
+    user.mode = mode;
+
+    scores.sort_unstable_by(|a, b| b.ended_at.cmp(&a.ended_at));
+
+    // Keep scores until there is a gap that's too large
+    let max_gap = Duration::minutes(gap as i64);
+
+    let session_len = scores
+        .windows(2)
+        .position(|window| window[0].ended_at - window[1].ended_at > max_gap)
+        .map_or(scores.len(), |idx| idx + 1);
+
+    scores.truncate(session_len);
+
+    let session_start = scores[scores.len() - 1].ended_at;
+
+    let user_args = UserArgs::new(user.username.as_str(), mode);
+    let top_fut = get_scores(&ctx, &user_args, &ScoreArgs::top(100));
+
+    let history_fut = ctx
+        .psql()
+        .get_osu_user_stats_history(user.user_id, mode, session_start);
+
+    let (top_result, history_result) = tokio::join!(top_fut, history_fut);
+
+    let new_tops: Vec<_> = match top_result {
+        Ok(top) => top
+            .iter()
+            .enumerate()
+            .filter(|(_, score)| score.ended_at >= session_start)
+            .map(|(i, _)| i)
+            .collect(),
+        Err(err) => {
+            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let report = Report::new(err).wrap_err("failed to get top scores");
+
+            return Err(report);
+        }
+    };
+
+    // The first snapshot is the latest one before the session started, if any
+    let before = match history_result {
+        Ok(history) => history
+            .into_iter()
+            .next()
+            .filter(|snapshot| snapshot.timestamp < session_start),
+        Err(err) => {
+            warn!("{:?}", err.wrap_err("failed to get stats history"));
+
+            None
+        }
+    };
+
+    let after = user.statistics.as_ref().map(|stats| UserStatsSnapshot {
+        timestamp: scores[0].ended_at,
+        pp: stats.pp,
+        global_rank: stats.global_rank,
+        accuracy: stats.accuracy,
+        playcount: stats.playcount,
+        ranked_score: stats.ranked_score,
+    });
+
+    let snapshots = before.as_ref().zip(after.as_ref());
+    let embed = SessionEmbed::new(&user, &scores, &new_tops, snapshots, gap).build();
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(&ctx, &builder).await?;
+
+    Ok(())
+}
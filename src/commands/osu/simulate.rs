@@ -2,13 +2,16 @@ use std::sync::Arc;
 
 use command_macros::{command, HasMods, SlashCommand};
 use eyre::{Report, Result};
-use rosu_v2::prelude::{BeatmapsetCompact, OsuError};
+use rosu_v2::prelude::{BeatmapsetCompact, GameMode, OsuError};
 use tokio::time::{sleep, Duration};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::channel::{message::MessageType, Message};
 
 use crate::{
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        OsuFutureExt,
+    },
     database::EmbedsSize,
     embeds::SimulateEmbed,
     util::{
@@ -28,7 +31,9 @@ use super::{HasMods, ModsResult};
 #[command(
     name = "simulate",
     help = "Simulate a score on a map.\n\
-    Note that hitresults, combo, and accuracy are ignored in mania; only score is important."
+    For mania, the pp are based on the score. \
+    If only hitresults are specified, the score will be approximated from them.\n\
+    Specifying `keys` on an osu!standard map will simulate the map's mania convert."
 )]
 /// Simulate a score on a map
 pub struct Simulate {
@@ -43,9 +48,15 @@ pub struct Simulate {
     /// Specify mods e.g. hdhr or nm
     mods: Option<String>,
     #[command(min_value = 0)]
+    /// Specify the amount of 320s (mania only)
+    n320: Option<u32>,
+    #[command(min_value = 0)]
     /// Specify the amount of 300s
     n300: Option<u32>,
     #[command(min_value = 0)]
+    /// Specify the amount of 200s (mania only)
+    n200: Option<u32>,
+    #[command(min_value = 0)]
     /// Specify the amount of 100s
     n100: Option<u32>,
     #[command(min_value = 0)]
@@ -69,6 +80,14 @@ pub struct Simulate {
     )]
     /// Specify the score
     score: Option<u32>,
+    #[command(
+        min_value = 1,
+        max_value = 9,
+        help = "Specify the key count for mania.\n\
+        On osu!standard maps this will simulate the map's mania convert."
+    )]
+    /// Specify the key count (mania only)
+    keys: Option<u8>,
 }
 
 impl TryFrom<Simulate> for SimulateArgs {
@@ -105,13 +124,16 @@ impl TryFrom<Simulate> for SimulateArgs {
         Ok(Self {
             map,
             mods,
+            n_geki: args.n320.map(|n| n as usize),
             n300: args.n300.map(|n| n as usize),
+            n_katu: args.n200.map(|n| n as usize),
             n100: args.n100.map(|n| n as usize),
             n50: args.n50.map(|n| n as usize),
             misses: args.misses.map(|n| n as usize),
             acc: args.acc,
             combo: args.combo.map(|n| n as usize),
             score: args.score,
+            keys: args.keys,
         })
     }
 }
@@ -122,20 +144,25 @@ impl TryFrom<Simulate> for SimulateArgs {
     "Simulate a (perfect) score on the given map. \
     Mods can be specified with `+mods` e.g. `+hdhr`.\n\
     There are also multiple options you can set by specifying `key=value`.\n\
-    For the keys `n300`, `n100`, `n50`, `misses`, `combo`, and `score` you must \
-    specify an interger value.\n\
+    For the keys `n320`, `n300`, `n200`, `n100`, `n50`, `misses`, `combo`, `score`, \
+    and `keys` you must specify an interger value.\n\
     For the `acc` key you must specify a number between 0.0 and 100.0.\n\
     If no map is given, I will choose the last map \
     I can find in the embeds of this channel.\n\
-    The `score` option is only relevant for mania."
+    The options `n320`, `n200`, `score`, and `keys` are only relevant for mania. \
+    If hitresults but no score are given for mania, the score will be approximated.\n\
+    Specifying `keys` on an osu!standard map will simulate its mania convert."
 )]
 #[usage(
-    "[map url / map id] [+mods] [acc=number] [combo=integer] [n300=integer] \
-    [n100=integer] [n50=integer] [misses=integer] [score=integer]"
+    "[map url / map id] [+mods] [acc=number] [combo=integer] [n320=integer] \
+    [n300=integer] [n200=integer] [n100=integer] [n50=integer] [misses=integer] \
+    [score=integer] [keys=integer]"
 )]
 #[example(
     "1980365 +hddt acc=99.3 combo=1234 n300=1422 n50=2 misses=1",
-    "https://osu.ppy.sh/beatmapsets/948199#osu/1980365 acc=97.56"
+    "https://osu.ppy.sh/beatmapsets/948199#osu/1980365 acc=97.56",
+    "1974394 n320=1650 n300=420 n200=13 n100=2 misses=1",
+    "1980365 keys=7 score=950000"
 )]
 #[alias("s")]
 #[group(AllModes)]
@@ -201,7 +228,13 @@ async fn simulate(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: SimulateArgs
     // Retrieving the beatmap
     let mut map = match map_result {
         Ok(map) => map,
-        Err(_) => match ctx.osu().beatmap().map_id(map_id).await {
+        Err(_) => match ctx
+            .osu()
+            .beatmap()
+            .map_id(map_id)
+            .observe(&ctx.stats, "beatmap")
+            .await
+        {
             Ok(map) => {
                 // Store map in DB
                 if let Err(err) = ctx.psql().insert_beatmap(&map).await {
@@ -227,6 +260,22 @@ async fn simulate(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: SimulateArgs
         },
     };
 
+    match (args.keys, map.mode) {
+        (None, _) | (Some(_), GameMode::Osu) => {}
+        (Some(keys), GameMode::Mania) if keys as f32 == map.cs => {}
+        (Some(_), GameMode::Mania) => {
+            let content = "The key count can only be changed for mania converts \
+                i.e. when simulating on an osu!standard map";
+
+            return orig.error(&ctx, content).await;
+        }
+        (Some(_), GameMode::Taiko | GameMode::Catch) => {
+            let content = "The key count can only be specified for osu!standard or mania maps";
+
+            return orig.error(&ctx, content).await;
+        }
+    }
+
     let mapset: BeatmapsetCompact = map.mapset.take().unwrap().into();
 
     let embeds_size = match config_result {
@@ -246,7 +295,8 @@ async fn simulate(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: SimulateArgs
 
     // Accumulate all necessary data
     let embed_data = match SimulateEmbed::new(None, &map, &mapset, args.into(), &ctx).await {
-        Ok(data) => data,
+        Ok(Ok(data)) => data,
+        Ok(Err(content)) => return orig.error(&ctx, content).await,
         Err(err) => {
             let _ = orig.error(&ctx, GENERAL_ISSUE).await;
 
@@ -304,13 +354,16 @@ async fn simulate(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: SimulateArgs
 pub struct SimulateArgs {
     map: Option<MapIdType>,
     pub mods: Option<ModSelection>,
+    pub n_geki: Option<usize>,
     pub n300: Option<usize>,
+    pub n_katu: Option<usize>,
     pub n100: Option<usize>,
     pub n50: Option<usize>,
     pub misses: Option<usize>,
     pub acc: Option<f32>,
     pub combo: Option<usize>,
     pub score: Option<u32>,
+    pub keys: Option<u8>,
 }
 
 macro_rules! parse_fail {
@@ -326,13 +379,16 @@ impl SimulateArgs {
     fn args(msg: &Message, args: Args<'_>) -> Result<Self, String> {
         let mut map = None;
         let mut mods = None;
+        let mut n_geki = None;
         let mut n300 = None;
+        let mut n_katu = None;
         let mut n100 = None;
         let mut n50 = None;
         let mut misses = None;
         let mut acc = None;
         let mut combo = None;
         let mut score = None;
+        let mut keys = None;
 
         for arg in args.map(|arg| arg.cow_to_ascii_lowercase()) {
             if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
@@ -340,10 +396,18 @@ impl SimulateArgs {
                 let value = &arg[idx + 1..];
 
                 match key {
+                    "n320" | "ngeki" | "geki" => match value.parse() {
+                        Ok(value) => n_geki = Some(value),
+                        Err(_) => parse_fail!(key, "a positive integer"),
+                    },
                     "n300" => match value.parse() {
                         Ok(value) => n300 = Some(value),
                         Err(_) => parse_fail!(key, "a positive integer"),
                     },
+                    "n200" | "nkatu" | "katu" => match value.parse() {
+                        Ok(value) => n_katu = Some(value),
+                        Err(_) => parse_fail!(key, "a positive integer"),
+                    },
                     "n100" => match value.parse() {
                         Ok(value) => n100 = Some(value),
                         Err(_) => parse_fail!(key, "a positive integer"),
@@ -368,6 +432,10 @@ impl SimulateArgs {
                         Ok(value) => score = Some(value),
                         Err(_) => parse_fail!(key, "a positive integer"),
                     },
+                    "keys" | "key" | "k" => match value.parse() {
+                        Ok(value @ 1..=9) => keys = Some(value),
+                        _ => parse_fail!(key, "an integer between 1 and 9"),
+                    },
                     "mods" => match value.parse() {
                         Ok(m) => mods = Some(ModSelection::Exact(m)),
                         Err(_) => return Err("Failed to parse mods. Be sure to specify a valid abbreviation e.g. `hdhr`.".to_owned()),
@@ -375,8 +443,8 @@ impl SimulateArgs {
                     _ => {
                         let content = format!(
                             "Unrecognized option `{key}`.\n\
-                            Available options are: `n300`, `n100`, `n50`, \
-                            `misses`, `acc`, `combo`, and `score`."
+                            Available options are: `n320`, `n300`, `n200`, `n100`, `n50`, \
+                            `misses`, `acc`, `combo`, `score`, and `keys`."
                         );
 
                         return Err(content);
@@ -412,13 +480,16 @@ impl SimulateArgs {
         Ok(Self {
             map,
             mods,
+            n_geki,
             n300,
+            n_katu,
             n100,
             n50,
             misses,
             acc,
             combo,
             score,
+            keys,
         })
     }
 }
@@ -7,7 +7,10 @@ use rosu_v2::prelude::{GameMode, OsuError};
 
 use crate::{
     commands::osu::{get_user, require_link, HasMods, ModsResult, UserArgs},
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        OsuFutureExt,
+    },
     custom_client::SnipeScoreParams,
     pagination::PlayerSnipeListPagination,
     util::{
@@ -160,7 +163,13 @@ pub(super) async fn player_list(
         let map_id = map_id as u32;
 
         if !maps.contains_key(&map_id) {
-            match ctx.osu().beatmap().map_id(map_id).await {
+            match ctx
+                .osu()
+                .beatmap()
+                .map_id(map_id)
+                .observe(&ctx.stats, "beatmap")
+                .await
+            {
                 Ok(map) => {
                     maps.insert(map_id, map);
                 }
@@ -9,7 +9,7 @@ use time::Date;
 
 use crate::{
     commands::osu::{get_user, require_link, UserArgs},
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, OsuFutureExt},
     embeds::{EmbedData, PlayerSnipeStatsEmbed},
     util::{
         builder::MessageBuilder,
@@ -135,7 +135,8 @@ pub(super) async fn player_stats(
             let score_fut = ctx
                 .osu()
                 .beatmap_user_score(oldest.beatmap_id, player.user_id)
-                .mode(GameMode::Osu);
+                .mode(GameMode::Osu)
+                .observe(&ctx.stats, "beatmap_user_score");
 
             match score_fut.await {
                 Ok(mut score) => match super::prepare_score(&ctx, &mut score.score).await {
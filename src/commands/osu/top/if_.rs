@@ -47,6 +47,10 @@ pub struct TopIf<'a> {
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
         ar, cs, hp, od, bpm, length, or stars like for example `fdfd ar>10 od>=9`.\n\
+        Scores can also be filtered by acc, pp, combo, miss, grade, mods, date, or rank \
+        e.g. `pp>500 mods>=hd date>=2022-01 grade=s`.\n\
+        Use `|` between words for alternatives, a leading `-` to exclude a word, \
+        and quotes for phrases e.g. `\"big black\" | -remix`.\n\
         While ar & co will be adjusted to mods, stars will not."
     )]
     /// Specify a search query containing artist, difficulty, AR, BPM, ...
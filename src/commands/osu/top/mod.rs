@@ -1,4 +1,4 @@
-use std::{borrow::Cow, fmt::Write, mem, sync::Arc};
+use std::{borrow::Cow, collections::HashSet, fmt::Write, mem, sync::Arc};
 
 use command_macros::{command, HasMods, HasName, SlashCommand};
 use eyre::{Report, Result};
@@ -18,9 +18,12 @@ use crate::{
         osu::{get_user_and_scores, ScoreArgs, UserArgs},
         GameModeOption, GradeOption,
     },
-    core::commands::{prefix::Args, CommandOrigin},
+    core::{
+        commands::{prefix::Args, CommandOrigin},
+        OsuFutureExt,
+    },
     custom_client::OsuTrackerMapsetEntry,
-    database::{EmbedsSize, ListSize, MinimizedPp},
+    database::{EmbedsSize, ListSize, MinimizedPp, ScoreKind},
     embeds::TopSingleEmbed,
     pagination::{TopCondensedPagination, TopPagination, TopSinglePagination},
     util::{
@@ -80,6 +83,10 @@ pub struct Top {
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
         ar, cs, hp, od, bpm, length, or stars like for example `fdfd ar>10 od>=9`.\n\
+        Scores can also be filtered by acc, pp, combo, miss, grade, mods, date, or rank \
+        e.g. `pp>500 mods>=hd date>=2022-01 grade=s`.\n\
+        Use `|` between words for alternatives, a leading `-` to exclude a word, \
+        and quotes for phrases e.g. `\"big black\" | -remix`.\n\
         While ar & co will be adjusted to mods, stars will not."
     )]
     /// Specify a search query containing artist, difficulty, AR, BPM, ...
@@ -100,6 +107,13 @@ pub struct Top {
       The default can be set with the `/config` command.")]
     /// Size of the embed
     size: Option<ListSize>,
+    #[command(
+        help = "Also include scores outside of the top100 that were archived by the bot.\n\
+        Recent scores are only archived for users who have used the `/link` command.\n\
+        Only the 1000 most recent archived scores are considered."
+    )]
+    /// Specify whether archived scores of linked users should be included
+    archive: Option<bool>,
 }
 
 #[derive(Copy, Clone, CommandOption, CreateOption, Eq, PartialEq)]
@@ -509,6 +523,7 @@ pub struct TopArgs<'a> {
     pub query: Option<String>,
     pub farm: Option<FarmFilter>,
     pub size: Option<ListSize>,
+    pub archive: bool,
     pub has_dash_r: bool,
     pub has_dash_p_or_i: bool,
 }
@@ -681,6 +696,7 @@ impl<'m> TopArgs<'m> {
             query: None,
             farm: None,
             size: None,
+            archive: false,
             has_dash_r: has_dash_r.unwrap_or(false),
             has_dash_p_or_i: has_dash_p_or_i.unwrap_or(false),
         };
@@ -716,6 +732,7 @@ impl TryFrom<Top> for TopArgs<'static> {
             query: args.query,
             farm: args.farm,
             size: args.size,
+            archive: args.archive.unwrap_or(false),
             has_dash_r: false,
             has_dash_p_or_i: false,
         })
@@ -724,6 +741,9 @@ impl TryFrom<Top> for TopArgs<'static> {
 
 const FARM_CUTOFF: usize = 727;
 
+/// Maximum amount of archived scores that are added to the top scores
+const ARCHIVE_LIMIT: usize = 1000;
+
 pub(super) async fn top(
     ctx: Arc<Context>,
     orig: CommandOrigin<'_>,
@@ -854,6 +874,25 @@ pub(super) async fn top(
     #[cfg(feature = "osutracking")]
     crate::tracking::process_osu_tracking(&ctx, &mut scores, Some(&user)).await;
 
+    if args.archive {
+        let archived_fut =
+            ctx.psql()
+                .get_archived_scores(user.user_id, mode, None, None, ARCHIVE_LIMIT);
+
+        match archived_fut.await {
+            Ok(archived) => {
+                let ids: HashSet<_> = scores.iter().map(|score| score.score_id).collect();
+                let archived = archived
+                    .into_iter()
+                    .filter(|score| !ids.contains(&score.score_id));
+
+                // Archived scores are indexed after the top100
+                scores.extend(archived);
+            }
+            Err(err) => warn!("{:?}", err.wrap_err("failed to get archived scores")),
+        }
+    }
+
     // Filter scores according to mods, combo, acc, and grade
     let scores = filter_scores(&ctx, scores, &args, &farm).await;
 
@@ -890,6 +929,7 @@ pub(super) async fn top(
                 num,
                 embeds_size,
                 minimized_pp,
+                config.score_kind(),
                 None,
             )
             .await?;
@@ -917,6 +957,7 @@ pub(super) async fn top(
                 0,
                 embeds_size,
                 minimized_pp,
+                config.score_kind(),
                 content,
             )
             .await?;
@@ -957,7 +998,9 @@ pub(super) async fn top(
                         (None, None) => MinimizedPp::default(),
                     };
 
-                    TopSinglePagination::builder(user, scores, minimized_pp)
+                    let score_kind = config.score_kind();
+
+                    TopSinglePagination::builder(user, scores, minimized_pp, score_kind)
                         .content(content.unwrap_or_default())
                         .start_by_update()
                         .defer_components()
@@ -1091,6 +1134,7 @@ async fn single_embed(
     idx: usize,
     embeds_size: EmbedsSize,
     minimized_pp: MinimizedPp,
+    score_kind: ScoreKind,
     content: Option<String>,
 ) -> Result<()> {
     let (idx, score) = scores.get(idx).unwrap();
@@ -1100,7 +1144,12 @@ async fn single_embed(
     let global_idx = match map.status {
         Ranked | Loved | Qualified | Approved => {
             // TODO: Add .limit(50) when supported by osu!api
-            match ctx.osu().beatmap_scores(map.map_id).await {
+            match ctx
+                .osu()
+                .beatmap_scores(map.map_id)
+                .observe(&ctx.stats, "beatmap_scores")
+                .await
+            {
                 Ok(scores) => scores.iter().position(|s| s == score),
                 Err(err) => {
                     let report = Report::new(err).wrap_err("Failed to get global scores");
@@ -1113,8 +1162,17 @@ async fn single_embed(
         _ => None,
     };
 
-    let embed_data =
-        TopSingleEmbed::new(&user, score, Some(*idx), global_idx, minimized_pp, &ctx).await?;
+    let embed_fut = TopSingleEmbed::new(
+        &user,
+        score,
+        Some(*idx),
+        global_idx,
+        minimized_pp,
+        score_kind,
+        &ctx,
+    );
+
+    let embed_data = embed_fut.await?;
 
     // Only maximize if config allows it
     match embeds_size {
@@ -1183,7 +1241,8 @@ fn write_content(name: &str, args: &TopArgs<'_>, amount: usize) -> Option<String
         || args.mods.is_some()
         || args.perfect_combo.is_some()
         || args.query.is_some()
-        || args.farm.is_some();
+        || args.farm.is_some()
+        || args.archive;
 
     if condition {
         Some(content_with_condition(args, amount))
@@ -1320,6 +1379,10 @@ fn content_with_condition(args: &TopArgs<'_>, amount: usize) -> String {
         None => {}
     }
 
+    if args.archive {
+        content.push_str(" ~ `Including archived scores`");
+    }
+
     let plural = if amount == 1 { "" } else { "s" };
     let _ = write!(content, "\nFound {amount} matching top score{plural}:");
 
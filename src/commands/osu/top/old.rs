@@ -1,4 +1,4 @@
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, cmp::Ordering, sync::Arc};
 
 use command_macros::{command, HasName, SlashCommand};
 use eyre::{Report, Result, WrapErr};
@@ -12,7 +12,7 @@ use twilight_model::id::{marker::UserMarker, Id};
 use crate::{
     commands::osu::{get_user_and_scores, require_link, ScoreArgs, ScoreOrder, UserArgs},
     core::commands::{prefix::Args, CommandOrigin},
-    pagination::TopIfPagination,
+    pagination::{TopIfPagination, TopOldComparePagination},
     util::{
         constants::{GENERAL_ISSUE, OSU_API_ISSUE},
         interaction::InteractionCommand,
@@ -66,6 +66,13 @@ pub struct TopOldOsu<'a> {
     )]
     /// Specify a linked discord user
     discord: Option<Id<UserMarker>>,
+    #[command(
+        help = "Instead of only showing the top plays on the previous pp system, \
+        show the current and the previous pp values side by side with their difference \
+        and a summary of which scores gained or lost the most."
+    )]
+    /// Compare the pp values with the current pp system
+    compare: Option<bool>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -132,6 +139,13 @@ pub struct TopOldTaiko<'a> {
     )]
     /// Specify a linked discord user
     discord: Option<Id<UserMarker>>,
+    #[command(
+        help = "Instead of only showing the top plays on the previous pp system, \
+        show the current and the previous pp values side by side with their difference \
+        and a summary of which scores gained or lost the most."
+    )]
+    /// Compare the pp values with the current pp system
+    compare: Option<bool>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -175,6 +189,13 @@ pub struct TopOldCatch<'a> {
     )]
     /// Specify a linked discord user
     discord: Option<Id<UserMarker>>,
+    #[command(
+        help = "Instead of only showing the top plays on the previous pp system, \
+        show the current and the previous pp values side by side with their difference \
+        and a summary of which scores gained or lost the most."
+    )]
+    /// Compare the pp values with the current pp system
+    compare: Option<bool>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -218,6 +239,13 @@ pub struct TopOldMania<'a> {
     )]
     /// Specify a linked discord user
     discord: Option<Id<UserMarker>>,
+    #[command(
+        help = "Instead of only showing the top plays on the previous pp system, \
+        show the current and the previous pp values side by side with their difference \
+        and a summary of which scores gained or lost the most."
+    )]
+    /// Compare the pp values with the current pp system
+    compare: Option<bool>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -253,6 +281,7 @@ pub async fn slash_topold(ctx: Arc<Context>, mut command: InteractionCommand) ->
     "Display how the user's **current** top100 would have looked like \
     in a previous year.\n\
     Note that the command will **not** change scores, just recalculate their pp.\n\
+    Add `-c` to show the current and previous pp values side by side.\n\
     The osu!standard pp history looks roughly like this:\n\
     - 2012: ppv1 (unavailable)\n\
     - 2014: ppv2 (unavailable)\n\
@@ -263,7 +292,7 @@ pub async fn slash_topold(ctx: Arc<Context>, mut command: InteractionCommand) ->
     - 2021: [Diff spike nerf, AR buff, FL-AR adjust](https://osu.ppy.sh/home/news/2021-07-27-performance-points-star-rating-updates)\n\
     - 2021: [Rhythm buff, slider buff, FL skill](https://osu.ppy.sh/home/news/2021-11-09-performance-points-star-rating-updates)"
 )]
-#[usage("[username] [year]")]
+#[usage("[username] [year] [-c]")]
 #[example("\"freddie benson\" 2015")]
 #[alias("to")]
 #[group(Osu)]
@@ -284,11 +313,12 @@ async fn prefix_topold(ctx: Arc<Context>, msg: &Message, args: Args<'_>) -> Resu
     "Display how the user's **current** top100 would have looked like \
     in a previous year.\n\
     Note that the command will **not** change scores, just recalculate their pp.\n\
+    Add `-c` to show the current and previous pp values side by side.\n\
     The osu!mania pp history looks roughly like this:\n\
     - 2014: ppv1\n\
     - 2018: [ppv2](https://osu.ppy.sh/home/news/2018-05-16-performance-updates)"
 )]
-#[usage("[username] [year]")]
+#[usage("[username] [year] [-c]")]
 #[example("\"freddie benson\" 2015")]
 #[alias("tom")]
 #[group(Mania)]
@@ -309,11 +339,12 @@ async fn prefix_topoldmania(ctx: Arc<Context>, msg: &Message, args: Args<'_>) ->
     "Display how the user's **current** top100 would have looked like \
     in a previous year.\n\
     Note that the command will **not** change scores, just recalculate their pp.\n\
+    Add `-c` to show the current and previous pp values side by side.\n\
     The osu!taiko pp history looks roughly like this:\n\
     - 2014: ppv1\n\
     - 2020: [Revamp](https://osu.ppy.sh/home/news/2020-09-15-changes-to-osutaiko-star-rating)"
 )]
-#[usage("[username] [year]")]
+#[usage("[username] [year] [-c]")]
 #[example("\"freddie benson\" 2015")]
 #[alias("tot")]
 #[group(Taiko)]
@@ -334,11 +365,12 @@ async fn prefix_topoldtaiko(ctx: Arc<Context>, msg: &Message, args: Args<'_>) ->
     "Display how the user's **current** top100 would have looked like \
     in a previous year.\n\
     Note that the command will **not** change scores, just recalculate their pp.\n\
+    Add `-c` to show the current and previous pp values side by side.\n\
     The osu!ctb pp history looks roughly like this:\n\
     - 2014: ppv1\n\
     - 2020: [Revamp](https://osu.ppy.sh/home/news/2020-05-14-osucatch-scoring-updates)"
 )]
-#[usage("[username] [year]")]
+#[usage("[username] [year] [-c]")]
 #[example("\"freddie benson\" 2019")]
 #[aliases("toc", "topoldcatch")]
 #[group(Catch)]
@@ -358,9 +390,12 @@ impl<'m> TopOld<'m> {
         let mut name = None;
         let mut discord = None;
         let mut year = None;
+        let mut compare = None;
 
-        for arg in args.take(2) {
-            if let Ok(num) = arg.parse() {
+        for arg in args.take(3) {
+            if matches!(arg, "-c" | "-compare") {
+                compare = Some(true);
+            } else if let Ok(num) = arg.parse() {
                 year = Some(num);
             } else if let Some(id) = matcher::get_mention_user(arg) {
                 discord = Some(id);
@@ -379,6 +414,7 @@ impl<'m> TopOld<'m> {
                     version,
                     name,
                     discord,
+                    compare,
                 };
 
                 Self::Osu(osu)
@@ -390,6 +426,7 @@ impl<'m> TopOld<'m> {
                     version,
                     name,
                     discord,
+                    compare,
                 };
 
                 Self::Taiko(taiko)
@@ -401,6 +438,7 @@ impl<'m> TopOld<'m> {
                     version,
                     name,
                     discord,
+                    compare,
                 };
 
                 Self::Catch(catch)
@@ -412,6 +450,7 @@ impl<'m> TopOld<'m> {
                     version,
                     name,
                     discord,
+                    compare,
                 };
 
                 Self::Mania(mania)
@@ -421,6 +460,17 @@ impl<'m> TopOld<'m> {
         Ok(args)
     }
 
+    fn compare(&self) -> bool {
+        let compare = match self {
+            TopOld::Osu(o) => o.compare,
+            TopOld::Taiko(t) => t.compare,
+            TopOld::Catch(c) => c.compare,
+            TopOld::Mania(m) => m.compare,
+        };
+
+        compare.unwrap_or(false)
+    }
+
     fn date_range(&self) -> &'static str {
         match self {
             TopOld::Osu(o) => match o.version {
@@ -596,6 +646,9 @@ async fn topold(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: TopOld<'_>) ->
         .as_ref()
         .map_or(0.0, |stats| stats.pp - actual_pp);
 
+    // Remember the pp of the current system before modifying the scores
+    let current_pps: Vec<_> = scores.iter().map(|score| score.pp.unwrap_or(0.0)).collect();
+
     let mut scores_data = match modify_scores(&ctx, scores, &args).await {
         Ok(scores) => scores,
         Err(err) => {
@@ -627,6 +680,28 @@ async fn topold(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: TopOld<'_>) ->
 
     let post_pp = user.statistics.as_ref().map_or(0.0, |stats| stats.pp);
 
+    if args.compare() {
+        let entries: Vec<_> = scores_data
+            .into_iter()
+            .zip(1..)
+            .map(|((old_idx, score, _), new_idx)| TopOldCompareEntry {
+                current_pp: current_pps[old_idx - 1],
+                old_idx,
+                new_idx,
+                score,
+            })
+            .collect();
+
+        let summary = TopOldCompareSummary::new(&entries);
+
+        return TopOldComparePagination::builder(user, entries, summary, post_pp, adjusted_pp)
+            .content(content)
+            .start_by_update()
+            .defer_components()
+            .start(ctx, orig)
+            .await;
+    }
+
     TopIfPagination::builder(user, scores_data, mode, post_pp, adjusted_pp, None)
         .content(content)
         .start_by_update()
@@ -635,6 +710,69 @@ async fn topold(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: TopOld<'_>) ->
         .await
 }
 
+pub struct TopOldCompareEntry {
+    /// Position in the top100 of the current pp system
+    pub old_idx: usize,
+    /// Position in the top100 of the chosen pp system
+    pub new_idx: usize,
+    /// pp value of the current pp system
+    pub current_pp: f32,
+    /// Score whose pp value was recalculated for the chosen pp system
+    pub score: Score,
+}
+
+impl TopOldCompareEntry {
+    pub fn pp_diff(&self) -> f32 {
+        self.score.pp.unwrap_or(0.0) - self.current_pp
+    }
+}
+
+/// Indices of the entries with the biggest pp gain and loss
+pub struct TopOldCompareSummary {
+    pub gains: Vec<usize>,
+    pub losses: Vec<usize>,
+    pub gained: usize,
+    pub lost: usize,
+}
+
+impl TopOldCompareSummary {
+    const AMOUNT: usize = 3;
+
+    fn new(entries: &[TopOldCompareEntry]) -> Self {
+        let mut indices: Vec<_> = (0..entries.len()).collect();
+
+        indices.sort_unstable_by(|&a, &b| {
+            entries[b]
+                .pp_diff()
+                .partial_cmp(&entries[a].pp_diff())
+                .unwrap_or(Ordering::Equal)
+        });
+
+        let gained = entries.iter().filter(|entry| entry.pp_diff() > 0.0).count();
+        let lost = entries.iter().filter(|entry| entry.pp_diff() < 0.0).count();
+
+        let gains = indices
+            .iter()
+            .copied()
+            .take(gained.min(Self::AMOUNT))
+            .collect();
+
+        let losses = indices
+            .iter()
+            .rev()
+            .copied()
+            .take(lost.min(Self::AMOUNT))
+            .collect();
+
+        Self {
+            gains,
+            losses,
+            gained,
+            lost,
+        }
+    }
+}
+
 async fn modify_scores(
     ctx: &Context,
     scores: Vec<Score>,
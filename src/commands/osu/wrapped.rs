@@ -0,0 +1,337 @@
+use std::{cmp::Ordering, collections::HashSet, sync::Arc};
+
+use command_macros::{HasName, SlashCommand};
+use eyre::{Report, Result};
+use handlebars::Handlebars;
+use hashbrown::HashMap;
+use once_cell::sync::Lazy;
+use rosu_v2::prelude::{GameMode, OsuError, Score, User};
+use serde::Serialize;
+use time::{Date, Month, OffsetDateTime, UtcOffset};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{
+    commands::GameModeOption,
+    core::{commands::CommandOrigin, Context},
+    embeds::{get_mods, EmbedData, WrappedEmbed},
+    util::{
+        builder::MessageBuilder,
+        constants::{GENERAL_ISSUE, MAP_THUMB_URL, OSU_API_ISSUE},
+        interaction::InteractionCommand,
+        numbers::round,
+        osu::flag_url_svg,
+        HtmlToPng, InteractionCommandExt,
+    },
+};
+
+use super::{get_user_and_scores, score_hours, GraphTopTimezone, ScoreArgs, UserArgs};
+
+/// Maximum amount of archived scores of the year that are considered
+const ARCHIVE_LIMIT: usize = 5000;
+
+static HTML_TEMPLATE: Lazy<Handlebars<'static>> = Lazy::new(|| {
+    let mut handlebars = Handlebars::new();
+
+    handlebars
+        .register_template_string("wrapped", include_str!("../../../templates/wrapped.hbs"))
+        .expect("failed to register wrapped template to handlebars");
+
+    handlebars
+});
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "wrapped",
+    help = "Create an image card that recaps a user's year on osu!.\n\
+    The card contains the most played mapsets, pp gained, the top play of the year, \
+    favourite mods and mappers, and at which hours of the day scores were set.\n\
+    Only the user's top100 and, for users who have used the `/link` command, \
+    their archived scores are considered.\n\
+    PP gains are only available if the user's stats have been stored during that year."
+)]
+/// Create a recap card of a user's year
+pub struct Wrapped {
+    /// Specify a gamemode
+    mode: Option<GameModeOption>,
+    /// Specify a username
+    name: Option<String>,
+    #[command(min_value = 2007, max_value = 2100)]
+    /// Specify a year, defaults to the current one
+    year: Option<i32>,
+    /// Specify a timezone for the hour-of-day activity
+    timezone: Option<GraphTopTimezone>,
+    #[command(
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    /// Specify a linked discord user
+    discord: Option<Id<UserMarker>>,
+}
+
+async fn slash_wrapped(ctx: Arc<Context>, mut command: InteractionCommand) -> Result<()> {
+    let args = Wrapped::from_interaction(command.input_data())?;
+
+    wrapped(ctx, (&mut command).into(), args).await
+}
+
+async fn wrapped(ctx: Arc<Context>, orig: CommandOrigin<'_>, args: Wrapped) -> Result<()> {
+    let (name, mode) = name_mode!(ctx, orig, args);
+
+    let tz = args.timezone.map_or(UtcOffset::UTC, UtcOffset::from);
+    let current_year = OffsetDateTime::now_utc().to_offset(tz).year();
+    let year = args.year.unwrap_or(current_year);
+
+    if year > current_year {
+        let content = "Can't recap a year that hasn't started yet";
+
+        return orig.error(&ctx, content).await;
+    }
+
+    let year_start = match Date::from_calendar_date(year, Month::January, 1) {
+        Ok(date) => date.midnight().assume_offset(tz),
+        Err(_) => return orig.error(&ctx, "Invalid year").await,
+    };
+
+    let user_args = UserArgs::new(name.as_str(), mode);
+    let score_args = ScoreArgs::top(100);
+
+    let (mut user, top) = match get_user_and_scores(&ctx, user_args, &score_args).await {
+        Ok(tuple) => tuple,
+        Err(OsuError::NotFound) => {
+            let content = format!("User `{name}` was not found");
+
+            return orig.error(&ctx, content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(&ctx, OSU_API_ISSUE).await;
+            let report = Report::new(err).wrap_err("failed to get user or scores");
+
+            return Err(report);
+        }
+    };
+
+    // Overwrite default mode
+    user.mode = mode;
+
+    let year_end = Date::from_calendar_date(year + 1, Month::January, 1)
+        .ok()
+        .map(|date| date.midnight().assume_offset(tz));
+
+    let archive_fut = ctx.psql().get_archived_scores(
+        user.user_id,
+        mode,
+        Some(year_start),
+        year_end,
+        ARCHIVE_LIMIT,
+    );
+    let history_fut = ctx
+        .psql()
+        .get_osu_user_stats_history(user.user_id, mode, year_start);
+
+    let (archive_result, history_result) = tokio::join!(archive_fut, history_fut);
+
+    let mut scores = top;
+
+    match archive_result {
+        Ok(archived) => {
+            let ids: HashSet<_> = scores.iter().map(|score| score.score_id).collect();
+            let archived = archived
+                .into_iter()
+                .filter(|score| !ids.contains(&score.score_id));
+
+            scores.extend(archived);
+        }
+        Err(err) => warn!("{:?}", err.wrap_err("failed to get archived scores")),
+    }
+
+    scores.retain(|score| score.ended_at.to_offset(tz).year() == year);
+
+    if scores.is_empty() {
+        let content = format!("No scores of `{name}` found for {year}");
+
+        return orig.error(&ctx, content).await;
+    }
+
+    let pp_gain = match history_result {
+        Ok(history) => {
+            let last = history
+                .iter()
+                .rev()
+                .find(|snapshot| snapshot.timestamp.to_offset(tz).year() == year);
+
+            // Use the current stats if the year is still ongoing
+            let last_pp = match (year == current_year, user.statistics.as_ref()) {
+                (true, Some(stats)) => Some(stats.pp),
+                _ => last.map(|snapshot| snapshot.pp),
+            };
+
+            history
+                .first()
+                .zip(last_pp)
+                .map(|(first, last)| last - first.pp)
+        }
+        Err(err) => {
+            warn!("{:?}", err.wrap_err("failed to get stats history"));
+
+            None
+        }
+    };
+
+    let render_data = WrappedData::new(&user, &scores, year, tz, pp_gain);
+
+    let html = match HTML_TEMPLATE.render("wrapped", &render_data) {
+        Ok(rendered) => rendered,
+        Err(err) => {
+            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+            let report = Report::new(err).wrap_err("failed to render wrapped template");
+
+            return Err(report);
+        }
+    };
+
+    let bytes = match HtmlToPng::convert(&html) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = orig.error(&ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to convert html"));
+        }
+    };
+
+    let embed = WrappedEmbed::new(&user, year).build();
+
+    let builder = MessageBuilder::new()
+        .attachment("wrapped.png", bytes)
+        .embed(embed);
+
+    orig.create_message(&ctx, &builder).await?;
+
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct WrappedData {
+    year: i32,
+    username: String,
+    user_pfp: String,
+    flag: String,
+    gamemode: &'static str,
+    score_count: usize,
+    pp_gained: Option<f32>,
+    top_play: Option<WrappedTopPlay>,
+    mapsets: Vec<WrappedCount>,
+    mods: Vec<WrappedCount>,
+    mappers: Vec<WrappedCount>,
+    hours: Vec<WrappedHour>,
+}
+
+#[derive(Serialize)]
+struct WrappedTopPlay {
+    title: String,
+    version: String,
+    mods: String,
+    pp: f32,
+    accuracy: f32,
+    cover: String,
+}
+
+#[derive(Serialize)]
+struct WrappedCount {
+    name: String,
+    count: usize,
+}
+
+#[derive(Serialize)]
+struct WrappedHour {
+    hour: u8,
+    count: u32,
+    /// Count relative to the busiest hour
+    percentage: u32,
+}
+
+impl WrappedData {
+    const LIST_LEN: usize = 5;
+
+    fn new(user: &User, scores: &[Score], year: i32, tz: UtcOffset, pp: Option<f32>) -> Self {
+        let top_play = scores
+            .iter()
+            .filter(|score| score.pp.is_some())
+            .max_by(|a, b| a.pp.partial_cmp(&b.pp).unwrap_or(Ordering::Equal))
+            .and_then(|score| {
+                let map = score.map.as_ref()?;
+                let mapset = score.mapset.as_ref()?;
+
+                Some(WrappedTopPlay {
+                    title: format!("{} - {}", mapset.artist, mapset.title),
+                    version: map.version.to_owned(),
+                    mods: get_mods(score.mods),
+                    pp: round(score.pp.unwrap_or(0.0)),
+                    accuracy: round(score.accuracy),
+                    cover: format!("{MAP_THUMB_URL}{}l.jpg", mapset.mapset_id),
+                })
+            });
+
+        let mut mapsets = HashMap::new();
+        let mut mods = HashMap::new();
+        let mut mappers = HashMap::new();
+
+        for score in scores {
+            if let Some(ref mapset) = score.mapset {
+                let name = format!("{} - {}", mapset.artist, mapset.title);
+                *mapsets.entry(name).or_insert(0) += 1;
+                *mappers.entry(mapset.creator_name.to_string()).or_insert(0) += 1;
+            }
+
+            *mods.entry(score.mods.to_string()).or_insert(0) += 1;
+        }
+
+        let hours = score_hours(scores, tz);
+        let max_hours = hours.iter().max().copied().unwrap_or(0).max(1);
+
+        let hours = hours
+            .into_iter()
+            .enumerate()
+            .map(|(hour, count)| WrappedHour {
+                hour: hour as u8,
+                count,
+                percentage: 100 * count / max_hours,
+            })
+            .collect();
+
+        let gamemode = match user.mode {
+            GameMode::Osu => "osu!standard",
+            GameMode::Taiko => "osu!taiko",
+            GameMode::Catch => "osu!catch",
+            GameMode::Mania => "osu!mania",
+        };
+
+        Self {
+            year,
+            username: user.username.to_string(),
+            user_pfp: user.avatar_url.to_owned(),
+            flag: flag_url_svg(&user.country_code),
+            gamemode,
+            score_count: scores.len(),
+            pp_gained: pp.map(round),
+            top_play,
+            mapsets: most_common(mapsets),
+            mods: most_common(mods),
+            mappers: most_common(mappers),
+            hours,
+        }
+    }
+}
+
+fn most_common(counts: HashMap<String, usize>) -> Vec<WrappedCount> {
+    let mut counts: Vec<_> = counts
+        .into_iter()
+        .map(|(name, count)| WrappedCount { name, count })
+        .collect();
+
+    counts.sort_unstable_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+    counts.truncate(WrappedData::LIST_LEN);
+
+    counts
+}
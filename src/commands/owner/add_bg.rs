@@ -8,7 +8,7 @@ use tokio::{
 };
 
 use crate::{
-    core::BotConfig,
+    core::{BotConfig, OsuFutureExt},
     util::{
         builder::MessageBuilder,
         constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
@@ -124,7 +124,12 @@ async fn prepare_mapset(
 
     let mapset = match db_fut.await {
         Ok(mapset) => mapset,
-        Err(_) => match ctx.osu().beatmapset(mapset_id).await {
+        Err(_) => match ctx
+            .osu()
+            .beatmapset(mapset_id)
+            .observe(&ctx.stats, "beatmapset")
+            .await
+        {
             Ok(mapset) => {
                 if let Err(err) = ctx.psql().insert_beatmapset(&mapset).await {
                     warn!(
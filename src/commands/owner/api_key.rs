@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use eyre::Result;
+use rand::{distributions::Alphanumeric, Rng};
+
+use crate::{
+    util::{
+        builder::MessageBuilder, constants::GENERAL_ISSUE, interaction::InteractionCommand,
+        InteractionCommandExt,
+    },
+    Context,
+};
+
+use super::{OwnerApiKey, OwnerApiKeyCreate, OwnerApiKeyRevoke};
+
+const KEY_LEN: usize = 32;
+const DEFAULT_RATELIMIT: u32 = 60;
+
+pub async fn apikey(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: OwnerApiKey,
+) -> Result<()> {
+    match args {
+        OwnerApiKey::Create(args) => create(ctx, command, args).await,
+        OwnerApiKey::Revoke(args) => revoke(ctx, command, args).await,
+    }
+}
+
+async fn create(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: OwnerApiKeyCreate,
+) -> Result<()> {
+    let OwnerApiKeyCreate {
+        user,
+        label,
+        ratelimit,
+    } = args;
+
+    if label.chars().count() > 64 {
+        let content = "The label must be at most 64 characters long";
+        command.error_callback(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let key: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(KEY_LEN)
+        .map(char::from)
+        .collect();
+
+    let ratelimit = ratelimit.map_or(DEFAULT_RATELIMIT, |limit| limit as u32);
+    let insert_fut = ctx.psql().insert_api_key(&key, user, &label, ratelimit);
+
+    let key_id = match insert_fut.await {
+        Ok(key_id) => key_id,
+        Err(err) => {
+            let _ = command.error_callback(&ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to insert api key"));
+        }
+    };
+
+    let content = format!(
+        "Created API key #{key_id} `{label}` for <@{user}> \
+        with a limit of {ratelimit} requests per minute:\n||`{key}`||"
+    );
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(&ctx, builder, true).await?;
+
+    Ok(())
+}
+
+async fn revoke(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: OwnerApiKeyRevoke,
+) -> Result<()> {
+    let key_id = args.key_id as u32;
+
+    let content = match ctx.psql().remove_api_key(key_id).await {
+        Ok(true) => format!("Revoked API key #{key_id}"),
+        Ok(false) => format!("There is no API key with id #{key_id}"),
+        Err(err) => {
+            let _ = command.error_callback(&ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to remove api key"));
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(&ctx, builder, true).await?;
+
+    Ok(())
+}
@@ -5,6 +5,9 @@ use eyre::Result;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::channel::Attachment;
 
+#[cfg(feature = "server")]
+use twilight_model::id::{marker::UserMarker, Id};
+
 use crate::{
     util::{interaction::InteractionCommand, InteractionCommandExt},
     Context,
@@ -18,12 +21,18 @@ use self::{add_bg::*, add_country::*, cache::*};
 #[cfg(feature = "osutracking")]
 use self::{tracking_interval::*, tracking_stats::*};
 
+#[cfg(feature = "server")]
+use self::api_key::*;
+
 use super::GameModeOption;
 
 mod add_bg;
 mod add_country;
 mod cache;
 
+#[cfg(feature = "server")]
+mod api_key;
+
 #[cfg(feature = "osutracking")]
 mod tracking_interval;
 
@@ -39,6 +48,9 @@ pub enum Owner {
     AddBg(OwnerAddBg),
     #[command(name = "add_country")]
     AddCountry(OwnerAddCountry),
+    #[cfg(feature = "server")]
+    #[command(name = "api_key")]
+    ApiKey(OwnerApiKey),
     #[command(name = "cache")]
     Cache(OwnerCache),
     #[cfg(feature = "osutracking")]
@@ -66,6 +78,41 @@ pub struct OwnerAddCountry {
     name: String,
 }
 
+#[cfg(feature = "server")]
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "api_key")]
+/// Manage keys for the web API
+pub enum OwnerApiKey {
+    #[command(name = "create")]
+    Create(OwnerApiKeyCreate),
+    #[command(name = "revoke")]
+    Revoke(OwnerApiKeyRevoke),
+}
+
+#[cfg(feature = "server")]
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "create")]
+/// Create a new API key
+pub struct OwnerApiKeyCreate {
+    /// Specify the user that receives the key
+    user: Id<UserMarker>,
+    /// Specify a label for the key
+    label: String,
+    #[command(min_value = 1, max_value = 600)]
+    /// Specify the maximum amount of requests per minute, defaults to 60
+    ratelimit: Option<i64>,
+}
+
+#[cfg(feature = "server")]
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "revoke")]
+/// Revoke an API key
+pub struct OwnerApiKeyRevoke {
+    #[command(min_value = 1)]
+    /// Specify the id of the key
+    key_id: i64,
+}
+
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "cache")]
 /// Display stats about the internal cache
@@ -109,6 +156,8 @@ async fn slash_owner(ctx: Arc<Context>, mut command: InteractionCommand) -> Resu
     match Owner::from_interaction(command.input_data())? {
         Owner::AddBg(bg) => addbg(ctx, command, bg).await,
         Owner::AddCountry(country) => addcountry(ctx, command, country).await,
+        #[cfg(feature = "server")]
+        Owner::ApiKey(args) => apikey(ctx, command, args).await,
         Owner::Cache(_) => cache(ctx, command).await,
         #[cfg(feature = "osutracking")]
         Owner::Tracking(OwnerTracking::Interval(interval)) => {
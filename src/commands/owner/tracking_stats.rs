@@ -14,7 +14,11 @@ use crate::{
 
 pub async fn trackingstats(ctx: Arc<Context>, command: InteractionCommand) -> Result<()> {
     let stats = ctx.tracking().stats().await;
-    let entry = stats.next_pop;
+
+    let next_pop = match stats.next_pop {
+        Some(entry) => format!("{} | {}", entry.user_id, entry.mode),
+        None => "-".to_owned(),
+    };
 
     let fields = vec![
         EmbedField {
@@ -39,7 +43,7 @@ pub async fn trackingstats(ctx: Arc<Context>, command: InteractionCommand) -> Re
         },
         EmbedField {
             name: "Next pop".to_owned(),
-            value: format!("{} | {}", entry.user_id, entry.mode),
+            value: next_pop,
             inline: true,
         },
     ];
@@ -97,6 +97,7 @@ async fn song(
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "song")]
 #[flags(SKIP_DEFER)]
+#[group(Songs)]
 /// Let me sing a song for you
 pub struct Song {
     #[command(help = "Currently available: \
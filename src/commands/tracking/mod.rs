@@ -26,6 +26,7 @@ mod untrack_all;
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "track")]
 #[flags(AUTHORITY, ONLY_GUILDS)]
+#[group(Tracking)]
 /// Track top score updates for players
 pub enum Track {
     #[command(name = "add")]
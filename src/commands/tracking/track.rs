@@ -2,11 +2,16 @@ use std::sync::Arc;
 
 use command_macros::command;
 use eyre::{Report, Result};
-use rosu_v2::prelude::{GameMode, OsuError};
+use rosu_v2::prelude::{GameMode, OsuError, Username};
 use time::OffsetDateTime;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+    Id,
+};
 
 use crate::{
     core::commands::CommandOrigin,
+    database::AuditAction,
     embeds::{EmbedData, TrackEmbed},
     util::{builder::MessageBuilder, constants::OSU_API_ISSUE, ChannelExt},
     Context,
@@ -65,6 +70,8 @@ pub(super) async fn track(
         }
     };
 
+    let guild = orig.guild_id().unwrap();
+    let author = orig.user_id()?;
     let channel = orig.channel_id();
     let mut success = Vec::with_capacity(users.len());
     let mut failure = Vec::new();
@@ -84,6 +91,7 @@ pub(super) async fn track(
             Ok(false) => failure.push(username),
             Err(err) => {
                 warn!("{:?}", err.wrap_err("Failed to add tracked entry"));
+                audit(&ctx, guild, author, channel, mode, limit, &success).await;
 
                 let embed = TrackEmbed::new(mode, success, failure, Some(username), limit).build();
 
@@ -95,6 +103,8 @@ pub(super) async fn track(
         }
     }
 
+    audit(&ctx, guild, author, channel, mode, limit, &success).await;
+
     let embed = TrackEmbed::new(mode, success, failure, None, limit);
     let builder = MessageBuilder::new().embed(embed.build());
     orig.create_message(&ctx, &builder).await?;
@@ -102,6 +112,29 @@ pub(super) async fn track(
     Ok(())
 }
 
+async fn audit(
+    ctx: &Context,
+    guild: Id<GuildMarker>,
+    author: Id<UserMarker>,
+    channel: Id<ChannelMarker>,
+    mode: GameMode,
+    limit: usize,
+    names: &[Username],
+) {
+    if names.is_empty() {
+        return;
+    }
+
+    let names: Vec<_> = names.iter().map(Username::as_str).collect();
+    let details = format!(
+        "{mode} in <#{channel}> (limit {limit}): {}",
+        names.join(", ")
+    );
+
+    ctx.audit_log(guild, author, AuditAction::TrackAdd, details)
+        .await;
+}
+
 #[command]
 #[desc("Track osu!standard user top scores")]
 #[help(
@@ -10,7 +10,7 @@ use rosu_v2::{
 use twilight_model::id::{marker::ChannelMarker, Id};
 
 use crate::{
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, OsuFutureExt},
     embeds::{EmbedData, TrackListEmbed},
     util::{builder::MessageBuilder, constants::OSU_API_ISSUE},
     Context,
@@ -94,7 +94,13 @@ async fn get_users(
                 mode,
                 limit,
             },
-            None => match ctx.osu().user(user_id).mode(mode).await {
+            None => match ctx
+                .osu()
+                .user(user_id)
+                .mode(mode)
+                .observe(&ctx.stats, "user")
+                .await
+            {
                 Ok(user) => {
                     if let Err(err) = ctx.psql().upsert_osu_user(&user, mode).await {
                         warn!("{:?}", err.wrap_err("Failed to upsert user"));
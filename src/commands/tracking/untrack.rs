@@ -7,6 +7,7 @@ use rosu_v2::prelude::{GameMode, OsuError, Username};
 
 use crate::{
     core::commands::CommandOrigin,
+    database::AuditAction,
     embeds::{EmbedData, UntrackEmbed},
     util::{builder::MessageBuilder, constants::OSU_API_ISSUE, ChannelExt},
     Context,
@@ -84,12 +85,12 @@ pub(super) async fn untrack(
             Err(err) => {
                 warn!("{:?}", err.wrap_err("Failed to remove tracked entry"));
 
-                return send_message(&ctx, orig, Some(&username), success).await;
+                return send_message(&ctx, orig, mode, Some(&username), success).await;
             }
         };
     }
 
-    send_message(&ctx, orig, None, success).await?;
+    send_message(&ctx, orig, mode, None, success).await?;
 
     Ok(())
 }
@@ -97,9 +98,25 @@ pub(super) async fn untrack(
 async fn send_message(
     ctx: &Context,
     orig: CommandOrigin<'_>,
+    mode: Option<GameMode>,
     name: Option<&Username>,
     success: HashSet<Username>,
 ) -> Result<()> {
+    if !success.is_empty() {
+        let guild = orig.guild_id().unwrap();
+        let channel = orig.channel_id();
+        let names: Vec<_> = success.iter().map(Username::as_str).collect();
+        let names = names.join(", ");
+
+        let details = match mode {
+            Some(mode) => format!("{mode} in <#{channel}>: {names}"),
+            None => format!("All modes in <#{channel}>: {names}"),
+        };
+
+        ctx.audit_log(guild, orig.user_id()?, AuditAction::TrackRemove, details)
+            .await;
+    }
+
     let success = success.into_iter().collect();
     let embed = UntrackEmbed::new(success, name).build();
     let builder = MessageBuilder::new().embed(embed);
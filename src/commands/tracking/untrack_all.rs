@@ -6,6 +6,7 @@ use rosu_v2::model::GameMode;
 
 use crate::{
     core::commands::CommandOrigin,
+    database::AuditAction,
     util::{builder::MessageBuilder, constants::GENERAL_ISSUE, ChannelExt},
     Context,
 };
@@ -51,6 +52,18 @@ pub async fn untrackall(
 
     match remove_fut.await {
         Ok(amount) => {
+            if amount > 0 {
+                let guild = orig.guild_id().unwrap();
+
+                let details = match mode {
+                    Some(mode) => format!("All {amount} {mode} users in <#{channel_id}>"),
+                    None => format!("All {amount} users in <#{channel_id}>"),
+                };
+
+                ctx.audit_log(guild, orig.user_id()?, AuditAction::TrackRemove, details)
+                    .await;
+            }
+
             let content = format!("Untracked {amount} users in this channel");
             let builder = MessageBuilder::new().content(content);
             orig.create_message(&ctx, &builder).await?;
@@ -3,6 +3,7 @@ use eyre::Result;
 
 use crate::{
     core::commands::CommandOrigin,
+    database::AuditAction,
     util::{
         builder::MessageBuilder,
         constants::{GENERAL_ISSUE, TWITCH_API_ISSUE},
@@ -59,6 +60,15 @@ pub async fn addstream(ctx: Arc<Context>, orig: CommandOrigin<'_>, name: &'_ str
 
             trace!("Now tracking twitch stream {name} for channel {channel}");
 
+            if let Some(guild) = orig.guild_id() {
+                let details = format!("`{name}` in <#{channel}>");
+                let action = AuditAction::StreamAdd;
+                ctx.audit_log(guild, orig.user_id()?, action, details).await;
+            }
+
+            #[cfg(all(feature = "server", feature = "twitchtracking"))]
+            crate::server::subscribe_stream(&ctx, twitch_id).await;
+
             orig.create_message(&ctx, &builder).await?;
 
             Ok(())
@@ -24,6 +24,7 @@ pub mod tracked;
     When the stream goes online, a notification will be send to this channel within a few minutes."
 )]
 #[flags(AUTHORITY, ONLY_GUILDS)]
+#[group(Twitch)]
 /// Track a twitch stream or list all tracked streams in this channel
 pub enum TrackStream {
     #[command(name = "add")]
@@ -3,6 +3,7 @@ use eyre::Result;
 
 use crate::{
     core::commands::CommandOrigin,
+    database::AuditAction,
     util::{
         builder::MessageBuilder,
         constants::{GENERAL_ISSUE, TWITCH_API_ISSUE},
@@ -57,6 +58,15 @@ pub async fn removestream(ctx: Arc<Context>, orig: CommandOrigin<'_>, name: &'_
         Ok(true) => {
             trace!("No longer tracking {name}'s twitch for channel {channel}");
 
+            if let Some(guild) = orig.guild_id() {
+                let details = format!("`{name}` in <#{channel}>");
+                let action = AuditAction::StreamRemove;
+                ctx.audit_log(guild, orig.user_id()?, action, details).await;
+            }
+
+            #[cfg(all(feature = "server", feature = "twitchtracking"))]
+            crate::server::unsubscribe_stream(&ctx, twitch_id).await;
+
             let content =
                 format!("I'm no longer tracking `{name}`'s twitch stream in this channel");
 
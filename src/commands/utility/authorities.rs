@@ -58,7 +58,9 @@ pub async fn authorities(
                 return orig.error_callback(&ctx, content).await;
             }
 
-            let update_fut = ctx.update_guild_config(guild_id, move |config| {
+            let author_id = orig.user_id()?;
+
+            let update_fut = ctx.update_guild_config(guild_id, author_id, move |config| {
                 if !config.authorities.contains(&role_id) {
                     config.authorities.push(role_id);
                 }
@@ -125,7 +127,7 @@ pub async fn authorities(
                 }
             }
 
-            let update_fut = ctx.update_guild_config(guild_id, move |config| {
+            let update_fut = ctx.update_guild_config(guild_id, author_id, move |config| {
                 config.authorities.retain(|id| *id != role_id);
             });
 
@@ -178,7 +180,7 @@ pub async fn authorities(
                 }
             }
 
-            let update_fut = ctx.update_guild_config(guild_id, move |config| {
+            let update_fut = ctx.update_guild_config(guild_id, author_id, move |config| {
                 config.authorities = roles.into_iter().map(|role| role.get()).collect();
             });
 
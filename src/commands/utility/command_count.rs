@@ -13,6 +13,7 @@ use crate::{
 #[derive(CreateCommand, SlashCommand)]
 #[command(name = "commands")]
 #[flags(SKIP_DEFER)]
+#[group(Utility)]
 /// Display a list of popular commands
 pub struct Commands;
 
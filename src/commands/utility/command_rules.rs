@@ -0,0 +1,264 @@
+use std::{fmt::Write, sync::Arc};
+
+use eyre::Result;
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker},
+    Id,
+};
+
+use crate::{
+    core::commands::{
+        prefix::{PrefixCommandGroup, PrefixCommands},
+        slash::SlashCommands,
+    },
+    database::{CommandTarget, DisabledCommand},
+    util::{
+        builder::MessageBuilder, constants::GENERAL_ISSUE, interaction::InteractionCommand,
+        Authored, InteractionCommandExt,
+    },
+    Context,
+};
+
+/// Maximum amount of disabled commands per guild
+const RULES_LIMIT: usize = 50;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "commands",
+    help = "Disable commands or groups of commands, either for the whole server \
+    or only for specific channels.\n\
+    Disabling a command affects its slash command as well as all aliases of its prefix command.\n\
+    Disabling a group affects all prefix commands of that group \
+    as well as its slash commands.\n\
+    The `serverconfig` command itself can not be disabled."
+)]
+/// Disable or enable commands for this server or its channels
+pub enum ServerConfigCommands {
+    #[command(name = "disable")]
+    Disable(ServerConfigCommandsDisable),
+    #[command(name = "enable")]
+    Enable(ServerConfigCommandsEnable),
+    #[command(name = "list")]
+    List(ServerConfigCommandsList),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "disable")]
+/// Disable a command or a group of commands
+pub struct ServerConfigCommandsDisable {
+    /// Specify the name of a command
+    command: Option<String>,
+    /// Specify a group of commands
+    group: Option<CommandGroupOption>,
+    #[command(channel_types = "guild_text")]
+    /// Only disable in this channel instead of the whole server
+    channel: Option<Id<ChannelMarker>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "enable")]
+/// Enable a previously disabled command or group of commands
+pub struct ServerConfigCommandsEnable {
+    /// Specify the name of a command
+    command: Option<String>,
+    /// Specify a group of commands
+    group: Option<CommandGroupOption>,
+    #[command(channel_types = "guild_text")]
+    /// Specify the channel if the command was only disabled in that channel
+    channel: Option<Id<ChannelMarker>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list")]
+/// Display all disabled commands
+pub struct ServerConfigCommandsList;
+
+#[derive(CommandOption, CreateOption)]
+pub enum CommandGroupOption {
+    #[option(name = "Games", value = "games")]
+    Games,
+    #[option(name = "Songs", value = "songs")]
+    Songs,
+    #[option(name = "osu!tracking", value = "tracking")]
+    Tracking,
+    #[option(name = "Twitch", value = "twitch")]
+    Twitch,
+    #[option(name = "Utility", value = "utility")]
+    Utility,
+}
+
+impl From<CommandGroupOption> for PrefixCommandGroup {
+    fn from(group: CommandGroupOption) -> Self {
+        match group {
+            CommandGroupOption::Games => Self::Games,
+            CommandGroupOption::Songs => Self::Songs,
+            CommandGroupOption::Tracking => Self::Tracking,
+            CommandGroupOption::Twitch => Self::Twitch,
+            CommandGroupOption::Utility => Self::Utility,
+        }
+    }
+}
+
+pub(super) async fn command_rules(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    guild_id: Id<GuildMarker>,
+    args: ServerConfigCommands,
+) -> Result<()> {
+    let content = match args {
+        ServerConfigCommands::Disable(args) => {
+            let rule = match parse_rule(args.command, args.group, args.channel) {
+                Ok(rule) => rule,
+                Err(content) => {
+                    command.error_callback(&ctx, content).await?;
+
+                    return Ok(());
+                }
+            };
+
+            let config = ctx.guild_config(guild_id).await;
+
+            if config.disabled_commands.contains(&rule) {
+                let content = format!("{rule} is already disabled");
+                command.error_callback(&ctx, content).await?;
+
+                return Ok(());
+            } else if config.disabled_commands.len() >= RULES_LIMIT {
+                let content = format!("Servers can have at most {RULES_LIMIT} disabled commands");
+                command.error_callback(&ctx, content).await?;
+
+                return Ok(());
+            }
+
+            let content = format!("Disabled {rule}");
+
+            let update_fut = ctx.update_guild_config(guild_id, command.user_id()?, |config| {
+                config.disabled_commands.push(rule);
+            });
+
+            if let Err(err) = update_fut.await {
+                let _ = command.error_callback(&ctx, GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("failed to update guild config"));
+            }
+
+            content
+        }
+        ServerConfigCommands::Enable(args) => {
+            let rule = match parse_rule(args.command, args.group, args.channel) {
+                Ok(rule) => rule,
+                Err(content) => {
+                    command.error_callback(&ctx, content).await?;
+
+                    return Ok(());
+                }
+            };
+
+            let config = ctx.guild_config(guild_id).await;
+
+            if !config.disabled_commands.contains(&rule) {
+                let content = format!("{rule} was not disabled to begin with");
+                command.error_callback(&ctx, content).await?;
+
+                return Ok(());
+            }
+
+            let content = format!("Enabled {rule}");
+
+            let update_fut = ctx.update_guild_config(guild_id, command.user_id()?, |config| {
+                config
+                    .disabled_commands
+                    .retain(|disabled| disabled != &rule);
+            });
+
+            if let Err(err) = update_fut.await {
+                let _ = command.error_callback(&ctx, GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("failed to update guild config"));
+            }
+
+            content
+        }
+        ServerConfigCommands::List(_) => {
+            let config = ctx.guild_config(guild_id).await;
+
+            if config.disabled_commands.is_empty() {
+                "No commands are disabled in this server".to_owned()
+            } else {
+                let mut content = String::from("Disabled commands:\n");
+
+                for rule in config.disabled_commands.iter() {
+                    let _ = writeln!(content, "- {rule}");
+                }
+
+                content
+            }
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(&ctx, builder, false).await?;
+
+    Ok(())
+}
+
+fn parse_rule(
+    command: Option<String>,
+    group: Option<CommandGroupOption>,
+    channel: Option<Id<ChannelMarker>>,
+) -> Result<DisabledCommand, String> {
+    let target = match (command, group) {
+        (Some(name), None) => CommandTarget::Command(command_name(&name)?),
+        (None, Some(group)) => CommandTarget::Group(group.into()),
+        (Some(_), Some(_)) => return Err("Specify either a command or a group, not both".into()),
+        (None, None) => return Err("You must specify either a command or a group".into()),
+    };
+
+    let rule = DisabledCommand {
+        target,
+        channel: channel.map(Id::get),
+    };
+
+    Ok(rule)
+}
+
+/// Returns the canonical name of the slash or prefix command that matches the given name
+/// so that the rule applies to the slash command as well as all prefix aliases
+fn command_name(name: &str) -> Result<String, String> {
+    let name = name.trim().trim_start_matches('/').to_ascii_lowercase();
+    let prefix_commands = PrefixCommands::get();
+
+    if SlashCommands::get().command(&name).is_none() && prefix_commands.command(&name).is_none() {
+        return Err(format!("There is no command `{name}`"));
+    }
+
+    let canonical = prefix_commands.canonical_name(&name);
+
+    if name == "serverconfig" || canonical == "serverconfig" {
+        return Err("The `serverconfig` command can not be disabled".to_owned());
+    }
+
+    Ok(canonical.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_name_canonical() {
+        assert_eq!(command_name("osu").as_deref(), Ok("osu"));
+
+        // Slash command that is a prefix alias
+        assert_eq!(command_name("profile").as_deref(), Ok("osu"));
+        assert_eq!(command_name(" /Profile ").as_deref(), Ok("osu"));
+    }
+
+    #[test]
+    fn test_command_name_invalid() {
+        assert!(command_name("notacommand").is_err());
+        assert!(command_name("serverconfig").is_err());
+        assert!(command_name("/serverconfig").is_err());
+    }
+}
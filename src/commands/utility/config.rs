@@ -7,7 +7,7 @@ use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand,
 
 use crate::{
     commands::{osu::ProfileSize, ShowHideOption},
-    database::{EmbedsSize, ListSize, MinimizedPp, UserConfig},
+    database::{EmbedsSize, ListSize, MinimizedPp, ScoreKind, UserConfig},
     embeds::{ConfigEmbed, EmbedData},
     util::{
         constants::GENERAL_ISSUE, interaction::InteractionCommand, Authored, InteractionCommandExt,
@@ -30,6 +30,7 @@ use crate::{
 #[derive(CommandModel, CreateCommand, Default, SlashCommand)]
 #[command(name = "config")]
 #[flags(EPHEMERAL)]
+#[group(Utility)]
 /// Adjust your default configuration for commands
 pub struct Config {
     #[command(help = "Most osu! commands require a specified username to work.\n\
@@ -71,12 +72,24 @@ pub struct Config {
     retries: Option<ShowHideOption>,
     /// Specify whether the recent command should show max or if-fc pp when minimized
     minimized_pp: Option<ConfigMinimizedPp>,
+    #[command(help = "Choose how the score value of a play should be displayed.\n\
+    `Stable` shows the score as provided by osu!stable i.e. ScoreV1.\n\
+    `Standardised` shows lazer's standardised score, ranging up to 1,000,000.\n\
+    `Classic` shows lazer's classic score which scales with the map's object count.\n\
+    With a lazer option, the embed also shows lazer's mod settings, slider end judgements, \
+    and pp. If the lazer data of a play is unavailable, its values are estimated from the \
+    accuracy and combo of the play and are marked with `~`.\n\
+    Affected commands are: `recent score` and any command showing top scores when \
+    the `index` option is specified.")]
+    /// Specify how the score value of plays should be displayed
+    score: Option<ScoreKind>,
 }
 
 #[cfg(not(feature = "server"))]
 #[derive(CommandModel, CreateCommand, Default, SlashCommand)]
 #[command(name = "config")]
 #[flags(EPHEMERAL)]
+#[group(Utility)]
 /// Adjust your default configuration for commands
 pub struct Config {
     #[command(help = "Always having to specify the `mode` option for any non-std \
@@ -102,6 +115,17 @@ pub struct Config {
     retries: Option<ShowHideOption>,
     /// Specify whether the recent command should show max or if-fc pp when minimized
     minimized_pp: Option<ConfigMinimizedPp>,
+    #[command(help = "Choose how the score value of a play should be displayed.\n\
+    `Stable` shows the score as provided by osu!stable i.e. ScoreV1.\n\
+    `Standardised` shows lazer's standardised score, ranging up to 1,000,000.\n\
+    `Classic` shows lazer's classic score which scales with the map's object count.\n\
+    With a lazer option, the embed also shows lazer's mod settings, slider end judgements, \
+    and pp. If the lazer data of a play is unavailable, its values are estimated from the \
+    accuracy and combo of the play and are marked with `~`.\n\
+    Affected commands are: `recent score` and any command showing top scores when \
+    the `index` option is specified.")]
+    /// Specify how the score value of plays should be displayed
+    score: Option<ScoreKind>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -193,6 +217,7 @@ pub async fn config(ctx: Arc<Context>, command: InteractionCommand, config: Conf
         list_embeds,
         retries,
         minimized_pp,
+        score,
     } = config;
 
     let author = command.user_id()?;
@@ -236,6 +261,10 @@ pub async fn config(ctx: Arc<Context>, command: InteractionCommand, config: Conf
         config.show_retries = Some(matches!(retries, ShowHideOption::Show));
     }
 
+    if let Some(score) = score {
+        config.score_kind = Some(score);
+    }
+
     #[cfg(feature = "server")]
     if let Some(ConfigLink::Unlink) = osu {
         config.osu.take();
@@ -278,7 +307,7 @@ pub async fn config(ctx: Arc<Context>, command: InteractionCommand, config: Conf
 const MSG_BADE: &str = "Contact Badewanne3 if you encounter issues with the website";
 
 #[cfg(feature = "server")]
-fn osu_content(state: u8) -> String {
+fn osu_content(state: &str) -> String {
     let config = BotConfig::get();
 
     format!(
@@ -292,7 +321,7 @@ fn osu_content(state: u8) -> String {
 }
 
 #[cfg(feature = "server")]
-fn twitch_content(state: u8) -> String {
+fn twitch_content(state: &str) -> String {
     let config = BotConfig::get();
 
     format!(
@@ -311,13 +340,14 @@ async fn handle_both_links(
     command: InteractionCommand,
     mut config: UserConfig,
 ) -> Result<()> {
-    let osu_fut = ctx.auth_standby.wait_for_osu();
-    let twitch_fut = ctx.auth_standby.wait_for_twitch();
+    let author_id = command.user_id()?;
+    let osu_fut = ctx.auth_standby.wait_for_osu(ctx, author_id).await?;
+    let twitch_fut = ctx.auth_standby.wait_for_twitch(ctx, author_id).await?;
 
     let content = format!(
         "{}\n{}",
-        osu_content(osu_fut.state),
-        twitch_content(twitch_fut.state)
+        osu_content(&osu_fut.state),
+        twitch_content(&twitch_fut.state)
     );
 
     let embed = EmbedBuilder::new().description(content).footer(MSG_BADE);
@@ -360,10 +390,11 @@ async fn handle_twitch_link(
     command: InteractionCommand,
     mut config: UserConfig,
 ) -> Result<()> {
-    let fut = ctx.auth_standby.wait_for_twitch();
+    let author_id = command.user_id()?;
+    let fut = ctx.auth_standby.wait_for_twitch(ctx, author_id).await?;
 
     let embed = EmbedBuilder::new()
-        .description(twitch_content(fut.state))
+        .description(twitch_content(&fut.state))
         .footer(MSG_BADE);
 
     let builder = MessageBuilder::new().embed(embed);
@@ -399,10 +430,11 @@ async fn handle_osu_link(
     command: InteractionCommand,
     mut config: UserConfig,
 ) -> Result<()> {
-    let fut = ctx.auth_standby.wait_for_osu();
+    let author_id = command.user_id()?;
+    let fut = ctx.auth_standby.wait_for_osu(ctx, author_id).await?;
 
     let embed = EmbedBuilder::new()
-        .description(osu_content(fut.state))
+        .description(osu_content(&fut.state))
         .footer(MSG_BADE);
 
     let builder = MessageBuilder::new().embed(embed);
@@ -17,6 +17,7 @@ use crate::{
 #[derive(CreateCommand, SlashCommand)]
 #[command(name = "invite")]
 #[flags(SKIP_DEFER)]
+#[group(Utility)]
 /// Invite me to your server
 pub struct Invite;
 
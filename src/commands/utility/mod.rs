@@ -1,5 +1,6 @@
 mod authorities;
 mod command_count;
+mod command_rules;
 mod config;
 mod invite;
 mod ping;
@@ -10,6 +11,6 @@ mod roll;
 mod server_config;
 
 pub use self::{
-    authorities::*, command_count::*, config::*, invite::*, ping::*, prefix::*, prune::*,
-    role_assign::*, roll::*, server_config::*,
+    authorities::*, command_count::*, command_rules::*, config::*, invite::*, ping::*, prefix::*,
+    prune::*, role_assign::*, roll::*, server_config::*,
 };
@@ -17,6 +17,7 @@ use crate::{
     to receive a response from discord after sending a message."
 )]
 #[flags(SKIP_DEFER)]
+#[group(Utility)]
 /// Check if the bot is online
 pub struct Ping;
 
@@ -68,7 +68,7 @@ async fn prefix_prefix(ctx: Arc<Context>, msg: &Message, mut args: Args<'_>) ->
         return Ok(());
     }
 
-    let update_fut = ctx.update_guild_config(guild_id, |config| match action {
+    let update_fut = ctx.update_guild_config(guild_id, msg.author.id, |config| match action {
         Action::Add => {
             config.prefixes.extend(args);
 
@@ -24,6 +24,7 @@ use crate::{
     Messages older than two weeks __cannot__ be deleted with this command."
 )]
 #[flags(AUTHORITY, ONLY_GUILDS)]
+#[group(Utility)]
 /// Delete the last few messages in a channel
 pub struct Prune {
     #[command(min_value = 1, max_value = 99)]
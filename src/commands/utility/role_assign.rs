@@ -16,6 +16,7 @@ use crate::{
         commands::{prefix::Args, CommandOrigin},
         CacheMiss,
     },
+    database::AuditAction,
     util::{
         builder::MessageBuilder, constants::GENERAL_ISSUE, interaction::InteractionCommand,
         matcher, ChannelExt, InteractionCommandExt,
@@ -32,6 +33,7 @@ use crate::{
     __**Note**__: Roles can only be assigned if they are lower than some role of the assigner i.e. the bot."
 )]
 #[flags(AUTHORITY, ONLY_GUILDS)]
+#[group(Utility)]
 /// Manage roles with reactions
 pub enum RoleAssign<'a> {
     #[command(name = "add")]
@@ -200,6 +202,16 @@ async fn roleassign(
 
             ctx.add_role_assign(add.channel, msg.id, add.role);
 
+            let details = format!(
+                "<@&{role}> for message {msg} in <#{channel}>",
+                role = add.role,
+                msg = msg.id,
+                channel = add.channel,
+            );
+
+            ctx.audit_log(guild, orig.user_id()?, AuditAction::RoleAssignAdd, details)
+                .await;
+
             let description = format!(
                 "Whoever reacts to <@{author}>'s [message]\
                 (https://discordapp.com/channels/{guild}/{channel}/{msg})\n\
@@ -274,6 +286,17 @@ async fn roleassign(
 
                     debug!("Removed from role_assign table");
                     ctx.remove_role_assign(remove.channel, msg.id, remove.role);
+
+                    let details = format!(
+                        "<@&{role}> for message {msg} in <#{channel}>",
+                        role = remove.role,
+                        msg = msg.id,
+                        channel = remove.channel,
+                    );
+
+                    let action = AuditAction::RoleAssignRemove;
+                    ctx.audit_log(guild, orig.user_id()?, action, details).await;
+
                     let builder = MessageBuilder::new().embed(description);
                     orig.create_message(&ctx, &builder).await?;
                 }
@@ -310,7 +333,7 @@ fn parse_msg_id(msg: Cow<'_, str>) -> Result<Id<MessageMarker>, &'static str> {
     }
 }
 
-async fn retrieve_data(
+pub async fn retrieve_data(
     ctx: &Context,
     channel: Id<ChannelMarker>,
     msg: Id<MessageMarker>,
@@ -334,7 +357,11 @@ async fn retrieve_data(
     Ok(Ok((role_pos, msg)))
 }
 
-fn has_permission(ctx: &Context, guild: Id<GuildMarker>, role_pos: i64) -> Result<bool, CacheMiss> {
+pub fn has_permission(
+    ctx: &Context,
+    guild: Id<GuildMarker>,
+    role_pos: i64,
+) -> Result<bool, CacheMiss> {
     let user_id = ctx.cache.current_user(|user| user.id)?;
 
     ctx.cache.member(guild, user_id, |m| {
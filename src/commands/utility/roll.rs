@@ -15,6 +15,7 @@ const DEFAULT_LIMIT: u64 = 100;
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "roll")]
 #[flags(SKIP_DEFER)]
+#[group(Utility)]
 /// Roll a random number
 pub struct Roll {
     #[command(min_value = 1)]
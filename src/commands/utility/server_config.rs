@@ -16,11 +16,15 @@ use crate::{
     commands::{osu::ProfileSize, EnableDisable, ShowHideOption},
     database::{GuildConfig, ListSize},
     embeds::{EmbedData, ServerConfigEmbed},
-    util::{constants::GENERAL_ISSUE, interaction::InteractionCommand, InteractionCommandExt},
+    pagination::AuditLogPagination,
+    util::{
+        builder::MessageBuilder, constants::GENERAL_ISSUE, interaction::InteractionCommand,
+        Authored, InteractionCommandExt,
+    },
     Context,
 };
 
-use super::{AuthorityCommandKind, ConfigEmbeds, ConfigMinimizedPp};
+use super::{AuthorityCommandKind, ConfigEmbeds, ConfigMinimizedPp, ServerConfigCommands};
 
 pub struct GuildData {
     pub icon: Option<ImageHash>,
@@ -42,14 +46,29 @@ impl From<&CachedGuild> for GuildData {
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "serverconfig")]
 #[flags(AUTHORITY, ONLY_GUILDS, SKIP_DEFER)]
+#[group(Utility)]
 /// Adjust configurations or authority roles for this server
 pub enum ServerConfig {
+    #[command(name = "auditlog")]
+    AuditLog(ServerConfigAuditLog),
     #[command(name = "authorities")]
     Authorities(ServerConfigAuthorities),
+    #[command(name = "commands")]
+    Commands(ServerConfigCommands),
     #[command(name = "edit")]
     Edit(ServerConfigEdit),
 }
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "auditlog",
+    help = "Display recent changes to this server's configuration, \
+    osu! and twitch trackings, and role assigns.\n\
+    Shows who made the change and when."
+)]
+/// Display recent changes to the server setup
+pub struct ServerConfigAuditLog;
+
 #[derive(CommandModel, CreateCommand)]
 #[command(
     name = "authorities",
@@ -149,6 +168,11 @@ pub struct ServerConfigEdit {
     track_limit: Option<i64>,
     /// Specify whether the recent command should show max or if-fc pp when minimized
     minimized_pp: Option<ConfigMinimizedPp>,
+    #[command(help = "Specify whether the server leaderboards of this server \
+        can be requested through the bot's web API.\n\
+        Disabled by default.")]
+    /// Should the server leaderboards be available through the web API?
+    public_api: Option<EnableDisable>,
 }
 
 impl ServerConfigEdit {
@@ -160,6 +184,7 @@ impl ServerConfigEdit {
             || self.retries.is_some()
             || self.track_limit.is_some()
             || self.minimized_pp.is_some()
+            || self.public_api.is_some()
     }
 }
 
@@ -178,19 +203,26 @@ async fn slash_serverconfig(ctx: Arc<Context>, mut command: InteractionCommand)
     };
 
     let args = match args {
+        ServerConfig::AuditLog(_) => return auditlog(ctx, command, guild).await,
         ServerConfig::Authorities(args) => {
             return super::authorities(ctx, (&mut command).into(), args.into()).await
         }
+        ServerConfig::Commands(args) => {
+            return super::command_rules(ctx, command, guild_id, args).await
+        }
         ServerConfig::Edit(edit) => edit,
     };
 
     if args.any() {
+        let author = command.user_id()?;
+
         let f = |config: &mut GuildConfig| {
             let ServerConfigEdit {
                 score_embeds,
                 list_embeds,
                 minimized_pp,
                 profile,
+                public_api,
                 retries,
                 song_commands,
                 track_limit,
@@ -223,9 +255,13 @@ async fn slash_serverconfig(ctx: Arc<Context>, mut command: InteractionCommand)
             if let Some(with_lyrics) = song_commands {
                 config.with_lyrics = Some(with_lyrics == EnableDisable::Enable);
             }
+
+            if let Some(public_api) = public_api {
+                config.public_api = Some(public_api == EnableDisable::Enable);
+            }
         };
 
-        if let Err(err) = ctx.update_guild_config(guild_id, f).await {
+        if let Err(err) = ctx.update_guild_config(guild_id, author, f).await {
             let _ = command.error_callback(&ctx, GENERAL_ISSUE).await;
 
             return Err(err.wrap_err("failed to update guild config"));
@@ -249,3 +285,33 @@ async fn slash_serverconfig(ctx: Arc<Context>, mut command: InteractionCommand)
 
     Ok(())
 }
+
+/// Amount of most recent audit log entries that can be viewed
+const AUDIT_LOG_LIMIT: usize = 200;
+
+async fn auditlog(
+    ctx: Arc<Context>,
+    mut command: InteractionCommand,
+    guild: GuildData,
+) -> Result<()> {
+    let entries = match ctx.psql().get_audit_log(guild.id, AUDIT_LOG_LIMIT).await {
+        Ok(entries) => entries,
+        Err(err) => {
+            let _ = command.error_callback(&ctx, GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get audit log"));
+        }
+    };
+
+    if entries.is_empty() {
+        let content = "No changes have been recorded for this server yet";
+        let builder = MessageBuilder::new().embed(content);
+        command.callback(&ctx, builder, false).await?;
+
+        return Ok(());
+    }
+
+    AuditLogPagination::builder(guild, entries)
+        .start(ctx, (&mut command).into())
+        .await
+}
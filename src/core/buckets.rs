@@ -70,33 +70,53 @@ impl Bucket {
     }
 
     pub fn take(&mut self, user_id: u64) -> i64 {
-        let time = OffsetDateTime::now_utc().unix_timestamp();
-
-        let user = self
-            .users
+        self.users
             .entry(user_id)
-            .or_insert_with(MemberRatelimit::default);
+            .or_insert_with(MemberRatelimit::default)
+            .take(&self.ratelimit)
+    }
+}
 
-        if let Some((timespan, limit)) = self.ratelimit.limit {
-            if (user.tickets + 1) > limit {
-                if time < (user.set_time + timespan) {
-                    return (user.set_time + timespan) - time;
+impl MemberRatelimit {
+    /// Returns the amount of seconds to wait until the next ticket can be taken,
+    /// or 0 if a ticket was taken.
+    pub fn take(&mut self, ratelimit: &Ratelimit) -> i64 {
+        let time = OffsetDateTime::now_utc().unix_timestamp();
+
+        if let Some((timespan, limit)) = ratelimit.limit {
+            if (self.tickets + 1) > limit {
+                if time < (self.set_time + timespan) {
+                    return (self.set_time + timespan) - time;
                 } else {
-                    user.tickets = 0;
-                    user.set_time = time;
+                    self.tickets = 0;
+                    self.set_time = time;
                 }
             }
         }
 
-        if time < user.last_time + self.ratelimit.delay {
-            (user.last_time + self.ratelimit.delay) - time
+        if time < self.last_time + ratelimit.delay {
+            (self.last_time + ratelimit.delay) - time
         } else {
-            user.tickets += 1;
-            user.last_time = time;
+            self.tickets += 1;
+            self.last_time = time;
 
             0
         }
     }
+
+    /// Returns the amount of seconds to wait until the next ticket can be taken
+    /// without taking a ticket.
+    pub fn cooldown(&self, ratelimit: &Ratelimit) -> i64 {
+        let time = OffsetDateTime::now_utc().unix_timestamp();
+
+        if let Some((timespan, limit)) = ratelimit.limit {
+            if (self.tickets + 1) > limit && time < (self.set_time + timespan) {
+                return (self.set_time + timespan) - time;
+            }
+        }
+
+        ((self.last_time + ratelimit.delay) - time).max(0)
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
@@ -135,6 +135,26 @@ impl Cache {
             .map_or_else(C::default, |entry| entry.iter().map(f).collect())
     }
 
+    pub fn guild_channels<F, T, C>(&self, guild: Id<GuildMarker>, f: F) -> C
+    where
+        C: Default + FromIterator<T>,
+        F: Fn(&Id<ChannelMarker>) -> T,
+    {
+        self.inner
+            .guild_channels(guild)
+            .map_or_else(C::default, |entry| entry.iter().map(f).collect())
+    }
+
+    pub fn guild_roles<F, T, C>(&self, guild: Id<GuildMarker>, f: F) -> C
+    where
+        C: Default + FromIterator<T>,
+        F: Fn(&Id<RoleMarker>) -> T,
+    {
+        self.inner
+            .guild_roles(guild)
+            .map_or_else(C::default, |entry| entry.iter().map(f).collect())
+    }
+
     pub fn role<F, T>(&self, role: Id<RoleMarker>, f: F) -> CacheResult<T>
     where
         F: FnOnce(&GuildResource<Role>) -> T,
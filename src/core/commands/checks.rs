@@ -4,12 +4,17 @@ use eyre::Result;
 use twilight_model::{
     guild::Permissions,
     id::{
-        marker::{GuildMarker, UserMarker},
+        marker::{ChannelMarker, GuildMarker, UserMarker},
         Id,
     },
 };
 
-use crate::core::{buckets::BucketName, cache::RolesLookup, Context};
+use crate::core::{
+    buckets::BucketName,
+    cache::RolesLookup,
+    commands::prefix::{PrefixCommandGroup, PrefixCommands},
+    Context,
+};
 
 /// Is authority -> Ok(None)
 /// No authority -> Ok(Some(message to user))
@@ -75,6 +80,28 @@ pub async fn check_authority(
     Ok(None)
 }
 
+/// Disabled in the guild or channel -> Some(message to user)
+pub async fn check_disabled(
+    ctx: &Context,
+    name: &str,
+    group: Option<PrefixCommandGroup>,
+    guild: Option<Id<GuildMarker>>,
+    channel: Id<ChannelMarker>,
+) -> Option<&'static str> {
+    let guild = guild?;
+
+    // Otherwise disabled commands could no longer be enabled
+    if name == "serverconfig" {
+        return None;
+    }
+
+    let name = PrefixCommands::get().canonical_name(name);
+
+    ctx.guild_command_disabled(guild, channel, name, group)
+        .await
+        .then_some("This command has been disabled here by the server's authorities")
+}
+
 pub async fn check_ratelimit(
     ctx: &Context,
     user: Id<UserMarker>,
@@ -4,6 +4,7 @@ use eyre::Result;
 use futures::Future;
 use once_cell::sync::OnceCell;
 use radix_trie::{Trie, TrieCommon};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     commands::{fun::*, help::HELP_PREFIX, osu::*, songs::*, utility::*},
@@ -45,7 +46,7 @@ pub type CommandResult<'fut> = Pin<Box<dyn Future<Output = Result<()>> + 'fut +
 
 type PrefixTrie = Trie<&'static str, &'static PrefixCommand>;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
 pub enum PrefixCommandGroup {
     AllModes,
     Osu,
@@ -180,6 +181,10 @@ impl PrefixCommands {
                 PPRANKINGTAIKO_PREFIX,
                 PREFIX_PREFIX,
                 PRETENDER_PREFIX,
+                PROGRESS_PREFIX,
+                PROGRESSCTB_PREFIX,
+                PROGRESSMANIA_PREFIX,
+                PROGRESSTAIKO_PREFIX,
                 PRUNE_PREFIX,
                 RANK_PREFIX,
                 RANKCTB_PREFIX,
@@ -217,6 +222,7 @@ impl PrefixCommands {
                 ROLL_PREFIX,
                 SAYGOODBYE_PREFIX,
                 SEARCH_PREFIX,
+                SESSION_PREFIX,
                 SIMULATE_PREFIX,
                 SIMULATERECENT_PREFIX,
                 SIMULATERECENTCTB_PREFIX,
@@ -268,6 +274,14 @@ impl PrefixCommands {
         self.0.get(command).copied()
     }
 
+    /// Name that identifies a command across its prefix aliases and its slash command.
+    ///
+    /// If the name belongs to a prefix command, that command's main name is returned,
+    /// otherwise the name itself.
+    pub fn canonical_name<'n>(&self, name: &'n str) -> &'n str {
+        self.command(name).map_or(name, |cmd| cmd.name())
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &'static PrefixCommand> + '_ {
         self.0.values().copied()
     }
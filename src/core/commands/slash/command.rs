@@ -3,7 +3,11 @@ use std::sync::Arc;
 use twilight_interactions::command::ApplicationCommandData;
 
 use crate::{
-    core::{buckets::BucketName, commands::flags::CommandFlags, Context},
+    core::{
+        buckets::BucketName,
+        commands::{flags::CommandFlags, prefix::PrefixCommandGroup},
+        Context,
+    },
     util::interaction::InteractionCommand,
 };
 
@@ -14,4 +18,5 @@ pub struct SlashCommand {
     pub create: fn() -> ApplicationCommandData,
     pub exec: fn(Arc<Context>, InteractionCommand) -> CommandResult,
     pub flags: CommandFlags,
+    pub group: Option<PrefixCommandGroup>,
 }
@@ -54,6 +54,7 @@ impl SlashCommands {
                 ClaimName => CLAIMNAME_SLASH,
                 Commands => COMMANDS_SLASH,
                 Compare => COMPARE_SLASH,
+                Competition => COMPETITION_SLASH,
                 Config => CONFIG_SLASH,
                 CountryTop => COUNTRYTOP_SLASH,
                 Cs => CS_SLASH,
@@ -67,6 +68,7 @@ impl SlashCommands {
                 Link => LINK_SLASH,
                 Map => MAP_SLASH,
                 Mapper => MAPPER_SLASH,
+                MapperStats => MAPPERSTATS_SLASH,
                 MatchCompare => MATCHCOMPARE_SLASH,
                 MatchCost => MATCHCOST_SLASH,
                 #[cfg(feature = "matchlive")]
@@ -74,6 +76,7 @@ impl SlashCommands {
                 Medal => MEDAL_SLASH,
                 Minesweeper => MINESWEEPER_SLASH,
                 MostPlayed => MOSTPLAYED_SLASH,
+                NameHistory => NAMEHISTORY_SLASH,
                 Nochoke => NOCHOKE_SLASH,
                 Osc => OSC_SLASH,
                 Osekai => OSEKAI_SLASH,
@@ -84,6 +87,7 @@ impl SlashCommands {
                 Popular => POPULAR_SLASH,
                 Pp => PP_SLASH,
                 Profile => PROFILE_SLASH,
+                Progress => PROGRESS_SLASH,
                 Prune => PRUNE_SLASH,
                 Rank => RANK_SLASH,
                 Ranking => RANKING_SLASH,
@@ -96,6 +100,7 @@ impl SlashCommands {
                 Search => SEARCH_SLASH,
                 ServerConfig => SERVERCONFIG_SLASH,
                 ServerLeaderboard => SERVERLEADERBOARD_SLASH,
+                Session => SESSION_SLASH,
                 Simulate => SIMULATE_SLASH,
                 Snipe => SNIPE_SLASH,
                 SnipePlayerSniped => SNIPEPLAYERSNIPED_SLASH,
@@ -108,6 +113,7 @@ impl SlashCommands {
                 #[cfg(feature = "twitch")]
                 TrackStream => TRACKSTREAM_SLASH,
                 WhatIf => WHATIF_SLASH,
+                Wrapped => WRAPPED_SLASH,
             }
         })
     }
@@ -44,11 +44,45 @@ pub struct Server {
     pub internal_ip: [u8; 4],
     pub internal_port: u16,
     pub external_url: String,
+    /// Seconds until a pending osu! or twitch authorization expires
+    pub auth_deadline: u64,
+}
+
+#[cfg(feature = "server")]
+impl Server {
+    const DEFAULT_AUTH_DEADLINE: u64 = 120;
+
+    /// Interaction tokens expire after 15 minutes so
+    /// the authorization must finish before that
+    const MAX_AUTH_DEADLINE: u64 = 14 * 60;
+
+    fn auth_deadline() -> Result<u64> {
+        if env::var("AUTH_DEADLINE").is_err() {
+            return Ok(Self::DEFAULT_AUTH_DEADLINE);
+        }
+
+        let deadline: u64 = env_var("AUTH_DEADLINE")?;
+
+        if deadline > Self::MAX_AUTH_DEADLINE {
+            warn!(
+                "AUTH_DEADLINE of {deadline} seconds is capped to {} seconds",
+                Self::MAX_AUTH_DEADLINE
+            );
+
+            return Ok(Self::MAX_AUTH_DEADLINE);
+        }
+
+        Ok(deadline)
+    }
 }
 
 #[derive(Debug)]
 pub struct Tokens {
     pub discord: String,
+    #[cfg(feature = "server")]
+    pub discord_client_id: u64,
+    #[cfg(feature = "server")]
+    pub discord_client_secret: String,
     pub osu_client_id: u64,
     pub osu_client_secret: String,
     pub osu_session: String,
@@ -56,6 +90,9 @@ pub struct Tokens {
     pub twitch_client_id: String,
     #[cfg(feature = "twitch")]
     pub twitch_token: String,
+    /// EventSub notifications are disabled if no secret is set
+    #[cfg(all(feature = "server", feature = "twitchtracking"))]
+    pub twitch_eventsub_secret: Option<String>,
 }
 
 impl BotConfig {
@@ -120,6 +157,10 @@ impl BotConfig {
             database_url: env_var("DATABASE_URL")?,
             tokens: Tokens {
                 discord: env_var("DISCORD_TOKEN")?,
+                #[cfg(feature = "server")]
+                discord_client_id: env_var("DISCORD_CLIENT_ID")?,
+                #[cfg(feature = "server")]
+                discord_client_secret: env_var("DISCORD_CLIENT_SECRET")?,
                 osu_client_id: env_var("OSU_CLIENT_ID")?,
                 osu_client_secret: env_var("OSU_CLIENT_SECRET")?,
                 osu_session: env_var("OSU_SESSION")?,
@@ -127,6 +168,10 @@ impl BotConfig {
                 twitch_client_id: env_var("TWITCH_CLIENT_ID")?,
                 #[cfg(feature = "twitch")]
                 twitch_token: env_var("TWITCH_TOKEN")?,
+                #[cfg(all(feature = "server", feature = "twitchtracking"))]
+                twitch_eventsub_secret: env::var("TWITCH_EVENTSUB_SECRET")
+                    .ok()
+                    .filter(|secret| !secret.is_empty()),
             },
             paths: Paths {
                 backgrounds: env_var("BG_PATH")?,
@@ -140,6 +185,7 @@ impl BotConfig {
                 internal_ip: env_var("INTERNAL_IP")?,
                 internal_port: env_var("INTERNAL_PORT")?,
                 external_url: env_var("EXTERNAL_URL")?,
+                auth_deadline: Server::auth_deadline()?,
             },
             grades,
             emotes,
@@ -0,0 +1,167 @@
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::{
+    database::{AuditAction, Authorities, DisabledCommands, GuildConfig, Prefixes},
+    embeds::ServerConfigEmbed,
+    Context,
+};
+
+impl Context {
+    /// Record a change of a guild's setup.
+    /// Failing to do so is only logged so that the change itself still goes through.
+    pub async fn audit_log(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        action: AuditAction,
+        details: impl AsRef<str>,
+    ) {
+        let insert_fut = self
+            .psql()
+            .insert_audit_log(guild_id, user_id, action, details.as_ref());
+
+        if let Err(err) = insert_fut.await {
+            let wrap = format!("failed to insert audit log entry for guild {guild_id}");
+            warn!("{:?}", err.wrap_err(wrap));
+        }
+    }
+}
+
+/// Describe all settings whose effective value differs between the two configs
+pub(super) fn config_changes(old: &GuildConfig, new: &GuildConfig) -> String {
+    let mut changes = String::new();
+
+    macro_rules! push_options {
+        ( $( $getter:ident: $name:literal, $options:ident; )* ) => {
+            $(
+                let (old_value, new_value) = (old.$getter(), new.$getter());
+
+                if old_value != new_value {
+                    let options = &ServerConfigEmbed::$options;
+                    let old_label = option_label(old_value, options);
+                    let new_label = option_label(new_value, options);
+                    push_change(&mut changes, $name, old_label, new_label);
+                }
+            )*
+        };
+    }
+
+    if old.authorities != new.authorities {
+        let old_roles = FormatRoles(&old.authorities);
+        let new_roles = FormatRoles(&new.authorities);
+        push_change(&mut changes, "Authorities", old_roles, new_roles);
+    }
+
+    if old.prefixes != new.prefixes {
+        let old_prefixes = FormatPrefixes(&old.prefixes);
+        let new_prefixes = FormatPrefixes(&new.prefixes);
+        push_change(&mut changes, "Prefixes", old_prefixes, new_prefixes);
+    }
+
+    if old.track_limit() != new.track_limit() {
+        push_change(
+            &mut changes,
+            "Track limit",
+            old.track_limit(),
+            new.track_limit(),
+        );
+    }
+
+    push_options! {
+        with_lyrics: "Song commands", LYRICS;
+        public_api: "Public API", PUBLIC_API;
+        show_retries: "Retries", RETRIES;
+        minimized_pp: "Minimized pp", MINIMIZED_PP;
+        embeds_size: "Score embeds", EMBEDS_SIZE;
+        list_size: "List embeds", LIST_SIZE;
+        profile_size: "Profile size", PROFILE_SIZE;
+    }
+
+    if old.disabled_commands != new.disabled_commands {
+        let old_rules = FormatDisabledCommands(&old.disabled_commands);
+        let new_rules = FormatDisabledCommands(&new.disabled_commands);
+        push_change(&mut changes, "Disabled commands", old_rules, new_rules);
+    }
+
+    changes
+}
+
+fn push_change(changes: &mut String, name: &str, old: impl Display, new: impl Display) {
+    if !changes.is_empty() {
+        changes.push('\n');
+    }
+
+    let _ = write!(changes, "{name}: {old} -> {new}");
+}
+
+fn option_label<T: Eq>(value: T, options: &[(T, &'static str)]) -> &'static str {
+    options
+        .iter()
+        .find_map(|(option, label)| (*option == value).then_some(*label))
+        .unwrap_or("unknown")
+}
+
+struct FormatRoles<'a>(&'a Authorities);
+
+impl Display for FormatRoles<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut roles = self.0.iter();
+
+        if let Some(role) = roles.next() {
+            write!(f, "<@&{role}>")?;
+
+            for role in roles {
+                write!(f, ", <@&{role}>")?;
+            }
+
+            Ok(())
+        } else {
+            f.write_str("None")
+        }
+    }
+}
+
+struct FormatPrefixes<'a>(&'a Prefixes);
+
+impl Display for FormatPrefixes<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut prefixes = self.0.iter();
+
+        if let Some(prefix) = prefixes.next() {
+            write!(f, "`{prefix}`")?;
+
+            for prefix in prefixes {
+                write!(f, ", `{prefix}`")?;
+            }
+
+            Ok(())
+        } else {
+            f.write_str("None")
+        }
+    }
+}
+
+struct FormatDisabledCommands<'a>(&'a DisabledCommands);
+
+impl Display for FormatDisabledCommands<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut rules = self.0.iter();
+
+        if let Some(rule) = rules.next() {
+            write!(f, "{rule}")?;
+
+            for rule in rules {
+                write!(f, ", {rule}")?;
+            }
+
+            Ok(())
+        } else {
+            f.write_str("None")
+        }
+    }
+}
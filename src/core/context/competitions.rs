@@ -0,0 +1,255 @@
+use std::sync::Arc;
+
+use eyre::{Report, Result, WrapErr};
+use rosu_v2::prelude::{Beatmap, OsuError};
+use time::OffsetDateTime;
+use tokio::time::{self, Duration, MissedTickBehavior};
+
+use crate::{
+    core::OsuFutureExt,
+    database::Competition,
+    embeds::{CompetitionEmbed, EmbedData},
+    util::{builder::MessageBuilder, numbers::with_comma_int, MessageExt},
+    Context,
+};
+
+impl Context {
+    /// Periodically refresh the leaderboards of all active competitions,
+    /// finish the ones whose deadline has passed, and retry failed announcements.
+    #[cold]
+    pub async fn competition_loop(ctx: Arc<Context>) {
+        let mut interval = time::interval(Duration::from_secs(60 * 10));
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+
+            let competitions = match ctx.psql().get_active_competitions().await {
+                Ok(competitions) => competitions,
+                Err(err) => {
+                    let wrap = "[Competition] Failed to get active competitions";
+                    warn!("{:?}", err.wrap_err(wrap));
+
+                    continue;
+                }
+            };
+
+            for competition in competitions {
+                if let Err(err) = ctx.update_competition(&competition, false).await {
+                    let wrap = format!(
+                        "[Competition] Failed to update competition {}",
+                        competition.competition_id
+                    );
+
+                    warn!("{:?}", err.wrap_err(wrap));
+                }
+            }
+
+            let unannounced = match ctx.psql().get_unannounced_competitions().await {
+                Ok(competitions) => competitions,
+                Err(err) => {
+                    let wrap = "[Competition] Failed to get unannounced competitions";
+                    warn!("{:?}", err.wrap_err(wrap));
+
+                    continue;
+                }
+            };
+
+            for competition in unannounced {
+                if let Err(err) = ctx.refresh_competition(&competition, true).await {
+                    let wrap = format!(
+                        "[Competition] Failed to announce competition {}",
+                        competition.competition_id
+                    );
+
+                    warn!("{:?}", err.wrap_err(wrap));
+                }
+            }
+        }
+    }
+
+    /// Request the current scores of all linked guild members on the competition's map,
+    /// refresh the leaderboard message, and announce the winner if the competition is over.
+    ///
+    /// If `end` is `true`, the competition will be finished regardless of its deadline.
+    pub async fn update_competition(&self, competition: &Competition, end: bool) -> Result<()> {
+        let members: Vec<_> = self
+            .cache
+            .members(competition.guild_id, |id| id.get() as i64);
+
+        let user_ids = self
+            .psql()
+            .get_member_osu_ids(&members)
+            .await
+            .wrap_err("failed to get osu ids of members")?;
+
+        // Don't flood the API
+        let mut delay = time::interval(Duration::from_millis(250));
+        delay.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        for user_id in user_ids {
+            delay.tick().await;
+
+            let scores_fut = self
+                .osu()
+                .beatmap_user_scores(competition.map_id, user_id)
+                .mode(competition.mode)
+                .observe(&self.stats, "beatmap_user_scores");
+
+            let scores = match scores_fut.await {
+                Ok(scores) => scores,
+                Err(OsuError::NotFound) => continue,
+                Err(err) => {
+                    let wrap = format!("failed to get scores of user {user_id}");
+                    warn!("{:?}", Report::new(err).wrap_err(wrap));
+
+                    continue;
+                }
+            };
+
+            // Only scores set during the competition with the required mods count
+            let score = scores
+                .into_iter()
+                .filter(|score| {
+                    score.ended_at >= competition.started_at
+                        && score.ended_at <= competition.ends_at
+                        && competition.mods.map_or(true, |mods| score.mods == mods)
+                })
+                .max_by_key(|score| score.score);
+
+            let score = match score {
+                Some(score) => score,
+                None => continue,
+            };
+
+            let upsert_fut = self
+                .psql()
+                .upsert_competition_score(competition.competition_id, &score);
+
+            if let Err(err) = upsert_fut.await {
+                warn!("{err:?}");
+            }
+        }
+
+        let finished = end || OffsetDateTime::now_utc() >= competition.ends_at;
+
+        // Only one caller may finish the competition and announce its winner
+        if finished
+            && !self
+                .psql()
+                .finish_competition(competition.competition_id)
+                .await?
+        {
+            return Ok(());
+        }
+
+        self.refresh_competition(competition, finished).await
+    }
+
+    /// Update the leaderboard message and, if the competition is finished,
+    /// announce its winner and mark it as announced.
+    async fn refresh_competition(&self, competition: &Competition, finished: bool) -> Result<()> {
+        let scores = self
+            .psql()
+            .get_competition_scores(competition.competition_id)
+            .await
+            .wrap_err("failed to get competition scores")?;
+
+        let user_ids: Vec<_> = scores.iter().map(|score| score.user_id as i32).collect();
+
+        let names = self
+            .psql()
+            .get_names_by_ids(&user_ids)
+            .await
+            .wrap_err("failed to get usernames")?;
+
+        let map = self.competition_map(competition.map_id).await?;
+        let embed = CompetitionEmbed::new(competition, &map, &scores, &names, finished).build();
+
+        match competition.message_id {
+            Some(msg_id) => {
+                let builder = MessageBuilder::new().embed(embed);
+
+                if let Err(err) = (msg_id, competition.channel_id)
+                    .update(self, &builder)
+                    .await
+                {
+                    let wrap = "failed to update competition leaderboard";
+                    warn!("{:?}", Report::new(err).wrap_err(wrap));
+                }
+            }
+            None => {
+                let msg = self
+                    .http
+                    .create_message(competition.channel_id)
+                    .embeds(&[embed])
+                    .wrap_err("failed to create competition leaderboard")?
+                    .exec()
+                    .await
+                    .wrap_err("failed to send competition leaderboard")?
+                    .model()
+                    .await
+                    .wrap_err("failed to deserialize competition leaderboard")?;
+
+                self.psql()
+                    .update_competition_message(competition.competition_id, msg.id)
+                    .await?;
+            }
+        }
+
+        if !finished {
+            return Ok(());
+        }
+
+        let content = match scores.first() {
+            Some(winner) => {
+                let name = names
+                    .get(&winner.user_id)
+                    .map_or_else(|| winner.user_id.to_string(), |name| name.to_string());
+
+                format!(
+                    "Competition #{} has ended, congratulations to **{name}** \
+                    for winning with a score of `{}`! :tada:",
+                    competition.competition_id,
+                    with_comma_int(winner.score),
+                )
+            }
+            None => format!(
+                "Competition #{} has ended without any scores :(",
+                competition.competition_id
+            ),
+        };
+
+        self.http
+            .create_message(competition.channel_id)
+            .content(&content)
+            .wrap_err("failed to create competition announcement")?
+            .exec()
+            .await
+            .wrap_err("failed to send competition announcement")?;
+
+        self.psql()
+            .set_competition_announced(competition.competition_id)
+            .await
+    }
+
+    async fn competition_map(&self, map_id: u32) -> Result<Beatmap> {
+        if let Ok(map) = self.psql().get_beatmap(map_id, true).await {
+            return Ok(map);
+        }
+
+        let map = self
+            .osu()
+            .beatmap()
+            .map_id(map_id)
+            .observe(&self.stats, "beatmap")
+            .await
+            .wrap_err("failed to request competition map")?;
+
+        if let Err(err) = self.psql().insert_beatmap(&map).await {
+            warn!("{:?}", err.wrap_err("failed to insert map in database"));
+        }
+
+        Ok(map)
+    }
+}
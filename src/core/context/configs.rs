@@ -1,18 +1,21 @@
 use eyre::{Result, WrapErr};
 use twilight_model::id::{
-    marker::{GuildMarker, UserMarker},
+    marker::{ChannelMarker, GuildMarker, UserMarker},
     Id,
 };
 
 use crate::{
     commands::osu::ProfileSize,
-    core::commands::prefix::Stream,
+    core::commands::prefix::{PrefixCommandGroup, Stream},
     database::{
-        Authorities, EmbedsSize, GuildConfig, ListSize, MinimizedPp, Prefix, Prefixes, UserConfig,
+        AuditAction, Authorities, EmbedsSize, GuildConfig, ListSize, MinimizedPp, Prefix, Prefixes,
+        UserConfig,
     },
     Context,
 };
 
+use super::audit_log::config_changes;
+
 impl Context {
     pub async fn user_config(&self, user_id: Id<UserMarker>) -> Result<UserConfig> {
         let config_fut = self.psql().get_user_config(user_id);
@@ -98,6 +101,10 @@ impl Context {
             .await
     }
 
+    pub async fn guild_public_api(&self, guild_id: Id<GuildMarker>) -> bool {
+        self.guild_config_(guild_id, GuildConfig::public_api).await
+    }
+
     pub async fn guild_show_retries(&self, guild_id: Id<GuildMarker>) -> bool {
         self.guild_config_(guild_id, GuildConfig::show_retries)
             .await
@@ -120,22 +127,46 @@ impl Context {
         self.guild_config_(guild_id, GuildConfig::list_size).await
     }
 
+    pub async fn guild_command_disabled(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+        name: &str,
+        group: Option<PrefixCommandGroup>,
+    ) -> bool {
+        let f = |config: &GuildConfig| {
+            config
+                .disabled_commands
+                .iter()
+                .any(|rule| rule.matches(name, group, channel_id.get()))
+        };
+
+        self.guild_config_(guild_id, f).await
+    }
+
     pub async fn guild_config(&self, guild_id: Id<GuildMarker>) -> GuildConfig {
         self.guild_config_(guild_id, GuildConfig::to_owned).await
     }
 
-    pub async fn update_guild_config<F>(&self, guild_id: Id<GuildMarker>, f: F) -> Result<()>
+    /// Apply the changes of the given user and record them in the audit log
+    pub async fn update_guild_config<F>(
+        &self,
+        guild_id: Id<GuildMarker>,
+        author: Id<UserMarker>,
+        f: F,
+    ) -> Result<()>
     where
         F: FnOnce(&mut GuildConfig),
     {
         let guilds = &self.data.guilds;
 
-        let mut config = guilds
+        let old = guilds
             .pin()
             .get(&guild_id)
             .map(GuildConfig::to_owned)
             .unwrap_or_default();
 
+        let mut config = old.clone();
         f(&mut config);
 
         self.psql()
@@ -143,8 +174,14 @@ impl Context {
             .await
             .wrap_err("failed to upsert guild config")?;
 
+        let changes = config_changes(&old, &config);
         guilds.pin().insert(guild_id, config);
 
+        if !changes.is_empty() {
+            self.audit_log(guild_id, author, AuditAction::ConfigEdit, changes)
+                .await;
+        }
+
         Ok(())
     }
 }
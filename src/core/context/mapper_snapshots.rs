@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use futures::stream::{self, StreamExt};
+use tokio::time::{self, Duration};
+
+use crate::{commands::osu::store_mapper_snapshot, Context};
+
+/// Amount of mappers whose mapsets are requested at the same time.
+/// The osu! client applies its own ratelimit on top of this.
+const SNAPSHOT_CONCURRENCY: usize = 4;
+
+impl Context {
+    /// Daily store the total playcount and favourites of all mappers
+    /// that were looked up through the mapper stats command
+    #[cold]
+    pub async fn mapper_snapshot_loop(ctx: Arc<Context>) {
+        let mut interval = time::interval(Duration::from_secs(60 * 60 * 24));
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+
+            let mappers = match ctx.psql().get_snapshot_mappers().await {
+                Ok(mappers) => mappers,
+                Err(err) => {
+                    warn!("{:?}", err.wrap_err("[Mappers] Failed to get mappers"));
+
+                    continue;
+                }
+            };
+
+            let mut snapshots = stream::iter(mappers.iter())
+                .map(|&user_id| {
+                    let ctx = &ctx;
+
+                    async move { (user_id, store_mapper_snapshot(ctx, user_id).await) }
+                })
+                .buffer_unordered(SNAPSHOT_CONCURRENCY);
+
+            while let Some((user_id, result)) = snapshots.next().await {
+                if let Err(err) = result {
+                    let wrap = format!("[Mappers] Failed to store snapshot of mapper {user_id}");
+                    warn!("{:?}", err.wrap_err(wrap));
+                }
+            }
+
+            info!("[Mappers] Stored snapshots of {} mappers", mappers.len());
+        }
+    }
+}
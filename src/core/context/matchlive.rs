@@ -9,6 +9,7 @@ use tokio::time::{interval, Duration};
 use twilight_model::id::{marker::ChannelMarker, Id};
 
 use crate::{
+    core::OsuFutureExt,
     embeds::MatchLiveEmbed,
     matchlive::{send_match_messages, Channel, MatchEntry, MatchTrackResult, TrackedMatch},
     util::ChannelExt,
@@ -81,7 +82,12 @@ impl Context {
                 MatchTrackResult::Added
             }
             // The match is not yet tracked -> request and store it
-            Entry::Vacant(e) => match self.osu().osu_match(match_id).await {
+            Entry::Vacant(e) => match self
+                .osu()
+                .osu_match(match_id)
+                .observe(&self.stats, "match")
+                .await
+            {
                 Ok(osu_match) => {
                     let embeds = MatchLiveEmbed::new(&osu_match);
 
@@ -185,7 +191,9 @@ impl Context {
                     let mut tracked_match = &mut entry.tracked;
 
                     // Request an update
-                    let next_match = match tracked_match.osu_match.get_next(ctx.osu()).await {
+                    let next_fut = tracked_match.osu_match.get_next(ctx.osu());
+
+                    let next_match = match next_fut.observe(&ctx.stats, "match").await {
                         Ok(next_match) => next_match,
                         Err(err) => {
                             let report = Report::new(err).wrap_err("failed to request match");
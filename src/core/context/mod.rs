@@ -7,7 +7,7 @@ use flexmap::{
     tokio::{TokioMutexMap, TokioRwLockMap},
 };
 use flurry::HashMap as FlurryMap;
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
 use parking_lot::Mutex;
 use rosu_v2::Osu;
 use smallvec::SmallVec;
@@ -37,14 +37,18 @@ use crate::{
 
 use super::{buckets::Buckets, cluster::build_cluster, BotStats, Cache, RedisCache};
 
+mod audit_log;
 mod background_loop;
+mod competitions;
 mod configs;
 mod countries;
 mod games;
 mod map_collect;
+mod mapper_snapshots;
 mod matchlive;
 mod messages;
 mod role_assign;
+mod score_archive;
 mod shutdown;
 mod twitch;
 
@@ -62,6 +66,8 @@ pub struct Context {
     pub paginations: Arc<TokioMutexMap<Id<MessageMarker>, Pagination, IntHasher>>,
     pub standby: Standby,
     pub stats: Arc<BotStats>,
+    #[cfg(feature = "server")]
+    pub web_tables: crate::server::WebTables,
     // private to avoid deadlocks by messing up references
     data: ContextData,
     clients: Clients,
@@ -170,7 +176,7 @@ impl Context {
             .wrap_err("failed to create context data")?;
 
         let (cache, resume_data) = Cache::new(&redis).await;
-        let stats = Arc::new(BotStats::new(osu.metrics()));
+        let stats = Arc::new(BotStats::new(osu.metrics(), custom.metrics()));
 
         if !resume_data.is_empty() {
             stats.populate(&cache);
@@ -195,6 +201,8 @@ impl Context {
             buckets: Buckets::new(),
             member_requests: MemberRequests::new(tx),
             paginations: Arc::new(TokioMutexMap::with_shard_amount_and_hasher(16, IntHasher)),
+            #[cfg(feature = "server")]
+            web_tables: crate::server::WebTables::new(),
         };
 
         Ok((ctx, events))
@@ -241,11 +249,15 @@ struct ContextData {
     #[cfg(feature = "matchlive")]
     matchlive: crate::matchlive::MatchLiveChannels,
     msgs_to_process: Mutex<HashSet<Id<MessageMarker>, IntHasher>>,
+    #[cfg(feature = "twitchtracking")]
+    online_streams: Mutex<HashMap<u64, time::OffsetDateTime, IntHasher>>,
     #[cfg(feature = "osutracking")]
     osu_tracking: crate::tracking::OsuTracking,
     role_assigns: FlurryMap<(u64, u64), AssignRoles>, // read-heavy
     snipe_countries: FlurryMap<CountryCode, String>,  // read-heavy
     tracked_streams: FlurryMap<u64, Vec<u64>, IntHasher>, // read-heavy
+    #[cfg(all(feature = "server", feature = "twitchtracking"))]
+    twitch_loop_tick: Mutex<time::OffsetDateTime>,
 }
 
 impl ContextData {
@@ -258,6 +270,8 @@ impl ContextData {
             #[cfg(feature = "matchlive")]
             matchlive: crate::matchlive::MatchLiveChannels::new(),
             msgs_to_process: Mutex::new(HashSet::default()),
+            #[cfg(feature = "twitchtracking")]
+            online_streams: Mutex::new(HashMap::default()),
             #[cfg(feature = "osutracking")]
             osu_tracking: crate::tracking::OsuTracking::new(psql)
                 .await
@@ -274,6 +288,8 @@ impl ContextData {
                 .get_stream_tracks()
                 .await
                 .wrap_err("failed to get stream tracks")?,
+            #[cfg(all(feature = "server", feature = "twitchtracking"))]
+            twitch_loop_tick: Mutex::new(time::OffsetDateTime::now_utc()),
         })
     }
 }
@@ -19,6 +19,20 @@ impl Context {
             .map(AssignRoles::to_owned)
     }
 
+    /// Role assigns of all given channels as `((channel, message), roles)`
+    pub fn role_assigns_in(
+        &self,
+        channels: &[Id<ChannelMarker>],
+    ) -> Vec<((u64, u64), AssignRoles)> {
+        self.data
+            .role_assigns
+            .pin()
+            .iter()
+            .filter(|((channel, _), _)| channels.iter().any(|id| id.get() == *channel))
+            .map(|(key, roles)| (*key, roles.to_owned()))
+            .collect()
+    }
+
     #[cold]
     pub fn add_role_assign(
         &self,
@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use eyre::{Report, Result, WrapErr};
+use futures::stream::{self, StreamExt};
+use rosu_v2::prelude::{
+    GameMode,
+    RankStatus::{Approved, Loved, Ranked},
+};
+use tokio::time::{self, Duration};
+
+use crate::{core::OsuFutureExt, Context};
+
+/// Amount of users whose scores are archived at the same time.
+/// The osu! client applies its own ratelimit on top of this.
+const ARCHIVE_CONCURRENCY: usize = 8;
+
+const MODES: [GameMode; 4] = [
+    GameMode::Osu,
+    GameMode::Taiko,
+    GameMode::Catch,
+    GameMode::Mania,
+];
+
+impl Context {
+    /// Periodically store the top and recent scores of all linked users in all modes
+    /// so that scores beyond the API's top100 and recent limits remain searchable.
+    #[cold]
+    pub async fn score_archive_loop(ctx: Arc<Context>) {
+        // Recent scores are only available for 24 hours
+        let mut interval = time::interval(Duration::from_secs(60 * 60 * 12));
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+
+            let users = match ctx.psql().get_linked_osu_users().await {
+                Ok(users) => users,
+                Err(err) => {
+                    warn!("{:?}", err.wrap_err("[Archive] Failed to get linked users"));
+
+                    continue;
+                }
+            };
+
+            let tasks = users
+                .iter()
+                .flat_map(|&user_id| MODES.map(|mode| (user_id, mode)));
+
+            let mut archives = stream::iter(tasks)
+                .map(|(user_id, mode)| {
+                    let ctx = &ctx;
+
+                    async move { (user_id, ctx.archive_scores(user_id, mode).await) }
+                })
+                .buffer_unordered(ARCHIVE_CONCURRENCY);
+
+            let mut inserted = 0;
+
+            while let Some((user_id, result)) = archives.next().await {
+                match result {
+                    Ok(count) => inserted += count,
+                    Err(err) => {
+                        let wrap = format!("[Archive] Failed to archive scores of user {user_id}");
+                        warn!("{:?}", err.wrap_err(wrap));
+                    }
+                }
+            }
+
+            info!(
+                "[Archive] Stored {inserted} new scores of {} linked users",
+                users.len()
+            );
+        }
+    }
+
+    /// Store the top scores and recent passes of a user on ranked, loved, or approved maps.
+    ///
+    /// Returns how many scores were newly inserted.
+    pub async fn archive_scores(&self, user_id: u32, mode: GameMode) -> Result<usize> {
+        let recent_fut = self
+            .osu()
+            .user_scores(user_id)
+            .recent()
+            .mode(mode)
+            .limit(100)
+            .observe(&self.stats, "user_scores");
+
+        let best_fut = self
+            .osu()
+            .user_scores(user_id)
+            .best()
+            .mode(mode)
+            .limit(100)
+            .observe(&self.stats, "user_scores");
+
+        let (mut scores, best) = tokio::try_join!(recent_fut, best_fut)
+            .wrap_err("failed to get recent or top scores")?;
+
+        scores.extend(best);
+        scores.sort_unstable_by_key(|score| score.score_id);
+        scores.dedup_by_key(|score| score.score_id);
+
+        scores.retain(|score| {
+            score.score_id > 0
+                && score
+                    .map
+                    .as_ref()
+                    .map_or(false, |map| matches!(map.status, Ranked | Loved | Approved))
+        });
+
+        if scores.is_empty() {
+            return Ok(0);
+        }
+
+        // Archived scores are only retrievable if their map is stored
+        let mut map_ids: Vec<_> = scores
+            .iter()
+            .filter_map(|score| score.map.as_ref())
+            .map(|map| map.map_id as i32)
+            .collect();
+
+        map_ids.sort_unstable();
+        map_ids.dedup();
+
+        let stored = self
+            .psql()
+            .get_beatmaps_combo(&map_ids)
+            .await
+            .wrap_err("failed to get stored maps")?;
+
+        for map_id in map_ids {
+            if stored.contains_key(&(map_id as u32)) {
+                continue;
+            }
+
+            match self
+                .osu()
+                .beatmap()
+                .map_id(map_id as u32)
+                .observe(&self.stats, "beatmap")
+                .await
+            {
+                Ok(map) => {
+                    if let Err(err) = self.psql().insert_beatmap(&map).await {
+                        warn!("{:?}", err.wrap_err("failed to insert map in database"));
+                    }
+                }
+                Err(err) => {
+                    let wrap = format!("failed to request map {map_id}");
+                    warn!("{:?}", Report::new(err).wrap_err(wrap));
+                }
+            }
+        }
+
+        self.psql()
+            .insert_scores(&scores)
+            .await
+            .wrap_err("failed to insert scores")
+    }
+}
@@ -4,6 +4,15 @@ use crate::Context;
 
 use twilight_model::id::{marker::ChannelMarker, Id};
 
+#[cfg(feature = "twitchtracking")]
+use hashbrown::HashSet;
+
+#[cfg(feature = "twitchtracking")]
+use crate::util::hasher::IntHasher;
+
+#[cfg(feature = "twitchtracking")]
+use time::OffsetDateTime;
+
 impl Context {
     pub fn add_tracking(&self, twitch_id: u64, channel_id: u64) {
         let streams = &self.data.tracked_streams;
@@ -59,4 +68,56 @@ impl Context {
             .filter_map(|(user, channels)| channels.contains(&channel).then_some(*user))
             .collect()
     }
+
+    /// Mark a stream as online.
+    /// Returns `false` if the stream was already known to be online.
+    #[cfg(all(feature = "server", feature = "twitchtracking"))]
+    pub fn set_stream_online(&self, twitch_id: u64) -> bool {
+        let now = OffsetDateTime::now_utc();
+
+        self.data
+            .online_streams
+            .lock()
+            .insert(twitch_id, now)
+            .is_none()
+    }
+
+    #[cfg(all(feature = "server", feature = "twitchtracking"))]
+    pub fn set_stream_offline(&self, twitch_id: u64) {
+        self.data.online_streams.lock().remove(&twitch_id);
+    }
+
+    /// Merge the streams that were polled as online at `polled_at`
+    /// into the currently online streams and return the newly online ones.
+    ///
+    /// Streams that were not polled as online are only removed if they
+    /// were marked before the poll so that concurrent EventSub notifications are kept.
+    #[cfg(feature = "twitchtracking")]
+    pub fn merge_online_streams(
+        &self,
+        polled: &HashSet<u64, IntHasher>,
+        polled_at: OffsetDateTime,
+    ) -> HashSet<u64, IntHasher> {
+        let now = OffsetDateTime::now_utc();
+        let mut online = self.data.online_streams.lock();
+
+        online.retain(|twitch_id, marked_at| polled.contains(twitch_id) || *marked_at > polled_at);
+
+        polled
+            .iter()
+            .copied()
+            .filter(|&twitch_id| online.insert(twitch_id, now).is_none())
+            .collect()
+    }
+
+    /// Store that the twitch tracking loop is still running
+    #[cfg(all(feature = "server", feature = "twitchtracking"))]
+    pub fn twitch_loop_ticked(&self) {
+        *self.data.twitch_loop_tick.lock() = OffsetDateTime::now_utc();
+    }
+
+    #[cfg(all(feature = "server", feature = "twitchtracking"))]
+    pub fn last_twitch_loop_tick(&self) -> OffsetDateTime {
+        *self.data.twitch_loop_tick.lock()
+    }
 }
@@ -1,14 +1,14 @@
-use std::{mem, sync::Arc};
+use std::{mem, sync::Arc, time::Instant};
 
 use eyre::Result;
 
 use crate::{
     core::{
         commands::{
-            checks::{check_authority, check_ratelimit},
+            checks::{check_authority, check_disabled, check_ratelimit},
             slash::{SlashCommand, SlashCommands},
         },
-        events::{EventKind, ProcessResult},
+        events::{result_label, EventKind, ProcessResult},
         BotConfig, Context,
     },
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
@@ -24,7 +24,13 @@ pub async fn handle_command(ctx: Arc<Context>, mut command: InteractionCommand)
         None => return error!("unknown slash command `{name}`"),
     };
 
-    match process_command(ctx, command, slash).await {
+    let start = Instant::now();
+    let res = process_command(Arc::clone(&ctx), command, &name, slash).await;
+    let elapsed = start.elapsed();
+    ctx.stats
+        .observe_command("slash", &name, result_label(&res), elapsed);
+
+    match res {
         Ok(ProcessResult::Success) => info!("Processed slash command `{name}`"),
         Ok(res) => info!("Command `/{name}` was not processed: {res:?}"),
         Err(err) => {
@@ -37,9 +43,10 @@ pub async fn handle_command(ctx: Arc<Context>, mut command: InteractionCommand)
 async fn process_command(
     ctx: Arc<Context>,
     command: InteractionCommand,
+    name: &str,
     slash: &SlashCommand,
 ) -> Result<ProcessResult> {
-    match pre_process_command(&ctx, &command, slash).await? {
+    match pre_process_command(&ctx, &command, name, slash).await? {
         Some(result) => Ok(result),
         None => {
             if slash.flags.defer() {
@@ -56,6 +63,7 @@ async fn process_command(
 async fn pre_process_command(
     ctx: &Context,
     command: &InteractionCommand,
+    name: &str,
     slash: &SlashCommand,
 ) -> Result<Option<ProcessResult>> {
     let guild_id = command.guild_id;
@@ -68,6 +76,15 @@ async fn pre_process_command(
         return Ok(Some(ProcessResult::NoDM));
     }
 
+    // Disabled in this guild or channel?
+    let disabled_fut = check_disabled(ctx, name, slash.group, guild_id, command.channel_id);
+
+    if let Some(content) = disabled_fut.await {
+        command.error_callback(ctx, content).await?;
+
+        return Ok(Some(ProcessResult::Disabled));
+    }
+
     let user_id = command.user_id()?;
 
     // Only for owner?
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 use eyre::Result;
 use twilight_model::{channel::Message, guild::Permissions};
@@ -7,7 +7,7 @@ use crate::{
     core::{
         buckets::BucketName,
         commands::{
-            checks::{check_authority, check_ratelimit},
+            checks::{check_authority, check_disabled, check_ratelimit},
             prefix::{Args, PrefixCommand, Stream},
         },
         Context,
@@ -17,7 +17,7 @@ use crate::{
 
 use self::parse::*;
 
-use super::{EventKind, ProcessResult};
+use super::{result_label, EventKind, ProcessResult};
 
 mod parse;
 
@@ -52,7 +52,13 @@ pub async fn handle_message(ctx: Arc<Context>, msg: Message) {
     EventKind::PrefixCommand.log(&ctx, &msg, name);
     ctx.stats.increment_message_command(name);
 
-    match process_command(ctx, cmd, &msg, stream, num).await {
+    let start = Instant::now();
+    let res = process_command(Arc::clone(&ctx), cmd, &msg, stream, num).await;
+    let elapsed = start.elapsed();
+    ctx.stats
+        .observe_command("prefix", name, result_label(&res), elapsed);
+
+    match res {
         Ok(ProcessResult::Success) => info!("Processed command `{name}`"),
         Ok(result) => info!("Command `{name}` was not processed: {result:?}"),
         Err(err) => {
@@ -108,6 +114,16 @@ async fn process_command(
         return Ok(ProcessResult::Ratelimited(BucketName::All));
     }
 
+    // Disabled in this guild or channel?
+    // Checked after the global ratelimit so the response can't be spammed
+    let disabled_fut = check_disabled(&ctx, cmd.name(), Some(cmd.group), msg.guild_id, channel);
+
+    if let Some(content) = disabled_fut.await {
+        msg.error(&ctx, content).await?;
+
+        return Ok(ProcessResult::Disabled);
+    }
+
     if let Some(bucket) = cmd.bucket {
         if let Some(cooldown) = check_ratelimit(&ctx, msg.author.id, bucket).await {
             trace!(
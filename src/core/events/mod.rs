@@ -25,6 +25,16 @@ enum ProcessResult {
     Ratelimited(BucketName),
     NoOwner,
     NoAuthority,
+    Disabled,
+}
+
+/// Label of a command's result for the execution time metric
+fn result_label(res: &Result<ProcessResult>) -> &'static str {
+    match res {
+        Ok(ProcessResult::Success) => "success",
+        Ok(_) => "not_processed",
+        Err(_) => "error",
+    }
 }
 
 enum EventKind {
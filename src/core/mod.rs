@@ -4,7 +4,7 @@ pub use self::{
     context::{AssignRoles, Context, Redis},
     events::event_loop,
     redis_cache::{ArchivedBytes, ArchivedResult, RedisCache},
-    stats::BotStats,
+    stats::{BotStats, OsuFutureExt},
 };
 
 mod cache;
@@ -59,17 +59,21 @@ impl<'c> RedisCache<'c> {
                 if let Ok(bytes) = conn.get::<_, Vec<u8>>(key).await {
                     if !bytes.is_empty() {
                         self.ctx.stats.inc_cached_badges();
+                        self.ctx.stats.redis_hit("badges");
                         trace!("Found badges in cache ({} bytes)", bytes.len());
 
                         return Ok(ArchivedBytes::new(bytes));
                     }
                 }
 
+                self.ctx.stats.redis_miss("badges");
+
                 conn
             }
             Err(err) => {
                 let report = Report::new(err).wrap_err("Failed to get redis connection");
                 warn!("{report:?}");
+                self.ctx.stats.redis_miss("badges");
 
                 let badges = self.ctx.client().get_osekai_badges().await?;
 
@@ -100,17 +104,21 @@ impl<'c> RedisCache<'c> {
                 if let Ok(bytes) = conn.get::<_, Vec<u8>>(key).await {
                     if !bytes.is_empty() {
                         self.ctx.stats.inc_cached_medals();
+                        self.ctx.stats.redis_hit("medals");
                         trace!("Found medals in cache ({} bytes)", bytes.len());
 
                         return Ok(ArchivedBytes::new(bytes));
                     }
                 }
 
+                self.ctx.stats.redis_miss("medals");
+
                 conn
             }
             Err(err) => {
                 let report = Report::new(err).wrap_err("Failed to get redis connection");
                 warn!("{report:?}");
+                self.ctx.stats.redis_miss("medals");
 
                 let medals = self.ctx.client().get_osekai_medals().await?;
                 let bytes =
@@ -144,17 +152,21 @@ impl<'c> RedisCache<'c> {
                 if let Ok(bytes) = conn.get::<_, Vec<u8>>(&key).await {
                     if !bytes.is_empty() {
                         self.ctx.stats.inc_cached_osekai_ranking();
+                        self.ctx.stats.redis_hit("osekai_ranking");
                         trace!("Found osekai ranking in cache ({} bytes)", bytes.len());
 
                         return Ok(ArchivedBytes::new(bytes));
                     }
                 }
 
+                self.ctx.stats.redis_miss("osekai_ranking");
+
                 conn
             }
             Err(err) => {
                 let report = Report::new(err).wrap_err("Failed to get redis connection");
                 warn!("{report:?}");
+                self.ctx.stats.redis_miss("osekai_ranking");
 
                 let ranking = self.ctx.client().get_osekai_ranking::<R>().await?;
                 let bytes = rkyv::to_bytes::<_, 70_000>(&ranking)
@@ -185,17 +197,21 @@ impl<'c> RedisCache<'c> {
                 if let Ok(bytes) = conn.get::<_, Vec<u8>>(&key).await {
                     if !bytes.is_empty() {
                         self.ctx.stats.inc_cached_osutracker_pp_group();
+                        self.ctx.stats.redis_hit("osutracker_pp_group");
                         trace!("Found osutracker pp group in cache ({} bytes)", bytes.len());
 
                         return Ok(ArchivedBytes::new(bytes));
                     }
                 }
 
+                self.ctx.stats.redis_miss("osutracker_pp_group");
+
                 conn
             }
             Err(err) => {
                 let report = Report::new(err).wrap_err("Failed to get redis connection");
                 warn!("{report:?}");
+                self.ctx.stats.redis_miss("osutracker_pp_group");
 
                 let group = self.ctx.client().get_osutracker_pp_group(pp).await?;
                 let bytes = rkyv::to_bytes::<_, 7_000>(&group)
@@ -228,17 +244,21 @@ impl<'c> RedisCache<'c> {
                 if let Ok(bytes) = conn.get::<_, Vec<u8>>(key).await {
                     if !bytes.is_empty() {
                         self.ctx.stats.inc_cached_osutracker_stats();
+                        self.ctx.stats.redis_hit("osutracker_stats");
                         trace!("Found osutracker stats in cache ({} bytes)", bytes.len());
 
                         return Ok(ArchivedBytes::new(bytes));
                     }
                 }
 
+                self.ctx.stats.redis_miss("osutracker_stats");
+
                 conn
             }
             Err(err) => {
                 let report = Report::new(err).wrap_err("Failed to get redis connection");
                 warn!("{report:?}");
+                self.ctx.stats.redis_miss("osutracker_stats");
 
                 let stats = self.ctx.client().get_osutracker_stats().await?;
                 let bytes = rkyv::to_bytes::<_, 190_000>(&stats)
@@ -270,6 +290,7 @@ impl<'c> RedisCache<'c> {
                 if let Ok(bytes) = conn.get::<_, Vec<u8>>(key).await {
                     if !bytes.is_empty() {
                         self.ctx.stats.inc_cached_osutracker_counts();
+                        self.ctx.stats.redis_hit("osutracker_counts");
                         trace!(
                             "Found osutracker id counts in cache ({} bytes)",
                             bytes.len()
@@ -279,11 +300,14 @@ impl<'c> RedisCache<'c> {
                     }
                 }
 
+                self.ctx.stats.redis_miss("osutracker_counts");
+
                 conn
             }
             Err(err) => {
                 let report = Report::new(err).wrap_err("Failed to get redis connection");
                 warn!("{report:?}");
+                self.ctx.stats.redis_miss("osutracker_counts");
 
                 let counts = self.ctx.client().get_osutracker_counts().await?;
                 let bytes = rkyv::to_bytes::<_, 330_000>(&counts)
@@ -325,24 +349,36 @@ impl<'c> RedisCache<'c> {
                 if let Ok(bytes) = conn.get::<_, Vec<u8>>(&key).await {
                     if !bytes.is_empty() {
                         self.ctx.stats.inc_cached_pp_ranking();
+                        self.ctx.stats.redis_hit("pp_ranking");
                         trace!("Found pp ranking in cache ({} bytes)", bytes.len());
 
                         return Ok(ArchivedBytes::new(bytes));
                     }
                 }
 
+                self.ctx.stats.redis_miss("pp_ranking");
+
                 conn
             }
             Err(err) => {
                 let report = Report::new(err).wrap_err("Failed to get redis connection");
                 warn!("{report:?}");
+                self.ctx.stats.redis_miss("pp_ranking");
 
                 let ranking_fut = self.ctx.osu().performance_rankings(mode).page(page);
 
                 let ranking = if let Some(country) = country {
-                    ranking_fut.country(country).await?
+                    let ranking_fut = ranking_fut.country(country);
+
+                    self.ctx
+                        .stats
+                        .observe_osu("pp_ranking", ranking_fut)
+                        .await?
                 } else {
-                    ranking_fut.await?
+                    self.ctx
+                        .stats
+                        .observe_osu("pp_ranking", ranking_fut)
+                        .await?
                 };
 
                 let bytes =
@@ -355,9 +391,17 @@ impl<'c> RedisCache<'c> {
         let ranking_fut = self.ctx.osu().performance_rankings(mode).page(page);
 
         let ranking = if let Some(country) = country {
-            ranking_fut.country(country).await?
+            let ranking_fut = ranking_fut.country(country);
+
+            self.ctx
+                .stats
+                .observe_osu("pp_ranking", ranking_fut)
+                .await?
         } else {
-            ranking_fut.await?
+            self.ctx
+                .stats
+                .observe_osu("pp_ranking", ranking_fut)
+                .await?
         };
 
         let bytes = rkyv::to_bytes::<_, 40_000>(&ranking).expect("failed to serialize ranking");
@@ -379,6 +423,7 @@ impl<'c> RedisCache<'c> {
                 if let Ok(bytes) = conn.get::<_, Vec<u8>>(&key).await {
                     if !bytes.is_empty() {
                         self.ctx.stats.inc_cached_user();
+                        self.ctx.stats.redis_hit("osu_user");
                         trace!(
                             "Found user `{}` in cache ({} bytes)",
                             args.name,
@@ -392,13 +437,18 @@ impl<'c> RedisCache<'c> {
                     }
                 }
 
+                self.ctx.stats.redis_miss("osu_user");
+
                 conn
             }
             Err(err) => {
                 let report = Report::new(err).wrap_err("Failed to get redis connection");
                 warn!("{report:?}");
+                self.ctx.stats.redis_miss("osu_user");
+
+                let user_fut = self.ctx.osu().user(args.name).mode(args.mode);
 
-                let user = match self.ctx.osu().user(args.name).mode(args.mode).await {
+                let user = match self.ctx.stats.observe_osu("user", user_fut).await {
                     Ok(user) => user,
                     Err(OsuError::NotFound) => {
                         // Remove stats of unknown/restricted users so they don't appear in the leaderboard
@@ -422,7 +472,9 @@ impl<'c> RedisCache<'c> {
             }
         };
 
-        let mut user = match self.ctx.osu().user(args.name).mode(args.mode).await {
+        let user_fut = self.ctx.osu().user(args.name).mode(args.mode);
+
+        let mut user = match self.ctx.stats.observe_osu("user", user_fut).await {
             Ok(user) => user,
             Err(OsuError::NotFound) => {
                 // Remove stats of unknown/restricted users so they don't appear in the leaderboard
@@ -1,4 +1,13 @@
-use prometheus::{IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry};
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use futures::future::BoxFuture;
+use prometheus::{
+    HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry,
+};
+use rosu_v2::{prelude::OsuError, OsuResult};
 use time::OffsetDateTime;
 
 use super::Cache;
@@ -58,6 +67,18 @@ pub struct CommandCounters {
     pub modals: IntCounterVec,
 }
 
+pub struct RequestStats {
+    pub commands: HistogramVec,
+    pub custom_client: HistogramVec,
+    pub osu: HistogramVec,
+}
+
+#[cfg(feature = "osutracking")]
+pub struct OsuTrackingStats {
+    pub queue: IntGauge,
+    pub lag: IntGauge,
+}
+
 pub struct CacheStats {
     pub guilds: IntGauge,
     pub unavailable_guilds: IntGauge,
@@ -74,6 +95,11 @@ pub struct BotStats {
     pub command_counts: CommandCounters,
     pub cache_counts: CacheStats,
     pub osu_metrics: OsuCounters,
+    pub request_times: RequestStats,
+    pub redis_cache: IntCounterVec,
+    pub paginations: IntGauge,
+    #[cfg(feature = "osutracking")]
+    pub tracking: OsuTrackingStats,
 }
 
 macro_rules! metric_vec {
@@ -84,10 +110,14 @@ macro_rules! metric_vec {
     (gauge: $opt:literal, $help:literal, $label:expr) => {
         IntGaugeVec::new(Opts::new($opt, $help), &[$label]).unwrap()
     };
+
+    (histogram: $opt:literal, $help:literal, $($label:expr),+) => {
+        HistogramVec::new(HistogramOpts::new($opt, $help), &[$($label),+]).unwrap()
+    };
 }
 
 impl BotStats {
-    pub fn new(osu_metrics: IntCounterVec) -> Self {
+    pub fn new(osu_metrics: IntCounterVec, custom_client_metrics: HistogramVec) -> Self {
         let event_counter = metric_vec!(counter: "gateway_events", "Gateway events", "events");
         let msg_counter = metric_vec!(counter: "messages", "Received messages", "sender_type");
         let message_commands =
@@ -100,6 +130,28 @@ impl BotStats {
             metric_vec!(counter: "autocompletes", "Executed command autocompletes", "name");
         let modals = metric_vec!(counter: "modals", "Executed modals", "name");
         let cache_counter = metric_vec!(gauge: "cache", "Cache counts", "cached_type");
+        let command_times = metric_vec!(
+            histogram: "command_times",
+            "Command execution times",
+            "kind",
+            "name",
+            "result"
+        );
+        let osu_times = metric_vec!(
+            histogram: "osu_requests",
+            "osu!api request times",
+            "request",
+            "result"
+        );
+        let redis_cache = IntCounterVec::new(
+            Opts::new("redis_cache", "Redis cache lookups"),
+            &["method", "result"],
+        )
+        .unwrap();
+        let paginations = IntGauge::new("paginations", "Active paginations").unwrap();
+
+        #[cfg(feature = "osutracking")]
+        let tracking_gauge = metric_vec!(gauge: "osu_tracking", "osu! tracking queue", "kind");
 
         let registry = Registry::new_custom(Some(String::from("bathbot")), None).unwrap();
         registry.register(Box::new(event_counter.clone())).unwrap();
@@ -112,6 +164,16 @@ impl BotStats {
         registry.register(Box::new(autocompletes.clone())).unwrap();
         registry.register(Box::new(cache_counter.clone())).unwrap();
         registry.register(Box::new(osu_metrics.clone())).unwrap();
+        registry.register(Box::new(command_times.clone())).unwrap();
+        registry
+            .register(Box::new(custom_client_metrics.clone()))
+            .unwrap();
+        registry.register(Box::new(osu_times.clone())).unwrap();
+        registry.register(Box::new(redis_cache.clone())).unwrap();
+        registry.register(Box::new(paginations.clone())).unwrap();
+
+        #[cfg(feature = "osutracking")]
+        registry.register(Box::new(tracking_gauge.clone())).unwrap();
 
         Self {
             registry,
@@ -177,6 +239,18 @@ impl BotStats {
                 pp_ranking_cached: osu_metrics.with_label_values(&["Rankings cached"]),
                 rosu: osu_metrics,
             },
+            request_times: RequestStats {
+                commands: command_times,
+                custom_client: custom_client_metrics,
+                osu: osu_times,
+            },
+            redis_cache,
+            paginations,
+            #[cfg(feature = "osutracking")]
+            tracking: OsuTrackingStats {
+                queue: tracking_gauge.with_label_values(&["Queue"]),
+                lag: tracking_gauge.with_label_values(&["Lag seconds"]),
+            },
         }
     }
 
@@ -221,6 +295,52 @@ impl BotStats {
         self.command_counts.modals.with_label_values(&[modal]).inc();
     }
 
+    /// Record the execution time of a command.
+    /// `kind` is the type of command, `result` whether it was processed successfully.
+    pub fn observe_command(&self, kind: &str, name: &str, result: &str, elapsed: Duration) {
+        self.request_times
+            .commands
+            .with_label_values(&[kind, name, result])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// Await an osu!api request and record how long it took
+    pub async fn observe_osu<T>(
+        &self,
+        request: &str,
+        fut: impl Future<Output = OsuResult<T>>,
+    ) -> OsuResult<T> {
+        let start = Instant::now();
+        let res = fut.await;
+
+        let result = match res {
+            Ok(_) => "success",
+            Err(OsuError::NotFound) => "not_found",
+            Err(_) => "error",
+        };
+
+        self.request_times
+            .osu
+            .with_label_values(&[request, result])
+            .observe(start.elapsed().as_secs_f64());
+
+        res
+    }
+
+    pub fn redis_hit(&self, method: &str) {
+        self.redis_cache.with_label_values(&[method, "hit"]).inc();
+    }
+
+    pub fn redis_miss(&self, method: &str) {
+        self.redis_cache.with_label_values(&[method, "miss"]).inc();
+    }
+
+    #[cfg(feature = "osutracking")]
+    pub fn set_tracking(&self, queue: usize, lag: i64) {
+        self.tracking.queue.set(queue as i64);
+        self.tracking.lag.set(lag);
+    }
+
     pub fn inc_cached_user(&self) {
         self.osu_metrics.user_cached.inc();
     }
@@ -253,3 +373,23 @@ impl BotStats {
         self.osu_metrics.osekai_ranking_cached.inc()
     }
 }
+
+/// Record the latency and result of osu!api requests
+pub trait OsuFutureExt<T>: Future<Output = OsuResult<T>> + Send + Sized {
+    /// Await the request and record it in the stats under the given name
+    fn observe<'a>(self, stats: &'a BotStats, request: &'static str) -> BoxFuture<'a, OsuResult<T>>
+    where
+        Self: 'a,
+        T: 'a;
+}
+
+impl<T: Send, F: Future<Output = OsuResult<T>> + Send> OsuFutureExt<T> for F {
+    #[inline]
+    fn observe<'a>(self, stats: &'a BotStats, request: &'static str) -> BoxFuture<'a, OsuResult<T>>
+    where
+        Self: 'a,
+        T: 'a,
+    {
+        Box::pin(stats.observe_osu(request, self))
+    }
+}
@@ -0,0 +1,223 @@
+use std::{fmt::Write, str::FromStr};
+
+use rosu_pp::ScoreState;
+use rosu_v2::prelude::{GameMode, GameMods};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use time::{Duration, OffsetDateTime};
+
+/// Client credentials token of the osu!api
+pub(super) struct OsuToken {
+    pub access_token: String,
+    pub expires_at: OffsetDateTime,
+}
+
+impl OsuToken {
+    /// Whether the token is still valid for at least another minute
+    pub fn is_fresh(&self) -> bool {
+        self.expires_at - Duration::MINUTE > OffsetDateTime::now_utc()
+    }
+}
+
+#[derive(Deserialize)]
+pub(super) struct OsuTokenResponse {
+    access_token: String,
+    expires_in: i64,
+}
+
+impl From<OsuTokenResponse> for OsuToken {
+    #[inline]
+    fn from(response: OsuTokenResponse) -> Self {
+        Self {
+            access_token: response.access_token,
+            expires_at: OffsetDateTime::now_utc() + Duration::seconds(response.expires_in),
+        }
+    }
+}
+
+/// A score in the format of osu!lazer, including mod settings
+/// and all hit results that the stable format does not provide
+#[derive(Deserialize)]
+pub struct LazerScore {
+    pub mods: Vec<LazerMod>,
+    pub statistics: LazerStatistics,
+    pub maximum_statistics: LazerStatistics,
+    /// Standardised score
+    pub total_score: u32,
+    pub legacy_total_score: Option<u32>,
+    pub max_combo: u32,
+    pub pp: Option<f32>,
+}
+
+#[derive(Deserialize)]
+pub struct LazerMod {
+    pub acronym: String,
+    #[serde(default)]
+    pub settings: Map<String, Value>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct LazerStatistics {
+    pub perfect: u32,
+    pub great: u32,
+    pub good: u32,
+    pub ok: u32,
+    pub meh: u32,
+    pub miss: u32,
+    pub large_tick_hit: u32,
+    pub large_tick_miss: u32,
+    pub small_tick_hit: u32,
+    pub small_tick_miss: u32,
+    pub slider_tail_hit: u32,
+}
+
+impl LazerStatistics {
+    /// Amount of judgements that are neither ticks nor bonus
+    fn basic(&self) -> u32 {
+        self.perfect + self.great + self.good + self.ok + self.meh + self.miss
+    }
+}
+
+impl LazerScore {
+    /// Score in lazer's classic scoring mode, converted from the standardised score
+    pub fn classic_score(&self, mode: GameMode) -> u64 {
+        classic_score(
+            self.total_score as f64,
+            self.maximum_statistics.basic(),
+            mode,
+        )
+    }
+
+    /// Mods including their customized settings e.g. `HDDT(1.3x)`
+    pub fn mods_string(&self) -> String {
+        let mut mods = String::new();
+
+        for m in self.mods.iter() {
+            mods.push_str(&m.acronym);
+
+            let settings: Vec<_> = m
+                .settings
+                .iter()
+                .filter_map(|(key, value)| {
+                    let value = value.as_f64()?;
+
+                    let setting = match key.as_str() {
+                        "speed_change" => format!("{value}x"),
+                        "approach_rate" => format!("AR{value}"),
+                        "overall_difficulty" => format!("OD{value}"),
+                        "circle_size" => format!("CS{value}"),
+                        "drain_rate" => format!("HP{value}"),
+                        _ => return None,
+                    };
+
+                    Some(setting)
+                })
+                .collect();
+
+            if !settings.is_empty() {
+                let _ = write!(mods, "({})", settings.join(","));
+            }
+        }
+
+        mods
+    }
+
+    /// Hits of slider ends and the total amount of slider ends in osu!standard
+    pub fn slider_ends(&self) -> Option<(u32, u32)> {
+        let max = self.maximum_statistics.slider_tail_hit;
+
+        (max > 0).then_some((self.statistics.slider_tail_hit, max))
+    }
+
+    /// The stable mods that correspond to this score's mods.
+    ///
+    /// Returns `None` if a mod or one of its settings can not be represented
+    /// by stable mods, e.g. a custom speed change or difficulty adjustments.
+    pub fn stable_mods(&self) -> Option<GameMods> {
+        let mut mods = GameMods::NoMod;
+
+        for m in self.mods.iter() {
+            // Classic only affects lazer-specific judgements
+            if m.acronym == "CL" {
+                continue;
+            }
+
+            let stable = GameMods::from_str(&m.acronym).ok()?;
+
+            if let Some(speed) = m.settings.get("speed_change") {
+                let default = if stable.intersects(GameMods::HalfTime) {
+                    0.75
+                } else {
+                    1.5
+                };
+
+                if speed.as_f64() != Some(default) {
+                    return None;
+                }
+            }
+
+            mods |= stable;
+        }
+
+        Some(mods)
+    }
+
+    /// Hit results mapped onto the stable judgements of the given mode.
+    ///
+    /// Returns `None` for osu!mania scores without a stable score since
+    /// its performance calculation is based on that value.
+    pub fn state(&self, mode: GameMode) -> Option<ScoreState> {
+        let stats = &self.statistics;
+
+        let mut state = ScoreState {
+            max_combo: self.max_combo as usize,
+            score: self.legacy_total_score.unwrap_or(0),
+            ..Default::default()
+        };
+
+        match mode {
+            GameMode::Osu | GameMode::Taiko => {
+                state.n300 = stats.great as usize;
+                state.n100 = stats.ok as usize;
+                state.n50 = stats.meh as usize;
+                state.misses = stats.miss as usize;
+            }
+            GameMode::Catch => {
+                state.n300 = stats.great as usize;
+                state.n100 = stats.large_tick_hit as usize;
+                state.n50 = stats.small_tick_hit as usize;
+                state.n_katu = stats.small_tick_miss as usize;
+                state.misses = (stats.miss + stats.large_tick_miss) as usize;
+            }
+            GameMode::Mania => {
+                self.legacy_total_score?;
+
+                state.n300 = (stats.perfect + stats.great) as usize;
+                state.n_katu = stats.good as usize;
+                state.n100 = stats.ok as usize;
+                state.n50 = stats.meh as usize;
+                state.misses = stats.miss as usize;
+            }
+        }
+
+        Some(state)
+    }
+}
+
+/// Convert a standardised score into lazer's classic score.
+///
+/// `max_basic` is the amount of judgements of a perfect play that are neither
+/// ticks nor bonus, e.g. hitcircles, slider heads, and spinners in osu!standard.
+pub fn classic_score(standardised: f64, max_basic: u32, mode: GameMode) -> u64 {
+    let multiplier = match mode {
+        GameMode::Osu => 36.0,
+        GameMode::Taiko => 22.0,
+        GameMode::Catch => 28.0,
+        GameMode::Mania => 16.0,
+    };
+
+    let scaled = standardised / 1_000_000.0 * max_basic.max(1) as f64;
+
+    (scaled * scaled * multiplier).round() as u64
+}
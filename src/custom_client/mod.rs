@@ -4,7 +4,7 @@ use bytes::Bytes;
 use eyre::{Result, WrapErr};
 use hashbrown::HashSet;
 use http::{
-    header::{CONTENT_LENGTH, COOKIE},
+    header::{AUTHORIZATION, CONTENT_LENGTH, COOKIE},
     request::Builder as RequestBuilder,
     Response, StatusCode,
 };
@@ -15,11 +15,15 @@ use hyper::{
 };
 use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
 use leaky_bucket_lite::LeakyBucket;
+use prometheus::{HistogramOpts, HistogramVec};
 use rosu_v2::prelude::{GameMode, GameMods, User};
 use serde::Serialize;
 use serde_json::{Map, Value};
 use time::{format_description::FormatItem, OffsetDateTime};
-use tokio::time::{sleep, timeout, Duration};
+use tokio::{
+    sync::Mutex,
+    time::{sleep, timeout, Duration, Instant},
+};
 use twilight_model::channel::Attachment;
 
 use crate::{
@@ -35,16 +39,21 @@ use crate::{
 };
 
 pub use self::{
-    osekai::*, osu_stats::*, osu_tracker::*, respektive::*, rkyv_impls::UsernameWrapper, score::*,
-    snipe::*,
+    lazer::*, osekai::*, osu_stats::*, osu_tracker::*, respektive::*, rkyv_impls::UsernameWrapper,
+    score::*, snipe::*,
 };
 
 #[cfg(feature = "twitch")]
 pub use self::twitch::*;
 
-use self::{rkyv_impls::*, score::ScraperScores};
+use self::{
+    lazer::{OsuToken, OsuTokenResponse},
+    rkyv_impls::*,
+    score::ScraperScores,
+};
 
 mod deserialize;
+mod lazer;
 mod osekai;
 mod osu_stats;
 mod osu_tracker;
@@ -59,6 +68,9 @@ static MY_USER_AGENT: &str = env!("CARGO_PKG_NAME");
 const APPLICATION_JSON: &str = "application/json";
 const APPLICATION_URLENCODED: &str = "application/x-www-form-urlencoded";
 
+/// osu!api version from which on scores are returned in lazer's format
+const LAZER_API_VERSION: &str = "20220705";
+
 #[derive(Copy, Clone, Eq, Hash, PartialEq)]
 #[repr(u8)]
 enum Site {
@@ -66,6 +78,7 @@ enum Site {
     DiscordAttachment,
     Huismetbenen,
     Osekai,
+    OsuApi,
     OsuAvatar,
     OsuBadge,
     OsuHiddenApi,
@@ -78,14 +91,38 @@ enum Site {
     Twitch,
 }
 
+impl Site {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Cards => "Cards",
+            Self::DiscordAttachment => "DiscordAttachment",
+            Self::Huismetbenen => "Huismetbenen",
+            Self::Osekai => "Osekai",
+            Self::OsuApi => "OsuApi",
+            Self::OsuAvatar => "OsuAvatar",
+            Self::OsuBadge => "OsuBadge",
+            Self::OsuHiddenApi => "OsuHiddenApi",
+            Self::OsuMapFile => "OsuMapFile",
+            Self::OsuMapsetCover => "OsuMapsetCover",
+            Self::OsuStats => "OsuStats",
+            Self::OsuTracker => "OsuTracker",
+            Self::Respektive => "Respektive",
+            #[cfg(feature = "twitch")]
+            Self::Twitch => "Twitch",
+        }
+    }
+}
+
 type Client = HyperClient<HttpsConnector<HttpConnector<GaiResolver>>, Body>;
 
 pub struct CustomClient {
     client: Client,
+    metrics: HistogramVec,
     osu_session: &'static str,
+    osu_token: Mutex<Option<OsuToken>>,
     #[cfg(feature = "twitch")]
     twitch: TwitchData,
-    ratelimiters: [LeakyBucket; 12 + cfg!(feature = "twitch") as usize],
+    ratelimiters: [LeakyBucket; 13 + cfg!(feature = "twitch") as usize],
 }
 
 impl CustomClient {
@@ -123,6 +160,7 @@ impl CustomClient {
             ratelimiter(2),  // DiscordAttachment
             ratelimiter(2),  // Huismetbenen
             ratelimiter(2),  // Osekai
+            ratelimiter(2),  // OsuApi
             ratelimiter(10), // OsuAvatar
             ratelimiter(10), // OsuBadge
             ratelimiter(2),  // OsuHiddenApi
@@ -135,9 +173,14 @@ impl CustomClient {
             ratelimiter(5), // Twitch
         ];
 
+        let opts = HistogramOpts::new("custom_client_requests", "Custom client request times");
+        let metrics = HistogramVec::new(opts, &["site", "status"]).unwrap();
+
         Ok(Self {
             client,
+            metrics,
             osu_session: &config.tokens.osu_session,
+            osu_token: Mutex::new(None),
             #[cfg(feature = "twitch")]
             twitch,
             ratelimiters,
@@ -180,6 +223,11 @@ impl CustomClient {
         })
     }
 
+    /// Latency and status of requests for each site
+    pub fn metrics(&self) -> HistogramVec {
+        self.metrics.clone()
+    }
+
     async fn ratelimit(&self, site: Site) {
         self.ratelimiters[site as usize].acquire_one().await
     }
@@ -193,8 +241,7 @@ impl CustomClient {
         self.ratelimit(site).await;
 
         let response = self
-            .client
-            .request(req)
+            .send_request(req, site)
             .await
             .wrap_err("failed to receive GET response")?;
 
@@ -229,8 +276,7 @@ impl CustomClient {
         self.ratelimit(Site::Twitch).await;
 
         let response = self
-            .client
-            .request(req)
+            .send_request(req, Site::Twitch)
             .await
             .wrap_err("failed to receive GET response from twitch")?;
 
@@ -248,10 +294,7 @@ impl CustomClient {
             #[cfg(feature = "twitch")]
             Site::Twitch => req
                 .header("Client-ID", self.twitch.client_id.clone())
-                .header(
-                    http::header::AUTHORIZATION,
-                    format!("Bearer {}", self.twitch.oauth_token),
-                ),
+                .header(AUTHORIZATION, format!("Bearer {}", self.twitch.oauth_token)),
             _ => req,
         }
     }
@@ -281,14 +324,30 @@ impl CustomClient {
         self.ratelimit(site).await;
 
         let response = self
-            .client
-            .request(req)
+            .send_request(req, site)
             .await
             .wrap_err("failed to receive POST response")?;
 
         Self::error_for_status(response, url).await
     }
 
+    /// Send the request and record its latency and status
+    async fn send_request(&self, req: Request<Body>, site: Site) -> hyper::Result<Response<Body>> {
+        let start = Instant::now();
+        let res = self.client.request(req).await;
+
+        let status = match res {
+            Ok(ref response) => response.status().as_str().to_owned(),
+            Err(_) => "error".to_owned(),
+        };
+
+        self.metrics
+            .with_label_values(&[site.as_str(), &status])
+            .observe(start.elapsed().as_secs_f64());
+
+        res
+    }
+
     async fn error_for_status(response: Response<Body>, url: &str) -> Result<Bytes> {
         let status = response.status();
 
@@ -648,9 +707,12 @@ impl CustomClient {
 
         self.ratelimit(Site::OsuStats).await;
 
-        let response = timeout(Duration::from_secs(4), self.client.request(req))
-            .await
-            .map_err(|_| eyre!("timeout while waiting for osustats"))??;
+        let response = timeout(
+            Duration::from_secs(4),
+            self.send_request(req, Site::OsuStats),
+        )
+        .await
+        .map_err(|_| eyre!("timeout while waiting for osustats"))??;
 
         let bytes = Self::error_for_status(response, url).await?;
 
@@ -705,9 +767,12 @@ impl CustomClient {
 
         self.ratelimit(Site::OsuStats).await;
 
-        let response = timeout(Duration::from_secs(4), self.client.request(req))
-            .await
-            .map_err(|_| eyre!("timeout while waiting for osustats"))??;
+        let response = timeout(
+            Duration::from_secs(4),
+            self.send_request(req, Site::OsuStats),
+        )
+        .await
+        .map_err(|_| eyre!("timeout while waiting for osustats"))??;
 
         let status = response.status();
 
@@ -840,6 +905,74 @@ impl CustomClient {
         Ok(scores.get())
     }
 
+    /// Retrieve a score in lazer's format, including mod settings and slider end judgements
+    pub async fn get_lazer_score(&self, score_id: u64, mode: GameMode) -> Result<LazerScore> {
+        let url = format!("{OSU_BASE}api/v2/scores/{mode}/{score_id}");
+        trace!("GET request of url {url}");
+
+        let token = self
+            .osu_token()
+            .await
+            .wrap_err("failed to get osu!api token")?;
+
+        let req = self
+            .make_get_request_(&url, Site::OsuApi)
+            .header(AUTHORIZATION, format!("Bearer {token}"))
+            .header("x-api-version", LAZER_API_VERSION)
+            .body(Body::empty())?;
+
+        self.ratelimit(Site::OsuApi).await;
+
+        let response = self
+            .send_request(req, Site::OsuApi)
+            .await
+            .wrap_err("failed to receive GET response")?;
+
+        let bytes = Self::error_for_status(response, &url).await?;
+
+        serde_json::from_slice(&bytes).wrap_err_with(|| {
+            let body = String::from_utf8_lossy(&bytes);
+
+            format!("failed to deserialize lazer score: {body}")
+        })
+    }
+
+    /// Access token of the osu!api, requested anew once the current one expires
+    async fn osu_token(&self) -> Result<String> {
+        let mut token = self.osu_token.lock().await;
+
+        if let Some(ref token) = *token {
+            if token.is_fresh() {
+                return Ok(token.access_token.clone());
+            }
+        }
+
+        let tokens = &BotConfig::get().tokens;
+        let client_id = tokens.osu_client_id.to_string();
+
+        let form = &[
+            ("client_id", client_id.as_str()),
+            ("client_secret", tokens.osu_client_secret.as_str()),
+            ("grant_type", "client_credentials"),
+            ("scope", "public"),
+        ];
+
+        let url = format!("{OSU_BASE}oauth/token");
+        let bytes = self.make_post_request(url, Site::OsuApi, form).await?;
+
+        let response: OsuTokenResponse = serde_json::from_slice(&bytes).wrap_err_with(|| {
+            let body = String::from_utf8_lossy(&bytes);
+
+            format!("failed to deserialize osu!api token: {body}")
+        })?;
+
+        let new_token = OsuToken::from(response);
+        let access_token = new_token.access_token.clone();
+        *token = Some(new_token);
+
+        Ok(access_token)
+    }
+
     pub async fn get_avatar(&self, url: &str) -> Result<Bytes> {
         self.make_get_request(url, Site::OsuAvatar).await
     }
@@ -992,3 +1125,116 @@ mod twitch_impls {
         }
     }
 }
+
+#[cfg(all(feature = "server", feature = "twitchtracking"))]
+mod eventsub_impls {
+    use eyre::{ContextCompat, Result, WrapErr};
+    use http::{Method, StatusCode};
+    use hyper::{header::CONTENT_TYPE, Body};
+    use serde_json::json;
+
+    use crate::{core::BotConfig, util::constants::TWITCH_EVENTSUB_ENDPOINT};
+
+    use super::{CustomClient, Site, TwitchSubscription, TwitchSubscriptionList};
+
+    impl CustomClient {
+        /// Subscribe to the `stream.online` event of the given twitch user.
+        ///
+        /// Returns `false` if the subscription already exists.
+        pub async fn subscribe_twitch_stream(&self, user_id: u64) -> Result<bool> {
+            let config = BotConfig::get();
+
+            let secret = config
+                .tokens
+                .twitch_eventsub_secret
+                .as_deref()
+                .wrap_err("missing eventsub secret")?;
+
+            let body = json!({
+                "type": "stream.online",
+                "version": "1",
+                "condition": {
+                    "broadcaster_user_id": user_id.to_string(),
+                },
+                "transport": {
+                    "method": "webhook",
+                    "callback": format!("{}/twitch/eventsub", config.server.external_url),
+                    "secret": secret,
+                },
+            });
+
+            let req = self
+                .make_get_request_(TWITCH_EVENTSUB_ENDPOINT, Site::Twitch)
+                .method(Method::POST)
+                .header(CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string()))?;
+
+            self.ratelimit(Site::Twitch).await;
+
+            let response = self
+                .send_request(req, Site::Twitch)
+                .await
+                .wrap_err("failed to receive POST response from twitch")?;
+
+            if response.status() == StatusCode::CONFLICT {
+                return Ok(false);
+            }
+
+            Self::error_for_status(response, TWITCH_EVENTSUB_ENDPOINT).await?;
+
+            Ok(true)
+        }
+
+        /// Retrieve all `stream.online` subscriptions
+        pub async fn get_twitch_subscriptions(&self) -> Result<Vec<TwitchSubscription>> {
+            let mut subscriptions = Vec::new();
+            let mut cursor = None;
+
+            loop {
+                let mut data = vec![("type", "stream.online".to_owned())];
+
+                if let Some(cursor) = cursor.take() {
+                    data.push(("after", cursor));
+                }
+
+                let bytes = self
+                    .make_twitch_get_request(TWITCH_EVENTSUB_ENDPOINT, data)
+                    .await?;
+
+                let list: TwitchSubscriptionList =
+                    serde_json::from_slice(&bytes).wrap_err_with(|| {
+                        let body = String::from_utf8_lossy(&bytes);
+
+                        format!("failed to deserialize twitch subscriptions: {body}")
+                    })?;
+
+                subscriptions.extend(list.data);
+
+                match list.pagination.cursor {
+                    Some(next) => cursor = Some(next),
+                    None => return Ok(subscriptions),
+                }
+            }
+        }
+
+        pub async fn delete_twitch_subscription(&self, subscription_id: &str) -> Result<()> {
+            let url = format!("{TWITCH_EVENTSUB_ENDPOINT}?id={subscription_id}");
+
+            let req = self
+                .make_get_request_(&url, Site::Twitch)
+                .method(Method::DELETE)
+                .body(Body::empty())?;
+
+            self.ratelimit(Site::Twitch).await;
+
+            let response = self
+                .send_request(req, Site::Twitch)
+                .await
+                .wrap_err("failed to receive DELETE response from twitch")?;
+
+            Self::error_for_status(response, &url).await?;
+
+            Ok(())
+        }
+    }
+}
@@ -127,7 +127,7 @@ impl fmt::Display for OsuStatsScoresOrder {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct OsuStatsParams {
     pub username: Username,
     pub mode: GameMode,
@@ -99,6 +99,36 @@ pub struct TwitchVideo {
     pub username: String,
 }
 
+#[cfg(all(feature = "server", feature = "twitchtracking"))]
+#[derive(Debug, Deserialize)]
+pub struct TwitchSubscription {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub status: String,
+    pub condition: TwitchSubscriptionCondition,
+}
+
+#[cfg(all(feature = "server", feature = "twitchtracking"))]
+#[derive(Debug, Deserialize)]
+pub struct TwitchSubscriptionCondition {
+    #[serde(deserialize_with = "str_to_u64")]
+    pub broadcaster_user_id: u64,
+}
+
+#[cfg(all(feature = "server", feature = "twitchtracking"))]
+#[derive(Deserialize)]
+pub struct TwitchSubscriptionList {
+    pub data: Vec<TwitchSubscription>,
+    pub pagination: TwitchPagination,
+}
+
+#[cfg(all(feature = "server", feature = "twitchtracking"))]
+#[derive(Deserialize)]
+pub struct TwitchPagination {
+    pub cursor: Option<String>,
+}
+
 fn duration_to_u32<'de, D: Deserializer<'de>>(d: D) -> Result<u32, D::Error> {
     let mut s: &str = Deserialize::deserialize(d)?;
     let mut seconds = 0;
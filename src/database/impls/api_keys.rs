@@ -0,0 +1,62 @@
+use eyre::Result;
+use sha2::{Digest, Sha256};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{database::ApiKey, Database};
+
+impl Database {
+    pub async fn get_api_key(&self, api_key: &str) -> Result<Option<ApiKey>> {
+        let query = sqlx::query!(
+            "SELECT key_id,owner_id,label,ratelimit,created_at \
+            FROM api_keys \
+            WHERE key_hash=$1",
+            hash_api_key(api_key)
+        );
+
+        let key = query.fetch_optional(&self.pool).await?.map(|row| ApiKey {
+            key_id: row.key_id as u32,
+            owner_id: Id::new(row.owner_id as u64),
+            label: row.label,
+            ratelimit: row.ratelimit as u32,
+            created_at: row.created_at,
+        });
+
+        Ok(key)
+    }
+
+    /// Returns the id of the new key
+    pub async fn insert_api_key(
+        &self,
+        api_key: &str,
+        owner_id: Id<UserMarker>,
+        label: &str,
+        ratelimit: u32,
+    ) -> Result<u32> {
+        let query = sqlx::query!(
+            "INSERT INTO api_keys (key_hash,owner_id,label,ratelimit) \
+            VALUES ($1,$2,$3,$4) \
+            RETURNING key_id",
+            hash_api_key(api_key),
+            owner_id.get() as i64,
+            label,
+            ratelimit as i32,
+        );
+
+        let row = query.fetch_one(&self.pool).await?;
+
+        Ok(row.key_id as u32)
+    }
+
+    /// Returns whether a key was removed
+    pub async fn remove_api_key(&self, key_id: u32) -> Result<bool> {
+        let query = sqlx::query!("DELETE FROM api_keys WHERE key_id=$1", key_id as i32);
+        let result = query.execute(&self.pool).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// Only hashes of API keys are stored so that leaked rows can't be used as keys
+fn hash_api_key(api_key: &str) -> String {
+    hex::encode(Sha256::digest(api_key.as_bytes()))
+}
@@ -0,0 +1,71 @@
+use eyre::Result;
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::{
+    database::{AuditAction, AuditLogEntry},
+    Database,
+};
+
+impl Database {
+    pub async fn insert_audit_log(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        action: AuditAction,
+        details: &str,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            "INSERT INTO audit_log (guild_id,user_id,action,details) \
+            VALUES ($1,$2,$3,$4)",
+            guild_id.get() as i64,
+            user_id.get() as i64,
+            action as i16,
+            details,
+        );
+
+        query.execute(&self.pool).await?;
+
+        Ok(())
+    }
+
+    /// Returns the most recent entries of a guild, newest first
+    pub async fn get_audit_log(
+        &self,
+        guild_id: Id<GuildMarker>,
+        limit: usize,
+    ) -> Result<Vec<AuditLogEntry>> {
+        let query = sqlx::query!(
+            "SELECT user_id,action,details,created_at \
+            FROM audit_log \
+            WHERE guild_id=$1 \
+            ORDER BY created_at DESC \
+            LIMIT $2",
+            guild_id.get() as i64,
+            limit as i64,
+        );
+
+        let entries = query
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .filter_map(|row| match AuditAction::try_from(row.action) {
+                Ok(action) => Some(AuditLogEntry {
+                    user_id: Id::new(row.user_id as u64),
+                    action,
+                    details: row.details,
+                    created_at: row.created_at,
+                }),
+                Err(code) => {
+                    warn!("Skipping audit log entry with unknown action {code}");
+
+                    None
+                }
+            })
+            .collect();
+
+        Ok(entries)
+    }
+}
@@ -0,0 +1,271 @@
+use eyre::{Result, WrapErr};
+use futures::stream::StreamExt;
+use rosu_v2::prelude::{GameMode, GameMods, Score};
+use time::OffsetDateTime;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker},
+    Id,
+};
+
+use crate::{
+    database::{Competition, CompetitionScore, CompetitionWinner},
+    Database,
+};
+
+macro_rules! competition {
+    ($row:ident) => {
+        Competition {
+            competition_id: $row.competition_id as u32,
+            guild_id: Id::new($row.guild_id as u64),
+            channel_id: Id::new($row.channel_id as u64),
+            message_id: $row.message_id.map(|id| Id::new(id as u64)),
+            map_id: $row.map_id as u32,
+            mode: GameMode::from($row.mode as u8),
+            mods: $row
+                .mods
+                .map(|mods| GameMods::from_bits(mods as u32).unwrap_or_default()),
+            started_at: $row.started_at,
+            ends_at: $row.ends_at,
+        }
+    };
+}
+
+impl Database {
+    /// Store a new competition and return its id.
+    ///
+    /// Returns `None` if the guild already has an active competition.
+    pub async fn insert_competition(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+        map_id: u32,
+        mode: GameMode,
+        mods: Option<GameMods>,
+        ends_at: OffsetDateTime,
+    ) -> Result<Option<u32>> {
+        let query = sqlx::query!(
+            "INSERT INTO competitions \
+                (guild_id,channel_id,map_id,mode,mods,started_at,ends_at) \
+            VALUES ($1,$2,$3,$4,$5,NOW(),$6) \
+            ON CONFLICT (guild_id) WHERE NOT finished DO NOTHING \
+            RETURNING competition_id",
+            guild_id.get() as i64,
+            channel_id.get() as i64,
+            map_id as i32,
+            mode as i16,
+            mods.map(|mods| mods.bits() as i32),
+            ends_at,
+        );
+
+        let row = query
+            .fetch_optional(&self.pool)
+            .await
+            .wrap_err("failed to insert competition")?;
+
+        Ok(row.map(|row| row.competition_id as u32))
+    }
+
+    pub async fn get_active_competitions(&self) -> Result<Vec<Competition>> {
+        let query = sqlx::query!(
+            "SELECT competition_id,guild_id,channel_id,message_id,map_id,mode,mods,started_at,ends_at \
+            FROM competitions \
+            WHERE NOT finished"
+        );
+
+        let mut stream = query.fetch(&self.pool);
+        let mut competitions = Vec::new();
+
+        while let Some(row) = stream.next().await.transpose()? {
+            competitions.push(competition!(row));
+        }
+
+        Ok(competitions)
+    }
+
+    /// Finished competitions whose winner announcement failed
+    /// and that are not currently being finished
+    pub async fn get_unannounced_competitions(&self) -> Result<Vec<Competition>> {
+        let query = sqlx::query!(
+            "SELECT competition_id,guild_id,channel_id,message_id,map_id,mode,mods,started_at,ends_at \
+            FROM competitions \
+            WHERE finished AND NOT announced AND finished_at < NOW() - interval '10 minutes'"
+        );
+
+        let mut stream = query.fetch(&self.pool);
+        let mut competitions = Vec::new();
+
+        while let Some(row) = stream.next().await.transpose()? {
+            competitions.push(competition!(row));
+        }
+
+        Ok(competitions)
+    }
+
+    pub async fn get_guild_competition(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Option<Competition>> {
+        let query = sqlx::query!(
+            "SELECT competition_id,guild_id,channel_id,message_id,map_id,mode,mods,started_at,ends_at \
+            FROM competitions \
+            WHERE guild_id=$1 AND NOT finished",
+            guild_id.get() as i64
+        );
+
+        let row = query
+            .fetch_optional(&self.pool)
+            .await
+            .wrap_err("failed to get guild competition")?;
+
+        Ok(row.map(|row| competition!(row)))
+    }
+
+    pub async fn update_competition_message(
+        &self,
+        competition_id: u32,
+        message_id: Id<MessageMarker>,
+    ) -> Result<()> {
+        sqlx::query!(
+            "UPDATE competitions SET message_id=$2 WHERE competition_id=$1",
+            competition_id as i32,
+            message_id.get() as i64,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err("failed to update competition message")?;
+
+        Ok(())
+    }
+
+    /// Mark the competition as finished.
+    ///
+    /// Returns `false` if it was already finished.
+    pub async fn finish_competition(&self, competition_id: u32) -> Result<bool> {
+        let query = sqlx::query!(
+            "UPDATE competitions \
+            SET finished=TRUE,finished_at=NOW() \
+            WHERE competition_id=$1 AND NOT finished \
+            RETURNING competition_id",
+            competition_id as i32,
+        );
+
+        let row = query
+            .fetch_optional(&self.pool)
+            .await
+            .wrap_err("failed to finish competition")?;
+
+        Ok(row.is_some())
+    }
+
+    pub async fn set_competition_announced(&self, competition_id: u32) -> Result<()> {
+        sqlx::query!(
+            "UPDATE competitions SET announced=TRUE WHERE competition_id=$1",
+            competition_id as i32,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err("failed to set competition as announced")?;
+
+        Ok(())
+    }
+
+    /// Store the score unless the user already has a better one in the competition
+    pub async fn upsert_competition_score(&self, competition_id: u32, score: &Score) -> Result<()> {
+        let query = sqlx::query!(
+            "INSERT INTO competition_scores \
+                (competition_id,user_id,score_id,score,accuracy,max_combo,mods,pp,ended_at) \
+            VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9) \
+            ON CONFLICT (competition_id,user_id) DO \
+            UPDATE \
+            SET score_id=$3,score=$4,accuracy=$5,max_combo=$6,mods=$7,pp=$8,ended_at=$9 \
+            WHERE competition_scores.score < $4",
+            competition_id as i32,
+            score.user_id as i32,
+            score.score_id as i64,
+            score.score as i32,
+            score.accuracy,
+            score.max_combo as i32,
+            score.mods.bits() as i32,
+            score.pp,
+            score.ended_at,
+        );
+
+        query
+            .execute(&self.pool)
+            .await
+            .wrap_err("failed to upsert competition score")?;
+
+        Ok(())
+    }
+
+    /// Scores of a competition, sorted by score in descending order
+    pub async fn get_competition_scores(
+        &self,
+        competition_id: u32,
+    ) -> Result<Vec<CompetitionScore>> {
+        let query = sqlx::query!(
+            "SELECT user_id,score_id,score,accuracy,max_combo,mods,pp,ended_at \
+            FROM competition_scores \
+            WHERE competition_id=$1 \
+            ORDER BY score DESC, ended_at ASC",
+            competition_id as i32
+        );
+
+        let mut stream = query.fetch(&self.pool);
+        let mut scores = Vec::new();
+
+        while let Some(row) = stream.next().await.transpose()? {
+            let score = CompetitionScore {
+                user_id: row.user_id as u32,
+                score_id: row.score_id as u64,
+                score: row.score as u32,
+                accuracy: row.accuracy,
+                max_combo: row.max_combo as u32,
+                mods: GameMods::from_bits(row.mods as u32).unwrap_or_default(),
+                pp: row.pp,
+                ended_at: row.ended_at,
+            };
+
+            scores.push(score);
+        }
+
+        Ok(scores)
+    }
+
+    /// Winners of all finished competitions in a guild, most recent first
+    pub async fn get_competition_winners(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<CompetitionWinner>> {
+        let query = sqlx::query!(
+            "SELECT DISTINCT ON (competitions.competition_id) \
+                competitions.competition_id,map_id,competitions.mods,ends_at,user_id,score \
+            FROM competitions \
+            JOIN competition_scores \
+                ON competitions.competition_id=competition_scores.competition_id \
+            WHERE guild_id=$1 AND finished \
+            ORDER BY competitions.competition_id DESC, score DESC, ended_at ASC",
+            guild_id.get() as i64
+        );
+
+        let mut stream = query.fetch(&self.pool);
+        let mut winners = Vec::new();
+
+        while let Some(row) = stream.next().await.transpose()? {
+            let winner = CompetitionWinner {
+                competition_id: row.competition_id as u32,
+                map_id: row.map_id as u32,
+                mods: row
+                    .mods
+                    .map(|mods| GameMods::from_bits(mods as u32).unwrap_or_default()),
+                ends_at: row.ends_at,
+                user_id: row.user_id as u32,
+                score: row.score as u32,
+            };
+
+            winners.push(winner);
+        }
+
+        Ok(winners)
+    }
+}
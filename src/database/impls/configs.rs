@@ -10,13 +10,15 @@ use twilight_model::id::{
 use crate::{
     commands::osu::ProfileSize,
     database::{
-        models::{EmbedsSize, ListSize, OsuData},
+        models::{EmbedsSize, ListSize, OsuData, ScoreKind},
         GuildConfig, MinimizedPp, UserConfig,
     },
     util::hasher::IntHasher,
     Database,
 };
 
+use super::osu_users::upsert_osu_name_;
+
 impl Database {
     #[cold]
     pub async fn get_guilds(&self) -> Result<FlurryMap<Id<GuildMarker>, GuildConfig, IntHasher>> {
@@ -27,13 +29,20 @@ impl Database {
             let gref = guilds.pin();
 
             while let Some(entry) = stream.next().await.transpose()? {
+                let disabled_commands = match entry.disabled_commands {
+                    Some(ref bytes) => serde_cbor::from_slice(bytes)?,
+                    None => Vec::new(),
+                };
+
                 let config = GuildConfig {
                     authorities: serde_cbor::from_slice(&entry.authorities)?,
+                    disabled_commands,
                     embeds_size: entry.embeds_size.map(EmbedsSize::from),
                     list_size: entry.list_size.map(ListSize::from),
                     minimized_pp: entry.minimized_pp.map(MinimizedPp::from),
                     prefixes: serde_cbor::from_slice(&entry.prefixes)?,
                     profile_size: entry.profile_size.map(ProfileSize::from),
+                    public_api: entry.public_api,
                     show_retries: entry.show_retries,
                     track_limit: entry.track_limit.map(|limit| limit as u8),
                     with_lyrics: entry.with_lyrics,
@@ -62,9 +71,11 @@ impl Database {
                 profile_size,\
                 show_retries,\
                 track_limit,\
-                with_lyrics\
+                with_lyrics,\
+                public_api,\
+                disabled_commands\
             )\
-            VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10) ON CONFLICT (guild_id) DO \
+            VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12) ON CONFLICT (guild_id) DO \
             UPDATE \
             SET authorities=$2,\
                 embeds_size=$3,\
@@ -74,7 +85,9 @@ impl Database {
                 profile_size=$7,\
                 show_retries=$8,\
                 track_limit=$9,\
-                with_lyrics=$10",
+                with_lyrics=$10,\
+                public_api=$11,\
+                disabled_commands=$12",
             guild_id.get() as i64,
             serde_cbor::to_vec(&config.authorities)?,
             config.embeds_size.map(|size| size as u8 as i16),
@@ -85,6 +98,8 @@ impl Database {
             config.show_retries,
             config.track_limit.map(|limit| limit as i16),
             config.with_lyrics,
+            config.public_api,
+            serde_cbor::to_vec(&config.disabled_commands)?,
         );
 
         query.execute(&self.pool).await?;
@@ -142,6 +157,7 @@ impl Database {
                     mode: entry.mode.map(|mode| mode as u8).map(GameMode::from),
                     osu: Some(osu),
                     profile_size: entry.profile_size.map(ProfileSize::from),
+                    score_kind: entry.score_kind.map(ScoreKind::from),
                     show_retries: entry.show_retries,
                     twitch_id: entry.twitch_id.map(|id| id as u64),
                 };
@@ -153,21 +169,28 @@ impl Database {
     }
 
     pub async fn get_user_config_by_osu(&self, username: &str) -> Result<Option<UserConfig>> {
+        // Prefer the current holder of the name, otherwise
+        // check for the most recent user that previously had it
         let query = sqlx::query!(
-            "SELECT * \
-            FROM\
-              (SELECT user_id \
-               FROM osu_user_names \
-               WHERE username=$1) AS user_ids \
-            JOIN user_configs ON user_ids.user_id=user_configs.osu_id",
+            "SELECT configs.*,names.user_id AS osu_user_id,names.username \
+            FROM user_configs AS configs \
+            JOIN osu_user_names AS names ON configs.osu_id=names.user_id \
+            LEFT JOIN \
+              (SELECT user_id,MAX(observed_at) AS observed_at \
+               FROM osu_user_name_history \
+               WHERE username=$1 \
+               GROUP BY user_id) AS previous ON configs.osu_id=previous.user_id \
+            WHERE names.username=$1 OR previous.user_id IS NOT NULL \
+            ORDER BY names.username=$1 DESC, previous.observed_at DESC \
+            LIMIT 1",
             username
         );
 
         match query.fetch_optional(&self.pool).await? {
             Some(entry) => {
                 let osu = OsuData::User {
-                    user_id: entry.user_id as u32,
-                    username: username.into(),
+                    user_id: entry.osu_user_id as u32,
+                    username: entry.username.into(),
                 };
 
                 let config = UserConfig {
@@ -177,6 +200,7 @@ impl Database {
                     mode: entry.mode.map(|mode| mode as u8).map(GameMode::from),
                     osu: Some(osu),
                     profile_size: entry.profile_size.map(ProfileSize::from),
+                    score_kind: entry.score_kind.map(ScoreKind::from),
                     show_retries: entry.show_retries,
                     twitch_id: entry.twitch_id.map(|id| id as u64),
                 };
@@ -205,10 +229,11 @@ impl Database {
                 mode,\
                 osu_id,\
                 profile_size,\
+                score_kind,\
                 show_retries,\
                 twitch_id\
             )\
-            VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9) ON CONFLICT (discord_id) DO \
+            VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10) ON CONFLICT (discord_id) DO \
             UPDATE \
             SET embeds_size=$2,\
                 list_size=$3,\
@@ -216,8 +241,9 @@ impl Database {
                 mode=$5,\
                 osu_id=$6,\
                 profile_size=$7,\
-                show_retries=$8,\
-                twitch_id=$9",
+                score_kind=$8,\
+                show_retries=$9,\
+                twitch_id=$10",
             user_id.get() as i64,
             config.score_size.map(|size| size as u8 as i16),
             config.list_size.map(|size| size as u8 as i16),
@@ -229,6 +255,7 @@ impl Database {
                 .and_then(OsuData::user_id)
                 .map(|id| id as i32),
             config.profile_size.map(|size| size as i16),
+            config.score_kind.map(|kind| kind as u8 as i16),
             config.show_retries,
             config.twitch_id.map(|id| id as i64)
         );
@@ -240,18 +267,9 @@ impl Database {
     }
 
     pub async fn upsert_osu_name(&self, user_id: u32, username: &str) -> Result<()> {
-        let query = sqlx::query!(
-            "INSERT INTO osu_user_names (user_id,username)\
-            VALUES ($1,$2) ON CONFLICT (user_id) DO \
-            UPDATE \
-            SET username=$2",
-            user_id as i32,
-            username,
-        );
-
-        query.execute(&self.pool).await?;
+        let mut conn = self.pool.acquire().await?;
 
-        Ok(())
+        upsert_osu_name_(&mut conn, user_id, username).await
     }
 
     pub async fn get_discord_from_osu_id(&self, user_id: u32) -> Result<Option<Id<UserMarker>>> {
@@ -267,4 +285,46 @@ impl Database {
 
         Ok(discord_id)
     }
+
+    /// osu! user ids of all linked users
+    pub async fn get_linked_osu_users(&self) -> Result<Vec<u32>> {
+        let query = sqlx::query!(
+            "SELECT DISTINCT osu_id \
+            FROM user_configs \
+            WHERE osu_id IS NOT NULL"
+        );
+
+        let mut stream = query.fetch(&self.pool);
+        let mut users = Vec::new();
+
+        while let Some(entry) = stream.next().await.transpose()? {
+            if let Some(user_id) = entry.osu_id {
+                users.push(user_id as u32);
+            }
+        }
+
+        Ok(users)
+    }
+
+    /// osu! user ids of all linked users among the given discord users
+    pub async fn get_member_osu_ids(&self, discord_ids: &[i64]) -> Result<Vec<u32>> {
+        let query = sqlx::query!(
+            "SELECT DISTINCT osu_id \
+            FROM user_configs \
+            WHERE discord_id=ANY($1) \
+                AND osu_id IS NOT NULL",
+            discord_ids
+        );
+
+        let mut stream = query.fetch(&self.pool);
+        let mut user_ids = Vec::new();
+
+        while let Some(entry) = stream.next().await.transpose()? {
+            if let Some(user_id) = entry.osu_id {
+                user_ids.push(user_id as u32);
+            }
+        }
+
+        Ok(user_ids)
+    }
 }
@@ -1,9 +1,14 @@
+mod api_keys;
+mod audit_log;
 mod bggame;
+mod competitions;
 mod configs;
 mod higherlower;
 mod maps;
 mod osu_users;
 mod role_assign;
+mod scores;
+mod skills;
 mod snipe_country;
 mod streams;
 
@@ -4,12 +4,15 @@ use eyre::{Result, WrapErr};
 use futures::stream::StreamExt;
 use hashbrown::HashMap;
 use rosu_v2::prelude::{GameMode, User, Username};
-use sqlx::Row;
+use sqlx::{PgConnection, Row};
 use time::OffsetDateTime;
 
 use crate::{
     commands::osu::UserValue,
-    database::{Database, UserStatsColumn, UserValueRaw},
+    database::{
+        CountryEntry, Database, MapperSnapshot, OsuUserHistory, UserStatsColumn, UserStatsSnapshot,
+        UserValueRaw, UsernameEntry,
+    },
     embeds::RankingEntry,
     util::hasher::IntHasher,
 };
@@ -294,19 +297,7 @@ LIMIT 1)) AS neighbors",
     pub async fn upsert_osu_user(&self, user: &User, mode: GameMode) -> Result<()> {
         let mut tx = self.pool.begin().await?;
 
-        let name_query = sqlx::query!(
-            "INSERT INTO osu_user_names (user_id, username)\
-            VALUES ($1,$2) ON CONFLICT (user_id) DO \
-            UPDATE \
-            SET username=$2",
-            user.user_id as i32,
-            user.username.as_str(),
-        );
-
-        name_query
-            .execute(&mut tx)
-            .await
-            .wrap_err("failed to insert name entry")?;
+        upsert_osu_name_(&mut tx, user.user_id, user.username.as_str()).await?;
 
         let stats_query = sqlx::query!(
             "INSERT INTO osu_user_stats (\
@@ -365,6 +356,24 @@ LIMIT 1)) AS neighbors",
             .await
             .wrap_err("failed to insert stats entry")?;
 
+        // Only store the country if it differs from the last observed one
+        let country_history_query = sqlx::query!(
+            "INSERT INTO osu_user_country_history (user_id,country_code) \
+            SELECT $1,$2 \
+            WHERE $2 IS DISTINCT FROM (\
+                SELECT country_code FROM osu_user_country_history \
+                WHERE user_id=$1 \
+                ORDER BY observed_at DESC LIMIT 1\
+            )",
+            user.user_id as i32,
+            user.country_code.as_str(),
+        );
+
+        country_history_query
+            .execute(&mut tx)
+            .await
+            .wrap_err("failed to insert country history entry")?;
+
         if let Some(ref stats) = user.statistics {
             let mode_stats_query = sqlx::query!(
                 "INSERT INTO osu_user_stats_mode (\
@@ -435,11 +444,233 @@ LIMIT 1)) AS neighbors",
                 .execute(&mut tx)
                 .await
                 .wrap_err("failed to insert mode stats entry")?;
+
+            // Only store a new snapshot if the user played since
+            // the last snapshot or if the last snapshot is a day old.
+            // The timestamp is taken from the bot's clock so that it
+            // can be compared with the bot's times when reading snapshots.
+            let snapshot_query = sqlx::query!(
+                "INSERT INTO osu_user_stats_history (\
+                    user_id,\
+                    mode,\
+                    timestamp,\
+                    pp,\
+                    global_rank,\
+                    accuracy,\
+                    playcount,\
+                    ranked_score\
+                )\
+                SELECT $1,$2,$3,$4,$5,$6,$7,$8 \
+                WHERE NOT EXISTS (\
+                    SELECT 1 FROM osu_user_stats_history \
+                    WHERE user_id=$1 AND mode=$2 AND playcount=$7 \
+                    AND timestamp > $3 - interval '1 day'\
+                )",
+                user.user_id as i32,
+                mode as i16,
+                OffsetDateTime::now_utc(),
+                stats.pp,
+                stats.global_rank.map(|rank| rank as i32),
+                stats.accuracy,
+                stats.playcount as i32,
+                stats.ranked_score as i64,
+            );
+
+            snapshot_query
+                .execute(&mut tx)
+                .await
+                .wrap_err("failed to insert stats snapshot")?;
         }
 
         Ok(tx.commit().await?)
     }
 
+    pub async fn get_osu_user_history(&self, user_id: u32) -> Result<OsuUserHistory> {
+        let names_query = sqlx::query!(
+            "SELECT username,observed_at \
+            FROM osu_user_name_history \
+            WHERE user_id=$1 \
+            ORDER BY observed_at ASC",
+            user_id as i32
+        );
+
+        let mut stream = names_query.fetch(&self.pool);
+        let mut history = OsuUserHistory::default();
+
+        while let Some(row) = stream.next().await.transpose()? {
+            let entry = UsernameEntry {
+                username: row.username.into(),
+                observed_at: observed_at(row.observed_at),
+            };
+
+            history.names.push(entry);
+        }
+
+        let countries_query = sqlx::query!(
+            "SELECT country_code,observed_at \
+            FROM osu_user_country_history \
+            WHERE user_id=$1 \
+            ORDER BY observed_at ASC",
+            user_id as i32
+        );
+
+        let mut stream = countries_query.fetch(&self.pool);
+
+        while let Some(row) = stream.next().await.transpose()? {
+            let entry = CountryEntry {
+                country_code: row.country_code.into(),
+                observed_at: observed_at(row.observed_at),
+            };
+
+            history.countries.push(entry);
+        }
+
+        Ok(history)
+    }
+
+    /// Stats snapshots of a user since the given timestamp, sorted by timestamp in ascending order.
+    ///
+    /// Also includes the latest snapshot before the timestamp, if any, to serve as baseline.
+    pub async fn get_osu_user_stats_history(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        since: OffsetDateTime,
+    ) -> Result<Vec<UserStatsSnapshot>> {
+        let baseline_query = sqlx::query!(
+            "SELECT timestamp,pp,global_rank,accuracy,playcount,ranked_score \
+            FROM osu_user_stats_history \
+            WHERE user_id=$1 AND mode=$2 AND timestamp<$3 \
+            ORDER BY timestamp DESC \
+            LIMIT 1",
+            user_id as i32,
+            mode as i16,
+            since,
+        );
+
+        let mut snapshots = Vec::new();
+
+        let baseline = baseline_query
+            .fetch_optional(&self.pool)
+            .await
+            .wrap_err("failed to get baseline snapshot")?;
+
+        if let Some(row) = baseline {
+            snapshots.push(UserStatsSnapshot {
+                timestamp: row.timestamp,
+                pp: row.pp,
+                global_rank: row.global_rank.map(|rank| rank as u32),
+                accuracy: row.accuracy,
+                playcount: row.playcount as u32,
+                ranked_score: row.ranked_score as u64,
+            });
+        }
+
+        let query = sqlx::query!(
+            "SELECT timestamp,pp,global_rank,accuracy,playcount,ranked_score \
+            FROM osu_user_stats_history \
+            WHERE user_id=$1 AND mode=$2 AND timestamp>=$3 \
+            ORDER BY timestamp ASC",
+            user_id as i32,
+            mode as i16,
+            since,
+        );
+
+        let mut stream = query.fetch(&self.pool);
+
+        while let Some(row) = stream
+            .next()
+            .await
+            .transpose()
+            .wrap_err("failed to get stats history")?
+        {
+            snapshots.push(UserStatsSnapshot {
+                timestamp: row.timestamp,
+                pp: row.pp,
+                global_rank: row.global_rank.map(|rank| rank as u32),
+                accuracy: row.accuracy,
+                playcount: row.playcount as u32,
+                ranked_score: row.ranked_score as u64,
+            });
+        }
+
+        Ok(snapshots)
+    }
+
+    /// Store the current popularity of a mapper's ranked mapsets,
+    /// unless a snapshot was already stored within the last day.
+    pub async fn insert_mapper_snapshot(
+        &self,
+        user_id: u32,
+        playcount: u64,
+        favourite_count: u64,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            "INSERT INTO mapper_stats_history (\
+                user_id,\
+                timestamp,\
+                playcount,\
+                favourite_count\
+            )\
+            SELECT $1,$2,$3,$4 \
+            WHERE NOT EXISTS (\
+                SELECT 1 FROM mapper_stats_history \
+                WHERE user_id=$1 AND timestamp > $2 - interval '1 day'\
+            )",
+            user_id as i32,
+            OffsetDateTime::now_utc(),
+            playcount as i64,
+            favourite_count as i64,
+        );
+
+        query
+            .execute(&self.pool)
+            .await
+            .wrap_err("failed to insert mapper snapshot")?;
+
+        Ok(())
+    }
+
+    /// Popularity snapshots of a mapper, sorted by timestamp in ascending order
+    pub async fn get_mapper_history(&self, user_id: u32) -> Result<Vec<MapperSnapshot>> {
+        let query = sqlx::query!(
+            "SELECT timestamp,playcount,favourite_count \
+            FROM mapper_stats_history \
+            WHERE user_id=$1 \
+            ORDER BY timestamp ASC",
+            user_id as i32,
+        );
+
+        let snapshots = query
+            .fetch_all(&self.pool)
+            .await
+            .wrap_err("failed to get mapper history")?
+            .into_iter()
+            .map(|row| MapperSnapshot {
+                timestamp: row.timestamp,
+                playcount: row.playcount as u64,
+                favourite_count: row.favourite_count as u64,
+            })
+            .collect();
+
+        Ok(snapshots)
+    }
+
+    /// All mappers that have popularity snapshots
+    pub async fn get_snapshot_mappers(&self) -> Result<Vec<u32>> {
+        let query = sqlx::query!("SELECT DISTINCT user_id FROM mapper_stats_history");
+
+        let user_ids = query
+            .fetch_all(&self.pool)
+            .await
+            .wrap_err("failed to get mappers")?
+            .into_iter()
+            .map(|row| row.user_id as u32)
+            .collect();
+
+        Ok(user_ids)
+    }
+
     pub async fn get_osu_users_stats(
         &self,
         column: UserStatsColumn,
@@ -1030,3 +1261,50 @@ LIMIT 1)) AS neighbors",
         Ok(users)
     }
 }
+
+/// Store the user's current name and add it to the name history
+/// if it differs from the last observed one
+pub(super) async fn upsert_osu_name_(
+    conn: &mut PgConnection,
+    user_id: u32,
+    username: &str,
+) -> Result<()> {
+    let name_query = sqlx::query!(
+        "INSERT INTO osu_user_names (user_id,username)\
+        VALUES ($1,$2) ON CONFLICT (user_id) DO \
+        UPDATE \
+        SET username=$2",
+        user_id as i32,
+        username,
+    );
+
+    name_query
+        .execute(&mut *conn)
+        .await
+        .wrap_err("failed to insert name entry")?;
+
+    let name_history_query = sqlx::query!(
+        "INSERT INTO osu_user_name_history (user_id,username) \
+        SELECT $1,$2 \
+        WHERE $2 IS DISTINCT FROM (\
+            SELECT username FROM osu_user_name_history \
+            WHERE user_id=$1 \
+            ORDER BY observed_at DESC LIMIT 1\
+        )",
+        user_id as i32,
+        username,
+    );
+
+    name_history_query
+        .execute(conn)
+        .await
+        .wrap_err("failed to insert name history entry")?;
+
+    Ok(())
+}
+
+/// History entries that were backfilled from existing data
+/// are stored with the unix epoch since their actual date is unknown
+fn observed_at(datetime: OffsetDateTime) -> Option<OffsetDateTime> {
+    (datetime != OffsetDateTime::UNIX_EPOCH).then_some(datetime)
+}
@@ -0,0 +1,279 @@
+use eyre::{Result, WrapErr};
+use futures::stream::StreamExt;
+use hashbrown::HashMap;
+use rosu_v2::prelude::{Beatmap, BeatmapsetCompact, GameMode, Grade, Score};
+use time::OffsetDateTime;
+
+use crate::{
+    database::{DBBeatmap, DBBeatmapset, DBScore},
+    util::hasher::IntHasher,
+    Database,
+};
+
+impl Database {
+    /// Store scores in the archive, skipping the ones that are already stored.
+    ///
+    /// Returns how many scores were newly inserted.
+    pub async fn insert_scores(&self, scores: &[Score]) -> Result<usize> {
+        let len = scores.len();
+
+        let mut score_ids = Vec::with_capacity(len);
+        let mut user_ids = Vec::with_capacity(len);
+        let mut map_ids = Vec::with_capacity(len);
+        let mut modes = Vec::with_capacity(len);
+        let mut mods = Vec::with_capacity(len);
+        let mut score_values = Vec::with_capacity(len);
+        let mut max_combos = Vec::with_capacity(len);
+        let mut accuracies = Vec::with_capacity(len);
+        let mut pps = Vec::with_capacity(len);
+        let mut grades = Vec::with_capacity(len);
+        let mut perfects = Vec::with_capacity(len);
+        let mut count_gekis = Vec::with_capacity(len);
+        let mut count_300s = Vec::with_capacity(len);
+        let mut count_katus = Vec::with_capacity(len);
+        let mut count_100s = Vec::with_capacity(len);
+        let mut count_50s = Vec::with_capacity(len);
+        let mut count_misses = Vec::with_capacity(len);
+        let mut ended_ats = Vec::with_capacity(len);
+
+        for score in scores {
+            let map_id = match score.map {
+                Some(ref map) => map.map_id,
+                None => continue,
+            };
+
+            score_ids.push(score.score_id as i64);
+            user_ids.push(score.user_id as i32);
+            map_ids.push(map_id as i32);
+            modes.push(score.mode as i16);
+            mods.push(score.mods.bits() as i32);
+            score_values.push(score.score as i32);
+            max_combos.push(score.max_combo as i32);
+            accuracies.push(score.accuracy);
+            pps.push(score.pp);
+            grades.push(grade_to_i16(score.grade));
+            perfects.push(score.perfect);
+            count_gekis.push(score.statistics.count_geki as i32);
+            count_300s.push(score.statistics.count_300 as i32);
+            count_katus.push(score.statistics.count_katu as i32);
+            count_100s.push(score.statistics.count_100 as i32);
+            count_50s.push(score.statistics.count_50 as i32);
+            count_misses.push(score.statistics.count_miss as i32);
+            ended_ats.push(score.ended_at);
+        }
+
+        if score_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let query = sqlx::query!(
+            "INSERT INTO scores (\
+                score_id,\
+                user_id,\
+                map_id,\
+                mode,\
+                mods,\
+                score,\
+                max_combo,\
+                accuracy,\
+                pp,\
+                grade,\
+                perfect,\
+                count_geki,\
+                count_300,\
+                count_katu,\
+                count_100,\
+                count_50,\
+                count_miss,\
+                ended_at\
+            )\
+            SELECT * FROM UNNEST(\
+                $1::INT8[],$2::INT4[],$3::INT4[],$4::INT2[],$5::INT4[],$6::INT4[],\
+                $7::INT4[],$8::FLOAT4[],$9::FLOAT4[],$10::INT2[],$11::BOOL[],$12::INT4[],\
+                $13::INT4[],$14::INT4[],$15::INT4[],$16::INT4[],$17::INT4[],$18::TIMESTAMPTZ[]\
+            )\
+            ON CONFLICT (score_id) DO NOTHING",
+            &score_ids,
+            &user_ids,
+            &map_ids,
+            &modes,
+            &mods,
+            &score_values,
+            &max_combos,
+            &accuracies,
+            &pps as _,
+            &grades,
+            &perfects,
+            &count_gekis,
+            &count_300s,
+            &count_katus,
+            &count_100s,
+            &count_50s,
+            &count_misses,
+            &ended_ats,
+        );
+
+        let result = query
+            .execute(&self.pool)
+            .await
+            .wrap_err("failed to insert scores")?;
+
+        Ok(result.rows_affected() as usize)
+    }
+
+    /// The `limit` most recent archived scores of a user, optionally only
+    /// those set within the given time span, sorted by date in descending order.
+    ///
+    /// Scores whose map or mapset is not stored are skipped.
+    pub async fn get_archived_scores(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        after: Option<OffsetDateTime>,
+        before: Option<OffsetDateTime>,
+        limit: usize,
+    ) -> Result<Vec<Score>> {
+        let query = sqlx::query_as!(
+            DBScore,
+            "SELECT * FROM scores \
+            WHERE user_id=$1 \
+                AND mode=$2 \
+                AND ($3::TIMESTAMPTZ IS NULL OR ended_at>=$3) \
+                AND ($4::TIMESTAMPTZ IS NULL OR ended_at<$4) \
+            ORDER BY ended_at DESC \
+            LIMIT $5",
+            user_id as i32,
+            mode as i16,
+            after,
+            before,
+            limit as i64,
+        );
+
+        let scores = query
+            .fetch_all(&self.pool)
+            .await
+            .wrap_err("failed to get archived scores")?;
+
+        self.with_maps(scores).await
+    }
+
+    /// Archived scores of a user on a map, sorted by date in descending order.
+    pub async fn get_archived_map_scores(&self, user_id: u32, map_id: u32) -> Result<Vec<Score>> {
+        let query = sqlx::query_as!(
+            DBScore,
+            "SELECT * FROM scores \
+            WHERE user_id=$1 AND map_id=$2 \
+            ORDER BY ended_at DESC",
+            user_id as i32,
+            map_id as i32,
+        );
+
+        let scores = query
+            .fetch_all(&self.pool)
+            .await
+            .wrap_err("failed to get archived map scores")?;
+
+        self.with_maps(scores).await
+    }
+
+    /// Archived scores of all linked users among the given discord users,
+    /// optionally only on a specific map.
+    pub async fn get_archived_member_scores(
+        &self,
+        discord_ids: &[i64],
+        mode: GameMode,
+        map_id: Option<u32>,
+    ) -> Result<Vec<Score>> {
+        let query = sqlx::query_as!(
+            DBScore,
+            "SELECT * FROM scores \
+            WHERE mode=$1 \
+                AND ($3::INT4 IS NULL OR map_id=$3) \
+                AND user_id IN \
+                (SELECT osu_id \
+                FROM user_configs \
+                WHERE discord_id=ANY($2) \
+                    AND osu_id IS NOT NULL)",
+            mode as i16,
+            discord_ids,
+            map_id.map(|id| id as i32),
+        );
+
+        let scores = query
+            .fetch_all(&self.pool)
+            .await
+            .wrap_err("failed to get archived member scores")?;
+
+        self.with_maps(scores).await
+    }
+
+    async fn with_maps(&self, scores: Vec<DBScore>) -> Result<Vec<Score>> {
+        if scores.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut map_ids: Vec<_> = scores.iter().map(|score| score.map_id).collect();
+        map_ids.sort_unstable();
+        map_ids.dedup();
+
+        let query = sqlx::query_as!(
+            DBBeatmap,
+            "SELECT * FROM maps WHERE map_id=ANY($1)",
+            &map_ids[..]
+        );
+
+        let mut stream = query.fetch(&self.pool);
+        let mut maps = HashMap::with_capacity_and_hasher(map_ids.len(), IntHasher);
+
+        while let Some(map) = stream.next().await.transpose()? {
+            maps.insert(map.map_id, Beatmap::from(map));
+        }
+
+        let mut mapset_ids: Vec<_> = maps.values().map(|map| map.mapset_id as i32).collect();
+        mapset_ids.sort_unstable();
+        mapset_ids.dedup();
+
+        let query = sqlx::query_as!(
+            DBBeatmapset,
+            "SELECT * FROM mapsets WHERE mapset_id=ANY($1)",
+            &mapset_ids[..]
+        );
+
+        let mut stream = query.fetch(&self.pool);
+        let mut mapsets = HashMap::with_capacity_and_hasher(mapset_ids.len(), IntHasher);
+
+        while let Some(mapset) = stream.next().await.transpose()? {
+            mapsets.insert(mapset.mapset_id as u32, BeatmapsetCompact::from(mapset));
+        }
+
+        let scores = scores
+            .into_iter()
+            .filter_map(|score| {
+                let map = maps.get(&score.map_id)?;
+                let mapset = mapsets.get(&map.mapset_id)?;
+
+                let mut score = Score::from(score);
+                score.map = Some(map.clone());
+                score.mapset = Some(mapset.clone());
+
+                Some(score)
+            })
+            .collect();
+
+        Ok(scores)
+    }
+}
+
+fn grade_to_i16(grade: Grade) -> i16 {
+    match grade {
+        Grade::F => 0,
+        Grade::D => 1,
+        Grade::C => 2,
+        Grade::B => 3,
+        Grade::A => 4,
+        Grade::S => 5,
+        Grade::SH => 6,
+        Grade::X => 7,
+        Grade::XH => 8,
+    }
+}
@@ -0,0 +1,95 @@
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use tokio_stream::StreamExt;
+
+use crate::{
+    database::{UserSkills, UserSkillsEntry},
+    Database,
+};
+
+impl Database {
+    /// Store the skill values of the current day, overwriting earlier values of the same day
+    pub async fn upsert_user_skills(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        skills: UserSkills,
+    ) -> Result<()> {
+        sqlx::query!(
+            "INSERT INTO osu_user_skills (user_id,mode,date,acc,aim,speed,strain,movement)\
+            VALUES ($1,$2,CURRENT_DATE,$3,$4,$5,$6,$7) ON CONFLICT (user_id,mode,date) DO \
+            UPDATE \
+            SET acc=$3,\
+                aim=$4,\
+                speed=$5,\
+                strain=$6,\
+                movement=$7",
+            user_id as i32,
+            mode as i16,
+            skills.acc,
+            skills.aim,
+            skills.speed,
+            skills.strain,
+            skills.movement,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err("failed to upsert user skills")?;
+
+        Ok(())
+    }
+
+    /// Whether skill values of the current day are already stored
+    pub async fn has_current_user_skills(&self, user_id: u32, mode: GameMode) -> Result<bool> {
+        let query = sqlx::query!(
+            "SELECT date \
+            FROM osu_user_skills \
+            WHERE user_id=$1 AND mode=$2 AND date=CURRENT_DATE",
+            user_id as i32,
+            mode as i16,
+        );
+
+        let row = query
+            .fetch_optional(&self.pool)
+            .await
+            .wrap_err("failed to check for current user skills")?;
+
+        Ok(row.is_some())
+    }
+
+    /// Skill values of a user, sorted by date in ascending order
+    pub async fn get_user_skills(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+    ) -> Result<Vec<UserSkillsEntry>> {
+        let query = sqlx::query!(
+            "SELECT date,acc,aim,speed,strain,movement \
+            FROM osu_user_skills \
+            WHERE user_id=$1 AND mode=$2 \
+            ORDER BY date ASC",
+            user_id as i32,
+            mode as i16,
+        );
+
+        let entries = query
+            .fetch(&self.pool)
+            .map(|res| {
+                res.map(|row| UserSkillsEntry {
+                    date: row.date,
+                    skills: UserSkills {
+                        acc: row.acc,
+                        aim: row.aim,
+                        speed: row.speed,
+                        strain: row.strain,
+                        movement: row.movement,
+                    },
+                })
+            })
+            .collect::<Result<_, _>>()
+            .await
+            .wrap_err("failed to get user skills")?;
+
+        Ok(entries)
+    }
+}
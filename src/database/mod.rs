@@ -18,4 +18,19 @@ impl Database {
 
         Ok(Self { pool })
     }
+
+    /// Check whether a connection to the database can be established
+    #[cfg(feature = "server")]
+    pub async fn ping(&self) -> Result<()> {
+        use eyre::WrapErr;
+        use sqlx::Connection;
+
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .wrap_err("failed to acquire connection")?;
+
+        conn.ping().await.wrap_err("failed to ping database")
+    }
 }
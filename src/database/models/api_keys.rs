@@ -0,0 +1,13 @@
+use time::OffsetDateTime;
+use twilight_model::id::{marker::UserMarker, Id};
+
+/// Key granting access to the server's `/api/v1` endpoints
+#[derive(Clone, Debug)]
+pub struct ApiKey {
+    pub key_id: u32,
+    pub owner_id: Id<UserMarker>,
+    pub label: String,
+    /// Maximum amount of requests per minute
+    pub ratelimit: u32,
+    pub created_at: OffsetDateTime,
+}
@@ -0,0 +1,59 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use time::OffsetDateTime;
+use twilight_model::id::{marker::UserMarker, Id};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum AuditAction {
+    ConfigEdit = 0,
+    TrackAdd = 1,
+    TrackRemove = 2,
+    StreamAdd = 3,
+    StreamRemove = 4,
+    RoleAssignAdd = 5,
+    RoleAssignRemove = 6,
+}
+
+impl TryFrom<i16> for AuditAction {
+    /// The unknown action code
+    type Error = i16;
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::ConfigEdit),
+            1 => Ok(Self::TrackAdd),
+            2 => Ok(Self::TrackRemove),
+            3 => Ok(Self::StreamAdd),
+            4 => Ok(Self::StreamRemove),
+            5 => Ok(Self::RoleAssignAdd),
+            6 => Ok(Self::RoleAssignRemove),
+            _ => Err(value),
+        }
+    }
+}
+
+impl Display for AuditAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let action = match self {
+            Self::ConfigEdit => "Edited config",
+            Self::TrackAdd => "Added tracking",
+            Self::TrackRemove => "Removed tracking",
+            Self::StreamAdd => "Added stream",
+            Self::StreamRemove => "Removed stream",
+            Self::RoleAssignAdd => "Added role assign",
+            Self::RoleAssignRemove => "Removed role assign",
+        };
+
+        f.write_str(action)
+    }
+}
+
+/// A change to a guild's configuration, trackings, or role assigns
+#[derive(Clone, Debug)]
+pub struct AuditLogEntry {
+    pub user_id: Id<UserMarker>,
+    pub action: AuditAction,
+    pub details: String,
+    pub created_at: OffsetDateTime,
+}
@@ -0,0 +1,46 @@
+use rosu_v2::prelude::{GameMode, GameMods};
+use time::OffsetDateTime;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker},
+    Id,
+};
+
+/// A guild competition on a single map
+#[derive(Clone, Debug)]
+pub struct Competition {
+    pub competition_id: u32,
+    pub guild_id: Id<GuildMarker>,
+    pub channel_id: Id<ChannelMarker>,
+    /// Message containing the live leaderboard
+    pub message_id: Option<Id<MessageMarker>>,
+    pub map_id: u32,
+    pub mode: GameMode,
+    /// If `None`, scores with any mods count
+    pub mods: Option<GameMods>,
+    pub started_at: OffsetDateTime,
+    pub ends_at: OffsetDateTime,
+}
+
+/// Best score of a user within a competition
+#[derive(Clone, Debug)]
+pub struct CompetitionScore {
+    pub user_id: u32,
+    pub score_id: u64,
+    pub score: u32,
+    pub accuracy: f32,
+    pub max_combo: u32,
+    pub mods: GameMods,
+    pub pp: Option<f32>,
+    pub ended_at: OffsetDateTime,
+}
+
+/// Winner of a finished competition
+#[derive(Clone, Debug)]
+pub struct CompetitionWinner {
+    pub competition_id: u32,
+    pub map_id: u32,
+    pub mods: Option<GameMods>,
+    pub ends_at: OffsetDateTime,
+    pub user_id: u32,
+    pub score: u32,
+}
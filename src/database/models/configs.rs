@@ -1,13 +1,17 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
 use rosu_v2::prelude::{GameMode, Username};
+use serde::{Deserialize, Serialize};
 use smallstr::SmallString;
 use smallvec::SmallVec;
 use twilight_interactions::command::{CommandOption, CreateOption};
 
-use crate::commands::osu::ProfileSize;
+use crate::{commands::osu::ProfileSize, core::commands::prefix::PrefixCommandGroup};
 
 pub type Prefix = SmallString<[u8; 2]>;
 pub type Prefixes = SmallVec<[Prefix; 5]>;
 pub type Authorities = SmallVec<[u64; 4]>;
+pub type DisabledCommands = Vec<DisabledCommand>;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u8)]
@@ -82,14 +86,83 @@ impl Default for ListSize {
     }
 }
 
+#[derive(Copy, Clone, CommandOption, CreateOption, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum ScoreKind {
+    #[option(name = "Stable", value = "stable")]
+    Stable = 0,
+    #[option(name = "Standardised", value = "standardised")]
+    Standardised = 1,
+    #[option(name = "Classic", value = "classic")]
+    Classic = 2,
+}
+
+impl From<i16> for ScoreKind {
+    fn from(value: i16) -> Self {
+        match value {
+            1 => Self::Standardised,
+            2 => Self::Classic,
+            _ => Self::Stable,
+        }
+    }
+}
+
+impl Default for ScoreKind {
+    fn default() -> Self {
+        Self::Stable
+    }
+}
+
+/// Either a single command or a whole group of commands
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum CommandTarget {
+    Command(String),
+    Group(PrefixCommandGroup),
+}
+
+/// Command(s) that cannot be used in a guild or, if specified, in one of its channels
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct DisabledCommand {
+    pub target: CommandTarget,
+    pub channel: Option<u64>,
+}
+
+impl DisabledCommand {
+    /// Whether the rule applies to the command with the given name and group in the channel
+    pub fn matches(&self, name: &str, group: Option<PrefixCommandGroup>, channel: u64) -> bool {
+        let target_matches = match self.target {
+            CommandTarget::Command(ref command) => command == name,
+            CommandTarget::Group(target) => group == Some(target),
+        };
+
+        target_matches && self.channel.map_or(true, |id| id == channel)
+    }
+}
+
+impl Display for DisabledCommand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.target {
+            CommandTarget::Command(ref command) => write!(f, "`{command}`")?,
+            CommandTarget::Group(group) => write!(f, "{} commands", group.name())?,
+        }
+
+        match self.channel {
+            Some(channel) => write!(f, " in <#{channel}>"),
+            None => f.write_str(" in the whole server"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GuildConfig {
     pub authorities: Authorities,
+    pub disabled_commands: DisabledCommands,
     pub embeds_size: Option<EmbedsSize>,
     pub list_size: Option<ListSize>,
     pub minimized_pp: Option<MinimizedPp>,
     pub prefixes: Prefixes,
     pub profile_size: Option<ProfileSize>,
+    pub public_api: Option<bool>,
     pub show_retries: Option<bool>,
     pub track_limit: Option<u8>,
     pub with_lyrics: Option<bool>,
@@ -116,6 +189,10 @@ impl GuildConfig {
         self.profile_size.unwrap_or_default()
     }
 
+    pub fn public_api(&self) -> bool {
+        self.public_api.unwrap_or(false)
+    }
+
     pub fn show_retries(&self) -> bool {
         self.show_retries.unwrap_or(true)
     }
@@ -129,11 +206,13 @@ impl Default for GuildConfig {
     fn default() -> Self {
         GuildConfig {
             authorities: SmallVec::new(),
+            disabled_commands: Vec::new(),
             embeds_size: None,
             list_size: None,
             minimized_pp: None,
             prefixes: smallvec::smallvec!["<".into()],
             profile_size: None,
+            public_api: None,
             show_retries: None,
             track_limit: None,
             with_lyrics: None,
@@ -190,6 +269,7 @@ pub struct UserConfig {
     pub mode: Option<GameMode>,
     pub osu: Option<OsuData>,
     pub profile_size: Option<ProfileSize>,
+    pub score_kind: Option<ScoreKind>,
     pub show_retries: Option<bool>,
     pub twitch_id: Option<u64>,
 }
@@ -214,4 +294,49 @@ impl UserConfig {
     pub fn minimized_pp(&self) -> MinimizedPp {
         self.minimized_pp.unwrap_or_default()
     }
+
+    pub fn score_kind(&self) -> ScoreKind {
+        self.score_kind.unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHANNEL: u64 = 1;
+    const OTHER_CHANNEL: u64 = 2;
+
+    fn rule(target: CommandTarget, channel: Option<u64>) -> DisabledCommand {
+        DisabledCommand { target, channel }
+    }
+
+    #[test]
+    fn test_disabled_command() {
+        let rule = rule(CommandTarget::Command("osu".to_owned()), None);
+
+        assert!(rule.matches("osu", Some(PrefixCommandGroup::AllModes), CHANNEL));
+        assert!(rule.matches("osu", None, OTHER_CHANNEL));
+        assert!(!rule.matches("mania", Some(PrefixCommandGroup::AllModes), CHANNEL));
+    }
+
+    #[test]
+    fn test_disabled_group() {
+        let rule = rule(CommandTarget::Group(PrefixCommandGroup::Mania), None);
+
+        assert!(rule.matches("mania", Some(PrefixCommandGroup::Mania), CHANNEL));
+        assert!(rule.matches("topmania", Some(PrefixCommandGroup::Mania), CHANNEL));
+        assert!(!rule.matches("mania", Some(PrefixCommandGroup::Taiko), CHANNEL));
+
+        // Slash commands have no group
+        assert!(!rule.matches("mania", None, CHANNEL));
+    }
+
+    #[test]
+    fn test_disabled_channel() {
+        let rule = rule(CommandTarget::Command("osu".to_owned()), Some(CHANNEL));
+
+        assert!(rule.matches("osu", None, CHANNEL));
+        assert!(!rule.matches("osu", None, OTHER_CHANNEL));
+    }
 }
@@ -1,20 +1,33 @@
 pub use self::{
+    api_keys::ApiKey,
+    audit_log::{AuditAction, AuditLogEntry},
     beatmap::{DBBeatmap, DBBeatmapset},
+    competitions::{Competition, CompetitionScore, CompetitionWinner},
     configs::{
-        Authorities, EmbedsSize, GuildConfig, ListSize, MinimizedPp, OsuData, Prefix, Prefixes,
-        UserConfig,
+        Authorities, CommandTarget, DisabledCommand, DisabledCommands, EmbedsSize, GuildConfig,
+        ListSize, MinimizedPp, OsuData, Prefix, Prefixes, ScoreKind, UserConfig,
     },
     map_tags::{MapsetTagWrapper, TagRow},
-    osu_users::{UserStatsColumn, UserValueRaw},
+    osu_users::{
+        CountryEntry, MapperSnapshot, OsuUserHistory, UserStatsColumn, UserStatsSnapshot,
+        UserValueRaw, UsernameEntry,
+    },
+    scores::DBScore,
+    skills::{UserSkills, UserSkillsEntry},
 };
 
 #[cfg(feature = "osutracking")]
 pub use self::tracking::TrackingUser;
 
+mod api_keys;
+mod audit_log;
 mod beatmap;
+mod competitions;
 mod configs;
 mod map_tags;
 mod osu_users;
+mod scores;
+mod skills;
 
 #[cfg(feature = "osutracking")]
 mod tracking;
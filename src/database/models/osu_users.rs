@@ -1,4 +1,5 @@
 use rosu_v2::prelude::{GameMode, Username};
+use time::OffsetDateTime;
 
 use crate::util::CountryCode;
 
@@ -133,3 +134,43 @@ pub struct UserValueRaw<T> {
     pub country_code: CountryCode,
     pub value: T,
 }
+
+/// Stats of a user at a point in time
+#[derive(Copy, Clone, Debug)]
+pub struct UserStatsSnapshot {
+    pub timestamp: OffsetDateTime,
+    pub pp: f32,
+    pub global_rank: Option<u32>,
+    pub accuracy: f32,
+    pub playcount: u32,
+    pub ranked_score: u64,
+}
+
+/// Summed up playcount and favourites of a mapper's ranked mapsets at a point in time
+#[derive(Copy, Clone, Debug)]
+pub struct MapperSnapshot {
+    pub timestamp: OffsetDateTime,
+    pub playcount: u64,
+    pub favourite_count: u64,
+}
+
+/// Observed usernames and countries of a user, sorted by timestamp in ascending order
+#[derive(Clone, Debug, Default)]
+pub struct OsuUserHistory {
+    pub names: Vec<UsernameEntry>,
+    pub countries: Vec<CountryEntry>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UsernameEntry {
+    pub username: Username,
+    /// When the name was first observed, `None` if it was stored before observations started
+    pub observed_at: Option<OffsetDateTime>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CountryEntry {
+    pub country_code: CountryCode,
+    /// When the country was first observed, `None` if it was stored before observations started
+    pub observed_at: Option<OffsetDateTime>,
+}
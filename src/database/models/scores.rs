@@ -0,0 +1,74 @@
+use rosu_v2::prelude::{GameMods, Grade, Score, ScoreStatistics};
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+#[derive(Debug, FromRow)]
+#[sqlx(type_name = "scores")]
+pub struct DBScore {
+    pub score_id: i64,
+    pub user_id: i32,
+    pub map_id: i32,
+    pub mode: i16,
+    pub mods: i32,
+    pub score: i32,
+    pub max_combo: i32,
+    pub accuracy: f32,
+    pub pp: Option<f32>,
+    pub grade: i16,
+    pub perfect: bool,
+    pub count_geki: i32,
+    pub count_300: i32,
+    pub count_katu: i32,
+    pub count_100: i32,
+    pub count_50: i32,
+    pub count_miss: i32,
+    pub ended_at: OffsetDateTime,
+}
+
+/// Map and mapset of the resulting score are not set
+impl From<DBScore> for Score {
+    fn from(score: DBScore) -> Self {
+        Score {
+            accuracy: score.accuracy,
+            ended_at: score.ended_at,
+            grade: grade_from_i16(score.grade),
+            max_combo: score.max_combo as u32,
+            map: None,
+            mapset: None,
+            mode: (score.mode as u8).into(),
+            mods: GameMods::from_bits(score.mods as u32).unwrap_or_default(),
+            perfect: score.perfect,
+            pp: score.pp,
+            rank_country: None,
+            rank_global: None,
+            replay: None,
+            score: score.score as u32,
+            score_id: score.score_id as u64,
+            statistics: ScoreStatistics {
+                count_geki: score.count_geki as u32,
+                count_300: score.count_300 as u32,
+                count_katu: score.count_katu as u32,
+                count_100: score.count_100 as u32,
+                count_50: score.count_50 as u32,
+                count_miss: score.count_miss as u32,
+            },
+            user: None,
+            user_id: score.user_id as u32,
+            weight: None,
+        }
+    }
+}
+
+fn grade_from_i16(grade: i16) -> Grade {
+    match grade {
+        1 => Grade::D,
+        2 => Grade::C,
+        3 => Grade::B,
+        4 => Grade::A,
+        5 => Grade::S,
+        6 => Grade::SH,
+        7 => Grade::X,
+        8 => Grade::XH,
+        _ => Grade::F,
+    }
+}
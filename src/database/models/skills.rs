@@ -0,0 +1,16 @@
+use time::Date;
+
+/// Skill values as calculated for the `/card` command
+#[derive(Copy, Clone, Debug)]
+pub struct UserSkills {
+    pub acc: f32,
+    pub aim: Option<f32>,
+    pub speed: Option<f32>,
+    pub strain: Option<f32>,
+    pub movement: Option<f32>,
+}
+
+pub struct UserSkillsEntry {
+    pub date: Date,
+    pub skills: UserSkills,
+}
@@ -0,0 +1,180 @@
+use std::fmt::Write;
+
+use command_macros::EmbedData;
+use hashbrown::HashMap;
+use rosu_v2::prelude::{Beatmap, Username};
+use time::OffsetDateTime;
+
+use crate::{
+    database::{Competition, CompetitionScore, CompetitionWinner},
+    embeds::get_mods,
+    util::{
+        builder::FooterBuilder,
+        constants::{MAP_THUMB_URL, OSU_BASE},
+        hasher::IntHasher,
+        numbers::with_comma_int,
+        CowUtils,
+    },
+};
+
+#[derive(EmbedData)]
+pub struct CompetitionEmbed {
+    description: String,
+    footer: FooterBuilder,
+    thumbnail: String,
+    timestamp: OffsetDateTime,
+    title: String,
+    url: String,
+}
+
+impl CompetitionEmbed {
+    const LIST_LEN: usize = 15;
+
+    pub fn new(
+        competition: &Competition,
+        map: &Beatmap,
+        scores: &[CompetitionScore],
+        names: &HashMap<u32, Username, IntHasher>,
+        finished: bool,
+    ) -> Self {
+        let title = match map.mapset {
+            Some(ref mapset) => format!("{} - {} [{}]", mapset.artist, mapset.title, map.version),
+            None => format!("[{}]", map.version),
+        };
+
+        let mut description = String::with_capacity(512);
+
+        match competition.mods {
+            Some(mods) if mods.is_empty() => description.push_str("**Mods:** `NM`"),
+            Some(mods) => {
+                let _ = write!(description, "**Mods:** `{}`", get_mods(mods));
+            }
+            None => description.push_str("**Mods:** Any"),
+        }
+
+        let _ = writeln!(
+            description,
+            " • **{}:** <t:{}:R>\n",
+            if finished { "Ended" } else { "Ends" },
+            competition.ends_at.unix_timestamp(),
+        );
+
+        if scores.is_empty() {
+            description.push_str("No scores have been set yet");
+        }
+
+        for (i, score) in scores.iter().take(Self::LIST_LEN).enumerate() {
+            let name = match names.get(&score.user_id) {
+                Some(name) => name.cow_escape_markdown().into_owned(),
+                None => score.user_id.to_string(),
+            };
+
+            let _ = writeln!(
+                description,
+                "**#{idx}** [{name}]({OSU_BASE}u/{user_id}): `{score}` • `{acc:.2}%` • \
+                `{combo}x` • `{mods}` • <t:{timestamp}:R>",
+                idx = i + 1,
+                user_id = score.user_id,
+                score = with_comma_int(score.score),
+                acc = score.accuracy,
+                combo = score.max_combo,
+                mods = if score.mods.is_empty() {
+                    "NM".to_owned()
+                } else {
+                    get_mods(score.mods)
+                },
+                timestamp = score.ended_at.unix_timestamp(),
+            );
+        }
+
+        let footer_text = if finished {
+            format!("Competition #{} has ended", competition.competition_id)
+        } else {
+            format!(
+                "Competition #{} • Scores refresh every few minutes",
+                competition.competition_id
+            )
+        };
+
+        Self {
+            description,
+            footer: FooterBuilder::new(footer_text),
+            thumbnail: format!("{MAP_THUMB_URL}{}l.jpg", map.mapset_id),
+            timestamp: competition.ends_at,
+            title,
+            url: format!("{OSU_BASE}b/{}", map.map_id),
+        }
+    }
+}
+
+#[derive(EmbedData)]
+pub struct CompetitionWinnersEmbed {
+    description: String,
+    footer: FooterBuilder,
+    title: String,
+}
+
+impl CompetitionWinnersEmbed {
+    const LIST_LEN: usize = 10;
+
+    pub fn new(
+        guild_name: &str,
+        winners: &[CompetitionWinner],
+        names: &HashMap<u32, Username, IntHasher>,
+    ) -> Self {
+        let name = |user_id: u32| match names.get(&user_id) {
+            Some(name) => name.cow_escape_markdown().into_owned(),
+            None => user_id.to_string(),
+        };
+
+        let mut wins = HashMap::with_hasher(IntHasher);
+
+        for winner in winners {
+            *wins.entry(winner.user_id).or_insert(0_usize) += 1;
+        }
+
+        let mut wins: Vec<_> = wins.into_iter().collect();
+        wins.sort_unstable_by(|(id_a, a), (id_b, b)| b.cmp(a).then_with(|| id_a.cmp(id_b)));
+
+        let mut description = String::with_capacity(512);
+        description.push_str("__**Most wins:**__\n");
+
+        for (i, (user_id, count)) in wins.iter().take(Self::LIST_LEN).enumerate() {
+            let _ = writeln!(
+                description,
+                "**#{}** [{}]({OSU_BASE}u/{user_id}): {count} win{}",
+                i + 1,
+                name(*user_id),
+                if *count == 1 { "" } else { "s" },
+            );
+        }
+
+        description.push_str("\n__**Recent competitions:**__\n");
+
+        for winner in winners.iter().take(Self::LIST_LEN) {
+            let mods = match winner.mods {
+                Some(mods) if mods.is_empty() => " `NM`".to_owned(),
+                Some(mods) => format!(" `{}`", get_mods(mods)),
+                None => String::new(),
+            };
+
+            let _ = writeln!(
+                description,
+                "**#{id}** [Map]({OSU_BASE}b/{map_id}){mods} • <t:{timestamp}:d> • \
+                [{name}]({OSU_BASE}u/{user_id}) with `{score}`",
+                id = winner.competition_id,
+                map_id = winner.map_id,
+                timestamp = winner.ends_at.unix_timestamp(),
+                name = name(winner.user_id),
+                user_id = winner.user_id,
+                score = with_comma_int(winner.score),
+            );
+        }
+
+        Self {
+            description,
+            footer: FooterBuilder::new(format!("{} finished competitions", winners.len())),
+            title: format!("Competition hall of fame for {guild_name}"),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::fmt::Write;
+
+use command_macros::EmbedData;
+use hashbrown::HashMap;
+use rosu_v2::prelude::User;
+
+use crate::{
+    commands::osu::{MapperMapsetEntry, MapperPpRecord, MapperSummary},
+    embeds::attachment,
+    pagination::Pages,
+    util::{
+        builder::{AuthorBuilder, FooterBuilder},
+        constants::OSU_BASE,
+        hasher::IntHasher,
+        numbers::{with_comma_float, with_comma_int},
+        CowUtils,
+    },
+};
+
+#[derive(EmbedData)]
+pub struct MapperStatsEmbed {
+    author: AuthorBuilder,
+    description: String,
+    footer: FooterBuilder,
+    image: Option<String>,
+    thumbnail: String,
+    title: String,
+}
+
+impl MapperStatsEmbed {
+    pub fn new(
+        user: &User,
+        summary: &MapperSummary,
+        entries: &[MapperMapsetEntry],
+        records: &HashMap<u32, Option<MapperPpRecord>, IntHasher>,
+        graph: bool,
+        pages: &Pages,
+    ) -> Self {
+        let mut description = String::with_capacity(1024);
+
+        let _ = writeln!(
+            description,
+            "**Mapsets:** `{mapsets}` • **Difficulties:** `{maps}`\n\
+            **Playcount:** `{playcount}` • **Favourites:** `{favourites}` • \
+            **Pass rate:** `{pass_rate:.2}%`\n\
+            **In top plays:** `{top_plays}` times across `{popular}` mapset{plural}\n",
+            mapsets = summary.mapsets,
+            maps = summary.maps,
+            playcount = with_comma_int(summary.playcount),
+            favourites = with_comma_int(summary.favourite_count),
+            pass_rate = summary.pass_rate(),
+            top_plays = with_comma_int(summary.top_plays),
+            popular = summary.popular_mapsets,
+            plural = if summary.popular_mapsets == 1 {
+                ""
+            } else {
+                "s"
+            },
+        );
+
+        for (entry, i) in entries.iter().zip(pages.index + 1..) {
+            let _ = writeln!(
+                description,
+                "**#{i}** [{name}]({OSU_BASE}s/{mapset_id}) • <t:{timestamp}:d>\n\
+                ⯈ `{playcount}` plays • `{favourites}` ♥ • `{pass_rate:.2}%` passed • \
+                `{top_plays}` top plays",
+                name = entry.name.cow_escape_markdown(),
+                mapset_id = entry.mapset_id,
+                timestamp = entry.ranked_date.unix_timestamp(),
+                playcount = with_comma_int(entry.playcount),
+                favourites = with_comma_int(entry.favourite_count),
+                pass_rate = entry.pass_rate(),
+                top_plays = with_comma_int(entry.top_plays),
+            );
+
+            if let Some(Some(record)) = records.get(&entry.mapset_id) {
+                let _ = writeln!(
+                    description,
+                    "⯈ pp record on [{version}]: `{pp}pp` by [{name}]({OSU_BASE}u/{user_id})",
+                    version = record.version.cow_escape_markdown(),
+                    pp = with_comma_float(record.pp),
+                    name = record.username.cow_escape_markdown(),
+                    user_id = record.user_id,
+                );
+            }
+        }
+
+        description.pop();
+
+        let footer_text = format!(
+            "Page {}/{} • Top play counts originate from https://osutracker.com",
+            pages.curr_page(),
+            pages.last_page()
+        );
+
+        let author =
+            AuthorBuilder::new(user.username.as_str()).url(format!("{OSU_BASE}u/{}", user.user_id));
+
+        Self {
+            author,
+            description,
+            footer: FooterBuilder::new(footer_text),
+            image: graph.then(|| attachment("popularity_graph.png")),
+            thumbnail: user.avatar_url.to_owned(),
+            title: "Ranked mapset statistics".to_owned(),
+        }
+    }
+}
@@ -5,6 +5,7 @@ mod card;
 mod claim_name;
 mod common;
 mod compare;
+mod competition;
 mod country_snipe_list;
 mod country_snipe_stats;
 mod fix_score;
@@ -12,6 +13,7 @@ mod graph;
 mod leaderboard;
 mod map;
 mod map_search;
+mod mapper_stats;
 mod match_compare;
 mod match_costs;
 mod match_live;
@@ -22,6 +24,7 @@ mod medals_list;
 mod medals_missing;
 mod most_played;
 mod most_played_common;
+mod name_history;
 mod nochoke;
 mod osekai_medal_count;
 mod osekai_medal_rarity;
@@ -38,6 +41,7 @@ mod player_snipe_stats;
 mod pp_missing;
 mod profile;
 mod profile_compare;
+mod progress;
 mod rank;
 mod rank_score;
 mod ranking;
@@ -46,13 +50,16 @@ mod ratio;
 mod recent;
 mod recent_list;
 mod scores;
+mod session;
 mod simulate;
 mod sniped;
 mod sniped_difference;
 mod top;
 mod top_if;
+mod top_old_compare;
 mod top_single;
 mod whatif;
+mod wrapped;
 
 use std::fmt::Write;
 
@@ -62,16 +69,17 @@ use rosu_v2::prelude::{Beatmap, GameMode, GameMods};
 use crate::util::{datetime::sec_to_minsec, numbers::round, BeatmapExt, ScoreExt};
 
 pub use self::{
-    attributes::*, badge::*, bws::*, card::*, claim_name::*, common::*, compare::*,
+    attributes::*, badge::*, bws::*, card::*, claim_name::*, common::*, compare::*, competition::*,
     country_snipe_list::*, country_snipe_stats::*, fix_score::*, graph::*, leaderboard::*, map::*,
-    map_search::*, match_compare::*, match_costs::*, medal::*, medal_stats::*, medals_common::*,
-    medals_list::*, medals_missing::*, most_played::*, most_played_common::*, nochoke::*,
-    osekai_medal_count::*, osekai_medal_rarity::*, osustats_counts::*, osustats_globals::*,
-    osustats_list::*, osutracker_countrytop::*, osutracker_mappers::*, osutracker_maps::*,
-    osutracker_mapsets::*, osutracker_mods::*, player_snipe_list::*, player_snipe_stats::*,
-    pp_missing::*, profile::*, profile_compare::*, rank::*, rank_score::*, ranking::*,
-    ranking_countries::*, ratio::*, recent::*, recent_list::*, scores::*, simulate::*, sniped::*,
-    sniped_difference::*, top::*, top_if::*, top_single::*, whatif::*,
+    map_search::*, mapper_stats::*, match_compare::*, match_costs::*, medal::*, medal_stats::*,
+    medals_common::*, medals_list::*, medals_missing::*, most_played::*, most_played_common::*,
+    name_history::*, nochoke::*, osekai_medal_count::*, osekai_medal_rarity::*, osustats_counts::*,
+    osustats_globals::*, osustats_list::*, osutracker_countrytop::*, osutracker_mappers::*,
+    osutracker_maps::*, osutracker_mapsets::*, osutracker_mods::*, player_snipe_list::*,
+    player_snipe_stats::*, pp_missing::*, profile::*, profile_compare::*, progress::*, rank::*,
+    rank_score::*, ranking::*, ranking_countries::*, ratio::*, recent::*, recent_list::*,
+    scores::*, session::*, simulate::*, sniped::*, sniped_difference::*, top::*, top_if::*,
+    top_old_compare::*, top_single::*, whatif::*, wrapped::*,
 };
 
 #[cfg(feature = "matchlive")]
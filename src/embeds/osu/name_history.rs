@@ -0,0 +1,97 @@
+use std::fmt::Write;
+
+use command_macros::EmbedData;
+use rosu_v2::prelude::User;
+use time::OffsetDateTime;
+
+use crate::{
+    database::OsuUserHistory,
+    util::{
+        builder::{AuthorBuilder, FooterBuilder},
+        constants::OSU_BASE,
+        osu::flag_url,
+        CowUtils,
+    },
+};
+
+#[derive(EmbedData)]
+pub struct NameHistoryEmbed {
+    author: AuthorBuilder,
+    description: String,
+    footer: FooterBuilder,
+    thumbnail: String,
+}
+
+impl NameHistoryEmbed {
+    pub fn new(user: &User, history: &OsuUserHistory) -> Self {
+        let mut description = String::with_capacity(512);
+        description.push_str("__**Observed usernames:**__\n");
+
+        if history.names.is_empty() {
+            description.push_str("None yet\n");
+        }
+
+        for entry in history.names.iter().rev() {
+            let _ = write!(description, "`{}`", entry.username.cow_escape_markdown());
+            push_since(&mut description, entry.observed_at);
+        }
+
+        description.push_str("\n__**Observed countries:**__\n");
+
+        if history.countries.is_empty() {
+            description.push_str("None yet\n");
+        }
+
+        for entry in history.countries.iter().rev() {
+            let _ = write!(
+                description,
+                ":flag_{}: `{}`",
+                entry.country_code.to_ascii_lowercase(),
+                entry.country_code,
+            );
+
+            push_since(&mut description, entry.observed_at);
+        }
+
+        if let Some(previous) = user
+            .previous_usernames
+            .as_ref()
+            .filter(|names| !names.is_empty())
+        {
+            description.push_str("\n__**Previous usernames according to osu!:**__\n");
+            let mut iter = previous.iter();
+
+            if let Some(name) = iter.next() {
+                let _ = write!(description, "`{}`", name.cow_escape_markdown());
+
+                for name in iter {
+                    let _ = write!(description, ", `{}`", name.cow_escape_markdown());
+                }
+            }
+        }
+
+        let author = AuthorBuilder::new(user.username.as_str())
+            .url(format!("{OSU_BASE}u/{}", user.user_id))
+            .icon_url(flag_url(user.country_code.as_str()));
+
+        let footer = FooterBuilder::new(
+            "Changes are only observed when the user is requested through some command",
+        );
+
+        Self {
+            author,
+            description,
+            footer,
+            thumbnail: user.avatar_url.to_owned(),
+        }
+    }
+}
+
+fn push_since(description: &mut String, observed_at: Option<OffsetDateTime>) {
+    match observed_at {
+        Some(observed_at) => {
+            let _ = writeln!(description, " since <t:{}:d>", observed_at.unix_timestamp());
+        }
+        None => description.push('\n'),
+    }
+}
@@ -10,6 +10,7 @@ use twilight_model::{
 
 use crate::{
     commands::osu::ProfileResult,
+    database::OsuUserHistory,
     embeds::attachment,
     util::{
         builder::{AuthorBuilder, FooterBuilder},
@@ -32,6 +33,8 @@ pub struct ProfileEmbed {
 }
 
 impl ProfileEmbed {
+    const HISTORY_LEN: usize = 5;
+
     pub fn compact(user: &User, max_pp: f32, discord_id: Option<Id<UserMarker>>) -> Self {
         let stats = user.statistics.as_ref().unwrap();
         let level = stats.level.float();
@@ -90,6 +93,7 @@ impl ProfileEmbed {
         own_top_scores: usize,
         discord_id: Option<Id<UserMarker>>,
         mapper_names: &HashMap<u32, Username, IntHasher>,
+        history: &OsuUserHistory,
     ) -> Self {
         let mode = Emote::from(user.mode).text();
 
@@ -264,6 +268,42 @@ impl ProfileEmbed {
             description.push_str("\n\n No Top scores");
         }
 
+        if history.names.len() > 1 || history.countries.len() > 1 {
+            let mut value = String::with_capacity(128);
+
+            if history.names.len() > 1 {
+                value.push_str("Names: ");
+                let iter = history.names.iter().rev().take(Self::HISTORY_LEN);
+
+                for (entry, i) in iter.zip(0..) {
+                    let arrow = if i == 0 { "" } else { " ← " };
+                    let _ = write!(value, "{arrow}`{}`", entry.username);
+
+                    if let Some(observed_at) = entry.observed_at {
+                        let _ = write!(value, " since <t:{}:d>", observed_at.unix_timestamp());
+                    }
+                }
+
+                value.push('\n');
+            }
+
+            if history.countries.len() > 1 {
+                value.push_str("Countries: ");
+                let iter = history.countries.iter().rev().take(Self::HISTORY_LEN);
+
+                for (entry, i) in iter.zip(0..) {
+                    let arrow = if i == 0 { "" } else { " ← " };
+                    let _ = write!(value, "{arrow}`{}`", entry.country_code);
+
+                    if let Some(observed_at) = entry.observed_at {
+                        let _ = write!(value, " since <t:{}:d>", observed_at.unix_timestamp());
+                    }
+                }
+            }
+
+            fields![fields { "Observed name & country changes", value, false }];
+        }
+
         Self {
             author: author!(user),
             description,
@@ -7,6 +7,8 @@ use command_macros::EmbedData;
 use rosu_v2::prelude::{GameMode, User, UserStatistics};
 use time::OffsetDateTime;
 
+use twilight_model::channel::embed::EmbedField;
+
 use crate::{
     commands::osu::CompareResult,
     embeds::attachment,
@@ -25,17 +27,17 @@ pub struct ProfileCompareEmbed {
 impl ProfileCompareEmbed {
     pub fn new(
         mode: GameMode,
-        user1: User,
-        user2: User,
-        result1: CompareResult,
-        result2: CompareResult,
+        user1: &User,
+        user2: &User,
+        result1: &CompareResult,
+        result2: &CompareResult,
     ) -> Self {
         let stats1 = user1.statistics.as_ref().unwrap();
         let stats2 = user2.statistics.as_ref().unwrap();
 
-        let left = CompareStrings::new(stats1, user1.join_date, &result1);
+        let left = CompareStrings::new(stats1, user1.join_date, result1);
         let max_left = left.max().max(user1.username.chars().count());
-        let right = CompareStrings::new(stats2, user2.join_date, &result2);
+        let right = CompareStrings::new(stats2, user2.join_date, result2);
         let max_right = right.max().max(user2.username.chars().count());
         let mut d = String::with_capacity(512);
         d.push_str("```\n");
@@ -127,21 +129,8 @@ impl ProfileCompareEmbed {
             max_right,
         );
 
-        let left_peak = user1
-            .monthly_playcounts
-            .unwrap()
-            .iter()
-            .map(|date_count| date_count.count as u64)
-            .max()
-            .unwrap_or(0);
-
-        let right_peak = user2
-            .monthly_playcounts
-            .unwrap()
-            .iter()
-            .map(|date_count| date_count.count as u64)
-            .max()
-            .unwrap_or(0);
+        let left_peak = playcount_peak(user1);
+        let right_peak = playcount_peak(user2);
 
         write_line(
             &mut d,
@@ -297,15 +286,15 @@ impl ProfileCompareEmbed {
             max_right,
         );
 
-        let medal1 = user1.medals.unwrap().len();
-        let medal2 = user2.medals.unwrap().len();
+        let medal1 = user1.medals.as_ref().unwrap().len();
+        let medal2 = user2.medals.as_ref().unwrap().len();
 
         write_line(
             &mut d, "Medals", medal1, medal2, medal1, medal2, max_left, max_right,
         );
 
-        let badges1 = user1.badges.unwrap().len();
-        let badges2 = user2.badges.unwrap().len();
+        let badges1 = user1.badges.as_ref().unwrap().len();
+        let badges2 = user2.badges.as_ref().unwrap().len();
 
         write_line(
             &mut d, "Badges", badges1, badges2, badges1, badges2, max_left, max_right,
@@ -342,6 +331,211 @@ impl ProfileCompareEmbed {
     }
 }
 
+#[derive(EmbedData)]
+pub struct ProfileCompareMultiEmbed {
+    fields: Vec<EmbedField>,
+    image: String,
+    title: String,
+}
+
+impl ProfileCompareMultiEmbed {
+    pub fn new(mode: GameMode, users: &[User], results: &[CompareResult]) -> Self {
+        type RowFn = fn(&CompareEntry<'_>) -> (String, Option<f64>);
+
+        let rows: [(&str, RowFn); 24] = [
+            ("Rank", |e| {
+                // Unranked users have no rank to compare
+                let rank = e.stats.global_rank.map(|rank| -(rank as f64));
+
+                (e.strings.rank.clone(), rank)
+            }),
+            ("PP", |e| (e.strings.pp.clone(), Some(e.stats.pp as f64))),
+            ("Accuracy", |e| {
+                (e.strings.accuracy.clone(), Some(e.stats.accuracy as f64))
+            }),
+            ("Level", |e| {
+                (e.strings.level.clone(), Some(e.stats.level.float() as f64))
+            }),
+            ("Playtime", |e| {
+                (e.strings.play_time.clone(), Some(e.stats.playtime as f64))
+            }),
+            ("Playcount", |e| {
+                (e.strings.play_count.clone(), Some(e.stats.playcount as f64))
+            }),
+            ("PC peak", |e| {
+                let peak = playcount_peak(e.user);
+
+                (with_comma_int(peak).to_string(), Some(peak as f64))
+            }),
+            ("Ranked score", |e| {
+                (
+                    e.strings.ranked_score.clone(),
+                    Some(e.stats.ranked_score as f64),
+                )
+            }),
+            ("Total score", |e| {
+                (
+                    e.strings.total_score.clone(),
+                    Some(e.stats.total_score as f64),
+                )
+            }),
+            ("Total hits", |e| {
+                (
+                    e.strings.total_hits.clone(),
+                    Some(e.stats.total_hits as f64),
+                )
+            }),
+            ("SS count", |e| {
+                let count = e.stats.grade_counts.ss + e.stats.grade_counts.ssh;
+
+                (e.strings.count_ss.clone(), Some(count as f64))
+            }),
+            ("S count", |e| {
+                let count = e.stats.grade_counts.s + e.stats.grade_counts.sh;
+
+                (e.strings.count_s.clone(), Some(count as f64))
+            }),
+            ("A count", |e| {
+                (
+                    e.strings.count_a.clone(),
+                    Some(e.stats.grade_counts.a as f64),
+                )
+            }),
+            ("Max Combo", |e| {
+                let combo = e.stats.max_combo;
+
+                (with_comma_int(combo).to_string(), Some(combo as f64))
+            }),
+            ("Bonus PP", |e| {
+                (
+                    e.strings.bonus_pp.clone(),
+                    Some(e.strings.bonus_pp_num as f64),
+                )
+            }),
+            ("PP spread", |e| {
+                let spread = e.result.pp.max() - e.result.pp.min();
+
+                (e.strings.pp_spread.clone(), Some(spread as f64))
+            }),
+            ("Avg PP", |e| {
+                (e.strings.avg_pp.clone(), Some(e.result.pp.avg() as f64))
+            }),
+            ("PP per month", |e| {
+                let pp = e.strings.pp_per_month_num as f64;
+
+                (e.strings.pp_per_month.clone(), Some(pp))
+            }),
+            ("Join date", |e| {
+                let date = e.user.join_date;
+                let value = -(date.unix_timestamp() as f64);
+
+                (date.format(DATE_FORMAT).unwrap(), Some(value))
+            }),
+            ("Avg map len", |e| {
+                let len = e.result.map_len.avg();
+
+                (e.strings.avg_map_len.clone(), Some(len as f64))
+            }),
+            ("Medals", |e| {
+                let medals = e.user.medals.as_ref().map_or(0, Vec::len);
+
+                (medals.to_string(), Some(medals as f64))
+            }),
+            ("Badges", |e| {
+                let badges = e.user.badges.as_ref().map_or(0, Vec::len);
+
+                (badges.to_string(), Some(badges as f64))
+            }),
+            ("Followers", |e| {
+                let followers = e.user.follower_count.unwrap_or(0);
+
+                (
+                    with_comma_int(followers).to_string(),
+                    Some(followers as f64),
+                )
+            }),
+            ("Replays seen", |e| {
+                let replays = e.stats.replays_watched;
+
+                (with_comma_int(replays).to_string(), Some(replays as f64))
+            }),
+        ];
+
+        let entries: Vec<_> = users
+            .iter()
+            .zip(results)
+            .map(|(user, result)| CompareEntry::new(user, result))
+            .collect();
+
+        let mut fields = Vec::with_capacity(rows.len());
+
+        for (title, row) in rows {
+            let values: Vec<_> = entries.iter().map(row).collect();
+
+            let max = values
+                .iter()
+                .filter_map(|(_, value)| *value)
+                .fold(f64::MIN, f64::max);
+
+            let mut value = String::with_capacity(values.len() * 32);
+
+            for (entry, (text, num)) in entries.iter().zip(values) {
+                let _ = if num.map_or(false, |num| num >= max) {
+                    writeln!(value, "`{}`: **{text}**", entry.user.username)
+                } else {
+                    writeln!(value, "`{}`: {text}", entry.user.username)
+                };
+            }
+
+            fields![fields { title, value, true }];
+        }
+
+        let mode = match mode {
+            GameMode::Osu => "osu!",
+            GameMode::Mania => "Mania",
+            GameMode::Taiko => "Taiko",
+            GameMode::Catch => "CtB",
+        };
+
+        Self {
+            fields,
+            image: attachment("profile_compare.png"),
+            title: format!("{mode} profile comparison of {} players", users.len()),
+        }
+    }
+}
+
+struct CompareEntry<'a> {
+    user: &'a User,
+    stats: &'a UserStatistics,
+    result: &'a CompareResult,
+    strings: CompareStrings,
+}
+
+impl<'a> CompareEntry<'a> {
+    fn new(user: &'a User, result: &'a CompareResult) -> Self {
+        let stats = user.statistics.as_ref().unwrap();
+        let strings = CompareStrings::new(stats, user.join_date, result);
+
+        Self {
+            user,
+            stats,
+            result,
+            strings,
+        }
+    }
+}
+
+fn playcount_peak(user: &User) -> u64 {
+    user.monthly_playcounts
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|date_count| date_count.count as u64)
+        .max()
+        .unwrap_or(0)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn write_line<T: PartialOrd, V: Display>(
     content: &mut String,
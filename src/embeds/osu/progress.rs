@@ -0,0 +1,95 @@
+use std::fmt::Write;
+
+use command_macros::EmbedData;
+use rosu_v2::prelude::User;
+use time::OffsetDateTime;
+
+use crate::{
+    database::UserStatsSnapshot,
+    embeds::attachment,
+    util::{
+        builder::{AuthorBuilder, FooterBuilder},
+        numbers::{round, with_comma_float, with_comma_int},
+    },
+};
+
+#[derive(EmbedData)]
+pub struct ProgressEmbed {
+    author: AuthorBuilder,
+    description: String,
+    footer: FooterBuilder,
+    image: String,
+    thumbnail: String,
+    timestamp: OffsetDateTime,
+}
+
+impl ProgressEmbed {
+    pub fn new(user: &User, first: &UserStatsSnapshot, last: &UserStatsSnapshot) -> Self {
+        let mut description = String::with_capacity(256);
+
+        let pp_diff = last.pp - first.pp;
+
+        let _ = writeln!(
+            description,
+            "**PP:** `{}pp` → `{}pp` (`{}{}`)",
+            with_comma_float(first.pp),
+            with_comma_float(last.pp),
+            if pp_diff < 0.0 { '-' } else { '+' },
+            with_comma_float(round(pp_diff.abs())),
+        );
+
+        let _ = write!(description, "**Rank:** ");
+
+        match (first.global_rank, last.global_rank) {
+            (None, None) => description.push_str("`-`"),
+            (None, Some(last)) => {
+                let _ = write!(description, "`-` → `#{}`", with_comma_int(last));
+            }
+            (Some(first), None) => {
+                let _ = write!(description, "`#{}` → `-`", with_comma_int(first));
+            }
+            (Some(first), Some(last)) => {
+                let _ = write!(
+                    description,
+                    "`#{}` → `#{}` (`{:+}`)",
+                    with_comma_int(first),
+                    with_comma_int(last),
+                    first as i64 - last as i64,
+                );
+            }
+        }
+
+        let _ = writeln!(
+            description,
+            "\n**Accuracy:** `{:.2}%` → `{:.2}%` (`{:+.2}%`)",
+            first.accuracy,
+            last.accuracy,
+            last.accuracy - first.accuracy,
+        );
+
+        let _ = writeln!(
+            description,
+            "**Playcount:** `{}` → `{}` (`+{}`)",
+            with_comma_int(first.playcount),
+            with_comma_int(last.playcount),
+            with_comma_int(last.playcount.saturating_sub(first.playcount)),
+        );
+
+        let _ = write!(
+            description,
+            "**Ranked score:** `{}` → `{}` (`+{}`)",
+            with_comma_int(first.ranked_score),
+            with_comma_int(last.ranked_score),
+            with_comma_int(last.ranked_score.saturating_sub(first.ranked_score)),
+        );
+
+        Self {
+            author: author!(user),
+            description,
+            footer: FooterBuilder::new("Progress since"),
+            image: attachment("progress_graph.png"),
+            thumbnail: user.avatar_url.to_owned(),
+            timestamp: first.timestamp,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use std::{
     cmp::Ordering,
+    fmt::Write,
     fmt::{Display, Formatter, Result as FmtResult},
     iter,
 };
@@ -8,7 +9,7 @@ use command_macros::EmbedData;
 use rosu_v2::{model::score::Score, prelude::UserCompact};
 
 use crate::{
-    commands::osu::RankData,
+    commands::osu::{RankData, RankProjection},
     util::{
         builder::AuthorBuilder,
         numbers::{with_comma_float, with_comma_int},
@@ -26,8 +27,13 @@ pub struct RankEmbed {
 }
 
 impl RankEmbed {
-    pub fn new(data: RankData, scores: Option<Vec<Score>>, each: Option<f32>) -> Self {
-        let (title, description, user) = match data {
+    pub fn new(
+        data: RankData,
+        scores: Option<Vec<Score>>,
+        each: Option<f32>,
+        projection: Option<RankProjection>,
+    ) -> Self {
+        let (title, mut description, user) = match data {
             RankData::Sub10k {
                 user,
                 rank,
@@ -412,6 +418,51 @@ impl RankEmbed {
             }
         };
 
+        if let Some(projection) = projection {
+            let name = user.username.cow_escape_markdown();
+            description.push_str("\n\n");
+
+            match projection {
+                RankProjection::MissingHistory => {
+                    let _ = write!(
+                        description,
+                        "Not enough stats of {name} have been stored yet \
+                        to project when the rank will be reached."
+                    );
+                }
+                RankProjection::NoGain { pp_per_day } => {
+                    let _ = write!(
+                        description,
+                        "{name} is not gaining enough pp recently ({pp_per_day:+.2}pp per day) \
+                        to project when the rank will be reached."
+                    );
+                }
+                RankProjection::TooFarOut { pp_per_day, years } => {
+                    let _ = write!(
+                        description,
+                        "At the current rate of **{pp_per_day:.2}pp** per day, {name} would \
+                        need about {years} years to reach that.",
+                        years = with_comma_int(years),
+                    );
+                }
+                RankProjection::Reach {
+                    pp_per_day,
+                    date,
+                    month_rank,
+                } => {
+                    let _ = write!(
+                        description,
+                        "At the current rate of **{pp_per_day:.2}pp** per day, {name} would \
+                        reach that around <t:{timestamp}:D> (<t:{timestamp}:R>), assuming the \
+                        required pp stay the same.\nIn 30 days, {name} would be around rank \
+                        **#{month_rank}**.",
+                        timestamp = date.unix_timestamp(),
+                        month_rank = with_comma_int(month_rank),
+                    );
+                }
+            }
+        }
+
         Self {
             title,
             description,
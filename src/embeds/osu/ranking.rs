@@ -70,6 +70,11 @@ pub enum RankingKindData {
     RankedScore {
         mode: GameMode,
     },
+    ScoreStats {
+        guild_icon: Option<(Id<GuildMarker>, ImageHash)>,
+        mode: GameMode,
+        title: String,
+    },
     UserStats {
         guild_icon: Option<(Id<GuildMarker>, ImageHash)>,
         kind: UserStatsColumn,
@@ -183,6 +188,18 @@ impl RankingKindData {
 
                 EmbedHeader::title(text, url)
             }
+            Self::ScoreStats {
+                guild_icon,
+                mode,
+                title,
+            } => {
+                let author_text = format!(
+                    "Server leaderboard for osu!{mode}: {title}",
+                    mode = mode_str(*mode),
+                );
+
+                EmbedHeader::Author(guild_author(author_text, guild_icon))
+            }
             Self::UserStats { guild_icon, kind } => {
                 let mode = kind.mode();
 
@@ -232,15 +249,7 @@ impl RankingKindData {
 
                 let _ = write!(author_text, ": {kind}");
 
-                let mut author = AuthorBuilder::new(author_text);
-
-                if let Some((id, icon)) = guild_icon {
-                    let ext = if icon.is_animated() { "gif" } else { "webp" };
-                    let url = format!("https://cdn.discordapp.com/icons/{id}/{icon}.{ext}");
-                    author = author.icon_url(url);
-                }
-
-                EmbedHeader::Author(author)
+                EmbedHeader::Author(guild_author(author_text, guild_icon))
             }
         }
     }
@@ -364,6 +373,20 @@ impl EmbedData for RankingEmbed {
     }
 }
 
+fn guild_author(text: String, guild_icon: &Option<(Id<GuildMarker>, ImageHash)>) -> AuthorBuilder {
+    let author = AuthorBuilder::new(text);
+
+    match guild_icon {
+        Some((id, icon)) => {
+            let ext = if icon.is_animated() { "gif" } else { "webp" };
+            let url = format!("https://cdn.discordapp.com/icons/{id}/{icon}.{ext}");
+
+            author.icon_url(url)
+        }
+        None => author,
+    }
+}
+
 fn mode_str(mode: GameMode) -> &'static str {
     match mode {
         GameMode::Osu => "",
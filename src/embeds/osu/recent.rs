@@ -8,15 +8,20 @@ use twilight_model::channel::embed::Embed;
 
 use crate::{
     core::Context,
-    database::MinimizedPp,
+    custom_client::LazerScore,
+    database::{MinimizedPp, ScoreKind},
     embeds::osu,
+    pp,
     util::{
         builder::{AuthorBuilder, EmbedBuilder, FooterBuilder},
         constants::AVATAR_URL,
         datetime::{how_long_ago_dynamic, HowLongAgoFormatterDynamic},
         matcher::highlight_funny_numeral,
-        numbers::{round, with_comma_int},
-        osu::{grade_completion_mods, prepare_beatmap_file},
+        numbers::round,
+        osu::{
+            grade_completion_mods, grade_lazer_mods, lazer_score, prepare_beatmap_file,
+            score_by_kind,
+        },
         CowUtils, ScoreExt,
     },
 };
@@ -58,10 +63,12 @@ impl RecentEmbed {
         map_score: Option<&BeatmapUserScore>,
         #[cfg(feature = "twitch")] twitch_vod: Option<TwitchVideo>,
         minimized_pp: MinimizedPp,
+        score_kind: ScoreKind,
         ctx: &Context,
     ) -> Result<Self> {
         let map = score.map.as_ref().unwrap();
         let mapset = score.mapset.as_ref().unwrap();
+        let lazer = lazer_score(ctx, score, score_kind).await;
 
         let map_path = prepare_beatmap_file(ctx, map.map_id)
             .await
@@ -111,8 +118,16 @@ impl RecentEmbed {
         let (if_fc, _) = IfFC::new(score, &rosu_map, attributes, mods);
 
         let max_pp = Some(max_pp);
-        let hits = score.hits_string(map.mode);
-        let grade_completion_mods = grade_completion_mods(score, map);
+        let mut hits = score.hits_string(map.mode);
+
+        let grade_completion_mods = match lazer {
+            Some(ref lazer) => grade_lazer_mods(score, map, lazer),
+            None => grade_completion_mods(score, map),
+        };
+
+        if let Some((hit, total)) = lazer.as_ref().and_then(LazerScore::slider_ends) {
+            let _ = write!(hits, " • {hit}/{total} ends");
+        }
 
         let (combo, title) = if map.mode == GameMode::Mania {
             let mut ratio = score.statistics.count_geki as f32;
@@ -194,7 +209,13 @@ impl RecentEmbed {
                     .map(|idx| idx + 1)
             });
 
-        let pp = Some(pp);
+        let lazer_pp = lazer.as_ref().and_then(|lazer| {
+            pp::lazer_pp(&rosu_map, lazer, score.mode)
+                .map(|pp| pp as f32)
+                .or(lazer.pp)
+        });
+
+        let pp = Some(lazer_pp.unwrap_or(pp));
 
         let global_idx = map_score
             .and_then(|s| (&s.score == score).then_some(s.pos))
@@ -234,7 +255,7 @@ impl RecentEmbed {
             thumbnail: mapset.covers.list.to_owned(),
             grade_completion_mods,
             stars,
-            score: with_comma_int(score.score).to_string(),
+            score: score_by_kind(score, lazer.as_ref(), score_kind),
             acc: round(score.accuracy),
             ago: how_long_ago_dynamic(&score.ended_at),
             pp,
@@ -0,0 +1,202 @@
+use std::{cmp::Ordering, collections::HashSet, fmt::Write};
+
+use command_macros::EmbedData;
+use rosu_pp::Mods;
+use rosu_v2::prelude::{Grade, Score, User};
+use time::OffsetDateTime;
+use twilight_model::channel::embed::EmbedField;
+
+use crate::{
+    database::UserStatsSnapshot,
+    util::{
+        builder::{AuthorBuilder, FooterBuilder},
+        constants::OSU_BASE,
+        numbers::{round, with_comma_int},
+        osu, CowUtils, ScoreExt,
+    },
+};
+
+#[derive(EmbedData)]
+pub struct SessionEmbed {
+    author: AuthorBuilder,
+    fields: Vec<EmbedField>,
+    footer: FooterBuilder,
+    thumbnail: String,
+    timestamp: OffsetDateTime,
+}
+
+impl SessionEmbed {
+    /// `session` must be sorted by date in descending order and `new_tops` contains
+    /// the top score indices of all session scores that made it into the top100.
+    pub fn new(
+        user: &User,
+        session: &[Score],
+        new_tops: &[usize],
+        snapshots: Option<(&UserStatsSnapshot, &UserStatsSnapshot)>,
+        gap: u32,
+    ) -> Self {
+        let newest = &session[0];
+        let oldest = &session[session.len() - 1];
+
+        let oldest_len = oldest.map.as_ref().map_or(0.0, |map| {
+            map.seconds_total as f64 / oldest.mods.bits().clock_rate()
+        });
+
+        let duration = (newest.ended_at - oldest.ended_at).whole_seconds() + oldest_len as i64;
+
+        let unique_maps = session
+            .iter()
+            .filter_map(|score| score.map.as_ref())
+            .map(|map| map.map_id)
+            .collect::<HashSet<_>>()
+            .len();
+
+        let passes = session
+            .iter()
+            .filter(|score| score.grade != Grade::F)
+            .count();
+
+        let mut fields = Vec::with_capacity(7);
+
+        let plays = format!(
+            "`{}` (`{unique_maps}` unique maps)",
+            with_comma_int(session.len())
+        );
+
+        let pass_rate = format!(
+            "`{}%` (`{passes}/{}`)",
+            round(100.0 * passes as f32 / session.len() as f32),
+            session.len(),
+        );
+
+        fields![fields {
+            "Duration", format!("`{}`", format_duration(duration)), true;
+            "Plays", plays, true;
+            "Pass rate", pass_rate, true;
+        }];
+
+        let mut new_tops_value = String::new();
+
+        if new_tops.is_empty() {
+            new_tops_value.push_str("None");
+        } else {
+            let _ = write!(new_tops_value, "`{}`: ", new_tops.len());
+
+            for (i, idx) in new_tops.iter().enumerate() {
+                if i > 0 {
+                    new_tops_value.push_str(", ");
+                }
+
+                let _ = write!(new_tops_value, "#{}", idx + 1);
+            }
+        }
+
+        fields![fields { "New top plays", new_tops_value, true }];
+
+        let (pp_value, rank_value) = match snapshots {
+            Some((before, after)) => {
+                let pp_diff = after.pp - before.pp;
+
+                let pp_value = format!(
+                    "`{}{}pp`",
+                    if pp_diff < 0.0 { '-' } else { '+' },
+                    round(pp_diff.abs())
+                );
+
+                let rank_value = match (before.global_rank, after.global_rank) {
+                    (Some(before), Some(after)) => format!(
+                        "`#{}` → `#{}` (`{:+}`)",
+                        with_comma_int(before),
+                        with_comma_int(after),
+                        before as i64 - after as i64
+                    ),
+                    _ => "`-`".to_owned(),
+                };
+
+                (pp_value, rank_value)
+            }
+            None => ("Unknown".to_owned(), "Unknown".to_owned()),
+        };
+
+        fields![fields {
+            "PP gained", pp_value, true;
+            "Rank", rank_value, true;
+        }];
+
+        let passed = session.iter().filter(|score| score.grade != Grade::F);
+
+        let best = passed.clone().max_by(|a, b| compare_scores(a, b));
+        let worst = passed
+            .min_by(|a, b| compare_scores(a, b))
+            .filter(|_| passes > 1);
+
+        if let Some(best) = best {
+            fields![fields { "Best play", score_line(best), false }];
+        }
+
+        if let Some(worst) = worst {
+            fields![fields { "Worst play", score_line(worst), false }];
+        }
+
+        let footer = FooterBuilder::new(format!(
+            "Sessions are split by {gap} minutes of inactivity • Session start"
+        ));
+
+        Self {
+            author: author!(user),
+            fields,
+            footer,
+            thumbnail: user.avatar_url.to_owned(),
+            timestamp: oldest.ended_at,
+        }
+    }
+}
+
+/// Compare by pp, falling back to accuracy
+fn compare_scores(a: &Score, b: &Score) -> Ordering {
+    match (a.pp, b.pp) {
+        (Some(a_pp), Some(b_pp)) => a_pp.partial_cmp(&b_pp).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Greater,
+        (None, Some(_)) => Ordering::Less,
+        (None, None) => a
+            .accuracy
+            .partial_cmp(&b.accuracy)
+            .unwrap_or(Ordering::Equal),
+    }
+}
+
+fn score_line(score: &Score) -> String {
+    let (title, version, map_id) = match (&score.map, &score.mapset) {
+        (Some(map), Some(mapset)) => (
+            mapset.title.cow_escape_markdown(),
+            map.version.cow_escape_markdown(),
+            map.map_id,
+        ),
+        _ => return format!("{:.2}%", score.accuracy),
+    };
+
+    let pp = match score.pp {
+        Some(pp) => format!("**{pp:.2}pp** • "),
+        None => String::new(),
+    };
+
+    format!(
+        "[{title} [{version}]]({OSU_BASE}b/{map_id}) {mods}\n\
+        {grade} {pp}{acc:.2}% • {combo}x",
+        mods = osu::get_mods(score.mods),
+        grade = score.grade_emote(score.mode),
+        acc = score.accuracy,
+        combo = score.max_combo,
+    )
+}
+
+fn format_duration(seconds: i64) -> String {
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+
+    if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else {
+        format!("{minutes}m")
+    }
+}
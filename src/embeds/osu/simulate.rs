@@ -1,7 +1,7 @@
 use std::{borrow::Cow, fmt::Write};
 
 use eyre::{Result, WrapErr};
-use rosu_pp::{Beatmap as Map, BeatmapExt, DifficultyAttributes};
+use rosu_pp::{Beatmap as Map, BeatmapExt, DifficultyAttributes, ManiaPP, PerformanceAttributes};
 use rosu_v2::prelude::{
     Beatmap, BeatmapsetCompact, GameMode, GameMods, Grade, Score, ScoreStatistics,
 };
@@ -27,7 +27,11 @@ use crate::{
 
 pub struct SimulateArgs {
     mods: Option<ModSelection>,
+    /// Key mod for osu!mania, applied on top of `mods`
+    keys: Option<GameMods>,
+    n_geki: Option<usize>,
     n300: Option<usize>,
+    n_katu: Option<usize>,
     n100: Option<usize>,
     n50: Option<usize>,
     misses: Option<usize>,
@@ -39,7 +43,10 @@ pub struct SimulateArgs {
 impl SimulateArgs {
     fn is_some(&self) -> bool {
         self.mods.is_some()
+            || self.keys.is_some()
+            || self.n_geki.is_some()
             || self.n300.is_some()
+            || self.n_katu.is_some()
             || self.n100.is_some()
             || self.n50.is_some()
             || self.misses.is_some()
@@ -47,6 +54,45 @@ impl SimulateArgs {
             || self.combo.is_some()
             || self.score.is_some()
     }
+
+    /// Ensure that the specified hits fit into the map's amount of objects
+    fn validate_hits(&self, mode: GameMode, n_objects: usize) -> Result<(), String> {
+        let hits = match mode {
+            GameMode::Osu => vec![self.n300, self.n100, self.n50, self.misses],
+            GameMode::Taiko => vec![self.n300, self.n100, self.misses],
+            // Catch hits are capped by the amount of fruits, droplets, and tiny droplets
+            GameMode::Catch => return Ok(()),
+            GameMode::Mania => vec![
+                self.n_geki,
+                self.n300,
+                self.n_katu,
+                self.n100,
+                self.n50,
+                self.misses,
+            ],
+        };
+
+        let total: usize = hits.iter().flatten().sum();
+
+        if total > n_objects {
+            let content = format!(
+                "The specified hits add up to {total} but the map only has {n_objects} objects"
+            );
+
+            return Err(content);
+        }
+
+        Ok(())
+    }
+
+    fn has_mania_hits(&self) -> bool {
+        self.n_geki.is_some()
+            || self.n300.is_some()
+            || self.n_katu.is_some()
+            || self.n100.is_some()
+            || self.n50.is_some()
+            || self.misses.is_some()
+    }
 }
 
 static ERR_PARSE_MODS: &str = "Failed to parse mods. Be sure to either specify them directly \
@@ -64,7 +110,10 @@ impl TryFrom<RecentSimulateOsu<'_>> for SimulateArgs {
 
         Ok(Self {
             mods,
+            keys: None,
+            n_geki: None,
             n300: args.n300.map(|n| n as usize),
+            n_katu: None,
             n100: args.n100.map(|n| n as usize),
             n50: args.n50.map(|n| n as usize),
             misses: args.misses.map(|n| n as usize),
@@ -87,7 +136,10 @@ impl TryFrom<RecentSimulateTaiko<'_>> for SimulateArgs {
 
         Ok(Self {
             mods,
+            keys: None,
+            n_geki: None,
             n300: args.n300.map(|n| n as usize),
+            n_katu: None,
             n100: args.n100.map(|n| n as usize),
             n50: None,
             misses: args.misses.map(|n| n as usize),
@@ -110,7 +162,10 @@ impl TryFrom<RecentSimulateCatch<'_>> for SimulateArgs {
 
         Ok(Self {
             mods,
+            keys: None,
+            n_geki: None,
             n300: args.fruits.map(|n| n as usize),
+            n_katu: None,
             n100: args.droplets.map(|n| n as usize),
             n50: args.tiny_droplets.map(|n| n as usize),
             misses: args.misses.map(|n| n as usize),
@@ -133,7 +188,10 @@ impl TryFrom<RecentSimulateMania<'_>> for SimulateArgs {
 
         Ok(Self {
             mods,
+            keys: None,
+            n_geki: None,
             n300: None,
+            n_katu: None,
             n100: None,
             n50: None,
             misses: None,
@@ -148,7 +206,10 @@ impl From<crate::commands::osu::SimulateArgs> for SimulateArgs {
     fn from(args: crate::commands::osu::SimulateArgs) -> Self {
         Self {
             mods: args.mods,
+            keys: args.keys.map(key_mod),
+            n_geki: args.n_geki,
             n300: args.n300,
+            n_katu: args.n_katu,
             n100: args.n100,
             n50: args.n50,
             misses: args.misses,
@@ -176,6 +237,7 @@ pub struct SimulateEmbed {
     combo: String,
     prev_hits: Option<String>,
     hits: String,
+    ratio: Option<f32>,
     removed_misses: Option<u32>,
     map_info: String,
     mapset_id: u32,
@@ -188,13 +250,35 @@ impl SimulateEmbed {
         mapset: &BeatmapsetCompact,
         args: SimulateArgs,
         ctx: &Context,
-    ) -> Result<Self> {
+    ) -> Result<Result<Self, String>> {
         let is_some = args.is_some();
 
-        let title = if map.mode == GameMode::Mania {
+        let mut mods = score.as_ref().map_or(GameMods::NoMod, |score| score.mods);
+
+        match args.mods {
+            Some(ModSelection::Exact(selected) | ModSelection::Include(selected)) => {
+                mods = selected
+            }
+            Some(ModSelection::Exclude(excluded)) => mods.remove(excluded),
+            None => {}
+        }
+
+        if let Some(keys) = args.keys {
+            if let Some(key_mod) = mods.has_key_mod() {
+                mods.remove(key_mod);
+            }
+
+            mods |= keys;
+        }
+
+        // A key mod on an osu!standard map means simulating its mania convert
+        let convert = map.mode == GameMode::Osu && mods.has_key_mod().is_some();
+        let mode = if convert { GameMode::Mania } else { map.mode };
+
+        let title = if mode == GameMode::Mania {
             format!(
                 "{} {} - {} [{}]",
-                osu::get_keys(GameMods::default(), map),
+                osu::get_keys(mods, map),
                 mapset.artist.cow_escape_markdown(),
                 mapset.title.cow_escape_markdown(),
                 map.version.cow_escape_markdown(),
@@ -238,46 +322,88 @@ impl SimulateEmbed {
             .await
             .wrap_err("failed to parse map")?;
 
-        if let Some(ModSelection::Exact(mods)) | Some(ModSelection::Include(mods)) = args.mods {
-            unchoked_score.mods = mods;
+        unchoked_score.mods = mods;
+
+        // Converts have a different amount of objects than the original map
+        let n_objects = match mode {
+            GameMode::Osu => map.count_objects() as usize,
+            GameMode::Taiko => map.count_circles as usize,
+            GameMode::Catch => map.count_objects() as usize,
+            GameMode::Mania => rosu_map
+                .convert_mode(rosu_pp::GameMode::Mania)
+                .hit_objects
+                .len(),
+        };
+
+        if let Err(content) = args.validate_hits(mode, n_objects) {
+            return Ok(Err(content));
         }
 
-        let performance_attributes = rosu_map.max_pp(unchoked_score.mods.bits());
+        let performance_attributes: PerformanceAttributes = if convert {
+            ManiaPP::new(&rosu_map)
+                .mods(unchoked_score.mods.bits())
+                .calculate()
+                .into()
+        } else {
+            rosu_map.max_pp(unchoked_score.mods.bits())
+        };
+
         let attributes = performance_attributes.difficulty_attributes();
         let max_pp = performance_attributes.pp() as f32;
 
         let stars = round(attributes.stars() as f32);
 
         if is_some {
-            simulate_score(&mut unchoked_score, map, args, &attributes);
+            simulate_score(&mut unchoked_score, n_objects, args, &attributes);
         } else {
-            unchoke_score(&mut unchoked_score, map, &attributes);
+            unchoke_score(&mut unchoked_score, map, n_objects, &attributes);
         }
 
-        let pp = rosu_map
-            .pp()
-            .attributes(attributes)
-            .mods(unchoked_score.mods.bits())
-            .combo(unchoked_score.max_combo as usize)
-            .n300(unchoked_score.statistics.count_300 as usize)
-            .n100(unchoked_score.statistics.count_100 as usize)
-            .n50(unchoked_score.statistics.count_50 as usize)
-            .n_katu(unchoked_score.statistics.count_katu as usize)
-            .misses(unchoked_score.statistics.count_miss as usize)
-            .score(unchoked_score.score)
-            .calculate()
-            .pp() as f32;
+        let pp = if convert {
+            ManiaPP::new(&rosu_map)
+                .attributes(attributes)
+                .mods(unchoked_score.mods.bits())
+                .score(unchoked_score.score)
+                .calculate()
+                .pp() as f32
+        } else {
+            rosu_map
+                .pp()
+                .attributes(attributes)
+                .mods(unchoked_score.mods.bits())
+                .state(unchoked_score.state())
+                .calculate()
+                .pp() as f32
+        };
 
         let grade_completion_mods = grade_completion_mods(&unchoked_score, map);
         let pp = osu::get_pp(Some(pp), Some(max_pp));
-        let hits = unchoked_score.hits_string(map.mode);
+        let hits = unchoked_score.hits_string(mode);
+
+        let ratio = (mode == GameMode::Mania).then(|| {
+            let mut ratio = unchoked_score.statistics.count_geki as f32;
+
+            if unchoked_score.statistics.count_300 > 0 {
+                ratio /= unchoked_score.statistics.count_300 as f32
+            }
+
+            ratio
+        });
 
-        let (combo, acc) = match map.mode {
+        let (combo, acc) = match mode {
             GameMode::Osu | GameMode::Catch => (
                 osu::get_combo(&unchoked_score, map),
                 round(unchoked_score.accuracy),
             ),
-            GameMode::Mania => (String::from("**-**/-"), 100.0),
+            GameMode::Mania => {
+                let combo = if unchoked_score.max_combo == 0 {
+                    "**-**/-".to_owned()
+                } else {
+                    format!("**{}x**/-", unchoked_score.max_combo)
+                };
+
+                (combo, round(unchoked_score.accuracy))
+            }
             GameMode::Taiko => {
                 let acc = round(unchoked_score.accuracy);
 
@@ -300,7 +426,7 @@ impl SimulateEmbed {
         let footer = FooterBuilder::new(format!("{:?} map by {}", map.status, mapset.creator_name))
             .icon_url(format!("{AVATAR_URL}{}", mapset.creator_id));
 
-        Ok(Self {
+        let embed = Self {
             title,
             url: map.url.to_owned(),
             footer,
@@ -308,18 +434,21 @@ impl SimulateEmbed {
             grade_completion_mods,
             stars,
             score: unchoked_score.score as u64,
-            mode: map.mode,
+            mode,
             acc,
             pp,
             combo,
             hits,
+            ratio,
             map_info: osu::get_map_info(map, unchoked_score.mods, stars),
             removed_misses: misses,
             prev_hits,
             prev_combo,
             prev_pp,
             mapset_id: mapset.mapset_id,
-        })
+        };
+
+        Ok(Ok(embed))
     }
 
     pub fn as_maximized(&self) -> Embed {
@@ -350,14 +479,18 @@ impl SimulateEmbed {
 
         if self.mode == GameMode::Mania {
             fields![fields { "Score", with_comma_int(self.score).to_string(), true }];
+        }
+
+        let hits = if let Some(ref prev_hits) = self.prev_hits {
+            format!("{prev_hits} → {}", &self.hits)
         } else {
-            let hits = if let Some(ref prev_hits) = self.prev_hits {
-                format!("{prev_hits} → {}", &self.hits)
-            } else {
-                self.hits.to_owned()
-            };
+            self.hits.to_owned()
+        };
+
+        fields![fields { "Hits", hits, true }];
 
-            fields![fields { "Hits", hits, true }];
+        if let Some(ratio) = self.ratio {
+            fields![fields { "Ratio", format!("{ratio:.2}"), true }];
         }
 
         fields![fields { "Map Info", self.map_info.clone(), false }];
@@ -378,9 +511,7 @@ impl SimulateEmbed {
             self.pp
         };
 
-        if self.mode != GameMode::Mania {
-            let _ = write!(value, " {}", self.hits);
-        }
+        let _ = write!(value, " {}", self.hits);
 
         if let Some(misses) = self.removed_misses.filter(|misses| *misses > 0) {
             let _ = write!(value, " (+{misses}miss)");
@@ -416,7 +547,7 @@ impl SimulateEmbed {
 
 fn simulate_score(
     score: &mut Score,
-    map: &Beatmap,
+    n_objects: usize,
     args: SimulateArgs,
     attributes: &DifficultyAttributes,
 ) {
@@ -426,7 +557,6 @@ fn simulate_score(
             let mut n50 = args.n50.unwrap_or(0);
             let mut n100 = args.n100.unwrap_or(0);
             let mut miss = args.misses.unwrap_or(0);
-            let n_objects = map.count_objects() as usize;
 
             let combo = args
                 .combo
@@ -489,26 +619,54 @@ fn simulate_score(
             score.grade = score.grade(None);
         }
         DifficultyAttributes::Mania(_) => {
-            let mut max_score = 1_000_000;
-
             let mods = score.mods;
+            let max_score = mania_max_score(mods);
 
-            if mods.contains(GameMods::Easy) {
-                max_score /= 2;
-            }
+            score.mode = GameMode::Mania;
+            score.max_combo = args.combo.map_or(0, |combo| combo as u32);
+
+            if args.has_mania_hits() {
+                let n_katu = args.n_katu.unwrap_or(0);
+                let n100 = args.n100.unwrap_or(0);
+                let n50 = args.n50.unwrap_or(0);
+                let miss = args.misses.unwrap_or(0);
+                let rest = n_katu + n100 + n50 + miss;
+
+                // Fill up the remaining objects with whichever of n320 and n300 was not specified
+                let (n_geki, n300) = match (args.n_geki, args.n300) {
+                    (Some(n_geki), Some(n300)) => (n_geki, n300),
+                    (Some(n_geki), None) => (n_geki, n_objects.saturating_sub(n_geki + rest)),
+                    (None, n300) => {
+                        let n300 = n300.unwrap_or(0);
+
+                        (n_objects.saturating_sub(n300 + rest), n300)
+                    }
+                };
 
-            if mods.contains(GameMods::NoFail) {
-                max_score /= 2;
-            }
+                score.statistics.count_geki = n_geki as u32;
+                score.statistics.count_300 = n300 as u32;
+                score.statistics.count_katu = n_katu as u32;
+                score.statistics.count_100 = n100 as u32;
+                score.statistics.count_50 = n50 as u32;
+                score.statistics.count_miss = miss as u32;
+                score.accuracy = score.accuracy();
+
+                // Approximate the score through the hit values if none was specified
+                let total = (n_geki + n300 + rest).max(1);
+                let hit_value = 320 * n_geki + 300 * n300 + 200 * n_katu + 100 * n100 + 50 * n50;
+                let approx_score = max_score as f64 * hit_value as f64 / (320 * total) as f64;
 
-            if mods.contains(GameMods::HalfTime) {
-                max_score /= 2;
+                score.score = args
+                    .score
+                    .map_or(approx_score.round() as u32, |s| s.min(max_score));
+
+                score.grade = score.grade(Some(score.accuracy));
+
+                return;
             }
 
-            score.mode = GameMode::Mania;
-            score.max_combo = 0;
             score.score = args.score.map_or(max_score, |s| s.min(max_score));
-            score.statistics.count_geki = map.count_objects();
+            score.statistics.count_geki = n_objects as u32;
             score.statistics.count_300 = 0;
             score.statistics.count_katu = 0;
             score.statistics.count_100 = 0;
@@ -532,7 +690,6 @@ fn simulate_score(
             let n100 = args.n100.unwrap_or(0);
             let n300 = args.n300.unwrap_or(0);
             let miss = args.misses.unwrap_or(0);
-            let n_objects = map.count_circles as usize;
             let missing = n_objects - (n300 + n100 + miss);
 
             match args.acc {
@@ -640,7 +797,12 @@ fn simulate_score(
     }
 }
 
-fn unchoke_score(score: &mut Score, map: &Beatmap, attributes: &DifficultyAttributes) {
+fn unchoke_score(
+    score: &mut Score,
+    map: &Beatmap,
+    n_objects: usize,
+    attributes: &DifficultyAttributes,
+) {
     let max_combo = map
         .max_combo
         .unwrap_or_else(|| attributes.max_combo().map_or(0, |combo| combo as u32));
@@ -651,7 +813,7 @@ fn unchoke_score(score: &mut Score, map: &Beatmap, attributes: &DifficultyAttrib
 
     match attributes {
         DifficultyAttributes::Osu(attrs) => {
-            let total_objects = map.count_objects() as usize;
+            let total_objects = n_objects;
             let passed_objects = score.total_hits() as usize;
 
             let mut count300 =
@@ -669,20 +831,7 @@ fn unchoke_score(score: &mut Score, map: &Beatmap, attributes: &DifficultyAttrib
             score.max_combo = map.max_combo.unwrap_or(attrs.max_combo as u32);
         }
         DifficultyAttributes::Mania(_) => {
-            score.score = 1_000_000;
-            let mods = score.mods;
-
-            if mods.contains(GameMods::Easy) {
-                score.score /= 2;
-            }
-
-            if mods.contains(GameMods::NoFail) {
-                score.score /= 2;
-            }
-
-            if mods.contains(GameMods::HalfTime) {
-                score.score /= 2;
-            }
+            score.score = mania_max_score(score.mods);
 
             let hdfl = GameMods::Flashlight | GameMods::Hidden;
 
@@ -718,7 +867,7 @@ fn unchoke_score(score: &mut Score, map: &Beatmap, attributes: &DifficultyAttrib
             score.max_combo = total_objects as u32;
         }
         DifficultyAttributes::Taiko(_) => {
-            let total_objects = map.count_circles as usize;
+            let total_objects = n_objects;
             let passed_objects = score.total_hits() as usize;
 
             let mut count300 =
@@ -741,6 +890,38 @@ fn unchoke_score(score: &mut Score, map: &Beatmap, attributes: &DifficultyAttrib
     score.accuracy = score.accuracy();
 }
 
+fn mania_max_score(mods: GameMods) -> u32 {
+    let mut max_score = 1_000_000;
+
+    if mods.contains(GameMods::Easy) {
+        max_score /= 2;
+    }
+
+    if mods.contains(GameMods::NoFail) {
+        max_score /= 2;
+    }
+
+    if mods.contains(GameMods::HalfTime) {
+        max_score /= 2;
+    }
+
+    max_score
+}
+
+fn key_mod(keys: u8) -> GameMods {
+    match keys {
+        1 => GameMods::Key1,
+        2 => GameMods::Key2,
+        3 => GameMods::Key3,
+        4 => GameMods::Key4,
+        5 => GameMods::Key5,
+        6 => GameMods::Key6,
+        7 => GameMods::Key7,
+        8 => GameMods::Key8,
+        _ => GameMods::Key9,
+    }
+}
+
 fn default_score() -> Score {
     Score {
         accuracy: 100.0,
@@ -0,0 +1,126 @@
+use std::fmt::Write;
+
+use command_macros::EmbedData;
+use rosu_v2::prelude::User;
+use twilight_model::channel::embed::EmbedField;
+
+use crate::{
+    commands::osu::{TopOldCompareEntry, TopOldCompareSummary},
+    embeds::osu,
+    pagination::Pages,
+    util::{
+        builder::{AuthorBuilder, FooterBuilder},
+        constants::OSU_BASE,
+        CowUtils, ScoreExt,
+    },
+};
+
+#[derive(EmbedData)]
+pub struct TopOldCompareEmbed {
+    author: AuthorBuilder,
+    description: String,
+    fields: Vec<EmbedField>,
+    footer: FooterBuilder,
+    thumbnail: String,
+    title: String,
+}
+
+impl TopOldCompareEmbed {
+    pub fn new(
+        user: &User,
+        entries: &[TopOldCompareEntry],
+        summary: &TopOldCompareSummary,
+        pre_pp: f32,
+        post_pp: f32,
+        pages: &Pages,
+    ) -> Self {
+        let pp_diff = (100.0 * (post_pp - pre_pp)).round() / 100.0;
+        let mut description = String::with_capacity(1024);
+
+        for entry in entries.iter().skip(pages.index).take(pages.per_page) {
+            let score = &entry.score;
+            let map = score.map.as_ref().unwrap();
+            let mapset = score.mapset.as_ref().unwrap();
+
+            let _ = writeln!(
+                description,
+                "**{idx}. [{title} [{version}]]({OSU_BASE}b/{id}) {mods}** [{stars:.2}★]\n\
+                {grade} {current_pp:.2} → **{pp:.2}pp** ({diff:+.2}) ~ {movement}",
+                idx = entry.new_idx,
+                title = mapset.title.cow_escape_markdown(),
+                version = map.version.cow_escape_markdown(),
+                id = map.map_id,
+                mods = osu::get_mods(score.mods),
+                stars = map.stars,
+                grade = score.grade_emote(score.mode),
+                current_pp = entry.current_pp,
+                pp = score.pp.unwrap_or(0.0),
+                diff = entry.pp_diff(),
+                movement = movement(entry.old_idx, entry.new_idx),
+            );
+        }
+
+        description.pop();
+
+        let fields = vec![
+            EmbedField {
+                inline: false,
+                name: format!("Biggest gains ({} scores gained pp)", summary.gained),
+                value: summary_value(entries, &summary.gains),
+            },
+            EmbedField {
+                inline: false,
+                name: format!("Biggest losses ({} scores lost pp)", summary.lost),
+                value: summary_value(entries, &summary.losses),
+            },
+        ];
+
+        let footer_text = format!("Page {}/{}", pages.curr_page(), pages.last_page());
+
+        Self {
+            author: author!(user),
+            description,
+            fields,
+            footer: FooterBuilder::new(footer_text),
+            thumbnail: user.avatar_url.to_owned(),
+            title: format!("Total pp: {pre_pp} → **{post_pp}pp** ({pp_diff:+})"),
+        }
+    }
+}
+
+fn movement(old_idx: usize, new_idx: usize) -> String {
+    if old_idx == new_idx {
+        format!("#{new_idx}")
+    } else {
+        format!("#{old_idx} → #{new_idx}")
+    }
+}
+
+fn summary_value(entries: &[TopOldCompareEntry], indices: &[usize]) -> String {
+    if indices.is_empty() {
+        return "None".to_owned();
+    }
+
+    let mut value = String::with_capacity(indices.len() * 64);
+
+    for &i in indices {
+        let entry = &entries[i];
+        let score = &entry.score;
+        let map = score.map.as_ref().unwrap();
+        let mapset = score.mapset.as_ref().unwrap();
+
+        let _ = writeln!(
+            value,
+            "`{diff:+.2}pp` [{title} [{version}]]({OSU_BASE}b/{id}) {mods}",
+            diff = entry.pp_diff(),
+            title = mapset.title.cow_escape_markdown(),
+            version = map.version.cow_escape_markdown(),
+            id = map.map_id,
+            mods = osu::get_mods(score.mods),
+        );
+    }
+
+    value.pop();
+
+    value
+}
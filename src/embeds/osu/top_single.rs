@@ -8,14 +8,19 @@ use twilight_model::channel::embed::Embed;
 
 use crate::{
     core::Context,
-    database::MinimizedPp,
+    custom_client::LazerScore,
+    database::{MinimizedPp, ScoreKind},
     embeds::osu,
+    pp,
     util::{
         builder::{AuthorBuilder, EmbedBuilder, FooterBuilder},
         constants::AVATAR_URL,
         datetime::{how_long_ago_dynamic, HowLongAgoFormatterDynamic},
-        numbers::{round, with_comma_int},
-        osu::{grade_completion_mods, prepare_beatmap_file},
+        numbers::round,
+        osu::{
+            grade_completion_mods, grade_lazer_mods, lazer_score, prepare_beatmap_file,
+            score_by_kind,
+        },
         CowUtils, ScoreExt,
     },
 };
@@ -52,10 +57,12 @@ impl TopSingleEmbed {
         personal_idx: Option<usize>,
         global_idx: Option<usize>,
         minimized_pp: MinimizedPp,
+        score_kind: ScoreKind,
         ctx: &Context,
     ) -> Result<Self> {
         let map = score.map.as_ref().unwrap();
         let mapset = score.mapset.as_ref().unwrap();
+        let lazer = lazer_score(ctx, score, score_kind).await;
 
         let map_path = prepare_beatmap_file(ctx, map.map_id)
             .await
@@ -80,9 +87,23 @@ impl TopSingleEmbed {
 
         let if_fc = IfFC::new(score, &rosu_map, attributes, mods);
 
-        let pp = score.pp;
-        let hits = score.hits_string(score.mode);
-        let grade_completion_mods = grade_completion_mods(score, map);
+        let lazer_pp = lazer.as_ref().and_then(|lazer| {
+            pp::lazer_pp(&rosu_map, lazer, score.mode)
+                .map(|pp| pp as f32)
+                .or(lazer.pp)
+        });
+
+        let pp = lazer_pp.or(score.pp);
+        let mut hits = score.hits_string(score.mode);
+
+        let grade_completion_mods = match lazer {
+            Some(ref lazer) => grade_lazer_mods(score, map, lazer),
+            None => grade_completion_mods(score, map),
+        };
+
+        if let Some((hit, total)) = lazer.as_ref().and_then(LazerScore::slider_ends) {
+            let _ = write!(hits, " • {hit}/{total} ends");
+        }
 
         let (combo, title) = if score.mode == GameMode::Mania {
             let mut ratio = score.statistics.count_geki as f32;
@@ -167,7 +188,7 @@ impl TopSingleEmbed {
             timestamp: score.ended_at,
             grade_completion_mods,
             stars,
-            score: with_comma_int(score.score).to_string(),
+            score: score_by_kind(score, lazer.as_ref(), score_kind),
             acc: round(score.accuracy),
             ago: how_long_ago_dynamic(&score.ended_at),
             pp,
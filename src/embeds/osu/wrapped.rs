@@ -0,0 +1,24 @@
+use command_macros::EmbedData;
+use rosu_v2::prelude::User;
+
+use crate::{
+    embeds::attachment,
+    util::builder::{AuthorBuilder, FooterBuilder},
+};
+
+#[derive(EmbedData)]
+pub struct WrappedEmbed {
+    author: AuthorBuilder,
+    footer: FooterBuilder,
+    image: String,
+}
+
+impl WrappedEmbed {
+    pub fn new(user: &User, year: i32) -> Self {
+        Self {
+            author: author!(user),
+            footer: FooterBuilder::new(format!("Recap of {year}")),
+            image: attachment("wrapped.png"),
+        }
+    }
+}
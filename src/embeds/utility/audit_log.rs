@@ -0,0 +1,59 @@
+use std::fmt::Write;
+
+use command_macros::EmbedData;
+
+use crate::{
+    commands::utility::GuildData,
+    database::AuditLogEntry,
+    pagination::Pages,
+    util::builder::{AuthorBuilder, FooterBuilder},
+};
+
+#[derive(EmbedData)]
+pub struct AuditLogEmbed {
+    author: AuthorBuilder,
+    description: String,
+    footer: FooterBuilder,
+    title: &'static str,
+}
+
+impl AuditLogEmbed {
+    pub fn new(guild: &GuildData, entries: &[AuditLogEntry], pages: &Pages) -> Self {
+        let mut author = AuthorBuilder::new(guild.name.as_str());
+
+        if let Some(ref hash) = guild.icon {
+            let url = format!(
+                "https://cdn.discordapp.com/icons/{}/{hash}.{}",
+                guild.id,
+                if hash.is_animated() { "gif" } else { "webp" }
+            );
+
+            author = author.icon_url(url);
+        }
+
+        let mut description = String::with_capacity(entries.len() * 128);
+
+        for entry in entries {
+            let _ = writeln!(
+                description,
+                "**{action}** by <@{user}> <t:{timestamp}:R>",
+                action = entry.action,
+                user = entry.user_id,
+                timestamp = entry.created_at.unix_timestamp(),
+            );
+
+            for line in entry.details.lines() {
+                let _ = writeln!(description, "> {line}");
+            }
+        }
+
+        let footer_text = format!("Page {}/{}", pages.curr_page(), pages.last_page());
+
+        Self {
+            author,
+            description,
+            footer: FooterBuilder::new(footer_text),
+            title: "Recent changes of this server:",
+        }
+    }
+}
@@ -6,7 +6,7 @@ use twilight_model::{channel::embed::EmbedField, user::User};
 
 use crate::{
     commands::osu::ProfileSize,
-    database::{EmbedsSize, ListSize, MinimizedPp, UserConfig},
+    database::{EmbedsSize, ListSize, MinimizedPp, ScoreKind, UserConfig},
     util::builder::AuthorBuilder,
 };
 
@@ -88,6 +88,15 @@ impl ConfigEmbed {
                     (ListSize::Single, "single"),
                 ],
             ),
+            create_field(
+                "Score",
+                config.score_kind(),
+                &[
+                    (ScoreKind::Stable, "stable"),
+                    (ScoreKind::Standardised, "standardised"),
+                    (ScoreKind::Classic, "classic"),
+                ],
+            ),
             create_field(
                 "Profile",
                 config.profile_size.unwrap_or_default(),
@@ -1,7 +1,9 @@
+mod audit_log;
 mod command_counter;
 mod config;
 mod server_config;
 
 pub use self::{
-    command_counter::CommandCounterEmbed, config::ConfigEmbed, server_config::ServerConfigEmbed,
+    audit_log::AuditLogEmbed, command_counter::CommandCounterEmbed, config::ConfigEmbed,
+    server_config::ServerConfigEmbed,
 };
@@ -21,6 +21,31 @@ pub struct ServerConfigEmbed {
 }
 
 impl ServerConfigEmbed {
+    pub const LYRICS: [(bool, &'static str); 2] = [(true, "enabled"), (false, "disabled")];
+    pub const PUBLIC_API: [(bool, &'static str); 2] = [(true, "enabled"), (false, "disabled")];
+    pub const RETRIES: [(bool, &'static str); 2] = [(true, "show"), (false, "hide")];
+
+    pub const MINIMIZED_PP: [(MinimizedPp, &'static str); 2] =
+        [(MinimizedPp::Max, "max pp"), (MinimizedPp::IfFc, "if FC")];
+
+    pub const EMBEDS_SIZE: [(EmbedsSize, &'static str); 3] = [
+        (EmbedsSize::AlwaysMinimized, "always minimized"),
+        (EmbedsSize::AlwaysMaximized, "always maximized"),
+        (EmbedsSize::InitialMaximized, "initial maximized"),
+    ];
+
+    pub const LIST_SIZE: [(ListSize, &'static str); 3] = [
+        (ListSize::Condensed, "condensed"),
+        (ListSize::Detailed, "detailed"),
+        (ListSize::Single, "single"),
+    ];
+
+    pub const PROFILE_SIZE: [(ProfileSize, &'static str); 3] = [
+        (ProfileSize::Compact, "compact"),
+        (ProfileSize::Medium, "medium"),
+        (ProfileSize::Full, "full"),
+    ];
+
     pub fn new(guild: GuildData, config: GuildConfig, authorities: &[String]) -> Self {
         let mut author = AuthorBuilder::new(guild.name);
 
@@ -67,48 +92,13 @@ impl ServerConfigEmbed {
         let _ = writeln!(description, "\nDefault track limit: {track_limit}\n```");
 
         let fields = vec![
-            create_field(
-                "Song commands",
-                config.with_lyrics(),
-                &[(true, "enabled"), (false, "disabled")],
-            ),
-            create_field(
-                "Retries*",
-                config.show_retries(),
-                &[(true, "show"), (false, "hide")],
-            ),
-            create_field(
-                "Minimized PP*",
-                config.minimized_pp(),
-                &[(MinimizedPp::Max, "max pp"), (MinimizedPp::IfFc, "if FC")],
-            ),
-            create_field(
-                "Score embeds*",
-                config.embeds_size(),
-                &[
-                    (EmbedsSize::AlwaysMinimized, "always minimized"),
-                    (EmbedsSize::AlwaysMaximized, "always maximized"),
-                    (EmbedsSize::InitialMaximized, "initial maximized"),
-                ],
-            ),
-            create_field(
-                "List embeds*",
-                config.list_size(),
-                &[
-                    (ListSize::Condensed, "condensed"),
-                    (ListSize::Detailed, "detailed"),
-                    (ListSize::Single, "single"),
-                ],
-            ),
-            create_field(
-                "Profile*",
-                config.profile_size.unwrap_or_default(),
-                &[
-                    (ProfileSize::Compact, "compact"),
-                    (ProfileSize::Medium, "medium"),
-                    (ProfileSize::Full, "full"),
-                ],
-            ),
+            create_field("Song commands", config.with_lyrics(), &Self::LYRICS),
+            create_field("Public API", config.public_api(), &Self::PUBLIC_API),
+            create_field("Retries*", config.show_retries(), &Self::RETRIES),
+            create_field("Minimized PP*", config.minimized_pp(), &Self::MINIMIZED_PP),
+            create_field("Score embeds*", config.embeds_size(), &Self::EMBEDS_SIZE),
+            create_field("List embeds*", config.list_size(), &Self::LIST_SIZE),
+            create_field("Profile*", config.profile_size(), &Self::PROFILE_SIZE),
         ];
 
         Self {
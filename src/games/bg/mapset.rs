@@ -2,7 +2,7 @@ use eyre::{Result, WrapErr};
 use rosu_v2::prelude::BeatmapsetCompact;
 
 use crate::{
-    core::Context,
+    core::{Context, OsuFutureExt},
     util::{gestalt_pattern_matching, levenshtein_similarity},
 };
 
@@ -24,6 +24,7 @@ impl GameMapset {
                 let mapset = ctx
                     .osu()
                     .beatmapset(mapset_id)
+                    .observe(&ctx.stats, "beatmapset")
                     .await
                     .wrap_err("failed to request mapset")?;
 
@@ -6,7 +6,7 @@ use rand::{prelude::SliceRandom, Rng};
 use time::OffsetDateTime;
 
 use crate::{
-    core::{ArchivedBytes, Context},
+    core::{ArchivedBytes, Context, OsuFutureExt},
     custom_client::OsuTrackerIdCount,
 };
 
@@ -142,6 +142,7 @@ impl FarmMap {
                     .osu()
                     .beatmap()
                     .map_id(map_id)
+                    .observe(&ctx.stats, "beatmap")
                     .await
                     .wrap_err("failed to request beatmap")?;
 
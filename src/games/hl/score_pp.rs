@@ -9,7 +9,7 @@ use rosu_v2::{
 };
 
 use crate::{
-    core::Context,
+    core::{Context, OsuFutureExt},
     embeds::get_mods,
     games::hl::mapset_cover,
     util::{
@@ -77,6 +77,7 @@ impl ScorePp {
             .limit(100)
             .mode(mode)
             .best()
+            .observe(&ctx.stats, "user_scores")
             .await
             .wrap_err("failed to get user scores")?;
 
@@ -92,7 +93,13 @@ impl ScorePp {
 
         let map = match ctx.psql().get_beatmap(map_id, true).await {
             Ok(map) => map,
-            Err(_) => match ctx.osu().beatmap().map_id(map_id).await {
+            Err(_) => match ctx
+                .osu()
+                .beatmap()
+                .map_id(map_id)
+                .observe(&ctx.stats, "beatmap")
+                .await
+            {
                 Ok(map) => {
                     // Store map in DB
                     if let Err(err) = ctx.psql().insert_beatmap(&map).await {
@@ -135,6 +135,18 @@ async fn async_main() -> Result<()> {
     let background_ctx = Arc::clone(&ctx);
     tokio::spawn(Context::background_loop(background_ctx));
 
+    // Spawn score archive worker
+    let archive_ctx = Arc::clone(&ctx);
+    tokio::spawn(Context::score_archive_loop(archive_ctx));
+
+    // Spawn mapper popularity worker
+    let mapper_ctx = Arc::clone(&ctx);
+    tokio::spawn(Context::mapper_snapshot_loop(mapper_ctx));
+
+    // Spawn competition worker
+    let competition_ctx = Arc::clone(&ctx);
+    tokio::spawn(Context::competition_loop(competition_ctx));
+
     #[cfg(feature = "matchlive")]
     {
         // Spawn osu match ticker worker
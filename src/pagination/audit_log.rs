@@ -0,0 +1,25 @@
+use command_macros::pagination;
+use twilight_model::channel::embed::Embed;
+
+use crate::{
+    commands::utility::GuildData,
+    database::AuditLogEntry,
+    embeds::{AuditLogEmbed, EmbedData},
+};
+
+use super::Pages;
+
+#[pagination(per_page = 10, entries = "entries")]
+pub struct AuditLogPagination {
+    guild: GuildData,
+    entries: Vec<AuditLogEntry>,
+}
+
+impl AuditLogPagination {
+    pub fn build_page(&mut self, pages: &Pages) -> Embed {
+        let idx = pages.index;
+        let entries = &self.entries[idx..(idx + pages.per_page).min(self.entries.len())];
+
+        AuditLogEmbed::new(&self.guild, entries, pages).build()
+    }
+}
@@ -9,8 +9,14 @@ use crate::{
     embeds::{EmbedData, LeaderboardEmbed},
 };
 
+#[cfg(feature = "server")]
+use crate::util::constants::OSU_BASE;
+
 use super::Pages;
 
+#[cfg(feature = "server")]
+use super::{score_cells, WebCell, WebTable};
+
 #[pagination(per_page = 10, entries = "scores")]
 pub struct LeaderboardPagination {
     map: Beatmap,
@@ -34,4 +40,42 @@ impl LeaderboardPagination {
 
         embed_fut.await.map(EmbedData::build)
     }
+
+    #[cfg(feature = "server")]
+    pub fn web_table(&self) -> WebTable {
+        let rows = self
+            .scores
+            .iter()
+            .enumerate()
+            .map(|(i, score)| {
+                let mut row = Vec::with_capacity(10);
+                row.push(WebCell::number((i + 1).to_string(), (i + 1) as f64));
+                row.push(
+                    WebCell::text(score.username.as_str())
+                        .url(format!("{OSU_BASE}u/{}", score.user_id)),
+                );
+                row.extend(score_cells(score, self.map.mode));
+                row.push(WebCell::date(score.date));
+
+                row
+            })
+            .collect();
+
+        let title = match self.map.mapset {
+            Some(ref mapset) => format!(
+                "Leaderboard of {} - {} [{}]",
+                mapset.artist, mapset.title, self.map.version
+            ),
+            None => format!("Leaderboard of map {}", self.map.map_id),
+        };
+
+        WebTable {
+            title,
+            columns: &[
+                "#", "Player", "Mods", "Score", "PP", "Accuracy", "Combo", "Misses", "Grade",
+                "Date",
+            ],
+            rows,
+        }
+    }
 }
@@ -5,6 +5,7 @@ use twilight_model::channel::embed::Embed;
 
 use crate::{
     commands::osu::Search,
+    core::OsuFutureExt,
     embeds::{EmbedData, MapSearchEmbed},
     Context,
 };
@@ -46,7 +47,9 @@ impl MapSearchPagination {
         if count < pages.per_page {
             let next_fut = self.search_result.get_next(ctx.osu());
 
-            if let Some(mut next_search_result) = next_fut.await.transpose()? {
+            let next_fut = async { next_fut.await.transpose() }.observe(&ctx.stats, "map_search");
+
+            if let Some(mut next_search_result) = next_fut.await? {
                 let idx = pages.index;
 
                 let iter = next_search_result
@@ -0,0 +1,90 @@
+use std::cmp::Ordering;
+
+use command_macros::pagination;
+use eyre::{Report, Result};
+use hashbrown::HashMap;
+use rosu_v2::prelude::User;
+use twilight_model::channel::embed::Embed;
+
+use crate::{
+    commands::osu::{MapperMapsetEntry, MapperPpRecord, MapperSummary},
+    core::{Context, OsuFutureExt},
+    embeds::{EmbedData, MapperStatsEmbed},
+    util::hasher::IntHasher,
+};
+
+use super::Pages;
+
+#[pagination(per_page = 5, entries = "entries")]
+pub struct MapperStatsPagination {
+    user: User,
+    summary: MapperSummary,
+    entries: Vec<MapperMapsetEntry>,
+    records: HashMap<u32, Option<MapperPpRecord>, IntHasher>,
+    graph: bool,
+}
+
+impl MapperStatsPagination {
+    pub async fn build_page(&mut self, ctx: &Context, pages: &Pages) -> Result<Embed> {
+        let idx = pages.index;
+        let entries = &self.entries[idx..self.entries.len().min(idx + pages.per_page)];
+
+        for entry in entries {
+            if self.records.contains_key(&entry.mapset_id) {
+                continue;
+            }
+
+            let map = match entry.top_map {
+                Some(ref map) => map,
+                None => {
+                    self.records.insert(entry.mapset_id, None);
+
+                    continue;
+                }
+            };
+
+            let scores_fut = ctx
+                .osu()
+                .beatmap_scores(map.map_id)
+                .mode(map.mode)
+                .observe(&ctx.stats, "beatmap_scores");
+
+            // Not storing the failure so the record is requested again next time
+            let scores = match scores_fut.await {
+                Ok(scores) => scores,
+                Err(err) => {
+                    let report = Report::new(err).wrap_err("failed to get map leaderboard");
+                    warn!("{report:?}");
+
+                    continue;
+                }
+            };
+
+            let record = scores
+                .into_iter()
+                .filter_map(|score| score.pp.map(|pp| (pp, score)))
+                .max_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+                .map(|(pp, score)| MapperPpRecord {
+                    user_id: score.user_id,
+                    username: score
+                        .user
+                        .map_or_else(|| score.user_id.to_string().into(), |user| user.username),
+                    pp,
+                    version: map.version.clone(),
+                });
+
+            self.records.insert(entry.mapset_id, record);
+        }
+
+        let embed = MapperStatsEmbed::new(
+            &self.user,
+            &self.summary,
+            entries,
+            &self.records,
+            self.graph,
+            pages,
+        );
+
+        Ok(embed.build())
+    }
+}
@@ -1,6 +1,7 @@
 use std::{sync::Arc, time::Duration};
 
 use eyre::{Report, Result, WrapErr};
+use time::OffsetDateTime;
 use tokio::{
     sync::watch::{self, Receiver, Sender},
     time::sleep,
@@ -20,15 +21,19 @@ use crate::{
 };
 
 pub use self::{
-    badges::*, command_count::*, common::*, country_snipe_list::*, leaderboard::*, map::*,
-    map_search::*, match_compare::*, medal_recent::*, medals_common::*, medals_list::*,
-    medals_missing::*, most_played::*, most_played_common::*, nochoke::*, osekai_medal_count::*,
-    osekai_medal_rarity::*, osustats_globals::*, osustats_list::*, osutracker_countrytop::*,
-    osutracker_mappers::*, osutracker_maps::*, osutracker_mapsets::*, osutracker_mods::*,
-    player_snipe_list::*, profile::*, ranking::*, ranking_countries::*, recent_list::*, scores::*,
-    sniped_difference::*, top::*, top_if::*,
+    audit_log::*, badges::*, command_count::*, common::*, country_snipe_list::*, leaderboard::*,
+    map::*, map_search::*, mapper_stats::*, match_compare::*, medal_recent::*, medals_common::*,
+    medals_list::*, medals_missing::*, most_played::*, most_played_common::*, nochoke::*,
+    osekai_medal_count::*, osekai_medal_rarity::*, osustats_globals::*, osustats_list::*,
+    osutracker_countrytop::*, osutracker_mappers::*, osutracker_maps::*, osutracker_mapsets::*,
+    osutracker_mods::*, player_snipe_list::*, profile::*, ranking::*, ranking_countries::*,
+    recent_list::*, scores::*, sniped_difference::*, top::*, top_if::*, top_old_compare::*,
 };
 
+#[cfg(feature = "server")]
+pub use self::web::*;
+
+mod audit_log;
 mod badges;
 mod command_count;
 mod common;
@@ -36,6 +41,7 @@ mod country_snipe_list;
 mod leaderboard;
 mod map;
 mod map_search;
+mod mapper_stats;
 mod match_compare;
 mod medal_recent;
 mod medals_common;
@@ -62,10 +68,15 @@ mod scores;
 mod sniped_difference;
 mod top;
 mod top_if;
+mod top_old_compare;
+
+#[cfg(feature = "server")]
+mod web;
 
 pub mod components;
 
 pub enum PaginationKind {
+    AuditLog(Box<AuditLogPagination>),
     Badge(Box<BadgePagination>),
     CommandCount(Box<CommandCountPagination>),
     Common(Box<CommonPagination>),
@@ -73,6 +84,7 @@ pub enum PaginationKind {
     Leaderboard(Box<LeaderboardPagination>),
     Map(Box<MapPagination>),
     MapSearch(Box<MapSearchPagination>),
+    MapperStats(Box<MapperStatsPagination>),
     MatchCompare(Box<MatchComparePagination>),
     MedalCount(Box<MedalCountPagination>),
     MedalRarity(Box<MedalRarityPagination>),
@@ -100,12 +112,14 @@ pub enum PaginationKind {
     Top(Box<TopPagination>),
     TopCondensed(Box<TopCondensedPagination>),
     TopIf(Box<TopIfPagination>),
+    TopOldCompare(Box<TopOldComparePagination>),
     TopSingle(Box<TopSinglePagination>),
 }
 
 impl PaginationKind {
     async fn build_page(&mut self, ctx: &Context, pages: &Pages) -> Result<Embed> {
         match self {
+            Self::AuditLog(kind) => Ok(kind.build_page(pages)),
             Self::Badge(kind) => kind.build_page(ctx, pages).await,
             Self::CommandCount(kind) => Ok(kind.build_page(pages)),
             Self::Common(kind) => Ok(kind.build_page(pages)),
@@ -113,6 +127,7 @@ impl PaginationKind {
             Self::Leaderboard(kind) => kind.build_page(ctx, pages).await,
             Self::Map(kind) => kind.build_page(ctx, pages).await,
             Self::MapSearch(kind) => kind.build_page(ctx, pages).await,
+            Self::MapperStats(kind) => kind.build_page(ctx, pages).await,
             Self::MatchCompare(kind) => Ok(kind.build_page(pages)),
             Self::MedalCount(kind) => Ok(kind.build_page(pages)),
             Self::MedalRarity(kind) => Ok(kind.build_page(pages)),
@@ -140,9 +155,22 @@ impl PaginationKind {
             Self::Top(kind) => Ok(kind.build_page(ctx, pages).await),
             Self::TopCondensed(kind) => Ok(kind.build_page(ctx, pages).await),
             Self::TopIf(kind) => Ok(kind.build_page(pages).await),
+            Self::TopOldCompare(kind) => Ok(kind.build_page(pages)),
             Self::TopSingle(kind) => kind.build_page(ctx, pages).await,
         }
     }
+
+    /// The full result set for paginations that can be viewed on the website
+    #[cfg(feature = "server")]
+    fn web_table(&self) -> Option<WebTable> {
+        match self {
+            Self::Leaderboard(kind) => Some(kind.web_table()),
+            Self::OsuStatsGlobals(kind) => Some(kind.web_table()),
+            Self::Top(kind) => Some(kind.web_table()),
+            Self::TopCondensed(kind) => Some(kind.web_table()),
+            _ => None,
+        }
+    }
 }
 
 pub struct Pagination {
@@ -152,6 +180,8 @@ pub struct Pagination {
     kind: PaginationKind,
     component_kind: ComponentKind,
     tx: Sender<()>,
+    #[cfg(feature = "server")]
+    web_url: Option<String>,
 }
 
 impl Pagination {
@@ -175,7 +205,25 @@ impl Pagination {
             .await
             .wrap_err("failed to build page")?;
 
-        let components = pages.components(component_kind);
+        #[allow(unused_mut)]
+        let mut components = pages.components(component_kind);
+
+        #[cfg(feature = "server")]
+        let web_link = if pages.last_index > 0 {
+            kind.web_table().map(|table| {
+                let (token, expires_at) = ctx.web_tables.insert(table);
+                let url = &crate::core::BotConfig::get().server.external_url;
+
+                (format!("{url}/tables/{token}"), expires_at)
+            })
+        } else {
+            None
+        };
+
+        #[cfg(feature = "server")]
+        if let Some((ref url, _)) = web_link {
+            components.push(web_link_row(url));
+        }
 
         let mut builder = MessageBuilder::new().embed(embed).components(components);
 
@@ -205,8 +253,18 @@ impl Pagination {
         let channel = response.channel_id;
         let msg = response.id;
 
+        // The link to the website stays after the pagination expires
+        // until the table itself expires
+        #[cfg(feature = "server")]
+        let remaining_components = web_link
+            .as_ref()
+            .map(|(url, expires_at)| (vec![web_link_row(url)], *expires_at));
+
+        #[cfg(not(feature = "server"))]
+        let remaining_components = None;
+
         let (tx, rx) = watch::channel(());
-        Self::spawn_timeout(Arc::clone(&ctx), rx, msg, channel);
+        Self::spawn_timeout(Arc::clone(&ctx), rx, msg, channel, remaining_components);
 
         let pagination = Pagination {
             author: orig.user_id()?,
@@ -215,9 +273,12 @@ impl Pagination {
             kind,
             pages,
             tx,
+            #[cfg(feature = "server")]
+            web_url: web_link.map(|(url, _)| url),
         };
 
         ctx.paginations.own(msg).await.insert(pagination);
+        ctx.stats.paginations.inc();
 
         Ok(())
     }
@@ -236,7 +297,13 @@ impl Pagination {
             .await
             .wrap_err("failed to build page")?;
 
-        let components = self.pages.components(self.component_kind);
+        #[allow(unused_mut)]
+        let mut components = self.pages.components(self.component_kind);
+
+        #[cfg(feature = "server")]
+        if let Some(ref url) = self.web_url {
+            components.push(web_link_row(url));
+        }
 
         Ok(MessageBuilder::new().embed(embed).components(components))
     }
@@ -250,6 +317,7 @@ impl Pagination {
         mut rx: Receiver<()>,
         msg: Id<MessageMarker>,
         channel: Id<ChannelMarker>,
+        remaining_components: Option<(Vec<Component>, OffsetDateTime)>,
     ) {
         static MINUTE: Duration = Duration::from_secs(60);
 
@@ -267,12 +335,41 @@ impl Pagination {
                         let pagination_active = ctx.paginations.lock(&msg).await.remove().is_some();
                         let msg_available = ctx.remove_msg(msg);
 
-                        if pagination_active && msg_available {
-                            let builder = MessageBuilder::new().components(Vec::new());
+                        if pagination_active {
+                            ctx.stats.paginations.dec();
+                        }
+
+                        if !(pagination_active && msg_available) {
+                            return;
+                        }
+
+                        let (components, expires_at) = match remaining_components {
+                            Some((components, expires_at)) => (components, Some(expires_at)),
+                            None => (Vec::new(), None),
+                        };
+
+                        let builder = MessageBuilder::new().components(components);
+
+                        if let Err(err) = (msg, channel).update(&ctx, &builder).await {
+                            let report = Report::new(err).wrap_err("failed to remove components");
+                            warn!("{report:?}");
 
-                            if let Err(err) = (msg, channel).update(&ctx, &builder).await {
-                                let report = Report::new(err).wrap_err("failed to remove components");
-                                warn!("{report:?}");
+                            return;
+                        }
+
+                        if let Some(expires_at) = expires_at {
+                            ctx.store_msg(msg);
+
+                            let until_expired = expires_at - OffsetDateTime::now_utc();
+                            sleep(until_expired.try_into().unwrap_or_default()).await;
+
+                            if ctx.remove_msg(msg) {
+                                let builder = MessageBuilder::new().components(Vec::new());
+
+                                if let Err(err) = (msg, channel).update(&ctx, &builder).await {
+                                    let report = Report::new(err).wrap_err("failed to remove web link");
+                                    warn!("{report:?}");
+                                }
                             }
                         }
 
@@ -548,3 +645,19 @@ enum ComponentKind {
     MapSearch,
     Profile,
 }
+
+#[cfg(feature = "server")]
+fn web_link_row(url: &str) -> Component {
+    let button = Button {
+        custom_id: None,
+        disabled: false,
+        emoji: None,
+        label: Some("Open in browser".to_owned()),
+        style: ButtonStyle::Link,
+        url: Some(url.to_owned()),
+    };
+
+    Component::ActionRow(ActionRow {
+        components: vec![Component::Button(button)],
+    })
+}
@@ -11,8 +11,14 @@ use crate::{
     Context,
 };
 
+#[cfg(feature = "server")]
+use crate::util::constants::OSU_BASE;
+
 use super::Pages;
 
+#[cfg(feature = "server")]
+use super::{score_cells, WebCell, WebTable};
+
 #[pagination(per_page = 5, total = "total")]
 pub struct OsuStatsGlobalsPagination {
     user: User,
@@ -48,4 +54,42 @@ impl OsuStatsGlobalsPagination {
 
         Ok(embed_fut.await.build())
     }
+
+    /// Only contains the scores that were retrieved so far
+    #[cfg(feature = "server")]
+    pub fn web_table(&self) -> WebTable {
+        let rows = self
+            .scores
+            .iter()
+            .map(|(i, score)| {
+                let map = &score.map;
+
+                let mut row = Vec::with_capacity(11);
+                row.push(WebCell::number((i + 1).to_string(), (i + 1) as f64));
+                row.push(WebCell::number(score.position.to_string(), score.position));
+                row.push(
+                    WebCell::text(format!("{} - {} [{}]", map.artist, map.title, map.version))
+                        .url(format!("{OSU_BASE}b/{}", map.beatmap_id)),
+                );
+                row.extend(score_cells(score, map.mode));
+                row.push(WebCell::date(score.date));
+
+                row
+            })
+            .collect();
+
+        WebTable {
+            title: format!(
+                "Global leaderboard scores of {} ({} out of {})",
+                self.user.username,
+                self.scores.len(),
+                self.total
+            ),
+            columns: &[
+                "#", "Rank", "Map", "Mods", "Score", "PP", "Accuracy", "Combo", "Misses", "Grade",
+                "Date",
+            ],
+            rows,
+        }
+    }
 }
@@ -7,7 +7,7 @@ use twilight_model::channel::embed::Embed;
 
 use crate::{
     commands::osu::MapsetEntry,
-    core::Context,
+    core::{Context, OsuFutureExt},
     custom_client::OsuTrackerMapsetEntry,
     embeds::{EmbedData, OsuTrackerMapsetsEmbed},
     util::hasher::IntHasher,
@@ -41,6 +41,7 @@ impl OsuTrackerMapsetsPagination {
                     let mapset = ctx
                         .osu()
                         .beatmapset(mapset_id)
+                        .observe(&ctx.stats, "beatmapset")
                         .await
                         .wrap_err("failed to request beatmapset")?;
 
@@ -7,6 +7,7 @@ use rosu_v2::prelude::{Beatmap, User};
 use twilight_model::channel::embed::Embed;
 
 use crate::{
+    core::OsuFutureExt,
     custom_client::{SnipeScore, SnipeScoreParams},
     embeds::{EmbedData, PlayerSnipeListEmbed},
     util::hasher::IntHasher,
@@ -75,7 +76,13 @@ impl PlayerSnipeListPagination {
                 let map_id = map_id as u32;
 
                 if !maps.contains_key(&map_id) {
-                    match ctx.osu().beatmap().map_id(map_id).await {
+                    match ctx
+                        .osu()
+                        .beatmap()
+                        .map_id(map_id)
+                        .observe(&ctx.stats, "beatmap")
+                        .await
+                    {
                         Ok(map) => {
                             maps.insert(map_id, map);
                         }
@@ -7,6 +7,7 @@ use twilight_model::{channel::embed::Embed, id::Id};
 
 use crate::{
     commands::osu::UserValue,
+    core::OsuFutureExt,
     embeds::{EmbedData, RankingEmbed, RankingEntry, RankingKindData},
     Context,
 };
@@ -122,6 +123,7 @@ impl RankingPagination {
                         .performance_rankings(*mode)
                         .country(country.as_str())
                         .page(page)
+                        .observe(&ctx.stats, "pp_ranking")
                         .await
                         .wrap_err("failed to get ranking page")?;
 
@@ -132,6 +134,7 @@ impl RankingPagination {
                         .osu()
                         .performance_rankings(*mode)
                         .page(page)
+                        .observe(&ctx.stats, "pp_ranking")
                         .await
                         .wrap_err("failed to get ranking page")?;
 
@@ -142,6 +145,7 @@ impl RankingPagination {
                         .osu()
                         .score_rankings(*mode)
                         .page(page)
+                        .observe(&ctx.stats, "score_ranking")
                         .await
                         .wrap_err("failed to get ranking page")?;
 
@@ -5,6 +5,7 @@ use std::collections::BTreeMap;
 use twilight_model::channel::embed::Embed;
 
 use crate::{
+    core::OsuFutureExt,
     embeds::{EmbedData, RankingCountriesEmbed},
     Context,
 };
@@ -45,6 +46,7 @@ impl RankingCountriesPagination {
                 .osu()
                 .country_rankings(self.mode)
                 .page(page as u32)
+                .observe(&ctx.stats, "country_ranking")
                 .await
                 .wrap_err("failed to get country rankings")?;
 
@@ -8,13 +8,19 @@ use crate::{
     commands::osu::TopScoreOrder,
     core::Context,
     custom_client::OsuTrackerMapsetEntry,
-    database::MinimizedPp,
+    database::{MinimizedPp, ScoreKind},
     embeds::{CondensedTopEmbed, EmbedData, TopEmbed, TopSingleEmbed},
     util::hasher::IntHasher,
 };
 
+#[cfg(feature = "server")]
+use crate::util::constants::OSU_BASE;
+
 use super::Pages;
 
+#[cfg(feature = "server")]
+use super::{score_cells, WebCell, WebTable};
+
 #[pagination(per_page = 5, entries = "scores")]
 pub struct TopPagination {
     user: User,
@@ -31,6 +37,11 @@ impl TopPagination {
 
         embed_fut.await.build()
     }
+
+    #[cfg(feature = "server")]
+    pub fn web_table(&self) -> WebTable {
+        top_table(&self.user, &self.scores)
+    }
 }
 
 #[pagination(per_page = 10, entries = "scores")]
@@ -50,6 +61,11 @@ impl TopCondensedPagination {
 
         embed_fut.await.build()
     }
+
+    #[cfg(feature = "server")]
+    pub fn web_table(&self) -> WebTable {
+        top_table(&self.user, &self.scores)
+    }
 }
 
 #[pagination(per_page = 1, entries = "scores")]
@@ -57,15 +73,58 @@ pub struct TopSinglePagination {
     user: User,
     scores: Vec<(usize, Score)>,
     minimized_pp: MinimizedPp,
+    score_kind: ScoreKind,
 }
 
 impl TopSinglePagination {
     pub async fn build_page(&mut self, ctx: &Context, pages: &Pages) -> Result<Embed> {
         let (idx, score) = self.scores.get(pages.index).unwrap();
 
-        TopSingleEmbed::new(&self.user, score, Some(*idx), None, self.minimized_pp, ctx)
+        let embed_fut = TopSingleEmbed::new(
+            &self.user,
+            score,
+            Some(*idx),
+            None,
+            self.minimized_pp,
+            self.score_kind,
+            ctx,
+        );
+
+        embed_fut
             .await
             .map(TopSingleEmbed::into_minimized)
             .wrap_err("failed to create embed data")
     }
 }
+
+#[cfg(feature = "server")]
+fn top_table(user: &User, scores: &[(usize, Score)]) -> WebTable {
+    let rows = scores
+        .iter()
+        .map(|(idx, score)| {
+            let (map, map_url) = match (&score.map, &score.mapset) {
+                (Some(map), Some(mapset)) => (
+                    format!("{} - {} [{}]", mapset.artist, mapset.title, map.version),
+                    format!("{OSU_BASE}b/{}", map.map_id),
+                ),
+                _ => ("<unknown map>".to_owned(), OSU_BASE.to_owned()),
+            };
+
+            let mut row = Vec::with_capacity(10);
+            row.push(WebCell::number((idx + 1).to_string(), (idx + 1) as f64));
+            row.push(WebCell::text(map).url(map_url));
+            row.extend(score_cells(score, score.mode));
+            row.push(WebCell::date(score.ended_at));
+
+            row
+        })
+        .collect();
+
+    WebTable {
+        title: format!("Top plays of {}", user.username),
+        columns: &[
+            "#", "Map", "Mods", "Score", "PP", "Accuracy", "Combo", "Misses", "Grade", "Date",
+        ],
+        rows,
+    }
+}
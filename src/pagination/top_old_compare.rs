@@ -0,0 +1,34 @@
+use command_macros::pagination;
+use rosu_v2::prelude::User;
+use twilight_model::channel::embed::Embed;
+
+use crate::{
+    commands::osu::{TopOldCompareEntry, TopOldCompareSummary},
+    embeds::{EmbedData, TopOldCompareEmbed},
+};
+
+use super::Pages;
+
+#[pagination(per_page = 10, entries = "entries")]
+pub struct TopOldComparePagination {
+    user: User,
+    entries: Vec<TopOldCompareEntry>,
+    summary: TopOldCompareSummary,
+    pre_pp: f32,
+    post_pp: f32,
+}
+
+impl TopOldComparePagination {
+    pub fn build_page(&mut self, pages: &Pages) -> Embed {
+        let embed = TopOldCompareEmbed::new(
+            &self.user,
+            &self.entries,
+            &self.summary,
+            self.pre_pp,
+            self.post_pp,
+            pages,
+        );
+
+        embed.build()
+    }
+}
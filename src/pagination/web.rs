@@ -0,0 +1,98 @@
+use rosu_v2::prelude::{GameMode, Grade};
+use time::OffsetDateTime;
+
+use crate::util::{
+    datetime::DATETIME_FORMAT,
+    numbers::{round, with_comma_float, with_comma_int},
+    ScoreExt,
+};
+
+/// Full result set of a pagination so it can be viewed on the website
+pub struct WebTable {
+    pub title: String,
+    pub columns: &'static [&'static str],
+    pub rows: Vec<Vec<WebCell>>,
+}
+
+pub struct WebCell {
+    pub text: String,
+    /// Value to sort the column by, sorts by text if `None`
+    pub value: Option<f64>,
+    pub url: Option<String>,
+}
+
+impl WebCell {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            value: None,
+            url: None,
+        }
+    }
+
+    pub fn number(text: impl Into<String>, value: impl Into<f64>) -> Self {
+        Self {
+            text: text.into(),
+            value: Some(value.into()),
+            url: None,
+        }
+    }
+
+    pub fn date(date: OffsetDateTime) -> Self {
+        let text = date.format(DATETIME_FORMAT).unwrap_or_default();
+
+        Self::number(text, date.unix_timestamp() as f64)
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+
+        self
+    }
+}
+
+/// Cells for the columns "Mods", "Score", "PP", "Accuracy", "Combo", "Misses", and "Grade"
+pub fn score_cells(score: &dyn ScoreExt, mode: GameMode) -> [WebCell; 7] {
+    let mods = score.mods();
+    let pp = score.pp().unwrap_or(0.0);
+    let acc = score.acc(mode);
+    let grade = score.grade(mode);
+
+    [
+        WebCell::number(mods.to_string(), mods.bits()),
+        WebCell::number(with_comma_int(score.score()).to_string(), score.score()),
+        WebCell::number(with_comma_float(pp).to_string(), pp),
+        WebCell::number(format!("{}%", round(acc)), acc),
+        WebCell::number(format!("{}x", score.max_combo()), score.max_combo()),
+        WebCell::number(score.count_miss().to_string(), score.count_miss()),
+        WebCell::number(grade_str(grade), grade_value(grade)),
+    ]
+}
+
+fn grade_str(grade: Grade) -> &'static str {
+    match grade {
+        Grade::XH => "SSH",
+        Grade::X => "SS",
+        Grade::SH => "SH",
+        Grade::S => "S",
+        Grade::A => "A",
+        Grade::B => "B",
+        Grade::C => "C",
+        Grade::D => "D",
+        Grade::F => "F",
+    }
+}
+
+fn grade_value(grade: Grade) -> u8 {
+    match grade {
+        Grade::XH => 8,
+        Grade::X => 7,
+        Grade::SH => 6,
+        Grade::S => 5,
+        Grade::A => 4,
+        Grade::B => 3,
+        Grade::C => 2,
+        Grade::D => 1,
+        Grade::F => 0,
+    }
+}
@@ -1,18 +1,19 @@
 use eyre::{Result, WrapErr};
 use rosu_pp::{Beatmap, BeatmapExt as rosu_v2BeatmapExt, DifficultyAttributes, ScoreState};
-use rosu_v2::model::GameMods;
+use rosu_v2::model::{GameMode, GameMods};
 
 use crate::{
     core::Context,
+    custom_client::LazerScore,
     util::{osu::prepare_beatmap_file, ScoreExt},
 };
 
-enum ScoreKind<'s> {
+enum PpInput<'s> {
     Mods(GameMods),
     Score(&'s dyn ScoreExt),
 }
 
-impl ScoreKind<'_> {
+impl PpInput<'_> {
     fn mods(&self) -> u32 {
         match self {
             Self::Mods(mods) => mods.bits(),
@@ -42,7 +43,7 @@ pub struct PpCalculator {
 
 pub struct PpCalculatorPrepared<'m, 's> {
     map: &'m Beatmap,
-    score: ScoreKind<'s>,
+    score: PpInput<'s>,
     difficulty: Option<DifficultyAttributes>,
 }
 
@@ -62,7 +63,7 @@ impl PpCalculator {
     pub fn mods<'m, 's>(&'m self, mods: GameMods) -> PpCalculatorPrepared<'m, 's> {
         PpCalculatorPrepared {
             map: &self.map,
-            score: ScoreKind::Mods(mods),
+            score: PpInput::Mods(mods),
             difficulty: None,
         }
     }
@@ -70,7 +71,7 @@ impl PpCalculator {
     pub fn score<'m, 's>(&'m self, score: &'s dyn ScoreExt) -> PpCalculatorPrepared<'m, 's> {
         PpCalculatorPrepared {
             map: &self.map,
-            score: ScoreKind::Score(score),
+            score: PpInput::Score(score),
             difficulty: None,
         }
     }
@@ -120,3 +121,20 @@ impl<'m, 's> PpCalculatorPrepared<'m, 's> {
             .pp()
     }
 }
+
+/// Performance of a lazer score based on its hit results.
+///
+/// Returns `None` if the score's mods can not be represented by stable mods
+/// or if the score was set on a convert.
+pub fn lazer_pp(map: &Beatmap, score: &LazerScore, mode: GameMode) -> Option<f64> {
+    if map.mode as u8 != mode as u8 {
+        return None;
+    }
+
+    let mods = score.stable_mods()?.bits();
+    let state = score.state(mode)?;
+
+    let pp = map.pp().mods(mods).state(state).calculate().pp();
+
+    Some(pp)
+}
@@ -0,0 +1,579 @@
+use std::net::IpAddr;
+
+use eyre::{Result, WrapErr};
+use hashbrown::HashMap;
+use hyper::{
+    header::{AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER},
+    Body, Request, Response, StatusCode,
+};
+use parking_lot::Mutex;
+use rosu_v2::prelude::GameMode;
+use routerify::ext::RequestExt;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use time::OffsetDateTime;
+use twilight_model::id::Id;
+
+use crate::{
+    commands::osu::UserValue,
+    core::buckets::{MemberRatelimit, Ratelimit},
+    database::UserStatsColumn,
+    games::hl::HlVersion,
+    util::hasher::IntHasher,
+    Context,
+};
+
+use super::{Context_, HandlerResult};
+
+/// Ratelimits of API keys are specified per minute
+const RATELIMIT_TIMESPAN: i64 = 60;
+
+/// Amount of requests with an invalid API key per IP and minute
+/// before further invalid requests of that IP are ratelimited
+const FAILED_AUTH_LIMIT: i32 = 10;
+
+/// Ratelimit entries are pruned at most once within this many seconds
+const PRUNE_INTERVAL: i64 = 10 * 60;
+
+const DEFAULT_LIMIT: usize = 50;
+const MAX_LIMIT: usize = 100;
+
+/// Ratelimit state of each API key and of IPs that failed to authenticate
+#[derive(Default)]
+pub struct ApiRatelimits {
+    keys: Mutex<HashMap<u32, MemberRatelimit, IntHasher>>,
+    failed_auth: Mutex<HashMap<IpAddr, MemberRatelimit>>,
+    /// Unix timestamp of the last pruning
+    last_prune: Mutex<i64>,
+}
+
+impl ApiRatelimits {
+    /// Remove entries whose last request is longer ago than the ratelimit timespan
+    fn prune(&self) {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+
+        {
+            let mut last_prune = self.last_prune.lock();
+
+            if now < *last_prune + PRUNE_INTERVAL {
+                return;
+            }
+
+            *last_prune = now;
+        }
+
+        let is_active =
+            |ratelimit: &MemberRatelimit| ratelimit.last_time + RATELIMIT_TIMESPAN > now;
+
+        self.keys.lock().retain(|_, ratelimit| is_active(ratelimit));
+        self.failed_auth
+            .lock()
+            .retain(|_, ratelimit| is_active(ratelimit));
+    }
+}
+
+/// Validates the request's API key and applies the key's ratelimit.
+///
+/// Returns `Some` response if the request may not be processed.
+async fn authenticate(req: &Request<Body>) -> Result<Option<Response<Body>>> {
+    let api_key = req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|header| header.to_str().ok())
+        .and_then(|header| header.strip_prefix("Bearer "));
+
+    let api_key = match api_key {
+        Some(api_key) => api_key.trim(),
+        None => {
+            let content = "missing api key in authorization header";
+
+            return error_response(StatusCode::UNAUTHORIZED, content).map(Some);
+        }
+    };
+
+    let ratelimits: &ApiRatelimits = req.data().unwrap();
+    ratelimits.prune();
+
+    let Context_(ctx) = req.data().unwrap();
+
+    let key_opt = ctx
+        .psql()
+        .get_api_key(api_key)
+        .await
+        .wrap_err("failed to get api key")?;
+
+    let key = match key_opt {
+        Some(key) => key,
+        None => {
+            let failed_auth_limit = Ratelimit {
+                delay: 0,
+                limit: Some((RATELIMIT_TIMESPAN, FAILED_AUTH_LIMIT)),
+            };
+
+            // Only requests with invalid keys count towards the IP's limit so that
+            // valid keys still work when all requests come through the same proxy
+            let cooldown = ratelimits
+                .failed_auth
+                .lock()
+                .entry(req.remote_addr().ip())
+                .or_default()
+                .take(&failed_auth_limit);
+
+            if cooldown > 0 {
+                return ratelimited_response(cooldown).map(Some);
+            }
+
+            return error_response(StatusCode::UNAUTHORIZED, "invalid api key").map(Some);
+        }
+    };
+
+    let ratelimit = Ratelimit {
+        delay: 0,
+        limit: Some((RATELIMIT_TIMESPAN, key.ratelimit as i32)),
+    };
+
+    let cooldown = ratelimits
+        .keys
+        .lock()
+        .entry(key.key_id)
+        .or_default()
+        .take(&ratelimit);
+
+    if cooldown > 0 {
+        return ratelimited_response(cooldown).map(Some);
+    }
+
+    Ok(None)
+}
+
+fn ratelimited_response(cooldown: i64) -> HandlerResult {
+    let body = json!({
+        "error": "ratelimited",
+        "retry_after": cooldown,
+    });
+
+    let response = Response::builder()
+        .status(StatusCode::TOO_MANY_REQUESTS)
+        .header(CONTENT_TYPE, "application/json")
+        .header(RETRY_AFTER, cooldown)
+        .body(Body::from(body.to_string()))?;
+
+    Ok(response)
+}
+
+fn json_response(value: &Value) -> HandlerResult {
+    let response = Response::builder()
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(value.to_string()))?;
+
+    Ok(response)
+}
+
+fn error_response(status: StatusCode, error: &str) -> HandlerResult {
+    let body = json!({ "error": error });
+
+    let response = Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))?;
+
+    Ok(response)
+}
+
+fn parse_query<'de, T: Deserialize<'de>>(req: &'de Request<Body>) -> Option<T> {
+    serde_urlencoded::from_str(req.uri().query().unwrap_or_default()).ok()
+}
+
+fn parse_mode(mode: Option<&str>) -> Option<GameMode> {
+    let mode = match mode {
+        None | Some("0" | "osu") => GameMode::Osu,
+        Some("1" | "taiko") => GameMode::Taiko,
+        Some("2" | "fruits" | "catch" | "ctb") => GameMode::Catch,
+        Some("3" | "mania") => GameMode::Mania,
+        Some(_) => return None,
+    };
+
+    Some(mode)
+}
+
+fn mode_str(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "osu",
+        GameMode::Taiko => "taiko",
+        GameMode::Catch => "fruits",
+        GameMode::Mania => "mania",
+    }
+}
+
+#[derive(Deserialize)]
+struct PageQuery {
+    limit: Option<usize>,
+    offset: Option<usize>,
+}
+
+impl PageQuery {
+    fn range(&self, len: usize) -> (usize, usize) {
+        let limit = self.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+        let start = self.offset.unwrap_or(0).min(len);
+
+        (start, len.min(start + limit))
+    }
+}
+
+#[cfg(feature = "osutracking")]
+pub async fn tracking_handler(req: Request<Body>) -> HandlerResult {
+    if let Some(response) = authenticate(&req).await? {
+        return Ok(response);
+    }
+
+    let user_id: u32 = match req.param("user_id").map(|id| id.parse()) {
+        Some(Ok(id)) => id,
+        Some(Err(_)) | None => {
+            return error_response(StatusCode::BAD_REQUEST, "the user id must be numeric")
+        }
+    };
+
+    let Context_(ctx) = req.data().unwrap();
+    let mut modes = Vec::new();
+
+    for mode in [
+        GameMode::Osu,
+        GameMode::Taiko,
+        GameMode::Catch,
+        GameMode::Mania,
+    ] {
+        if let Some((last_top_score, channels)) = ctx.tracking().get_tracked(user_id, mode).await {
+            modes.push(json!({
+                "mode": mode_str(mode),
+                "last_top_score": last_top_score.unix_timestamp(),
+                "channels": channels.len(),
+            }));
+        }
+    }
+
+    let body = json!({
+        "user_id": user_id,
+        "tracked": !modes.is_empty(),
+        "modes": modes,
+    });
+
+    json_response(&body)
+}
+
+#[derive(Deserialize)]
+struct ApproxRankQuery {
+    pp: f32,
+    mode: Option<String>,
+}
+
+pub async fn approx_rank_handler(req: Request<Body>) -> HandlerResult {
+    if let Some(response) = authenticate(&req).await? {
+        return Ok(response);
+    }
+
+    let query = match parse_query::<ApproxRankQuery>(&req) {
+        Some(query) if query.pp.is_finite() && query.pp >= 0.0 => query,
+        _ => {
+            let content = "the query must contain a non-negative `pp` value";
+
+            return error_response(StatusCode::BAD_REQUEST, content);
+        }
+    };
+
+    let mode = match parse_mode(query.mode.as_deref()) {
+        Some(mode) => mode,
+        None => return error_response(StatusCode::BAD_REQUEST, "invalid mode"),
+    };
+
+    let Context_(ctx) = req.data().unwrap();
+
+    let rank = ctx
+        .psql()
+        .approx_rank_from_pp(query.pp, mode)
+        .await
+        .wrap_err("failed to approximate rank")?;
+
+    let body = json!({
+        "mode": mode_str(mode),
+        "pp": query.pp,
+        "rank": rank,
+    });
+
+    json_response(&body)
+}
+
+#[derive(Deserialize)]
+struct ApproxPpQuery {
+    rank: u32,
+    mode: Option<String>,
+}
+
+pub async fn approx_pp_handler(req: Request<Body>) -> HandlerResult {
+    if let Some(response) = authenticate(&req).await? {
+        return Ok(response);
+    }
+
+    let query = match parse_query::<ApproxPpQuery>(&req) {
+        Some(query) if query.rank > 0 => query,
+        _ => {
+            let content = "the query must contain a positive `rank` value";
+
+            return error_response(StatusCode::BAD_REQUEST, content);
+        }
+    };
+
+    let mode = match parse_mode(query.mode.as_deref()) {
+        Some(mode) => mode,
+        None => return error_response(StatusCode::BAD_REQUEST, "invalid mode"),
+    };
+
+    let Context_(ctx) = req.data().unwrap();
+
+    let pp = ctx
+        .psql()
+        .approx_pp_from_rank(query.rank, mode)
+        .await
+        .wrap_err("failed to approximate pp")?;
+
+    let body = json!({
+        "mode": mode_str(mode),
+        "pp": pp,
+        "rank": query.rank,
+    });
+
+    json_response(&body)
+}
+
+#[derive(Deserialize)]
+struct LeaderboardQuery {
+    mode: Option<String>,
+    limit: Option<usize>,
+    offset: Option<usize>,
+}
+
+pub async fn guild_leaderboard_handler(req: Request<Body>) -> HandlerResult {
+    if let Some(response) = authenticate(&req).await? {
+        return Ok(response);
+    }
+
+    let guild_id = match req.param("guild_id").map(|id| id.parse()) {
+        Some(Ok(id)) => match Id::new_checked(id) {
+            Some(guild_id) => guild_id,
+            None => return error_response(StatusCode::BAD_REQUEST, "invalid guild id"),
+        },
+        Some(Err(_)) | None => {
+            return error_response(StatusCode::BAD_REQUEST, "the guild id must be numeric")
+        }
+    };
+
+    let query = match parse_query::<LeaderboardQuery>(&req) {
+        Some(query) => query,
+        None => return error_response(StatusCode::BAD_REQUEST, "invalid query"),
+    };
+
+    let mode = match parse_mode(query.mode.as_deref()) {
+        Some(mode) => mode,
+        None => return error_response(StatusCode::BAD_REQUEST, "invalid mode"),
+    };
+
+    let column = match req.param("kind").and_then(|kind| stats_column(kind, mode)) {
+        Some(column) => column,
+        None => return error_response(StatusCode::BAD_REQUEST, "invalid leaderboard kind"),
+    };
+
+    let Context_(ctx) = req.data().unwrap();
+
+    // Don't create configs for guilds the bot is not in
+    if ctx.cache.guild(guild_id, |_| ()).is_err() {
+        return error_response(StatusCode::NOT_FOUND, "unknown guild");
+    }
+
+    if !ctx.guild_public_api(guild_id).await {
+        let content = "the guild did not enable its leaderboards for the api";
+
+        return error_response(StatusCode::FORBIDDEN, content);
+    }
+
+    let members: Vec<_> = ctx.cache.members(guild_id, |id| id.get() as i64);
+
+    let leaderboard = ctx
+        .psql()
+        .get_osu_users_stats(column, &members)
+        .await
+        .wrap_err("failed to get osu users stats")?;
+
+    let page = PageQuery {
+        limit: query.limit,
+        offset: query.offset,
+    };
+
+    let (start, end) = page.range(leaderboard.len());
+
+    let entries: Vec<_> = leaderboard
+        .iter()
+        .skip(start)
+        .take(end - start)
+        .map(|(idx, entry)| {
+            json!({
+                "position": idx + 1,
+                "username": entry.name.as_str(),
+                "country_code": entry.country.as_ref().map(|code| code.as_str()),
+                "value": user_value_json(&entry.value),
+            })
+        })
+        .collect();
+
+    let body = json!({
+        "guild_id": guild_id,
+        "mode": mode_str(mode),
+        "total": leaderboard.len(),
+        "entries": entries,
+    });
+
+    json_response(&body)
+}
+
+/// Kinds use the same names as the options of the `serverleaderboard` command
+fn stats_column(kind: &str, mode: GameMode) -> Option<UserStatsColumn> {
+    let column = match kind {
+        "badges" => UserStatsColumn::Badges,
+        "comments" => UserStatsColumn::Comments,
+        "followers" => UserStatsColumn::Followers,
+        "forum_posts" => UserStatsColumn::ForumPosts,
+        "graveyard_mapsets" => UserStatsColumn::GraveyardMapsets,
+        "join_date" => UserStatsColumn::JoinDate,
+        "loved_mapsets" => UserStatsColumn::LovedMapsets,
+        "mapping_followers" => UserStatsColumn::MappingFollowers,
+        "medals" => UserStatsColumn::Medals,
+        "namechanges" => UserStatsColumn::Usernames,
+        "played_maps" => UserStatsColumn::PlayedMaps,
+        "ranked_mapsets" => UserStatsColumn::RankedMapsets,
+        "acc" => UserStatsColumn::Accuracy { mode },
+        "avg_hits" => UserStatsColumn::AverageHits { mode },
+        "count_ssh" => UserStatsColumn::CountSsh { mode },
+        "count_ss" => UserStatsColumn::CountSs { mode },
+        "total_ss" => UserStatsColumn::TotalSs { mode },
+        "count_sh" => UserStatsColumn::CountSh { mode },
+        "count_s" => UserStatsColumn::CountS { mode },
+        "total_s" => UserStatsColumn::TotalS { mode },
+        "count_a" => UserStatsColumn::CountA { mode },
+        "country_rank" => UserStatsColumn::RankCountry { mode },
+        "global_firsts" => UserStatsColumn::ScoresFirst { mode },
+        "global_rank" => UserStatsColumn::RankGlobal { mode },
+        "level" => UserStatsColumn::Level { mode },
+        "max_combo" => UserStatsColumn::MaxCombo { mode },
+        "playcount" => UserStatsColumn::Playcount { mode },
+        "playtime" => UserStatsColumn::Playtime { mode },
+        "pp" => UserStatsColumn::Pp { mode },
+        "ranked_score" => UserStatsColumn::ScoreRanked { mode },
+        "replays" => UserStatsColumn::Replays { mode },
+        "total_hits" => UserStatsColumn::TotalHits { mode },
+        "total_score" => UserStatsColumn::ScoreTotal { mode },
+        _ => return None,
+    };
+
+    Some(column)
+}
+
+fn user_value_json(value: &UserValue) -> Value {
+    match *value {
+        UserValue::Accuracy(acc) => json!(acc),
+        UserValue::Amount(amount) => json!(amount),
+        UserValue::AmountWithNegative(amount) => json!(amount),
+        UserValue::Date(date) => json!(date.unix_timestamp()),
+        UserValue::Float(value) => json!(value),
+        UserValue::MapScore {
+            rank,
+            score,
+            acc,
+            mods,
+        } => json!({
+            "rank": rank,
+            "score": score,
+            "accuracy": acc,
+            "mods": mods.to_string(),
+        }),
+        UserValue::Playtime(seconds) => json!(seconds),
+        UserValue::PpF32(pp) => json!(pp),
+        UserValue::PpU32(pp) => json!(pp),
+        UserValue::Rank(rank) => json!(rank),
+    }
+}
+
+pub async fn bg_leaderboard_handler(req: Request<Body>) -> HandlerResult {
+    if let Some(response) = authenticate(&req).await? {
+        return Ok(response);
+    }
+
+    let page = match parse_query::<PageQuery>(&req) {
+        Some(page) => page,
+        None => return error_response(StatusCode::BAD_REQUEST, "invalid query"),
+    };
+
+    let Context_(ctx) = req.data().unwrap();
+
+    let scores = ctx
+        .psql()
+        .all_bggame_scores()
+        .await
+        .wrap_err("failed to get bggame scores")?;
+
+    json_response(&game_leaderboard(ctx, scores, &page))
+}
+
+pub async fn hl_leaderboard_handler(req: Request<Body>) -> HandlerResult {
+    if let Some(response) = authenticate(&req).await? {
+        return Ok(response);
+    }
+
+    let version = match req.param("version").map(String::as_str) {
+        Some("score_pp") => HlVersion::ScorePp,
+        Some("farm") => HlVersion::FarmMaps,
+        _ => {
+            let content = "the version must be either `score_pp` or `farm`";
+
+            return error_response(StatusCode::BAD_REQUEST, content);
+        }
+    };
+
+    let page = match parse_query::<PageQuery>(&req) {
+        Some(page) => page,
+        None => return error_response(StatusCode::BAD_REQUEST, "invalid query"),
+    };
+
+    let Context_(ctx) = req.data().unwrap();
+
+    let scores = ctx
+        .psql()
+        .get_higherlower_scores(version)
+        .await
+        .wrap_err("failed to get higherlower scores")?;
+
+    json_response(&game_leaderboard(ctx, scores, &page))
+}
+
+fn game_leaderboard(ctx: &Context, mut scores: Vec<(u64, u32)>, page: &PageQuery) -> Value {
+    scores.sort_unstable_by(|(_, a), (_, b)| b.cmp(a));
+    let (start, end) = page.range(scores.len());
+
+    let entries: Vec<_> = scores[start..end]
+        .iter()
+        .zip(start + 1..)
+        .map(|(&(user_id, score), position)| {
+            let name = Id::new_checked(user_id)
+                .and_then(|user| ctx.cache.user(user, |user| user.name.clone()).ok());
+
+            json!({
+                "position": position,
+                "discord_id": user_id.to_string(),
+                "discord_name": name,
+                "score": score,
+            })
+        })
+        .collect();
+
+    json!({
+        "total": scores.len(),
+        "entries": entries,
+    })
+}
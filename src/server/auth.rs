@@ -1,44 +1,37 @@
 use std::{
     future::Future,
     pin::Pin,
-    sync::atomic::{AtomicU8, Ordering},
-    task::{Context, Poll},
+    task::{Context as TaskContext, Poll},
     time::Duration,
 };
 
-use flexmap::std::StdMutexMap;
+use bb8_redis::redis::{self, AsyncCommands};
+use eyre::{Result, WrapErr};
 use futures::future::FutureExt;
+use hashbrown::HashMap;
+use parking_lot::Mutex;
 use rosu_v2::prelude::User;
 use tokio::{
     sync::oneshot::{self, Receiver, Sender},
     time::{self, Timeout},
 };
+use twilight_model::id::{marker::UserMarker, Id};
 
-use crate::{custom_client::TwitchUser, util::hasher::IntHasher};
+use crate::{core::BotConfig, custom_client::TwitchUser, database::OsuData, Context};
 
-const DEADLINE: Duration = Duration::from_secs(120);
+use super::random_token;
 
 pub enum AuthenticationStandbyError {
     Canceled,
     Timeout,
 }
 
+/// Pending authorizations are stored in redis so that they survive restarts.
+/// Commands waiting for an authorization are notified through the in-memory senders.
+#[derive(Default)]
 pub struct AuthenticationStandby {
-    // u8 is sufficient for 256 concurrent authorization awaitings within two minutes
-    current_state: AtomicU8,
-    osu: StdMutexMap<u8, Sender<User>, IntHasher>,
-    twitch: StdMutexMap<u8, Sender<TwitchUser>, IntHasher>,
-}
-
-impl Default for AuthenticationStandby {
-    #[inline]
-    fn default() -> Self {
-        Self {
-            current_state: AtomicU8::default(),
-            osu: StdMutexMap::with_shard_amount_and_hasher(4, IntHasher),
-            twitch: StdMutexMap::with_shard_amount_and_hasher(4, IntHasher),
-        }
-    }
+    osu: Mutex<HashMap<String, Sender<User>>>,
+    twitch: Mutex<HashMap<String, Sender<TwitchUser>>>,
 }
 
 impl AuthenticationStandby {
@@ -47,52 +40,179 @@ impl AuthenticationStandby {
     }
 
     /// Wait for an osu! username to be authenticated.
-    pub fn wait_for_osu(&self) -> WaitForOsuAuth {
+    pub async fn wait_for_osu(
+        &self,
+        ctx: &Context,
+        user: Id<UserMarker>,
+    ) -> Result<WaitForOsuAuth> {
+        let state = store_state(ctx, AuthKind::Osu, user).await?;
         let (tx, rx) = oneshot::channel();
-        let state = self.generate_state();
-        let fut = Box::pin(time::timeout(DEADLINE, rx));
-        self.osu.own(state).insert(tx);
+        let fut = Box::pin(time::timeout(deadline(), rx));
 
-        WaitForOsuAuth { state, fut }
-    }
+        let mut osu = self.osu.lock();
+        osu.retain(|_, tx| !tx.is_closed());
+        osu.insert(state.clone(), tx);
 
-    pub fn is_osu_empty(&self) -> bool {
-        self.osu.is_empty()
+        Ok(WaitForOsuAuth { state, fut })
     }
 
     /// Wait for a twitch channel name to be authenticated.
-    pub fn wait_for_twitch(&self) -> WaitForTwitchAuth {
+    pub async fn wait_for_twitch(
+        &self,
+        ctx: &Context,
+        user: Id<UserMarker>,
+    ) -> Result<WaitForTwitchAuth> {
+        let state = store_state(ctx, AuthKind::Twitch, user).await?;
         let (tx, rx) = oneshot::channel();
-        let state = self.generate_state();
-        let fut = Box::pin(time::timeout(DEADLINE, rx));
-        self.twitch.own(state).insert(tx);
+        let fut = Box::pin(time::timeout(deadline(), rx));
 
-        WaitForTwitchAuth { state, fut }
+        let mut twitch = self.twitch.lock();
+        twitch.retain(|_, tx| !tx.is_closed());
+        twitch.insert(state.clone(), tx);
+
+        Ok(WaitForTwitchAuth { state, fut })
     }
 
-    pub fn is_twitch_empty(&self) -> bool {
-        self.twitch.is_empty()
+    /// Remove the pending osu! authorization and return the discord user that started it
+    pub(super) async fn take_osu_state(
+        &self,
+        ctx: &Context,
+        state: &str,
+    ) -> Result<Option<Id<UserMarker>>> {
+        take_state(ctx, AuthKind::Osu, state).await
     }
 
-    fn generate_state(&self) -> u8 {
-        self.current_state.fetch_add(1, Ordering::SeqCst)
+    /// Remove the pending twitch authorization and return the discord user that started it
+    pub(super) async fn take_twitch_state(
+        &self,
+        ctx: &Context,
+        state: &str,
+    ) -> Result<Option<Id<UserMarker>>> {
+        take_state(ctx, AuthKind::Twitch, state).await
     }
 
-    pub(super) fn process_osu(&self, user: User, id: u8) {
-        if let Some(tx) = self.osu.lock(&id).remove() {
+    /// Link the osu! user to the discord user and notify the waiting command, if any
+    pub(super) async fn process_osu(
+        &self,
+        ctx: &Context,
+        user: User,
+        discord: Id<UserMarker>,
+        state: &str,
+    ) -> Result<()> {
+        let mut config = ctx
+            .psql()
+            .get_user_config(discord)
+            .await
+            .wrap_err("failed to get user config")?
+            .unwrap_or_default();
+
+        config.osu = Some(OsuData::User {
+            user_id: user.user_id,
+            username: user.username.clone(),
+        });
+
+        ctx.psql()
+            .insert_user_config(discord, &config)
+            .await
+            .wrap_err("failed to insert user config")?;
+
+        if let Some(tx) = self.osu.lock().remove(state) {
             let _ = tx.send(user);
         }
+
+        Ok(())
     }
 
-    pub(super) fn process_twitch(&self, user: TwitchUser, id: u8) {
-        if let Some(tx) = self.twitch.lock(&id).remove() {
+    /// Link the twitch user to the discord user and notify the waiting command, if any
+    pub(super) async fn process_twitch(
+        &self,
+        ctx: &Context,
+        user: TwitchUser,
+        discord: Id<UserMarker>,
+        state: &str,
+    ) -> Result<()> {
+        let mut config = ctx
+            .psql()
+            .get_user_config(discord)
+            .await
+            .wrap_err("failed to get user config")?
+            .unwrap_or_default();
+
+        config.twitch_id = Some(user.user_id);
+
+        ctx.psql()
+            .insert_user_config(discord, &config)
+            .await
+            .wrap_err("failed to insert user config")?;
+
+        if let Some(tx) = self.twitch.lock().remove(state) {
             let _ = tx.send(user);
         }
+
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone)]
+enum AuthKind {
+    Osu,
+    Twitch,
+}
+
+impl AuthKind {
+    fn key(self, state: &str) -> String {
+        match self {
+            Self::Osu => format!("auth_osu_{state}"),
+            Self::Twitch => format!("auth_twitch_{state}"),
+        }
     }
 }
 
+fn deadline() -> Duration {
+    Duration::from_secs(BotConfig::get().server.auth_deadline)
+}
+
+/// Store a new random state for the discord user and return it
+async fn store_state(ctx: &Context, kind: AuthKind, user: Id<UserMarker>) -> Result<String> {
+    let state = random_token();
+    let seconds = BotConfig::get().server.auth_deadline as usize;
+
+    let mut conn = ctx
+        .redis_client()
+        .get()
+        .await
+        .wrap_err("failed to get redis connection")?;
+
+    conn.set_ex::<_, _, ()>(kind.key(&state), user.get(), seconds)
+        .await
+        .wrap_err("failed to store authorization state")?;
+
+    Ok(state)
+}
+
+async fn take_state(ctx: &Context, kind: AuthKind, state: &str) -> Result<Option<Id<UserMarker>>> {
+    let key = kind.key(state);
+
+    let mut conn = ctx
+        .redis_client()
+        .get()
+        .await
+        .wrap_err("failed to get redis connection")?;
+
+    let (user,): (Option<u64>,) = redis::pipe()
+        .atomic()
+        .get(&key)
+        .del(&key)
+        .ignore()
+        .query_async(&mut *conn)
+        .await
+        .wrap_err("failed to take authorization state")?;
+
+    Ok(user.and_then(Id::new_checked))
+}
+
 pub struct WaitForOsuAuth {
-    pub state: u8,
+    pub state: String,
     fut: Pin<Box<Timeout<Receiver<User>>>>,
 }
 
@@ -100,7 +220,7 @@ impl Future for WaitForOsuAuth {
     type Output = Result<User, AuthenticationStandbyError>;
 
     #[inline]
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    fn poll(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
         match self.fut.poll_unpin(cx) {
             Poll::Ready(Ok(Ok(user))) => Poll::Ready(Ok(user)),
             Poll::Ready(Ok(Err(_))) => Poll::Ready(Err(AuthenticationStandbyError::Canceled)),
@@ -111,7 +231,7 @@ impl Future for WaitForOsuAuth {
 }
 
 pub struct WaitForTwitchAuth {
-    pub state: u8,
+    pub state: String,
     fut: Pin<Box<Timeout<Receiver<TwitchUser>>>>,
 }
 
@@ -119,7 +239,7 @@ impl Future for WaitForTwitchAuth {
     type Output = Result<TwitchUser, AuthenticationStandbyError>;
 
     #[inline]
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    fn poll(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
         match self.fut.poll_unpin(cx) {
             Poll::Ready(Ok(Ok(user))) => Poll::Ready(Ok(user)),
             Poll::Ready(Ok(Err(_))) => Poll::Ready(Err(AuthenticationStandbyError::Canceled)),
@@ -0,0 +1,1120 @@
+use eyre::{Report, Result, WrapErr};
+use hashbrown::HashMap;
+use hyper::{
+    header::{AUTHORIZATION, CONTENT_TYPE, LOCATION, SET_COOKIE},
+    Body, Request, Response, StatusCode,
+};
+use rosu_v2::prelude::{GameMode, OsuError};
+use routerify::ext::RequestExt;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+use time::OffsetDateTime;
+use twilight_model::{
+    channel::ChannelType,
+    guild::Permissions,
+    id::{
+        marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
+    util::ImageHash,
+};
+
+use crate::{
+    commands::utility::{has_permission, retrieve_data},
+    core::{commands::checks::check_authority, BotConfig, OsuFutureExt},
+    database::{AuditAction, EmbedsSize, ListSize, MinimizedPp, Prefix},
+    util::{
+        constants::{
+            DISCORD_CURRENT_USER_ENDPOINT, DISCORD_CURRENT_USER_GUILDS_ENDPOINT,
+            DISCORD_OAUTH_AUTHORIZE, DISCORD_OAUTH_TOKEN, GENERAL_ISSUE,
+        },
+        matcher,
+    },
+    Context,
+};
+
+pub use self::{session::DashboardSessions, user::*};
+
+use self::session::{
+    DashboardSession, SESSION_COOKIE, SESSION_DURATION, STATE_COOKIE, STATE_DURATION,
+};
+
+use super::{
+    Client, Context_, DiscordClientId, DiscordClientSecret, DiscordRedirect, Handlebars_,
+    HandlerResult,
+};
+
+mod session;
+mod user;
+
+type GuildAccess = Result<(DashboardSession, Id<GuildMarker>), Response<Body>>;
+
+pub async fn dashboard_handler(req: Request<Body>) -> HandlerResult {
+    let sessions: &DashboardSessions = req.data().unwrap();
+
+    let session = match sessions.get(&req) {
+        Some(session) => session,
+        None => return redirect("/dashboard/login"),
+    };
+
+    let Context_(ctx) = req.data().unwrap();
+    let mut guilds = Vec::new();
+
+    for &guild_id in session.guilds.iter() {
+        if !is_authority(ctx, session.user_id, guild_id).await {
+            continue;
+        }
+
+        let guild_res = ctx.cache.guild(guild_id, |guild| {
+            json!({
+                "id": guild_id.to_string(),
+                "name": guild.name(),
+                "icon": guild.icon().map(|hash| icon_url(guild_id, hash)),
+            })
+        });
+
+        if let Ok(guild) = guild_res {
+            guilds.push(guild);
+        }
+    }
+
+    let render_data = json!({
+        "user": session.username,
+        "csrf": session.csrf,
+        "guilds": guilds,
+    });
+
+    let Handlebars_(handlebars) = req.data().unwrap();
+
+    let page = handlebars
+        .render("dashboard", &render_data)
+        .wrap_err("failed to render dashboard page")?;
+
+    Ok(Response::new(Body::from(page)))
+}
+
+pub async fn dashboard_login_handler(req: Request<Body>) -> HandlerResult {
+    let sessions: &DashboardSessions = req.data().unwrap();
+    let DiscordClientId(client_id) = req.data().unwrap();
+    let DiscordRedirect(redirect_uri) = req.data().unwrap();
+
+    let client_id = client_id.to_string();
+    let state = sessions.new_state();
+
+    let query = serde_urlencoded::to_string(&[
+        ("client_id", client_id.as_str()),
+        ("redirect_uri", redirect_uri.as_str()),
+        ("response_type", "code"),
+        ("scope", "identify guilds"),
+        ("state", state.as_str()),
+    ])?;
+
+    let cookie = format!(
+        "{STATE_COOKIE}={state}; Path=/dashboard; Max-Age={}; HttpOnly; SameSite=Lax{}",
+        STATE_DURATION.whole_seconds(),
+        secure_attribute(),
+    );
+
+    let response = Response::builder()
+        .status(StatusCode::SEE_OTHER)
+        .header(SET_COOKIE, cookie)
+        .header(LOCATION, format!("{DISCORD_OAUTH_AUTHORIZE}?{query}"))
+        .body(Body::empty())?;
+
+    Ok(response)
+}
+
+#[derive(Deserialize)]
+struct AuthQuery {
+    code: String,
+    state: String,
+}
+
+#[derive(Deserialize)]
+struct DiscordToken {
+    access_token: String,
+}
+
+#[derive(Deserialize)]
+struct DiscordUser {
+    id: Id<UserMarker>,
+    username: String,
+}
+
+#[derive(Deserialize)]
+struct DiscordGuild {
+    id: Id<GuildMarker>,
+}
+
+pub async fn dashboard_auth_handler(req: Request<Body>) -> HandlerResult {
+    let query = req
+        .uri()
+        .query()
+        .and_then(|query| serde_urlencoded::from_str::<AuthQuery>(query).ok());
+
+    let sessions: &DashboardSessions = req.data().unwrap();
+
+    let query = match query {
+        Some(query) if sessions.consume_state(&req, &query.state) => query,
+        _ => return plain_response(StatusCode::BAD_REQUEST, "Invalid or expired login attempt"),
+    };
+
+    let DiscordClientId(client_id) = req.data().unwrap();
+    let DiscordClientSecret(client_secret) = req.data().unwrap();
+    let DiscordRedirect(redirect_uri) = req.data().unwrap();
+    let Client(client) = req.data().unwrap();
+
+    let client_id = client_id.to_string();
+
+    let form = serde_urlencoded::to_string(&[
+        ("client_id", client_id.as_str()),
+        ("client_secret", client_secret.as_str()),
+        ("grant_type", "authorization_code"),
+        ("code", query.code.as_str()),
+        ("redirect_uri", redirect_uri.as_str()),
+    ])?;
+
+    let token_req = Request::post(DISCORD_OAUTH_TOKEN)
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(form))?;
+
+    let response = client
+        .request(token_req)
+        .await
+        .wrap_err("failed to send discord token request")?;
+
+    let bytes = hyper::body::to_bytes(response.into_body())
+        .await
+        .wrap_err("failed to await response for discord token")?;
+
+    let token = serde_json::from_slice::<DiscordToken>(&bytes)
+        .map(|token| format!("Bearer {}", token.access_token))
+        .wrap_err_with(|| {
+            let body = String::from_utf8_lossy(&bytes);
+
+            format!("failed to deserialize discord token: {body}")
+        })?;
+
+    let user: DiscordUser = discord_get(&req, DISCORD_CURRENT_USER_ENDPOINT, &token).await?;
+    let guilds: Vec<DiscordGuild> =
+        discord_get(&req, DISCORD_CURRENT_USER_GUILDS_ENDPOINT, &token).await?;
+
+    info!("Successful dashboard login for `{}`", user.username);
+
+    let guilds = guilds.into_iter().map(|guild| guild.id).collect();
+    let session_id = sessions.insert(user.id, user.username, guilds);
+
+    // `SameSite=Lax` prevents the cookie from being sent along cross-site form submissions,
+    // forms additionally require the session's csrf token
+    let cookie = format!(
+        "{SESSION_COOKIE}={session_id}; Path=/dashboard; Max-Age={}; HttpOnly; SameSite=Lax{}",
+        SESSION_DURATION.whole_seconds(),
+        secure_attribute(),
+    );
+
+    let state_cookie = format!("{STATE_COOKIE}=; Path=/dashboard; Max-Age=0");
+
+    let response = Response::builder()
+        .status(StatusCode::SEE_OTHER)
+        .header(SET_COOKIE, cookie)
+        .header(SET_COOKIE, state_cookie)
+        .header(LOCATION, "/dashboard")
+        .body(Body::empty())?;
+
+    Ok(response)
+}
+
+async fn discord_get<T: DeserializeOwned>(
+    req: &Request<Body>,
+    url: &'static str,
+    token: &str,
+) -> Result<T> {
+    let Client(client) = req.data().unwrap();
+
+    let discord_req = Request::get(url)
+        .header(AUTHORIZATION, token)
+        .body(Body::empty())?;
+
+    let response = client
+        .request(discord_req)
+        .await
+        .wrap_err_with(|| format!("failed to send request to {url}"))?;
+
+    let bytes = hyper::body::to_bytes(response.into_body())
+        .await
+        .wrap_err_with(|| format!("failed to await response of {url}"))?;
+
+    serde_json::from_slice(&bytes).wrap_err_with(|| {
+        let body = String::from_utf8_lossy(&bytes);
+
+        format!("failed to deserialize response of {url}: {body}")
+    })
+}
+
+pub async fn dashboard_logout_handler(mut req: Request<Body>) -> HandlerResult {
+    let sessions: &DashboardSessions = req.data().unwrap();
+
+    let session = match sessions.get(&req) {
+        Some(session) => session,
+        None => return redirect("/dashboard/login"),
+    };
+
+    if let Err(response) = parse_form(&mut req, &session).await {
+        return Ok(response);
+    }
+
+    let sessions: &DashboardSessions = req.data().unwrap();
+    sessions.remove(&req);
+
+    let cookie = format!("{SESSION_COOKIE}=; Path=/dashboard; Max-Age=0");
+
+    let response = Response::builder()
+        .header(SET_COOKIE, cookie)
+        .body(Body::from("Successfully logged out"))?;
+
+    Ok(response)
+}
+
+pub async fn dashboard_guild_handler(req: Request<Body>) -> HandlerResult {
+    let (session, guild_id) = match guild_access(&req).await {
+        Ok(tuple) => tuple,
+        Err(response) => return Ok(response),
+    };
+
+    #[derive(Deserialize)]
+    struct MessageQuery {
+        message: Option<String>,
+    }
+
+    let message = req
+        .uri()
+        .query()
+        .and_then(|query| serde_urlencoded::from_str::<MessageQuery>(query).ok())
+        .and_then(|query| query.message);
+
+    let Context_(ctx) = req.data().unwrap();
+
+    let (name, icon) = ctx.cache.guild(guild_id, |guild| {
+        let icon = guild.icon().map(|hash| icon_url(guild_id, hash));
+
+        (guild.name().to_owned(), icon)
+    })?;
+
+    let config = ctx.guild_config(guild_id).await;
+    let channels = text_channels(ctx, guild_id);
+    let roles = guild_roles(ctx, guild_id);
+
+    let role_name = |role: u64| {
+        roles
+            .iter()
+            .find(|(id, _)| id.get() == role)
+            .map_or_else(|| role.to_string(), |(_, name)| name.to_owned())
+    };
+
+    let channel_name = |channel: u64| {
+        channels
+            .iter()
+            .find(|(id, _)| id.get() == channel)
+            .map_or_else(|| channel.to_string(), |(_, name)| name.to_owned())
+    };
+
+    let authorities: Vec<_> = roles
+        .iter()
+        .map(|(id, name)| {
+            json!({
+                "id": id.to_string(),
+                "name": name,
+                "selected": config.authorities.contains(&id.get()),
+            })
+        })
+        .collect();
+
+    let config_data = json!({
+        "prefixes": config.prefixes.join(" "),
+        "authorities": authorities,
+        "embeds_size": select_options(
+            config.embeds_size() as u8,
+            &[(0, "Always minimized"), (1, "Initial maximized"), (2, "Always maximized")],
+        ),
+        "list_size": select_options(
+            config.list_size() as u8,
+            &[(0, "Condensed"), (1, "Detailed"), (2, "Single")],
+        ),
+        "minimized_pp": select_options(
+            config.minimized_pp() as u8,
+            &[(0, "If FC"), (1, "Max")],
+        ),
+        "profile_size": select_options(
+            config.profile_size() as u8,
+            &[(0, "Compact"), (1, "Medium"), (2, "Full")],
+        ),
+        "public_api": config.public_api(),
+        "show_retries": config.show_retries(),
+        "track_limit": config.track_limit(),
+        "with_lyrics": config.with_lyrics(),
+    });
+
+    let channel_ids: Vec<_> = channels.iter().map(|(id, _)| *id).collect();
+
+    #[cfg(feature = "osutracking")]
+    let osu_tracking = osu_tracking_entries(ctx, &channel_ids, &channel_name).await?;
+
+    #[cfg(not(feature = "osutracking"))]
+    let osu_tracking: Vec<Value> = Vec::new();
+
+    let twitch_tracking = twitch_tracking_entries(ctx, &channel_ids, &channel_name).await;
+
+    let role_assigns: Vec<_> = ctx
+        .role_assigns_in(&channel_ids)
+        .into_iter()
+        .flat_map(|((channel, message), roles)| {
+            roles.into_iter().map(move |role| (channel, message, role))
+        })
+        .map(|(channel, message, role)| {
+            json!({
+                "channel_id": channel.to_string(),
+                "channel_name": channel_name(channel),
+                "message_id": message.to_string(),
+                "message_url": format!(
+                    "https://discord.com/channels/{guild_id}/{channel}/{message}"
+                ),
+                "role_id": role.to_string(),
+                "role_name": role_name(role),
+            })
+        })
+        .collect();
+
+    let channels: Vec<_> = channels
+        .iter()
+        .map(|(id, name)| json!({ "id": id.to_string(), "name": name }))
+        .collect();
+
+    let roles: Vec<_> = roles
+        .iter()
+        .map(|(id, name)| json!({ "id": id.to_string(), "name": name }))
+        .collect();
+
+    let render_data = json!({
+        "user": session.username,
+        "csrf": session.csrf,
+        "guild": {
+            "id": guild_id.to_string(),
+            "name": name,
+            "icon": icon,
+        },
+        "message": message,
+        "config": config_data,
+        "channels": channels,
+        "roles": roles,
+        "osu_tracking_enabled": cfg!(feature = "osutracking"),
+        "osu_tracking": osu_tracking,
+        "twitch_tracking": twitch_tracking,
+        "role_assigns": role_assigns,
+    });
+
+    let Handlebars_(handlebars) = req.data().unwrap();
+
+    let page = handlebars
+        .render("dashboard_guild", &render_data)
+        .wrap_err("failed to render dashboard guild page")?;
+
+    Ok(Response::new(Body::from(page)))
+}
+
+#[cfg(feature = "osutracking")]
+async fn osu_tracking_entries(
+    ctx: &Context,
+    channels: &[Id<ChannelMarker>],
+    channel_name: &impl Fn(u64) -> String,
+) -> Result<Vec<Value>> {
+    let mut tracked = Vec::new();
+
+    for &channel in channels {
+        for (user_id, mode, limit) in ctx.tracking().list(channel).await {
+            tracked.push((channel, user_id, mode, limit));
+        }
+    }
+
+    let user_ids: Vec<_> = tracked
+        .iter()
+        .map(|(_, user_id, ..)| *user_id as i32)
+        .collect();
+
+    let names = ctx
+        .psql()
+        .get_names_by_ids(&user_ids)
+        .await
+        .wrap_err("failed to get names of tracked users")?;
+
+    let entries = tracked
+        .into_iter()
+        .map(|(channel, user_id, mode, limit)| {
+            json!({
+                "channel_id": channel.to_string(),
+                "channel_name": channel_name(channel.get()),
+                "user_id": user_id,
+                "username": names.get(&user_id).map(|name| name.as_str()),
+                "mode": mode as u8,
+                "mode_name": mode_name(mode),
+                "limit": limit,
+            })
+        })
+        .collect();
+
+    Ok(entries)
+}
+
+async fn twitch_tracking_entries(
+    ctx: &Context,
+    channels: &[Id<ChannelMarker>],
+    channel_name: &impl Fn(u64) -> String,
+) -> Vec<Value> {
+    let tracked: Vec<_> = channels
+        .iter()
+        .flat_map(|&channel| {
+            ctx.tracked_users_in(channel)
+                .into_iter()
+                .map(move |twitch_id| (channel, twitch_id))
+        })
+        .collect();
+
+    let mut twitch_ids: Vec<_> = tracked.iter().map(|(_, twitch_id)| *twitch_id).collect();
+    twitch_ids.sort_unstable();
+    twitch_ids.dedup();
+
+    let names: HashMap<_, _> = match ctx.client().get_twitch_users(&twitch_ids).await {
+        Ok(users) => users
+            .into_iter()
+            .map(|user| (user.user_id, user.display_name))
+            .collect(),
+        Err(err) => {
+            warn!("{:?}", err.wrap_err("Failed to get twitch users"));
+
+            HashMap::new()
+        }
+    };
+
+    tracked
+        .into_iter()
+        .map(|(channel, twitch_id)| {
+            json!({
+                "channel_id": channel.to_string(),
+                "channel_name": channel_name(channel.get()),
+                "twitch_id": twitch_id.to_string(),
+                "name": names.get(&twitch_id),
+            })
+        })
+        .collect()
+}
+
+pub async fn dashboard_config_handler(mut req: Request<Body>) -> HandlerResult {
+    let (session, guild_id) = match guild_access(&req).await {
+        Ok(tuple) => tuple,
+        Err(response) => return Ok(response),
+    };
+
+    let form = match parse_form(&mut req, &session).await {
+        Ok(form) => form,
+        Err(response) => return Ok(response),
+    };
+
+    let Context_(ctx) = req.data().unwrap();
+
+    let prefixes: Vec<Prefix> = form_value(&form, "prefixes")
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(Prefix::from)
+        .collect();
+
+    if prefixes.is_empty() || prefixes.len() > 5 {
+        return guild_redirect(guild_id, "There must be between one and five prefixes");
+    } else if prefixes
+        .iter()
+        .any(|prefix| matcher::is_custom_emote(prefix))
+    {
+        return guild_redirect(guild_id, "Prefixes cannot contain custom emotes");
+    }
+
+    let authorities: Vec<u64> = form
+        .iter()
+        .filter(|(key, _)| key == "authorities")
+        .filter_map(|(_, value)| value.parse().ok().and_then(Id::new_checked))
+        .filter(|&role| role_in_guild(ctx, role, guild_id))
+        .map(Id::get)
+        .collect();
+
+    if authorities.len() > 10 {
+        return guild_redirect(guild_id, "There can be at most 10 authority roles");
+    } else if !keeps_authority(ctx, session.user_id, guild_id, &authorities) {
+        let content = "You cannot set authority roles to something \
+            that would make you lose authority status";
+
+        return guild_redirect(guild_id, content);
+    }
+
+    let track_limit =
+        match form_value(&form, "track_limit").and_then(|limit| limit.parse::<u8>().ok()) {
+            Some(limit @ 1..=100) => limit,
+            _ => return guild_redirect(guild_id, "The track limit must be between 1 and 100"),
+        };
+
+    let parse_i16 = |key: &str| form_value(&form, key).and_then(|value| value.parse::<i16>().ok());
+
+    let embeds_size = parse_i16("embeds_size").map(EmbedsSize::from);
+    let list_size = parse_i16("list_size").map(ListSize::from);
+    let minimized_pp = parse_i16("minimized_pp").map(MinimizedPp::from);
+    let profile_size = parse_i16("profile_size").map(From::from);
+
+    // Unchecked checkboxes are not part of the form
+    let public_api = form_value(&form, "public_api").is_some();
+    let show_retries = form_value(&form, "show_retries").is_some();
+    let with_lyrics = form_value(&form, "with_lyrics").is_some();
+
+    let update_fut = ctx.update_guild_config(guild_id, session.user_id, move |config| {
+        config.prefixes = prefixes.into_iter().collect();
+        config.authorities = authorities.into_iter().collect();
+        config.track_limit = Some(track_limit);
+        config.public_api = Some(public_api);
+        config.show_retries = Some(show_retries);
+        config.with_lyrics = Some(with_lyrics);
+
+        if let Some(embeds_size) = embeds_size {
+            config.embeds_size = Some(embeds_size);
+        }
+
+        if let Some(list_size) = list_size {
+            config.list_size = Some(list_size);
+        }
+
+        if let Some(minimized_pp) = minimized_pp {
+            config.minimized_pp = Some(minimized_pp);
+        }
+
+        if let Some(profile_size) = profile_size {
+            config.profile_size = Some(profile_size);
+        }
+    });
+
+    update_fut.await.wrap_err("failed to update guild config")?;
+
+    guild_redirect(guild_id, "Configuration updated")
+}
+
+#[cfg(feature = "osutracking")]
+pub async fn dashboard_osu_tracking_handler(mut req: Request<Body>) -> HandlerResult {
+    let (session, guild_id) = match guild_access(&req).await {
+        Ok(tuple) => tuple,
+        Err(response) => return Ok(response),
+    };
+
+    let form = match parse_form(&mut req, &session).await {
+        Ok(form) => form,
+        Err(response) => return Ok(response),
+    };
+
+    let Context_(ctx) = req.data().unwrap();
+
+    let channel =
+        match form_value(&form, "channel").and_then(|id| channel_in_guild(ctx, id, guild_id)) {
+            Some(channel) => channel,
+            None => return guild_redirect(guild_id, "Invalid channel"),
+        };
+
+    let mode = match form_value(&form, "mode").and_then(|mode| mode.parse::<u8>().ok()) {
+        Some(mode @ 0..=3) => GameMode::from(mode),
+        _ => return guild_redirect(guild_id, "Invalid mode"),
+    };
+
+    match form_value(&form, "action") {
+        Some("add") => {
+            let name = match form_value(&form, "name").map(str::trim) {
+                Some(name) if !name.is_empty() && name.len() <= 15 => name,
+                _ => return guild_redirect(guild_id, "Invalid osu! username"),
+            };
+
+            let limit = match form_value(&form, "limit").filter(|limit| !limit.is_empty()) {
+                Some(limit) => match limit.parse::<usize>() {
+                    Ok(limit @ 1..=100) => limit,
+                    _ => return guild_redirect(guild_id, "The limit must be between 1 and 100"),
+                },
+                None => ctx.guild_track_limit(guild_id).await as usize,
+            };
+
+            let user = match ctx
+                .osu()
+                .user(name)
+                .mode(mode)
+                .observe(&ctx.stats, "user")
+                .await
+            {
+                Ok(user) => user,
+                Err(OsuError::NotFound) => {
+                    let content = format!("User `{name}` was not found");
+
+                    return guild_redirect(guild_id, &content);
+                }
+                Err(err) => return Err(Report::new(err).wrap_err("failed to get user")),
+            };
+
+            let add_fut = ctx.tracking().add(
+                user.user_id,
+                mode,
+                OffsetDateTime::now_utc(),
+                channel,
+                limit,
+                ctx.psql(),
+            );
+
+            let content = if add_fut.await.wrap_err("failed to add tracked entry")? {
+                let details = format!("{mode} in <#{channel}> (limit {limit}): {}", user.username);
+                let action = AuditAction::TrackAdd;
+                ctx.audit_log(guild_id, session.user_id, action, details)
+                    .await;
+
+                format!(
+                    "Now tracking `{}` in #{}",
+                    user.username,
+                    channel_display(ctx, channel)
+                )
+            } else {
+                format!(
+                    "Failed to track `{}`, the channel might be full",
+                    user.username
+                )
+            };
+
+            guild_redirect(guild_id, &content)
+        }
+        Some("remove") => {
+            let user_id = match form_value(&form, "user_id").and_then(|id| id.parse().ok()) {
+                Some(user_id) => user_id,
+                None => return guild_redirect(guild_id, "Invalid user id"),
+            };
+
+            ctx.tracking()
+                .remove_user(user_id, Some(mode), channel, ctx.psql())
+                .await
+                .wrap_err("failed to remove tracked entry")?;
+
+            let details = format!("{mode} in <#{channel}>: user id {user_id}");
+            let action = AuditAction::TrackRemove;
+            ctx.audit_log(guild_id, session.user_id, action, details)
+                .await;
+
+            guild_redirect(guild_id, "Removed osu! tracking")
+        }
+        _ => plain_response(StatusCode::BAD_REQUEST, "Invalid action"),
+    }
+}
+
+pub async fn dashboard_twitch_tracking_handler(mut req: Request<Body>) -> HandlerResult {
+    let (session, guild_id) = match guild_access(&req).await {
+        Ok(tuple) => tuple,
+        Err(response) => return Ok(response),
+    };
+
+    let form = match parse_form(&mut req, &session).await {
+        Ok(form) => form,
+        Err(response) => return Ok(response),
+    };
+
+    let Context_(ctx) = req.data().unwrap();
+
+    let channel =
+        match form_value(&form, "channel").and_then(|id| channel_in_guild(ctx, id, guild_id)) {
+            Some(channel) => channel.get(),
+            None => return guild_redirect(guild_id, "Invalid channel"),
+        };
+
+    match form_value(&form, "action") {
+        Some("add") => {
+            let name = match form_value(&form, "name").map(str::trim) {
+                Some(name) if !name.is_empty() => name.to_ascii_lowercase(),
+                _ => return guild_redirect(guild_id, "Invalid twitch name"),
+            };
+
+            let twitch_id = match ctx.client().get_twitch_user(&name).await {
+                Ok(Some(user)) => user.user_id,
+                Ok(None) => {
+                    let content = format!("Twitch user `{name}` was not found");
+
+                    return guild_redirect(guild_id, &content);
+                }
+                Err(err) => return Err(err.wrap_err("failed to get twitch user")),
+            };
+
+            let add_fut = ctx.psql().add_stream_track(channel, twitch_id);
+
+            let content = if add_fut.await.wrap_err("failed to add stream track")? {
+                ctx.add_tracking(twitch_id, channel);
+
+                #[cfg(feature = "twitchtracking")]
+                crate::server::subscribe_stream(ctx, twitch_id).await;
+
+                let details = format!("`{name}` in <#{channel}>");
+                let action = AuditAction::StreamAdd;
+                ctx.audit_log(guild_id, session.user_id, action, details)
+                    .await;
+
+                format!("Now tracking `{name}`'s twitch stream")
+            } else {
+                format!("Twitch user `{name}` is already being tracked in that channel")
+            };
+
+            guild_redirect(guild_id, &content)
+        }
+        Some("remove") => {
+            let twitch_id = match form_value(&form, "twitch_id").and_then(|id| id.parse().ok()) {
+                Some(twitch_id) => twitch_id,
+                None => return guild_redirect(guild_id, "Invalid twitch id"),
+            };
+
+            ctx.remove_tracking(twitch_id, channel);
+
+            ctx.psql()
+                .remove_stream_track(channel, twitch_id)
+                .await
+                .wrap_err("failed to remove stream track")?;
+
+            #[cfg(feature = "twitchtracking")]
+            crate::server::unsubscribe_stream(ctx, twitch_id).await;
+
+            let details = format!("Twitch user id {twitch_id} in <#{channel}>");
+            let action = AuditAction::StreamRemove;
+            ctx.audit_log(guild_id, session.user_id, action, details)
+                .await;
+
+            guild_redirect(guild_id, "Removed twitch tracking")
+        }
+        _ => plain_response(StatusCode::BAD_REQUEST, "Invalid action"),
+    }
+}
+
+pub async fn dashboard_role_assign_handler(mut req: Request<Body>) -> HandlerResult {
+    let (session, guild_id) = match guild_access(&req).await {
+        Ok(tuple) => tuple,
+        Err(response) => return Ok(response),
+    };
+
+    let form = match parse_form(&mut req, &session).await {
+        Ok(form) => form,
+        Err(response) => return Ok(response),
+    };
+
+    let Context_(ctx) = req.data().unwrap();
+
+    let channel =
+        match form_value(&form, "channel").and_then(|id| channel_in_guild(ctx, id, guild_id)) {
+            Some(channel) => channel,
+            None => return guild_redirect(guild_id, "Invalid channel"),
+        };
+
+    let msg = match form_value(&form, "message").and_then(|id| id.trim().parse().ok()) {
+        Some(msg) => match Id::new_checked(msg) {
+            Some(msg) => msg,
+            None => return guild_redirect(guild_id, "Invalid message id"),
+        },
+        None => return guild_redirect(guild_id, "Invalid message id"),
+    };
+
+    let role = form_value(&form, "role")
+        .and_then(|id| id.parse().ok())
+        .and_then(Id::new_checked)
+        .filter(|&role| role_in_guild(ctx, role, guild_id));
+
+    let role = match role {
+        Some(role) => role,
+        None => return guild_redirect(guild_id, "Invalid role"),
+    };
+
+    match form_value(&form, "action") {
+        Some("add") => {
+            let (role_pos, msg) = match retrieve_data(ctx, channel, msg, role).await? {
+                Ok(tuple) => tuple,
+                Err(content) => return guild_redirect(guild_id, content),
+            };
+
+            match has_permission(ctx, guild_id, role_pos) {
+                Ok(true) => {}
+                Ok(false) => {
+                    let content = "The role is higher than all my roles so I can't assign it";
+
+                    return guild_redirect(guild_id, content);
+                }
+                Err(err) => {
+                    warn!("{:?}", Report::new(err));
+
+                    return guild_redirect(guild_id, GENERAL_ISSUE);
+                }
+            }
+
+            ctx.psql()
+                .add_role_assign(channel.get(), msg.id.get(), role.get())
+                .await
+                .wrap_err("failed to insert role assign in database")?;
+
+            ctx.add_role_assign(channel, msg.id, role);
+
+            let details = format!("<@&{role}> for message {} in <#{channel}>", msg.id);
+            let action = AuditAction::RoleAssignAdd;
+            ctx.audit_log(guild_id, session.user_id, action, details)
+                .await;
+
+            guild_redirect(guild_id, "Added role assign")
+        }
+        Some("remove") => {
+            let remove_fut = ctx
+                .psql()
+                .remove_role_assign(channel.get(), msg.get(), role.get());
+
+            let content = if remove_fut
+                .await
+                .wrap_err("failed to remove role assign from database")?
+            {
+                ctx.remove_role_assign(channel, msg, role);
+
+                let details = format!("<@&{role}> for message {msg} in <#{channel}>");
+                let action = AuditAction::RoleAssignRemove;
+                ctx.audit_log(guild_id, session.user_id, action, details)
+                    .await;
+
+                "Removed role assign"
+            } else {
+                "The message was not linked to that role to begin with"
+            };
+
+            guild_redirect(guild_id, content)
+        }
+        _ => plain_response(StatusCode::BAD_REQUEST, "Invalid action"),
+    }
+}
+
+/// Check that the request has a session whose user is an authority in the guild
+async fn guild_access(req: &Request<Body>) -> GuildAccess {
+    let sessions: &DashboardSessions = req.data().unwrap();
+
+    let session = match sessions.get(req) {
+        Some(session) => session,
+        None => return Err(redirect_response("/dashboard/login")),
+    };
+
+    let guild_id = req
+        .param("guild_id")
+        .and_then(|id| id.parse().ok())
+        .and_then(Id::new_checked);
+
+    let guild_id = match guild_id {
+        Some(guild_id) => guild_id,
+        None => return Err(plain(StatusCode::BAD_REQUEST, "Invalid guild id")),
+    };
+
+    let Context_(ctx) = req.data().unwrap();
+
+    if !is_authority(ctx, session.user_id, guild_id).await {
+        let content = "You are not allowed to manage this server";
+
+        return Err(plain(StatusCode::FORBIDDEN, content));
+    }
+
+    Ok((session, guild_id))
+}
+
+async fn is_authority(ctx: &Context, user: Id<UserMarker>, guild: Id<GuildMarker>) -> bool {
+    // Don't create configs for guilds the bot is not in
+    if ctx.cache.guild(guild, |_| ()).is_err() {
+        return false;
+    }
+
+    matches!(check_authority(ctx, user, Some(guild)).await, Ok(None))
+}
+
+/// Make sure the user is still an authority after applying new authority roles
+fn keeps_authority(
+    ctx: &Context,
+    user: Id<UserMarker>,
+    guild: Id<GuildMarker>,
+    authorities: &[u64],
+) -> bool {
+    let (permissions, _) = ctx.cache.get_guild_permissions(user, guild);
+
+    if permissions.contains(Permissions::ADMINISTRATOR) {
+        return true;
+    }
+
+    ctx.cache
+        .member(guild, user, |member| {
+            member
+                .roles()
+                .iter()
+                .any(|role| authorities.contains(&role.get()))
+        })
+        .unwrap_or(false)
+}
+
+fn text_channels(ctx: &Context, guild: Id<GuildMarker>) -> Vec<(Id<ChannelMarker>, String)> {
+    let channel_ids: Vec<Id<ChannelMarker>> = ctx.cache.guild_channels(guild, |id| *id);
+
+    let mut channels: Vec<_> = channel_ids
+        .into_iter()
+        .filter_map(|id| {
+            ctx.cache
+                .channel(id, |channel| {
+                    (channel.kind == ChannelType::GuildText).then(|| {
+                        let name = channel.name.clone().unwrap_or_default();
+
+                        (channel.position.unwrap_or(0), id, name)
+                    })
+                })
+                .ok()
+                .flatten()
+        })
+        .collect();
+
+    channels.sort_unstable_by_key(|(position, id, _)| (*position, *id));
+
+    channels
+        .into_iter()
+        .map(|(_, id, name)| (id, name))
+        .collect()
+}
+
+fn guild_roles(ctx: &Context, guild: Id<GuildMarker>) -> Vec<(Id<RoleMarker>, String)> {
+    let role_ids: Vec<Id<RoleMarker>> = ctx.cache.guild_roles(guild, |id| *id);
+
+    let mut roles: Vec<_> = role_ids
+        .into_iter()
+        // Skip the @everyone role
+        .filter(|id| id.get() != guild.get())
+        .filter_map(|id| {
+            ctx.cache
+                .role(id, |role| (role.position, id, role.name.clone()))
+                .ok()
+        })
+        .collect();
+
+    roles.sort_unstable_by(|(a, ..), (b, ..)| b.cmp(a));
+
+    roles.into_iter().map(|(_, id, name)| (id, name)).collect()
+}
+
+fn channel_in_guild(ctx: &Context, id: &str, guild: Id<GuildMarker>) -> Option<Id<ChannelMarker>> {
+    let channel = Id::new_checked(id.parse().ok()?)?;
+
+    ctx.cache
+        .channel(channel, |channel| channel.guild_id == Some(guild))
+        .unwrap_or(false)
+        .then(|| channel)
+}
+
+#[cfg(feature = "osutracking")]
+fn channel_display(ctx: &Context, channel: Id<ChannelMarker>) -> String {
+    ctx.cache
+        .channel(channel, |channel| channel.name.clone())
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| channel.to_string())
+}
+
+fn role_in_guild(ctx: &Context, role: Id<RoleMarker>, guild: Id<GuildMarker>) -> bool {
+    ctx.cache
+        .role(role, |role| role.guild_id() == guild)
+        .unwrap_or(false)
+}
+
+#[cfg(feature = "osutracking")]
+fn mode_name(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "osu!standard",
+        GameMode::Taiko => "osu!taiko",
+        GameMode::Catch => "osu!ctb",
+        GameMode::Mania => "osu!mania",
+    }
+}
+
+fn select_options(current: u8, options: &[(u8, &str)]) -> Vec<Value> {
+    options
+        .iter()
+        .map(|&(value, name)| {
+            json!({
+                "value": value,
+                "name": name,
+                "selected": value == current,
+            })
+        })
+        .collect()
+}
+
+fn icon_url(guild: Id<GuildMarker>, hash: &ImageHash) -> String {
+    format!(
+        "https://cdn.discordapp.com/icons/{guild}/{hash}.{}",
+        if hash.is_animated() { "gif" } else { "webp" }
+    )
+}
+
+/// Parse the request's form and check that it contains the session's csrf token
+async fn parse_form(
+    req: &mut Request<Body>,
+    session: &DashboardSession,
+) -> Result<Vec<(String, String)>, Response<Body>> {
+    let form = hyper::body::to_bytes(req.body_mut())
+        .await
+        .ok()
+        .and_then(|bytes| serde_urlencoded::from_bytes::<Vec<(String, String)>>(&bytes).ok());
+
+    let form = match form {
+        Some(form) => form,
+        None => return Err(plain(StatusCode::BAD_REQUEST, "Invalid form data")),
+    };
+
+    if form_value(&form, "csrf") != Some(session.csrf.as_str()) {
+        return Err(plain(
+            StatusCode::FORBIDDEN,
+            "Invalid or expired form, please reload the page",
+        ));
+    }
+
+    Ok(form)
+}
+
+fn secure_attribute() -> &'static str {
+    if BotConfig::get().server.external_url.starts_with("https") {
+        "; Secure"
+    } else {
+        ""
+    }
+}
+
+fn form_value<'f>(form: &'f [(String, String)], key: &str) -> Option<&'f str> {
+    form.iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, value)| value.as_str())
+}
+
+fn guild_redirect(guild: Id<GuildMarker>, message: &str) -> HandlerResult {
+    let query = serde_urlencoded::to_string(&[("message", message)])?;
+
+    redirect(&format!("/dashboard/{guild}?{query}"))
+}
+
+fn redirect(location: &str) -> HandlerResult {
+    Ok(redirect_response(location))
+}
+
+fn redirect_response(location: &str) -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::SEE_OTHER)
+        .header(LOCATION, location)
+        .body(Body::empty())
+        .unwrap()
+}
+
+fn plain_response(status: StatusCode, content: &'static str) -> HandlerResult {
+    Ok(plain(status, content))
+}
+
+fn plain(status: StatusCode, content: &'static str) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .body(Body::from(content))
+        .unwrap()
+}
@@ -0,0 +1,137 @@
+use hashbrown::HashMap;
+use hyper::{header::COOKIE, Body, Request};
+use parking_lot::Mutex;
+use time::{Duration, OffsetDateTime};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::server::random_token;
+
+pub const SESSION_COOKIE: &str = "bathbot_dashboard";
+pub const SESSION_DURATION: Duration = Duration::days(1);
+
+/// Binds a pending OAuth state to the browser that started the login
+pub const STATE_COOKIE: &str = "bathbot_dashboard_state";
+pub const STATE_DURATION: Duration = Duration::minutes(10);
+
+/// Logins beyond this many pending ones evict the oldest pending login
+const MAX_PENDING_STATES: usize = 1000;
+
+#[derive(Clone)]
+pub struct DashboardSession {
+    pub user_id: Id<UserMarker>,
+    pub username: String,
+    /// All guilds the user was in when logging in
+    pub guilds: Vec<Id<GuildMarker>>,
+    /// Token that must be sent along all forms of this session
+    pub csrf: String,
+    expires_at: OffsetDateTime,
+}
+
+/// Logged in dashboard users and pending OAuth states
+#[derive(Default)]
+pub struct DashboardSessions {
+    sessions: Mutex<HashMap<String, DashboardSession>>,
+    states: Mutex<HashMap<String, OffsetDateTime>>,
+}
+
+impl DashboardSessions {
+    /// Generate a state to be passed along the OAuth authorization
+    pub fn new_state(&self) -> String {
+        let state = random_token();
+        let now = OffsetDateTime::now_utc();
+
+        let mut states = self.states.lock();
+        states.retain(|_, expires_at| *expires_at > now);
+
+        if states.len() >= MAX_PENDING_STATES {
+            let oldest = states
+                .iter()
+                .min_by_key(|(_, expires_at)| **expires_at)
+                .map(|(state, _)| state.to_owned());
+
+            if let Some(oldest) = oldest {
+                states.remove(&oldest);
+            }
+        }
+
+        states.insert(state.clone(), now + STATE_DURATION);
+
+        state
+    }
+
+    /// Returns whether the state was generated by [`DashboardSessions::new_state`],
+    /// has not expired yet, and belongs to the browser that started the login.
+    pub fn consume_state(&self, req: &Request<Body>, state: &str) -> bool {
+        if cookie(req, STATE_COOKIE) != Some(state) {
+            return false;
+        }
+
+        self.states
+            .lock()
+            .remove(state)
+            .map_or(false, |expires_at| expires_at > OffsetDateTime::now_utc())
+    }
+
+    /// Store a new session and return its id
+    pub fn insert(
+        &self,
+        user_id: Id<UserMarker>,
+        username: String,
+        guilds: Vec<Id<GuildMarker>>,
+    ) -> String {
+        let session_id = random_token();
+        let now = OffsetDateTime::now_utc();
+
+        let session = DashboardSession {
+            user_id,
+            username,
+            guilds,
+            csrf: random_token(),
+            expires_at: now + SESSION_DURATION,
+        };
+
+        let mut sessions = self.sessions.lock();
+        sessions.retain(|_, session| session.expires_at > now);
+        sessions.insert(session_id.clone(), session);
+
+        session_id
+    }
+
+    /// Get the session belonging to the request's cookie
+    pub fn get(&self, req: &Request<Body>) -> Option<DashboardSession> {
+        let session_id = cookie(req, SESSION_COOKIE)?;
+        let mut sessions = self.sessions.lock();
+
+        match sessions.get(session_id) {
+            Some(session) if session.expires_at > OffsetDateTime::now_utc() => {
+                Some(session.to_owned())
+            }
+            Some(_) => {
+                sessions.remove(session_id);
+
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn remove(&self, req: &Request<Body>) {
+        if let Some(session_id) = cookie(req, SESSION_COOKIE) {
+            self.sessions.lock().remove(session_id);
+        }
+    }
+}
+
+fn cookie<'r>(req: &'r Request<Body>, name: &str) -> Option<&'r str> {
+    req.headers()
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|header| header.to_str().ok())
+        .flat_map(|header| header.split(';'))
+        .filter_map(|cookie| cookie.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value)
+}
@@ -0,0 +1,227 @@
+use eyre::{Result, WrapErr};
+use hyper::{Body, Request, Response, StatusCode};
+use rosu_v2::prelude::GameMode;
+use routerify::ext::RequestExt;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{
+    commands::osu::ProfileSize,
+    database::{EmbedsSize, ListSize, MinimizedPp, UserConfig},
+    Context,
+};
+
+use super::{
+    form_value, parse_form, plain_response, redirect, select_options, Context_, DashboardSessions,
+    Handlebars_, HandlerResult,
+};
+
+pub async fn dashboard_user_handler(req: Request<Body>) -> HandlerResult {
+    let sessions: &DashboardSessions = req.data().unwrap();
+
+    let session = match sessions.get(&req) {
+        Some(session) => session,
+        None => return redirect("/dashboard/login"),
+    };
+
+    #[derive(Deserialize)]
+    struct MessageQuery {
+        message: Option<String>,
+    }
+
+    let message = req
+        .uri()
+        .query()
+        .and_then(|query| serde_urlencoded::from_str::<MessageQuery>(query).ok())
+        .and_then(|query| query.message);
+
+    let Context_(ctx) = req.data().unwrap();
+
+    let config = ctx
+        .psql()
+        .get_user_config(session.user_id)
+        .await
+        .wrap_err("failed to get user config")?
+        .unwrap_or_default();
+
+    let twitch_name = match config.twitch_id {
+        Some(twitch_id) => match ctx.client().get_twitch_user_by_id(twitch_id).await {
+            Ok(user) => user.map(|user| user.display_name),
+            Err(err) => {
+                warn!("{:?}", err.wrap_err("Failed to get twitch user by id"));
+
+                Some(twitch_id.to_string())
+            }
+        },
+        None => None,
+    };
+
+    let config_data = json!({
+        "mode": mode_options(config.mode),
+        "profile_size": select_options(
+            config.profile_size.unwrap_or_default() as u8,
+            &[(0, "Compact"), (1, "Medium"), (2, "Full")],
+        ),
+        "score_size": select_options(
+            config.embeds_size() as u8,
+            &[(0, "Always minimized"), (1, "Initial maximized"), (2, "Always maximized")],
+        ),
+        "list_size": select_options(
+            config.list_size() as u8,
+            &[(0, "Condensed"), (1, "Detailed"), (2, "Single")],
+        ),
+        "minimized_pp": select_options(
+            config.minimized_pp() as u8,
+            &[(0, "If FC"), (1, "Max")],
+        ),
+        "show_retries": config.show_retries.unwrap_or(true),
+    });
+
+    let render_data = json!({
+        "user": session.username,
+        "csrf": session.csrf,
+        "message": message,
+        "osu": config.username().map(|name| name.as_str()),
+        "twitch": twitch_name,
+        "config": config_data,
+    });
+
+    let Handlebars_(handlebars) = req.data().unwrap();
+
+    let page = handlebars
+        .render("dashboard_user", &render_data)
+        .wrap_err("failed to render dashboard user page")?;
+
+    Ok(Response::new(Body::from(page)))
+}
+
+pub async fn dashboard_user_config_handler(mut req: Request<Body>) -> HandlerResult {
+    let sessions: &DashboardSessions = req.data().unwrap();
+
+    let session = match sessions.get(&req) {
+        Some(session) => session,
+        None => return redirect("/dashboard/login"),
+    };
+
+    let form = match parse_form(&mut req, &session).await {
+        Ok(form) => form,
+        Err(response) => return Ok(response),
+    };
+
+    let Context_(ctx) = req.data().unwrap();
+
+    let mut config = ctx
+        .psql()
+        .get_user_config(session.user_id)
+        .await
+        .wrap_err("failed to get user config")?
+        .unwrap_or_default();
+
+    let parse_i16 = |key: &str| form_value(&form, key).and_then(|value| value.parse::<i16>().ok());
+
+    match form_value(&form, "mode") {
+        Some("none") => config.mode = None,
+        Some(mode) => match mode.parse::<u8>() {
+            Ok(mode @ 0..=3) => config.mode = Some(GameMode::from(mode)),
+            _ => return user_redirect("Invalid mode"),
+        },
+        None => {}
+    }
+
+    if let Some(size) = parse_i16("profile_size") {
+        config.profile_size = Some(ProfileSize::from(size));
+    }
+
+    if let Some(size) = parse_i16("score_size") {
+        config.score_size = Some(EmbedsSize::from(size));
+    }
+
+    if let Some(size) = parse_i16("list_size") {
+        config.list_size = Some(ListSize::from(size));
+    }
+
+    if let Some(pp) = parse_i16("minimized_pp") {
+        config.minimized_pp = Some(MinimizedPp::from(pp));
+    }
+
+    // Unchecked checkboxes are not part of the form
+    config.show_retries = Some(form_value(&form, "show_retries").is_some());
+
+    store_config(ctx, session.user_id, &config).await?;
+
+    user_redirect("Configuration updated")
+}
+
+pub async fn dashboard_user_unlink_handler(mut req: Request<Body>) -> HandlerResult {
+    let sessions: &DashboardSessions = req.data().unwrap();
+
+    let session = match sessions.get(&req) {
+        Some(session) => session,
+        None => return redirect("/dashboard/login"),
+    };
+
+    let form = match parse_form(&mut req, &session).await {
+        Ok(form) => form,
+        Err(response) => return Ok(response),
+    };
+
+    let Context_(ctx) = req.data().unwrap();
+
+    let mut config = ctx
+        .psql()
+        .get_user_config(session.user_id)
+        .await
+        .wrap_err("failed to get user config")?
+        .unwrap_or_default();
+
+    let content = match form_value(&form, "account") {
+        Some("osu") => match config.osu.take() {
+            Some(_) => "Unlinked osu! profile",
+            None => return user_redirect("No osu! profile was linked"),
+        },
+        Some("twitch") => match config.twitch_id.take() {
+            Some(_) => "Unlinked twitch channel",
+            None => return user_redirect("No twitch channel was linked"),
+        },
+        _ => return plain_response(StatusCode::BAD_REQUEST, "Invalid account"),
+    };
+
+    store_config(ctx, session.user_id, &config).await?;
+
+    user_redirect(content)
+}
+
+async fn store_config(ctx: &Context, user: Id<UserMarker>, config: &UserConfig) -> Result<()> {
+    ctx.psql()
+        .insert_user_config(user, config)
+        .await
+        .wrap_err("failed to insert user config")
+}
+
+fn mode_options(current: Option<GameMode>) -> Vec<Value> {
+    let modes = [
+        ("none", "None", None),
+        ("0", "osu!", Some(GameMode::Osu)),
+        ("1", "Taiko", Some(GameMode::Taiko)),
+        ("2", "Catch", Some(GameMode::Catch)),
+        ("3", "Mania", Some(GameMode::Mania)),
+    ];
+
+    modes
+        .into_iter()
+        .map(|(value, name, mode)| {
+            json!({
+                "value": value,
+                "name": name,
+                "selected": mode == current,
+            })
+        })
+        .collect()
+}
+
+fn user_redirect(message: &str) -> HandlerResult {
+    let query = serde_urlencoded::to_string(&[("message", message)])?;
+
+    redirect(&format!("/dashboard/user?{query}"))
+}
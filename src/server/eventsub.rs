@@ -0,0 +1,404 @@
+#![cfg(feature = "twitchtracking")]
+
+use std::sync::Arc;
+
+use eyre::{Result, WrapErr};
+use hashbrown::{HashMap, HashSet};
+use hmac::{Hmac, Mac};
+use hyper::{header::CONTENT_TYPE, Body, HeaderMap, Request, Response, StatusCode};
+use parking_lot::Mutex;
+use routerify::ext::RequestExt;
+use serde::Deserialize;
+use sha2::Sha256;
+use time::{format_description::well_known::Rfc3339, Duration, OffsetDateTime};
+use tokio::time::{sleep, Duration as StdDuration};
+
+use crate::{
+    core::BotConfig,
+    custom_client::{TwitchStream, TwitchSubscription},
+    tracking::notify_streams,
+    util::hasher::IntHasher,
+    Context,
+};
+
+use super::{Context_, HandlerResult};
+
+const MESSAGE_ID: &str = "Twitch-Eventsub-Message-Id";
+const MESSAGE_TIMESTAMP: &str = "Twitch-Eventsub-Message-Timestamp";
+const MESSAGE_SIGNATURE: &str = "Twitch-Eventsub-Message-Signature";
+const MESSAGE_TYPE: &str = "Twitch-Eventsub-Message-Type";
+
+/// Messages older than this are rejected to prevent replay attacks
+const MESSAGE_MAX_AGE: Duration = Duration::minutes(10);
+
+/// The stream endpoint might not list a stream right after it went online
+const STREAM_RETRIES: usize = 4;
+const STREAM_RETRY_DELAY: StdDuration = StdDuration::from_secs(30);
+
+/// Ids of recently received messages since twitch might send a message multiple times
+#[derive(Default)]
+pub struct EventSubMessages {
+    ids: Mutex<HashMap<String, OffsetDateTime>>,
+}
+
+impl EventSubMessages {
+    /// Returns `false` if the message was already received
+    fn insert(&self, id: String) -> bool {
+        let now = OffsetDateTime::now_utc();
+
+        let mut ids = self.ids.lock();
+        ids.retain(|_, received_at| now - *received_at < MESSAGE_MAX_AGE);
+
+        ids.insert(id, now).is_none()
+    }
+}
+
+#[derive(Deserialize)]
+struct EventSubPayload {
+    challenge: Option<String>,
+    subscription: TwitchSubscription,
+}
+
+pub async fn eventsub_handler(mut req: Request<Body>) -> HandlerResult {
+    let bytes = hyper::body::to_bytes(req.body_mut())
+        .await
+        .wrap_err("failed to receive eventsub body")?;
+
+    let headers = req.headers();
+    let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+
+    let (id, timestamp, kind) = match (
+        header(MESSAGE_ID),
+        header(MESSAGE_TIMESTAMP),
+        header(MESSAGE_TYPE),
+    ) {
+        (Some(id), Some(timestamp), Some(kind)) => (id, timestamp, kind),
+        _ => return plain_response(StatusCode::BAD_REQUEST, "Missing eventsub headers"),
+    };
+
+    let secret = match BotConfig::get().tokens.twitch_eventsub_secret {
+        Some(ref secret) => secret,
+        None => return plain_response(StatusCode::NOT_FOUND, "EventSub is disabled"),
+    };
+
+    if !verify_signature(secret, headers, id, timestamp, &bytes) {
+        return plain_response(StatusCode::FORBIDDEN, "Invalid signature");
+    }
+
+    match OffsetDateTime::parse(timestamp, &Rfc3339) {
+        Ok(timestamp) if OffsetDateTime::now_utc() - timestamp < MESSAGE_MAX_AGE => {}
+        _ => return plain_response(StatusCode::FORBIDDEN, "Invalid timestamp"),
+    }
+
+    let messages: &EventSubMessages = req.data().unwrap();
+
+    // Already processed, just acknowledge it again
+    if !messages.insert(id.to_owned()) {
+        return plain_response(StatusCode::NO_CONTENT, "");
+    }
+
+    let payload: EventSubPayload = match serde_json::from_slice(&bytes) {
+        Ok(payload) => payload,
+        Err(_) => return plain_response(StatusCode::BAD_REQUEST, "Invalid payload"),
+    };
+
+    let subscription = &payload.subscription;
+    let user_id = subscription.condition.broadcaster_user_id;
+
+    match kind {
+        "webhook_callback_verification" => {
+            let challenge = payload.challenge.unwrap_or_default();
+            debug!("Verified eventsub subscription for twitch user {user_id}");
+
+            let response = Response::builder()
+                .header(CONTENT_TYPE, "text/plain")
+                .body(Body::from(challenge))?;
+
+            return Ok(response);
+        }
+        "notification" if subscription.kind == "stream.online" => {
+            let Context_(ctx) = req.data().unwrap();
+            tokio::spawn(process_stream_online(Arc::clone(ctx), user_id));
+        }
+        "notification" => {}
+        "revocation" => warn!(
+            "Eventsub subscription for twitch user {user_id} was revoked: {}",
+            subscription.status
+        ),
+        _ => warn!("Received unknown eventsub message type `{kind}`"),
+    }
+
+    plain_response(StatusCode::NO_CONTENT, "")
+}
+
+fn verify_signature(
+    secret: &str,
+    headers: &HeaderMap,
+    id: &str,
+    timestamp: &str,
+    body: &[u8],
+) -> bool {
+    let signature = headers
+        .get(MESSAGE_SIGNATURE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("sha256="))
+        .and_then(|value| hex::decode(value).ok());
+
+    let signature = match signature {
+        Some(signature) => signature,
+        None => return false,
+    };
+
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take keys of any size");
+
+    mac.update(id.as_bytes());
+    mac.update(timestamp.as_bytes());
+    mac.update(body);
+
+    mac.verify_slice(&signature).is_ok()
+}
+
+async fn process_stream_online(ctx: Arc<Context>, user_id: u64) {
+    let tracked = ctx
+        .tracked_channels_for(user_id)
+        .map_or(false, |channels| !channels.is_empty());
+
+    // Only notify once, either through eventsub or through the tracking loop
+    if !tracked || !ctx.set_stream_online(user_id) {
+        return;
+    }
+
+    for _ in 0..STREAM_RETRIES {
+        match ctx.client().get_twitch_streams(&[user_id]).await {
+            Ok(mut streams) => {
+                streams.retain(TwitchStream::is_live);
+
+                if !streams.is_empty() {
+                    // Refresh the mark in case a tracking loop poll
+                    // started before the stream was listed as live
+                    ctx.set_stream_online(user_id);
+
+                    if let Err(err) = notify_streams(&ctx, streams).await {
+                        warn!("{:?}", err.wrap_err("Failed to notify streams"));
+                    }
+
+                    return;
+                }
+            }
+            Err(err) => warn!("{:?}", err.wrap_err("Failed to retrieve stream")),
+        }
+
+        sleep(STREAM_RETRY_DELAY).await;
+    }
+
+    // Let the tracking loop handle it instead
+    ctx.set_stream_offline(user_id);
+}
+
+fn eventsub_enabled() -> bool {
+    BotConfig::get().tokens.twitch_eventsub_secret.is_some()
+}
+
+/// Subscribe to `stream.online` events of the twitch user
+pub async fn subscribe_stream(ctx: &Context, user_id: u64) {
+    if !eventsub_enabled() {
+        return;
+    }
+
+    if let Err(err) = ctx.client().subscribe_twitch_stream(user_id).await {
+        let wrap = format!("Failed to subscribe to stream of twitch user {user_id}");
+        warn!("{:?}", err.wrap_err(wrap));
+    }
+}
+
+/// Unsubscribe from `stream.online` events of the twitch user
+/// if the user is no longer tracked in any channel
+pub async fn unsubscribe_stream(ctx: &Context, user_id: u64) {
+    let tracked = ctx
+        .tracked_channels_for(user_id)
+        .map_or(false, |channels| !channels.is_empty());
+
+    if tracked || !eventsub_enabled() {
+        return;
+    }
+
+    if let Err(err) = unsubscribe_stream_(ctx, user_id).await {
+        let wrap = format!("Failed to unsubscribe from stream of twitch user {user_id}");
+        warn!("{:?}", err.wrap_err(wrap));
+    }
+}
+
+async fn unsubscribe_stream_(ctx: &Context, user_id: u64) -> Result<()> {
+    let subscriptions = ctx
+        .client()
+        .get_twitch_subscriptions()
+        .await
+        .wrap_err("failed to get subscriptions")?;
+
+    let matching = subscriptions
+        .iter()
+        .filter(|subscription| subscription.condition.broadcaster_user_id == user_id);
+
+    for subscription in matching {
+        ctx.client()
+            .delete_twitch_subscription(&subscription.id)
+            .await
+            .wrap_err("failed to delete subscription")?;
+    }
+
+    Ok(())
+}
+
+/// Align eventsub subscriptions with the currently tracked streams
+pub async fn sync_subscriptions(ctx: Arc<Context>) {
+    if !eventsub_enabled() {
+        info!("No eventsub secret set, stream notifications only come from polling");
+
+        return;
+    }
+
+    if let Err(err) = sync_subscriptions_(&ctx).await {
+        warn!(
+            "{:?}",
+            err.wrap_err("Failed to sync eventsub subscriptions")
+        );
+    }
+}
+
+async fn sync_subscriptions_(ctx: &Context) -> Result<()> {
+    let subscriptions = ctx
+        .client()
+        .get_twitch_subscriptions()
+        .await
+        .wrap_err("failed to get subscriptions")?;
+
+    let tracked: HashSet<_, IntHasher> = ctx
+        .tracked_users()
+        .into_iter()
+        .filter(|&user_id| {
+            ctx.tracked_channels_for(user_id)
+                .map_or(false, |channels| !channels.is_empty())
+        })
+        .collect();
+
+    let mut subscribed = HashSet::with_hasher(IntHasher);
+
+    for subscription in subscriptions {
+        let user_id = subscription.condition.broadcaster_user_id;
+
+        // Subscriptions that failed verification or got revoked must be recreated
+        if subscription.status == "enabled" && tracked.contains(&user_id) {
+            subscribed.insert(user_id);
+
+            continue;
+        }
+
+        ctx.client()
+            .delete_twitch_subscription(&subscription.id)
+            .await
+            .wrap_err("failed to delete subscription")?;
+    }
+
+    let missing: Vec<_> = tracked.difference(&subscribed).copied().collect();
+    let count = missing.len();
+
+    for user_id in missing {
+        subscribe_stream(ctx, user_id).await;
+    }
+
+    info!("Synced eventsub subscriptions, subscribed to {count} new streams");
+
+    Ok(())
+}
+
+fn plain_response(status: StatusCode, content: &'static str) -> HandlerResult {
+    let response = Response::builder()
+        .status(status)
+        .body(Body::from(content))?;
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::header::{HeaderName, HeaderValue};
+
+    use super::*;
+
+    const SECRET: &str = "0123456789abcdef";
+    const ID: &str = "e76c6bd4-55c9-4987-8304-da1588d8988b";
+    const TIMESTAMP: &str = "2022-07-25T12:34:56.789Z";
+    const BODY: &[u8] = br#"{"subscription":{"type":"stream.online"}}"#;
+
+    fn signature(secret: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(ID.as_bytes());
+        mac.update(TIMESTAMP.as_bytes());
+        mac.update(BODY);
+
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    fn signature_headers(signature: &str) -> HeaderMap {
+        let name = HeaderName::from_bytes(MESSAGE_SIGNATURE.as_bytes()).unwrap();
+        let value = HeaderValue::from_str(signature).unwrap();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(name, value);
+
+        headers
+    }
+
+    #[test]
+    fn test_valid_signature() {
+        let headers = signature_headers(&format!("sha256={}", signature(SECRET)));
+
+        assert!(verify_signature(SECRET, &headers, ID, TIMESTAMP, BODY));
+    }
+
+    #[test]
+    fn test_invalid_signature() {
+        let headers = signature_headers(&format!("sha256={}", signature(SECRET)));
+
+        assert!(!verify_signature(
+            "0123456789abcdeg",
+            &headers,
+            ID,
+            TIMESTAMP,
+            BODY
+        ));
+        assert!(!verify_signature(
+            SECRET, &headers, "other id", TIMESTAMP, BODY
+        ));
+        assert!(!verify_signature(SECRET, &headers, ID, TIMESTAMP, b"{}"));
+
+        let headers = signature_headers(&format!("sha256={}", signature("0123456789abcdeg")));
+        assert!(!verify_signature(SECRET, &headers, ID, TIMESTAMP, BODY));
+    }
+
+    #[test]
+    fn test_malformed_signature() {
+        assert!(!verify_signature(
+            SECRET,
+            &HeaderMap::new(),
+            ID,
+            TIMESTAMP,
+            BODY
+        ));
+        assert!(!verify_signature(
+            SECRET,
+            &signature_headers(&signature(SECRET)),
+            ID,
+            TIMESTAMP,
+            BODY
+        ));
+        assert!(!verify_signature(
+            SECRET,
+            &signature_headers("sha256=xyz"),
+            ID,
+            TIMESTAMP,
+            BODY
+        ));
+    }
+}
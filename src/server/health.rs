@@ -0,0 +1,265 @@
+use std::future::Future;
+
+use eyre::{Report, Result};
+use hyper::{header::CONTENT_TYPE, Body, Request, Response, StatusCode};
+use parking_lot::Mutex;
+use routerify::ext::RequestExt;
+use serde_json::{json, Map, Value};
+use time::{Duration, OffsetDateTime};
+use tokio::time::{timeout, Duration as StdDuration};
+use twilight_gateway::shard::Stage;
+
+use crate::{core::OsuFutureExt, Context};
+
+use super::{Context_, HandlerResult};
+
+/// How long a single subsystem check may take before it counts as failed
+const CHECK_TIMEOUT: StdDuration = StdDuration::from_secs(5);
+
+/// Result of the osu!api check is reused for this long so probes don't spam the api
+const OSU_CHECK_DURATION: Duration = Duration::minutes(1);
+
+/// The twitch loop ticks every 10 minutes
+#[cfg(feature = "twitchtracking")]
+const TWITCH_LOOP_MAX_AGE: Duration = Duration::minutes(25);
+
+/// Cached result of the last osu!api check
+#[derive(Default)]
+pub struct OsuApiCheck(Mutex<Option<(OffsetDateTime, Check)>>);
+
+#[derive(Clone)]
+struct Check {
+    ok: bool,
+    details: Value,
+    /// Failures of external services are reported but don't make the bot unhealthy
+    external: bool,
+}
+
+impl Check {
+    fn ok(details: Value) -> Self {
+        Self {
+            ok: true,
+            details,
+            external: false,
+        }
+    }
+
+    fn failed(details: Value) -> Self {
+        Self {
+            ok: false,
+            details,
+            external: false,
+        }
+    }
+
+    fn external(self) -> Self {
+        Self {
+            external: true,
+            ..self
+        }
+    }
+
+    async fn from_future(fut: impl Future<Output = Result<()>>) -> Self {
+        match timeout(CHECK_TIMEOUT, fut).await {
+            Ok(Ok(_)) => Self::ok(Value::Null),
+            Ok(Err(err)) => Self::failed(json!({ "error": format!("{err:#}") })),
+            Err(_) => Self::failed(json!({ "error": "timed out" })),
+        }
+    }
+}
+
+/// Whether the bot can handle events i.e. all shards are connected
+/// and both postgres and redis are reachable
+pub async fn ready_handler(req: Request<Body>) -> HandlerResult {
+    let Context_(ctx) = req.data().unwrap();
+
+    let (postgres, redis) = tokio::join!(check_postgres(ctx), check_redis(ctx));
+
+    let checks = [
+        ("shards", check_shards(ctx)),
+        ("postgres", postgres),
+        ("redis", redis),
+    ];
+
+    checks_response(checks)
+}
+
+/// Full report of all subsystems, including external apis and background loops.
+///
+/// External apis being down only degrades the status but still responds with 200
+/// since restarting the bot wouldn't fix them.
+pub async fn health_handler(req: Request<Body>) -> HandlerResult {
+    let Context_(ctx) = req.data().unwrap();
+    let osu_check: &OsuApiCheck = req.data().unwrap();
+
+    let (postgres, redis, osu) = tokio::join!(
+        check_postgres(ctx),
+        check_redis(ctx),
+        check_osu(ctx, osu_check)
+    );
+
+    #[allow(unused_mut)]
+    let mut checks = vec![
+        ("shards", check_shards(ctx)),
+        ("postgres", postgres),
+        ("redis", redis),
+        ("osu_api", osu.external()),
+    ];
+
+    #[cfg(feature = "osutracking")]
+    checks.push(("osu_tracking", check_osu_tracking(ctx).await));
+
+    #[cfg(feature = "twitchtracking")]
+    checks.push(("twitch_tracking", check_twitch_tracking(ctx)));
+
+    checks_response(checks)
+}
+
+fn checks_response(checks: impl IntoIterator<Item = (&'static str, Check)>) -> HandlerResult {
+    let mut healthy = true;
+    let mut degraded = false;
+    let mut map = Map::new();
+
+    for (name, check) in checks {
+        if check.external {
+            degraded |= !check.ok;
+        } else {
+            healthy &= check.ok;
+        }
+
+        let value = json!({
+            "healthy": check.ok,
+            "external": check.external,
+            "details": check.details,
+        });
+
+        map.insert(name.to_owned(), value);
+    }
+
+    let (status, status_str) = match (healthy, degraded) {
+        (true, false) => (StatusCode::OK, "healthy"),
+        (true, true) => (StatusCode::OK, "degraded"),
+        (false, _) => (StatusCode::SERVICE_UNAVAILABLE, "unhealthy"),
+    };
+
+    let body = json!({
+        "status": status_str,
+        "checks": map,
+    });
+
+    let response = Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))?;
+
+    Ok(response)
+}
+
+fn check_shards(ctx: &Context) -> Check {
+    let mut shards: Vec<_> = ctx
+        .cluster
+        .info()
+        .into_iter()
+        .map(|(id, info)| (id, info.stage()))
+        .collect();
+
+    shards.sort_unstable_by_key(|(id, _)| *id);
+
+    let connected = shards
+        .iter()
+        .filter(|(_, stage)| matches!(stage, Stage::Connected))
+        .count();
+
+    let stages: Map<_, _> = shards
+        .iter()
+        .map(|(id, stage)| (id.to_string(), json!(format!("{stage:?}"))))
+        .collect();
+
+    let details = json!({
+        "total": shards.len(),
+        "connected": connected,
+        "stages": stages,
+    });
+
+    if !shards.is_empty() && connected == shards.len() {
+        Check::ok(details)
+    } else {
+        Check::failed(details)
+    }
+}
+
+async fn check_postgres(ctx: &Context) -> Check {
+    Check::from_future(ctx.psql().ping()).await
+}
+
+async fn check_redis(ctx: &Context) -> Check {
+    let fut = async {
+        let mut conn = ctx.redis_client().get().await?;
+
+        bb8_redis::redis::cmd("PING")
+            .query_async::<_, String>(&mut *conn)
+            .await?;
+
+        Ok::<_, Report>(())
+    };
+
+    Check::from_future(fut).await
+}
+
+async fn check_osu(ctx: &Context, osu_check: &OsuApiCheck) -> Check {
+    let now = OffsetDateTime::now_utc();
+
+    if let Some((checked_at, check)) = osu_check.0.lock().as_ref() {
+        if now - *checked_at < OSU_CHECK_DURATION {
+            return check.to_owned();
+        }
+    }
+
+    let fut = async {
+        ctx.osu().user(2_u32).observe(&ctx.stats, "user").await?;
+
+        Ok::<_, Report>(())
+    };
+
+    let check = Check::from_future(fut).await;
+    *osu_check.0.lock() = Some((now, check.clone()));
+
+    check
+}
+
+#[cfg(feature = "osutracking")]
+async fn check_osu_tracking(ctx: &Context) -> Check {
+    let stats = ctx.tracking().stats().await;
+    let since_pop = OffsetDateTime::now_utc() - stats.last_pop;
+
+    let details = json!({
+        "tracking": stats.tracking,
+        "queue": stats.queue,
+        "last_pop": stats.last_pop.unix_timestamp(),
+    });
+
+    // Each user is popped once per interval so with a non-empty
+    // queue, the last pop can't be longer ago than the interval
+    let stalled = stats.tracking && stats.queue > 0 && since_pop.whole_seconds() > stats.interval;
+
+    if stalled {
+        Check::failed(details)
+    } else {
+        Check::ok(details)
+    }
+}
+
+#[cfg(feature = "twitchtracking")]
+fn check_twitch_tracking(ctx: &Context) -> Check {
+    let last_tick = ctx.last_twitch_loop_tick();
+
+    let details = json!({
+        "last_tick": last_tick.unix_timestamp(),
+    });
+
+    if OffsetDateTime::now_utc() - last_tick < TWITCH_LOOP_MAX_AGE {
+        Check::ok(details)
+    } else {
+        Check::failed(details)
+    }
+}
@@ -17,6 +17,7 @@ use hyper::{
 };
 use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
 use prometheus::{Encoder, TextEncoder};
+use rand::{distributions::Alphanumeric, Rng};
 use rosu_v2::Osu;
 use routerify::{ext::RequestExt, RouteError, Router, RouterService};
 use serde_json::json;
@@ -29,16 +30,39 @@ use crate::{
     Context,
 };
 
-pub use self::auth::{
-    AuthenticationStandby, AuthenticationStandbyError, WaitForOsuAuth, WaitForTwitchAuth,
+pub use self::{
+    auth::{AuthenticationStandby, AuthenticationStandbyError, WaitForOsuAuth, WaitForTwitchAuth},
+    tables::WebTables,
 };
 
+#[cfg(feature = "twitchtracking")]
+pub use self::eventsub::{subscribe_stream, unsubscribe_stream};
+
+use self::{
+    api::*,
+    dashboard::*,
+    health::{health_handler, ready_handler, OsuApiCheck},
+    tables::table_handler,
+};
+
+#[cfg(feature = "twitchtracking")]
+use self::eventsub::{eventsub_handler, sync_subscriptions, EventSubMessages};
+
+mod api;
 mod auth;
+mod dashboard;
+mod eventsub;
+mod health;
+mod tables;
 
 pub async fn run_server(ctx: Arc<Context>, shutdown_rx: Receiver<()>) {
     let ip = BotConfig::get().server.internal_ip;
     let port = BotConfig::get().server.internal_port;
     let addr = SocketAddr::from((ip, port));
+
+    #[cfg(feature = "twitchtracking")]
+    tokio::spawn(sync_subscriptions(Arc::clone(&ctx)));
+
     let router = router(ctx);
 
     let service = RouterService::new(router).expect("failed to create RouterService");
@@ -61,6 +85,10 @@ struct Client(HyperClient<HttpsConnector<HttpConnector<GaiResolver>>, Body>);
 struct Context_(Arc<Context>);
 struct Handlebars_(Handlebars<'static>);
 
+struct DiscordClientId(u64);
+struct DiscordClientSecret(String);
+struct DiscordRedirect(String);
+
 struct OsuClientId(u64);
 struct OsuClientSecret(String);
 struct OsuRedirect(String);
@@ -79,6 +107,9 @@ fn router(ctx: Arc<Context>) -> Router<Body, Report> {
     let client = HyperClient::builder().build(connector);
     let config = BotConfig::get();
 
+    let discord_client_id = config.tokens.discord_client_id;
+    let discord_client_secret = config.tokens.discord_client_secret.to_owned();
+
     let osu_client_id = config.tokens.osu_client_id;
     let osu_client_secret = config.tokens.osu_client_secret.to_owned();
 
@@ -86,6 +117,7 @@ fn router(ctx: Arc<Context>) -> Router<Body, Report> {
     let twitch_client_secret = config.tokens.twitch_token.to_owned();
 
     let url = &config.server.external_url;
+    let discord_redirect = format!("{url}/dashboard/auth");
     let osu_redirect = format!("{url}/auth/osu");
     let twitch_redirect = format!("{url}/auth/twitch");
 
@@ -97,23 +129,101 @@ fn router(ctx: Arc<Context>) -> Router<Body, Report> {
         .register_template_file("auth", path)
         .expect("failed to register auth template to handlebars");
 
-    Router::builder()
+    handlebars
+        .register_partial(
+            "dashboard_style",
+            include_str!("../../templates/dashboard_style.hbs"),
+        )
+        .expect("failed to register dashboard style partial to handlebars");
+
+    let templates = [
+        ("dashboard", include_str!("../../templates/dashboard.hbs")),
+        (
+            "dashboard_guild",
+            include_str!("../../templates/dashboard_guild.hbs"),
+        ),
+        (
+            "dashboard_user",
+            include_str!("../../templates/dashboard_user.hbs"),
+        ),
+        ("table", include_str!("../../templates/table.hbs")),
+    ];
+
+    for (name, template) in templates {
+        if let Err(err) = handlebars.register_template_string(name, template) {
+            panic!("failed to register {name} template to handlebars: {err}");
+        }
+    }
+
+    let builder = Router::builder()
+        .data(ApiRatelimits::default())
         .data(Client(client))
         .data(Context_(ctx))
+        .data(DashboardSessions::default())
+        .data(DiscordClientId(discord_client_id))
+        .data(DiscordClientSecret(discord_client_secret))
+        .data(DiscordRedirect(discord_redirect))
         .data(Handlebars_(handlebars))
         .data(OsuClientId(osu_client_id))
+        .data(OsuApiCheck::default())
         .data(OsuClientSecret(osu_client_secret))
         .data(OsuRedirect(osu_redirect))
         .data(TwitchClientId(twitch_client_id))
         .data(TwitchClientSecret(twitch_client_secret))
         .data(TwitchRedirect(twitch_redirect))
         .get("/metrics", metrics_handler)
+        .get("/health", health_handler)
+        .get("/ready", ready_handler)
         .get("/guild_count", guild_count_handler)
         .get("/auth/osu", auth_osu_handler)
         .get("/auth/twitch", auth_twitch_handler)
         .get("/auth/auth.css", auth_css_handler)
         .get("/auth/icon.svg", auth_icon_handler)
         .get("/osudirect/:mapset_id", osudirect_handler)
+        .get("/tables/:token", table_handler)
+        .get("/dashboard", dashboard_handler)
+        .get("/dashboard/login", dashboard_login_handler)
+        .get("/dashboard/auth", dashboard_auth_handler)
+        .post("/dashboard/logout", dashboard_logout_handler)
+        .get("/dashboard/user", dashboard_user_handler)
+        .post("/dashboard/user/config", dashboard_user_config_handler)
+        .post("/dashboard/user/unlink", dashboard_user_unlink_handler)
+        .get("/dashboard/:guild_id", dashboard_guild_handler)
+        .post("/dashboard/:guild_id/config", dashboard_config_handler)
+        .post(
+            "/dashboard/:guild_id/twitch_tracking",
+            dashboard_twitch_tracking_handler,
+        )
+        .post(
+            "/dashboard/:guild_id/role_assign",
+            dashboard_role_assign_handler,
+        )
+        .get("/api/v1/approx/rank", approx_rank_handler)
+        .get("/api/v1/approx/pp", approx_pp_handler)
+        .get(
+            "/api/v1/guilds/:guild_id/leaderboard/:kind",
+            guild_leaderboard_handler,
+        )
+        .get("/api/v1/games/bg/leaderboard", bg_leaderboard_handler)
+        .get(
+            "/api/v1/games/higherlower/:version/leaderboard",
+            hl_leaderboard_handler,
+        );
+
+    #[cfg(feature = "osutracking")]
+    let builder = builder
+        .get("/api/v1/users/:user_id/tracking", tracking_handler)
+        .post(
+            "/dashboard/:guild_id/osu_tracking",
+            dashboard_osu_tracking_handler,
+        );
+
+    #[cfg(feature = "twitchtracking")]
+    let builder = builder
+        .data(EventSubMessages::default())
+        .post("/twitch/eventsub", eventsub_handler);
+
+    builder
         .any(handle_404)
         .err_handler(error_handler)
         .build()
@@ -148,6 +258,14 @@ async fn error_handler(err: RouteError) -> Response<Body> {
 
 type HandlerResult = Result<Response<Body>>;
 
+fn random_token() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect()
+}
+
 async fn handle_404(_req: Request<Body>) -> HandlerResult {
     let response = Response::builder()
         .status(StatusCode::NOT_FOUND)
@@ -208,22 +326,17 @@ async fn auth_osu_handler_(req: &Request<Body>) -> HandlerResult {
         None => return invalid_auth_query(req),
     };
 
-    let id_opt = query.and_then(|q| {
-        q.split('&')
-            .find(|q| q.starts_with("state="))
-            .map(|q| q[6..].parse())
-    });
-
-    let id = match id_opt {
-        Some(Ok(state)) => state,
-        None | Some(Err(_)) => return invalid_auth_query(req),
+    let state = match query.and_then(|q| q.split('&').find(|q| q.starts_with("state="))) {
+        Some(query) => &query[6..],
+        None => return invalid_auth_query(req),
     };
 
     let Context_(ctx) = req.data().unwrap();
 
-    if ctx.auth_standby.is_osu_empty() {
-        return unexpected_auth(req);
-    }
+    let discord = match ctx.auth_standby.take_osu_state(ctx, state).await? {
+        Some(discord) => discord,
+        None => return unexpected_auth(req),
+    };
 
     let OsuClientId(client_id) = req.data().unwrap();
     let OsuClientSecret(client_secret) = req.data().unwrap();
@@ -253,7 +366,9 @@ async fn auth_osu_handler_(req: &Request<Body>) -> HandlerResult {
 
     info!("Successful osu! authorization for `{}`", user.username);
 
-    ctx.auth_standby.process_osu(user, id);
+    ctx.auth_standby
+        .process_osu(ctx, user, discord, state)
+        .await?;
 
     Ok(Response::new(Body::from(page)))
 }
@@ -292,22 +407,17 @@ async fn auth_twitch_handler_(req: &Request<Body>) -> HandlerResult {
         None => return invalid_auth_query(req),
     };
 
-    let id_opt = query.and_then(|q| {
-        q.split('&')
-            .find(|q| q.starts_with("state="))
-            .map(|q| q[6..].parse())
-    });
-
-    let id = match id_opt {
-        Some(Ok(state)) => state,
-        None | Some(Err(_)) => return invalid_auth_query(req),
+    let state = match query.and_then(|q| q.split('&').find(|q| q.starts_with("state="))) {
+        Some(query) => &query[6..],
+        None => return invalid_auth_query(req),
     };
 
     let Context_(ctx) = req.data().unwrap();
 
-    if ctx.auth_standby.is_twitch_empty() {
-        return unexpected_auth(req);
-    }
+    let discord = match ctx.auth_standby.take_twitch_state(ctx, state).await? {
+        Some(discord) => discord,
+        None => return unexpected_auth(req),
+    };
 
     let TwitchClientId(client_id) = req.data().unwrap();
     let TwitchClientSecret(client_secret) = req.data().unwrap();
@@ -373,7 +483,9 @@ async fn auth_twitch_handler_(req: &Request<Body>) -> HandlerResult {
         user.display_name
     );
 
-    ctx.auth_standby.process_twitch(user, id);
+    ctx.auth_standby
+        .process_twitch(ctx, user, discord, state)
+        .await?;
 
     Ok(Response::new(Body::from(page)))
 }
@@ -0,0 +1,163 @@
+use std::{cmp::Ordering, sync::Arc};
+
+use eyre::WrapErr;
+use hashbrown::HashMap;
+use hyper::{Body, Request, Response, StatusCode};
+use parking_lot::Mutex;
+use routerify::ext::RequestExt;
+use serde::Deserialize;
+use serde_json::json;
+use time::{Duration, OffsetDateTime};
+
+use crate::pagination::{WebCell, WebTable};
+
+use super::{random_token, Context_, Handlebars_, HandlerResult};
+
+const TABLE_DURATION: Duration = Duration::hours(1);
+
+/// Tables that are stored at most at the same time.
+/// If the limit is reached, the table closest to expiring is removed.
+const MAX_TABLES: usize = 500;
+
+/// Pagination results that can be viewed on the website
+pub struct WebTables {
+    tables: Mutex<HashMap<String, (Arc<WebTable>, OffsetDateTime)>>,
+}
+
+impl WebTables {
+    pub fn new() -> Self {
+        Self {
+            tables: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Store the table and return the token under which it can be accessed
+    /// as well as the time at which it expires
+    pub fn insert(&self, table: WebTable) -> (String, OffsetDateTime) {
+        let token = random_token();
+        let now = OffsetDateTime::now_utc();
+        let expires_at = now + TABLE_DURATION;
+
+        let mut tables = self.tables.lock();
+        tables.retain(|_, (_, expires_at)| *expires_at > now);
+
+        if tables.len() >= MAX_TABLES {
+            let oldest = tables
+                .iter()
+                .min_by_key(|(_, (_, expires_at))| *expires_at)
+                .map(|(token, _)| token.to_owned());
+
+            if let Some(oldest) = oldest {
+                tables.remove(&oldest);
+            }
+        }
+
+        tables.insert(token.clone(), (Arc::new(table), expires_at));
+
+        (token, expires_at)
+    }
+
+    fn get(&self, token: &str) -> Option<(Arc<WebTable>, OffsetDateTime)> {
+        self.tables
+            .lock()
+            .get(token)
+            .filter(|(_, expires_at)| *expires_at > OffsetDateTime::now_utc())
+            .map(|(table, expires_at)| (Arc::clone(table), *expires_at))
+    }
+}
+
+#[derive(Deserialize)]
+struct TableQuery {
+    sort: Option<usize>,
+    #[serde(default)]
+    desc: bool,
+}
+
+pub async fn table_handler(req: Request<Body>) -> HandlerResult {
+    let Context_(ctx) = req.data().unwrap();
+    let token = req.param("token").map_or("", String::as_str);
+
+    let (table, expires_at) = match ctx.web_tables.get(token) {
+        Some(tuple) => tuple,
+        None => {
+            let response = Response::builder()
+                .status(StatusCode::NOT_FOUND)
+                .body(Body::from("This table does not exist or has expired"))?;
+
+            return Ok(response);
+        }
+    };
+
+    let query = req
+        .uri()
+        .query()
+        .and_then(|query| serde_urlencoded::from_str::<TableQuery>(query).ok())
+        .unwrap_or(TableQuery {
+            sort: None,
+            desc: false,
+        });
+
+    let mut rows: Vec<_> = table.rows.iter().collect();
+
+    if let Some(column) = query.sort.filter(|&column| column < table.columns.len()) {
+        rows.sort_by(|a, b| {
+            let ordering = cmp_cells(&a[column], &b[column]);
+
+            if query.desc {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+
+    let columns: Vec<_> = table
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let sorted = query.sort == Some(i);
+
+            // Clicking on the currently sorted column flips the order
+            let desc = sorted && !query.desc;
+
+            json!({
+                "name": name,
+                "url": format!("/tables/{token}?sort={i}&desc={desc}"),
+                "sorted": sorted,
+                "desc": sorted && query.desc,
+            })
+        })
+        .collect();
+
+    let rows: Vec<_> = rows
+        .into_iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| json!({ "text": cell.text, "url": cell.url }))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let render_data = json!({
+        "title": table.title,
+        "columns": columns,
+        "rows": rows,
+        "expires_at": expires_at.unix_timestamp(),
+    });
+
+    let Handlebars_(handlebars) = req.data().unwrap();
+
+    let page = handlebars
+        .render("table", &render_data)
+        .wrap_err("failed to render table page")?;
+
+    Ok(Response::new(Body::from(page)))
+}
+
+fn cmp_cells(a: &WebCell, b: &WebCell) -> Ordering {
+    match (a.value, b.value) {
+        (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        _ => a.text.to_lowercase().cmp(&b.text.to_lowercase()),
+    }
+}
@@ -5,7 +5,7 @@ pub use self::osu::{
 };
 
 #[cfg(feature = "twitchtracking")]
-pub use self::twitch::twitch_loop::twitch_tracking_loop;
+pub use self::twitch::twitch_loop::{notify_streams, twitch_tracking_loop};
 
 mod osu;
 mod twitch;
@@ -18,25 +18,29 @@ use twilight_model::{
 
 use crate::{
     commands::osu::prepare_score,
+    core::OsuFutureExt,
     embeds::{EmbedData, TrackNotificationEmbed},
     util::{constants::UNKNOWN_CHANNEL, hasher::IntHasher},
     Context,
 };
 
-use super::osu_queue::TrackingEntry;
+use super::osu_queue::{TrackingEntry, TrackingPop};
 
 #[cold]
 pub async fn osu_tracking_loop(ctx: Arc<Context>) {
     loop {
-        if let Some((entry, amount)) = ctx.tracking().pop().await {
+        if let Some(popped) = ctx.tracking().pop().await {
+            let TrackingPop { entry, amount, .. } = popped;
             let TrackingEntry { user_id, mode } = entry;
+            ctx.stats.set_tracking(popped.queue, popped.lag);
 
             let scores_fut = ctx
                 .osu()
                 .user_scores(user_id)
                 .best()
                 .mode(mode)
-                .limit(amount);
+                .limit(amount)
+                .observe(&ctx.stats, "user_scores");
 
             match scores_fut.await {
                 Ok(mut scores) => {
@@ -219,7 +223,17 @@ impl<'u> TrackUser<'u> {
         let data = if let Some(user) = self.user.as_deref() {
             TrackNotificationEmbed::new(user, score, idx, ctx).await
         } else {
-            let user = ctx.osu().user(self.user_id).mode(self.mode).await?;
+            let user = ctx
+                .osu()
+                .user(self.user_id)
+                .mode(self.mode)
+                .observe(&ctx.stats, "user")
+                .await?;
+
+            if let Err(err) = ctx.psql().upsert_osu_user(&user, self.mode).await {
+                warn!("{:?}", err.wrap_err("failed to upsert osu user"));
+            }
+
             let user = self.user.get_or_insert(Cow::Owned(user));
 
             TrackNotificationEmbed::new(user.as_ref(), score, idx, ctx).await
@@ -30,7 +30,7 @@ type TrackingQueue =
 type Channels = HashMap<Id<ChannelMarker>, usize, IntHasher>;
 
 pub struct TrackingStats {
-    pub next_pop: TrackingEntry,
+    pub next_pop: Option<TrackingEntry>,
     pub users: usize,
     pub queue: usize,
     pub last_pop: OffsetDateTime,
@@ -40,6 +40,16 @@ pub struct TrackingStats {
     pub ms_per_track: i64,
 }
 
+/// A user that is due to be checked
+pub struct TrackingPop {
+    pub entry: TrackingEntry,
+    pub amount: usize,
+    /// Remaining length of the queue
+    pub queue: usize,
+    /// How many seconds the entry was popped after it was due
+    pub lag: i64,
+}
+
 #[derive(Copy, Clone, Eq, Hash, PartialEq)]
 pub struct TrackingEntry {
     pub user_id: u32,
@@ -117,7 +127,7 @@ impl OsuTracking {
         self.queue.get_tracked(user_id, mode).await
     }
 
-    pub async fn pop(&self) -> Option<(TrackingEntry, usize)> {
+    pub async fn pop(&self) -> Option<TrackingPop> {
         self.queue.pop().await
     }
 
@@ -299,7 +309,7 @@ impl OsuTrackingQueue {
     }
 
     /// Pop a user from the queue to be checked for tracking
-    async fn pop(&self) -> Option<(TrackingEntry, usize)> {
+    async fn pop(&self) -> Option<TrackingPop> {
         let len = self.queue.lock().await.len();
 
         if len == 0 || self.stop_tracking.load(Ordering::Acquire) {
@@ -309,17 +319,31 @@ impl OsuTrackingQueue {
         }
 
         let last_date = *self.last_date.lock().await;
-        let interval = last_date + *self.interval.read() - OffsetDateTime::now_utc();
-        let ms_per_track = interval.whole_milliseconds() as f32 / len as f32;
+        let interval = *self.interval.read();
+        let remaining = last_date + interval - OffsetDateTime::now_utc();
+        let ms_per_track = remaining.whole_milliseconds() as f32 / len as f32;
         time::sleep(StdDuration::from_millis(ms_per_track as u64)).await;
 
         // Pop user and return them
         loop {
-            let entry = self.queue.lock().await.pop().map(|(entry, _)| entry)?;
+            let (entry, Reverse(date), queue) = {
+                let mut queue = self.queue.lock().await;
+                let (entry, date) = queue.pop()?;
+
+                (entry, date, queue.len())
+            };
+
             let guard = self.users.lock(&entry).await;
 
             if let Some(amount) = guard.get().and_then(|u| u.channels.values().max().copied()) {
-                return Some((entry, amount));
+                let lag = (OffsetDateTime::now_utc() - (date + interval)).whole_seconds();
+
+                return Some(TrackingPop {
+                    entry,
+                    amount,
+                    queue,
+                    lag: lag.max(0),
+                });
             }
         }
     }
@@ -478,7 +502,7 @@ impl OsuTrackingQueue {
         let (next_pop, queue) = {
             let guard = self.queue.lock().await;
 
-            (guard.peek().map(|(&key, _)| key).unwrap(), guard.len())
+            (guard.peek().map(|(&key, _)| key), guard.len())
         };
 
         let users = self.users.len().await;
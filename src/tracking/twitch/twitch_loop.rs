@@ -1,8 +1,9 @@
 use std::{fmt::Write, sync::Arc};
 
-use eyre::Report;
+use eyre::{Report, Result, WrapErr};
 use hashbrown::{HashMap, HashSet};
 use rand::Rng;
+use time::OffsetDateTime;
 use tokio::time::{interval, Duration};
 use twilight_http::{
     api_error::{ApiError, GeneralApiError},
@@ -19,16 +20,20 @@ use crate::{
 
 #[cold]
 pub async fn twitch_tracking_loop(ctx: Arc<Context>) {
-    let mut online_streams = HashSet::with_hasher(IntHasher);
     let mut interval = interval(Duration::from_secs(10 * 60));
     interval.tick().await;
 
     loop {
         interval.tick().await;
 
+        #[cfg(feature = "server")]
+        ctx.twitch_loop_ticked();
+
         // Get data about what needs to be tracked for which channel
         let user_ids = ctx.tracked_users();
 
+        let polled_at = OffsetDateTime::now_utc();
+
         // Get stream data about all streams that need to be tracked
         let mut streams = match ctx.client().get_twitch_streams(&user_ids).await {
             Ok(streams) => streams,
@@ -44,65 +49,71 @@ pub async fn twitch_tracking_loop(ctx: Arc<Context>) {
         let now_online: HashSet<_, IntHasher> =
             streams.iter().map(|stream| stream.user_id).collect();
 
-        // If there was no activity change since last time, don't do anything
-        if now_online == online_streams {
-            continue;
-        }
-
-        // Filter streams whether its already known they're live
-        streams.retain(|stream| !online_streams.contains(&stream.user_id));
+        // Filter streams whether its already known they're live,
+        // either from the previous iteration or from an EventSub notification
+        let newly_online = ctx.merge_online_streams(&now_online, polled_at);
+        streams.retain(|stream| newly_online.contains(&stream.user_id));
 
         // Nothing to do if streams is empty
         // (i.e. the change was that streamers went offline)
         if streams.is_empty() {
-            online_streams = now_online;
-
             continue;
         }
 
-        let ids: Vec<_> = streams.iter().map(|s| s.user_id).collect();
-
-        // TODO: IntHasher
-        let users: HashMap<_, _> = match ctx.client().get_twitch_users(&ids).await {
-            Ok(users) => users.into_iter().map(|u| (u.user_id, u)).collect(),
-            Err(err) => {
-                warn!("{:?}", err.wrap_err("Failed to retrieve twitch users"));
+        if let Err(err) = notify_streams(&ctx, streams).await {
+            warn!("{:?}", err.wrap_err("Failed to notify streams"));
+        }
+    }
+}
 
-                continue;
-            }
+/// Send a notification to all channels that track any of the streams
+pub async fn notify_streams(ctx: &Context, streams: Vec<TwitchStream>) -> Result<()> {
+    let ids: Vec<_> = streams.iter().map(|s| s.user_id).collect();
+
+    let users: HashMap<_, _, IntHasher> = ctx
+        .client()
+        .get_twitch_users(&ids)
+        .await
+        .wrap_err("failed to retrieve twitch users")?
+        .into_iter()
+        .map(|u| (u.user_id, u))
+        .collect();
+
+    // Generate random width and height to avoid discord caching the thumbnail url
+    let (width, height) = {
+        let mut rng = rand::thread_rng();
+
+        let width: u32 = rng.gen_range(350..=370);
+        let height: u32 = rng.gen_range(175..=185);
+
+        (width, height)
+    };
+
+    // Process each stream by notifying all corresponding channels
+    for mut stream in streams {
+        let channels = match ctx.tracked_channels_for(stream.user_id) {
+            Some(channels) => channels,
+            None => continue,
         };
 
-        // Generate random width and height to avoid discord caching the thumbnail url
-        let (width, height) = {
-            let mut rng = rand::thread_rng();
-
-            let width: u32 = rng.gen_range(350..=370);
-            let height: u32 = rng.gen_range(175..=185);
-
-            (width, height)
+        let user = match users.get(&stream.user_id) {
+            Some(user) => user,
+            None => continue,
         };
 
-        // Process each stream by notifying all corresponding channels
-        for mut stream in streams {
-            let channels = match ctx.tracked_channels_for(stream.user_id) {
-                Some(channels) => channels,
-                None => continue,
-            };
+        // Adjust streams' thumbnail url
+        let url_len = stream.thumbnail_url.len();
+        stream.thumbnail_url.truncate(url_len - 20); // cut off "{width}x{height}.jpg"
+        let _ = write!(stream.thumbnail_url, "{width}x{height}.jpg");
 
-            // Adjust streams' thumbnail url
-            let url_len = stream.thumbnail_url.len();
-            stream.thumbnail_url.truncate(url_len - 20); // cut off "{width}x{height}.jpg"
-            let _ = write!(stream.thumbnail_url, "{width}x{height}.jpg");
+        let data = TwitchNotifEmbed::new(&stream, user);
 
-            let data = TwitchNotifEmbed::new(&stream, &users[&stream.user_id]);
-
-            for channel in channels {
-                send_notif(&ctx, &data, channel).await;
-            }
+        for channel in channels {
+            send_notif(ctx, &data, channel).await;
         }
-
-        online_streams = now_online;
     }
+
+    Ok(())
 }
 
 async fn send_notif(ctx: &Context, data: &TwitchNotifEmbed, channel: Id<ChannelMarker>) {
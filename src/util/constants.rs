@@ -23,6 +23,18 @@ pub const TWITCH_USERS_ENDPOINT: &str = "https://api.twitch.tv/helix/users";
 pub const TWITCH_VIDEOS_ENDPOINT: &str = "https://api.twitch.tv/helix/videos";
 #[cfg(feature = "twitch")]
 pub const TWITCH_OAUTH: &str = "https://id.twitch.tv/oauth2/token";
+#[cfg(all(feature = "server", feature = "twitchtracking"))]
+pub const TWITCH_EVENTSUB_ENDPOINT: &str = "https://api.twitch.tv/helix/eventsub/subscriptions";
+
+// discord
+#[cfg(feature = "server")]
+pub const DISCORD_OAUTH_AUTHORIZE: &str = "https://discord.com/api/oauth2/authorize";
+#[cfg(feature = "server")]
+pub const DISCORD_OAUTH_TOKEN: &str = "https://discord.com/api/oauth2/token";
+#[cfg(feature = "server")]
+pub const DISCORD_CURRENT_USER_ENDPOINT: &str = "https://discord.com/api/users/@me";
+#[cfg(feature = "server")]
+pub const DISCORD_CURRENT_USER_GUILDS_ENDPOINT: &str = "https://discord.com/api/users/@me/guilds";
 
 // Error messages
 pub const GENERAL_ISSUE: &str = "Something went wrong, blame bade";
@@ -10,8 +10,15 @@ use tokio::time::Duration;
 use crate::Context;
 
 pub use self::{
-    bitflags::*, boyer_moore::*, country_code::CountryCode, cow::CowUtils, emote::Emote, ext::*,
-    html_to_png::*, matrix::Matrix, monthly::Monthly,
+    bitflags::*,
+    boyer_moore::*,
+    country_code::CountryCode,
+    cow::CowUtils,
+    emote::Emote,
+    ext::*,
+    html_to_png::*,
+    matrix::Matrix,
+    monthly::{Monthly, RangedDate},
 };
 
 pub mod builder;
@@ -13,8 +13,14 @@ use twilight_model::channel::{embed::Embed, Message};
 
 use crate::{
     core::{BotConfig, Context},
-    custom_client::OsuTrackerCountryScore,
-    util::{constants::OSU_BASE, matcher, numbers::round, BeatmapExt, Emote, ScoreExt},
+    custom_client::{classic_score, LazerScore, OsuTrackerCountryScore},
+    database::ScoreKind,
+    util::{
+        constants::OSU_BASE,
+        matcher,
+        numbers::{round, with_comma_int},
+        BeatmapExt, Emote, ScoreExt,
+    },
 };
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -157,6 +163,42 @@ pub fn grade_completion_mods(score: &dyn ScoreExt, map: &Beatmap) -> Cow<'static
     }
 }
 
+/// Same as [`grade_completion_mods`] but showing lazer's mods including their settings
+pub fn grade_lazer_mods(
+    score: &dyn ScoreExt,
+    map: &Beatmap,
+    lazer: &LazerScore,
+) -> Cow<'static, str> {
+    let grade = BotConfig::get().grade(score.grade(map.mode()));
+    let mods = lazer.mods_string();
+
+    if mods.is_empty() {
+        grade.into()
+    } else {
+        format!("{grade} +{mods}").into()
+    }
+}
+
+/// Retrieve the lazer data of a passed score if the given [`ScoreKind`] shows lazer values
+pub async fn lazer_score(ctx: &Context, score: &Score, kind: ScoreKind) -> Option<LazerScore> {
+    if kind == ScoreKind::Stable || score.grade == Grade::F || score.score_id == 0 {
+        return None;
+    }
+
+    match ctx
+        .client()
+        .get_lazer_score(score.score_id, score.mode)
+        .await
+    {
+        Ok(lazer) => Some(lazer),
+        Err(err) => {
+            warn!("{:?}", err.wrap_err("failed to get lazer score"));
+
+            None
+        }
+    }
+}
+
 fn completion(score: &dyn ScoreExt, map: &Beatmap) -> u32 {
     let passed = score.hits(map.mode() as u8);
     let total = map.count_objects();
@@ -394,6 +436,108 @@ impl MapIdType {
     }
 }
 
+/// Display the score value of a stable score in the given [`ScoreKind`].
+///
+/// If the score's lazer data is available, its standardised score is shown
+/// as is and converted to classic score. Otherwise, the value is estimated
+/// based on the accuracy and combo of the score and marked as such.
+pub fn score_by_kind(score: &Score, lazer: Option<&LazerScore>, kind: ScoreKind) -> String {
+    match (kind, lazer) {
+        (ScoreKind::Stable, _) => with_comma_int(score.score).to_string(),
+        (ScoreKind::Standardised, Some(lazer)) => with_comma_int(lazer.total_score).to_string(),
+        (ScoreKind::Classic, Some(lazer)) => {
+            with_comma_int(lazer.classic_score(score.mode)).to_string()
+        }
+        (ScoreKind::Standardised, None) => {
+            let value = standardised_score(score).round() as u32;
+
+            format!("~{} (est.)", with_comma_int(value))
+        }
+        (ScoreKind::Classic, None) => {
+            let stats = &score.statistics;
+
+            let max_basic = match score.mode {
+                GameMode::Osu => {
+                    stats.count_300 + stats.count_100 + stats.count_50 + stats.count_miss
+                }
+                GameMode::Taiko => stats.count_300 + stats.count_100 + stats.count_miss,
+                GameMode::Catch => stats.count_300 + stats.count_miss,
+                GameMode::Mania => score.total_hits(),
+            };
+
+            let value = classic_score(standardised_score(score), max_basic, score.mode);
+
+            format!("~{} (est.)", with_comma_int(value))
+        }
+    }
+}
+
+fn standardised_score(score: &Score) -> f64 {
+    let (acc_portion, combo_portion) = match score.mode {
+        GameMode::Osu | GameMode::Catch => (0.3, 0.7),
+        GameMode::Taiko => (0.75, 0.25),
+        GameMode::Mania => (0.99, 0.01),
+    };
+
+    let combo_ratio = score
+        .map
+        .as_ref()
+        .and_then(|map| map.max_combo)
+        .filter(|&max_combo| max_combo > 0)
+        .map_or(1.0, |max_combo| {
+            (score.max_combo as f64 / max_combo as f64).min(1.0)
+        });
+
+    let acc = score.accuracy as f64 / 100.0;
+    let mod_mult = lazer_mod_multiplier(score.mods, score.mode);
+
+    1_000_000.0 * (acc_portion * acc + combo_portion * combo_ratio) * mod_mult
+}
+
+fn lazer_mod_multiplier(mods: GameMods, mode: GameMode) -> f64 {
+    let mut mult = 1.0;
+
+    if mods.contains(GameMods::NoFail) {
+        mult *= 0.5;
+    }
+
+    if mods.contains(GameMods::Easy) {
+        mult *= 0.5;
+    }
+
+    if mods.contains(GameMods::HalfTime) {
+        mult *= 0.3;
+    }
+
+    if mods.contains(GameMods::SpunOut) {
+        mult *= 0.9;
+    }
+
+    if mods.contains(GameMods::Relax) || mods.contains(GameMods::Autopilot) {
+        mult *= 0.1;
+    }
+
+    if mode != GameMode::Mania {
+        if mods.contains(GameMods::HardRock) {
+            mult *= if mode == GameMode::Taiko { 1.06 } else { 1.1 };
+        }
+
+        if mods.contains(GameMods::Hidden) {
+            mult *= 1.06;
+        }
+
+        if mods.contains(GameMods::DoubleTime) {
+            mult *= 1.12;
+        }
+
+        if mods.contains(GameMods::Flashlight) {
+            mult *= 1.12;
+        }
+    }
+
+    mult
+}
+
 // Credits to https://github.com/RoanH/osu-BonusPP/blob/master/BonusPP/src/me/roan/bonuspp/BonusPP.java#L202
 pub struct BonusPP {
     pp: f32,
@@ -1,6 +1,9 @@
 use std::{borrow::Cow, cmp::Ordering, fmt};
 
-use crate::util::{matcher::QUERY_SYNTAX_REGEX, CowUtils};
+use rosu_v2::prelude::{GameMods, Grade};
+use time::{util::days_in_year_month, Date, Month, OffsetDateTime};
+
+use crate::util::{matcher::QUERY_SYNTAX_REGEX, osu::ModSelection, CowUtils};
 
 #[derive(Debug, Default)]
 pub struct FilterCriteria<'q> {
@@ -13,11 +16,21 @@ pub struct FilterCriteria<'q> {
     pub bpm: OptionalRange<f32>,
     pub keys: OptionalRange<f32>,
 
+    pub acc: OptionalRange<f32>,
+    pub pp: OptionalRange<f32>,
+    pub combo: OptionalRange<f32>,
+    pub miss: OptionalRange<f32>,
+    pub rank: OptionalRange<f32>,
+    grade: OptionalRange<f32>,
+    date: OptionalRange<f32>,
+    pub mods: OptionalMods,
+
     pub artist: OptionalText<'q>,
     pub creator: OptionalText<'q>,
     pub title: OptionalText<'q>,
 
     search_text: String,
+    search_groups: Vec<Vec<SearchTerm>>,
 }
 
 impl<'q> FilterCriteria<'q> {
@@ -80,16 +93,52 @@ impl<'q> FilterCriteria<'q> {
         }
 
         criteria.search_text.make_ascii_lowercase();
+        criteria.search_groups = parse_search_groups(&criteria.search_text);
 
         criteria
     }
 
     pub fn has_search_terms(&self) -> bool {
-        !self.search_text.is_empty()
+        !self.search_groups.is_empty()
     }
 
+    /// Iterate over all search terms that are not negated
     pub fn search_terms(&self) -> impl Iterator<Item = &str> {
-        self.search_text.split_whitespace()
+        self.search_groups
+            .iter()
+            .flatten()
+            .filter(|term| !term.negated)
+            .map(|term| term.text.as_str())
+    }
+
+    /// Iterate over all search terms that are negated
+    pub fn negated_search_terms(&self) -> impl Iterator<Item = &str> {
+        self.search_groups
+            .iter()
+            .flatten()
+            .filter(|term| term.negated)
+            .map(|term| term.text.as_str())
+    }
+
+    /// Check whether the search text is fulfilled.
+    ///
+    /// Each group must be fulfilled and a group is fulfilled if any of its
+    /// terms is fulfilled, i.e. whitespace acts as AND while `|` acts as OR.
+    /// Negated terms are fulfilled if `matches_term` returns `false`.
+    pub fn matches_search(&self, matches_term: impl Fn(&str) -> bool) -> bool {
+        self.search_groups.iter().all(|group| {
+            group
+                .iter()
+                .any(|term| matches_term(&term.text) != term.negated)
+        })
+    }
+
+    pub fn matches_grade(&self, grade: Grade) -> bool {
+        self.grade.contains(grade_value(grade))
+    }
+
+    pub fn matches_date(&self, datetime: OffsetDateTime) -> bool {
+        self.date.contains(datetime.date().to_julian_day() as f32)
     }
 
     fn try_parse_keyword_criteria(
@@ -107,13 +156,36 @@ impl<'q> FilterCriteria<'q> {
             "bpm" => self.bpm.try_update(op, &value, 0.05),
             "length" | "len" => self.try_update_len(op, &value),
             "creator" | "mapper" => self.creator.try_update(op, value),
-            "artist" => self.creator.try_update(op, value),
+            "artist" => self.artist.try_update(op, value),
             "title" => self.title.try_update(op, value),
             "key" | "keys" => self.keys.try_update(op, &value, 0.5),
+            "acc" | "accuracy" => self.acc.try_update(op, value.trim_end_matches('%'), 0.005),
+            "pp" => self.pp.try_update(op, &value, 0.005),
+            "combo" => self.combo.try_update(op, value.trim_end_matches('x'), 0.5),
+            "miss" | "misses" => self.miss.try_update(op, &value, 0.5),
+            "rank" => self.rank.try_update(op, value.trim_start_matches('#'), 0.5),
+            "grade" => self.try_update_grade(op, &value),
+            "date" => self.date.try_update_date(op, &value),
+            "mods" | "mod" => self.mods.try_update(op, &value),
             _ => false,
         }
     }
 
+    fn try_update_grade(&mut self, op: Operator, value: &str) -> bool {
+        let grade = match value {
+            "f" => Grade::F,
+            "d" => Grade::D,
+            "c" => Grade::C,
+            "b" => Grade::B,
+            "a" => Grade::A,
+            "s" | "sh" => Grade::S,
+            "x" | "xh" | "ss" | "ssh" => Grade::X,
+            _ => return false,
+        };
+
+        self.grade.try_update_(op, grade_value(grade), 0.5)
+    }
+
     fn try_update_len(&mut self, op: Operator, value: &str) -> bool {
         let len: f32 = match value.trim_end_matches(&['m', 's', 'h']).parse() {
             Ok(value) => value,
@@ -269,9 +341,80 @@ impl OptionalRange<f32> {
 
         true
     }
+
+    /// Parse a date of the form `YYYY`, `YYYY-MM`, or `YYYY-MM-DD`
+    /// and update the range in terms of julian days.
+    fn try_update_date(&mut self, op: Operator, value: &str) -> bool {
+        let mut split = value.split('-');
+
+        let year = match split.next().map(str::parse::<i32>) {
+            Some(Ok(year)) => year,
+            _ => return false,
+        };
+
+        let month = match split.next().map(str::parse::<u8>) {
+            Some(Ok(month)) => match Month::try_from(month) {
+                Ok(month) => Some(month),
+                Err(_) => return false,
+            },
+            Some(Err(_)) => return false,
+            None => None,
+        };
+
+        let day = match split.next().map(str::parse::<u8>) {
+            Some(Ok(day)) => Some(day),
+            Some(Err(_)) => return false,
+            None => None,
+        };
+
+        if split.next().is_some() {
+            return false;
+        }
+
+        let (first_day, last_day) = match (month, day) {
+            (Some(month), Some(day)) => (
+                Date::from_calendar_date(year, month, day),
+                Date::from_calendar_date(year, month, day),
+            ),
+            (Some(month), None) => (
+                Date::from_calendar_date(year, month, 1),
+                Date::from_calendar_date(year, month, days_in_year_month(year, month)),
+            ),
+            (None, _) => (
+                Date::from_calendar_date(year, Month::January, 1),
+                Date::from_calendar_date(year, Month::December, 31),
+            ),
+        };
+
+        let (start, end) = match (first_day, last_day) {
+            (Ok(first), Ok(last)) => (first.to_julian_day() as f32, last.to_julian_day() as f32),
+            _ => return false,
+        };
+
+        match op {
+            Operator::Equal => {
+                self.min = Some(start - 0.5);
+                self.max = Some(end + 0.5);
+            }
+            Operator::Less => self.max = Some(start - 0.5),
+            Operator::LessOrEqual => self.max = Some(end + 0.5),
+            Operator::Greater => self.min = Some(end + 0.5),
+            Operator::GreaterOrEqual => self.min = Some(start - 0.5),
+        }
+
+        true
+    }
 }
 
 impl<T: PartialOrd> OptionalRange<T> {
+    /// Values that are not available only match if the range is unbounded
+    pub fn contains_opt(&self, value: Option<T>) -> bool {
+        match value {
+            Some(value) => self.contains(value),
+            None => self.min.is_none() && self.max.is_none(),
+        }
+    }
+
     pub fn contains(&self, value: T) -> bool {
         if let Some(ref min) = self.min {
             match value.partial_cmp(min) {
@@ -292,3 +435,277 @@ impl<T: PartialOrd> OptionalRange<T> {
         true
     }
 }
+
+#[derive(Default)]
+pub struct OptionalMods {
+    selection: Option<ModSelection>,
+}
+
+impl fmt::Debug for OptionalMods {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.selection {
+            Some(ModSelection::Exact(mods)) => write!(f, "Exact({mods})"),
+            Some(ModSelection::Include(mods)) => write!(f, "Include({mods})"),
+            Some(ModSelection::Exclude(mods)) => write!(f, "Exclude({mods})"),
+            None => f.write_str("None"),
+        }
+    }
+}
+
+impl OptionalMods {
+    pub fn matches(&self, mods: GameMods) -> bool {
+        match self.selection {
+            None => true,
+            Some(ModSelection::Exact(selected)) => mods == selected,
+            Some(ModSelection::Include(selected)) if selected.is_empty() => mods.is_empty(),
+            Some(ModSelection::Include(selected)) => mods.contains(selected),
+            Some(ModSelection::Exclude(selected)) if selected.is_empty() => !mods.is_empty(),
+            Some(ModSelection::Exclude(selected)) => !mods.intersects(selected),
+        }
+    }
+
+    /// `=` requires exact mods, `>` or `>=` requires included mods,
+    /// and `<` or `<=` requires excluded mods.
+    fn try_update(&mut self, op: Operator, value: &str) -> bool {
+        let mods = match value.trim_start_matches('+').trim_end_matches('!') {
+            "nm" | "nomod" => GameMods::NoMod,
+            value => match value.parse() {
+                Ok(mods) => mods,
+                Err(_) => return false,
+            },
+        };
+
+        self.selection = match op {
+            Operator::Equal => Some(ModSelection::Exact(mods)),
+            Operator::Greater | Operator::GreaterOrEqual => Some(ModSelection::Include(mods)),
+            Operator::Less | Operator::LessOrEqual => Some(ModSelection::Exclude(mods)),
+        };
+
+        true
+    }
+}
+
+#[derive(Debug)]
+struct SearchTerm {
+    text: String,
+    negated: bool,
+}
+
+/// Split the search text into groups of terms.
+///
+/// Terms are separated by whitespace unless they're within quotes,
+/// terms joined by `|` or `or` are put in the same group,
+/// and terms prefixed with `-` or `!` are negated.
+fn parse_search_groups(search_text: &str) -> Vec<Vec<SearchTerm>> {
+    let mut tokens = Vec::new();
+    let mut chars = search_text.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+
+        let negated = matches!(c, '-' | '!')
+            && chars
+                .peek()
+                .filter(|(_, next)| !next.is_whitespace())
+                .is_some();
+
+        let start = if negated {
+            match chars.next() {
+                Some((i, _)) => i,
+                None => break,
+            }
+        } else {
+            start
+        };
+
+        let quoted = search_text[start..].starts_with('"');
+
+        let end = if quoted {
+            match search_text[start + 1..].find('"') {
+                Some(idx) => start + 1 + idx + 1,
+                None => search_text.len(),
+            }
+        } else {
+            search_text[start..]
+                .find(char::is_whitespace)
+                .map_or(search_text.len(), |idx| start + idx)
+        };
+
+        while chars.peek().filter(|(i, _)| *i < end).is_some() {
+            chars.next();
+        }
+
+        let text = search_text[start..end].trim_matches('"');
+
+        if !quoted && !negated && matches!(text, "|" | "or") {
+            tokens.push(None);
+        } else if !text.is_empty() && !matches!(text, "-" | "!") {
+            let term = SearchTerm {
+                text: text.to_owned(),
+                negated,
+            };
+
+            tokens.push(Some(term));
+        }
+    }
+
+    let mut groups: Vec<Vec<SearchTerm>> = Vec::new();
+    let mut join_next = false;
+
+    for token in tokens {
+        match token {
+            Some(term) => match groups.last_mut() {
+                Some(group) if join_next => group.push(term),
+                _ => groups.push(vec![term]),
+            },
+            None => {
+                join_next = !groups.is_empty();
+
+                continue;
+            }
+        }
+
+        join_next = false;
+    }
+
+    groups
+}
+
+fn grade_value(grade: Grade) -> f32 {
+    match grade {
+        Grade::F => 0.0,
+        Grade::D => 1.0,
+        Grade::C => 2.0,
+        Grade::B => 3.0,
+        Grade::A => 4.0,
+        Grade::S | Grade::SH => 5.0,
+        Grade::X | Grade::XH => 6.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Negated terms are prefixed with `-`
+    fn groups(search_text: &str) -> Vec<Vec<String>> {
+        parse_search_groups(search_text)
+            .into_iter()
+            .map(|group| {
+                group
+                    .into_iter()
+                    .map(|term| match term.negated {
+                        true => format!("-{}", term.text),
+                        false => term.text,
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_search_groups_and() {
+        assert_eq!(groups("big black"), vec![vec!["big"], vec!["black"]]);
+        assert!(groups("   ").is_empty());
+    }
+
+    #[test]
+    fn test_search_groups_or() {
+        assert_eq!(
+            groups("big | black or blue"),
+            vec![vec!["big", "black", "blue"]]
+        );
+        assert_eq!(groups("| big or"), vec![vec!["big"]]);
+    }
+
+    #[test]
+    fn test_search_groups_negated() {
+        assert_eq!(groups("-remix !cut"), vec![vec!["-remix"], vec!["-cut"]]);
+        assert_eq!(groups("- big"), vec![vec!["big"]]);
+        assert_eq!(groups("top | -remix"), vec![vec!["top", "-remix"]]);
+    }
+
+    #[test]
+    fn test_search_groups_quoted() {
+        assert_eq!(
+            groups("\"big black\" -\"tv size\""),
+            vec![vec!["big black"], vec!["-tv size"]]
+        );
+        assert_eq!(groups("\"or\""), vec![vec!["or"]]);
+        assert_eq!(groups("\"big black"), vec![vec!["big black"]]);
+    }
+
+    fn julian(year: i32, month: Month, day: u8) -> f32 {
+        Date::from_calendar_date(year, month, day)
+            .unwrap()
+            .to_julian_day() as f32
+    }
+
+    #[test]
+    fn test_date_year() {
+        let mut range = OptionalRange::default();
+        assert!(range.try_update_date(Operator::Equal, "2021"));
+
+        assert!(range.contains(julian(2021, Month::January, 1)));
+        assert!(range.contains(julian(2021, Month::December, 31)));
+        assert!(!range.contains(julian(2020, Month::December, 31)));
+        assert!(!range.contains(julian(2022, Month::January, 1)));
+    }
+
+    #[test]
+    fn test_date_month() {
+        let mut range = OptionalRange::default();
+        assert!(range.try_update_date(Operator::Equal, "2020-02"));
+
+        assert!(range.contains(julian(2020, Month::February, 29)));
+        assert!(!range.contains(julian(2020, Month::March, 1)));
+        assert!(!range.contains(julian(2020, Month::January, 31)));
+    }
+
+    #[test]
+    fn test_date_day() {
+        let mut range = OptionalRange::default();
+        assert!(range.try_update_date(Operator::Equal, "2022-07-15"));
+
+        assert!(range.contains(julian(2022, Month::July, 15)));
+        assert!(!range.contains(julian(2022, Month::July, 14)));
+        assert!(!range.contains(julian(2022, Month::July, 16)));
+    }
+
+    #[test]
+    fn test_date_operators() {
+        let mut range = OptionalRange::default();
+        assert!(range.try_update_date(Operator::Less, "2022-07"));
+        assert!(range.contains(julian(2022, Month::June, 30)));
+        assert!(!range.contains(julian(2022, Month::July, 1)));
+
+        let mut range = OptionalRange::default();
+        assert!(range.try_update_date(Operator::LessOrEqual, "2022-07"));
+        assert!(range.contains(julian(2022, Month::July, 31)));
+        assert!(!range.contains(julian(2022, Month::August, 1)));
+
+        let mut range = OptionalRange::default();
+        assert!(range.try_update_date(Operator::Greater, "2022"));
+        assert!(range.contains(julian(2023, Month::January, 1)));
+        assert!(!range.contains(julian(2022, Month::December, 31)));
+
+        let mut range = OptionalRange::default();
+        assert!(range.try_update_date(Operator::GreaterOrEqual, "2022"));
+        assert!(range.contains(julian(2022, Month::January, 1)));
+        assert!(!range.contains(julian(2021, Month::December, 31)));
+    }
+
+    #[test]
+    fn test_date_invalid() {
+        let mut range = OptionalRange::default();
+
+        assert!(!range.try_update_date(Operator::Equal, "abc"));
+        assert!(!range.try_update_date(Operator::Equal, "2022-13"));
+        assert!(!range.try_update_date(Operator::Equal, "2022-00"));
+        assert!(!range.try_update_date(Operator::Equal, "2021-02-29"));
+        assert!(!range.try_update_date(Operator::Equal, "2022-01-01-01"));
+        assert!(!range.try_update_date(Operator::Equal, "2022-x"));
+    }
+}
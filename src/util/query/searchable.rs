@@ -3,7 +3,10 @@ use std::borrow::Cow;
 use rosu_pp::{beatmap::BeatmapAttributesBuilder, Mods};
 use rosu_v2::prelude::{Beatmap, Beatmapset, GameMode, GameMods, Score};
 
-use crate::{custom_client::OsuTrackerCountryScore, util::CowUtils};
+use crate::{
+    custom_client::{OsuStatsScore, OsuTrackerCountryScore, ScraperScore},
+    util::CowUtils,
+};
 
 use super::FilterCriteria;
 
@@ -31,7 +34,15 @@ impl Searchable for Beatmap {
         if matches && criteria.has_search_terms() {
             let version = self.version.cow_to_ascii_lowercase();
 
-            matches &= criteria.search_terms().any(|term| version.contains(term));
+            let mut terms = criteria.search_terms().peekable();
+
+            if terms.peek().is_some() {
+                matches &= terms.any(|term| version.contains(term));
+            }
+
+            matches &= !criteria
+                .negated_search_terms()
+                .any(|term| version.contains(term));
         }
 
         matches
@@ -57,7 +68,7 @@ impl Searchable for Beatmapset {
         if matches && criteria.has_search_terms() {
             let terms = [artist, creator, title];
 
-            matches &= criteria.search_terms().all(|term| {
+            matches &= criteria.matches_search(|term| {
                 if terms.iter().any(|searchable| searchable.contains(term)) {
                     true
                 } else if let Some(ref maps) = self.maps {
@@ -83,7 +94,18 @@ impl Searchable for Score {
         let mut title = Cow::default();
         let mut version = Cow::default();
 
-        if let Some(ref map) = self.map {
+        matches &= criteria.acc.contains(self.accuracy);
+        matches &= criteria.pp.contains_opt(self.pp);
+        matches &= criteria.combo.contains(self.max_combo as f32);
+        matches &= criteria.miss.contains(self.statistics.count_miss as f32);
+        matches &= criteria
+            .rank
+            .contains_opt(self.rank_global.map(|rank| rank as f32));
+        matches &= criteria.matches_grade(self.grade);
+        matches &= criteria.matches_date(self.ended_at);
+        matches &= criteria.mods.matches(self.mods);
+
+        if let Some(map) = self.map.as_ref().filter(|_| matches) {
             let mode = match map.mode {
                 GameMode::Osu => rosu_pp::GameMode::Osu,
                 GameMode::Taiko => rosu_pp::GameMode::Taiko,
@@ -145,8 +167,75 @@ impl Searchable for Score {
             let terms = [artist, creator, version, title];
 
             matches &= criteria
-                .search_terms()
-                .all(|term| terms.iter().any(|searchable| searchable.contains(term)));
+                .matches_search(|term| terms.iter().any(|searchable| searchable.contains(term)));
+        }
+
+        matches
+    }
+}
+
+impl Searchable for OsuStatsScore {
+    fn matches(&self, criteria: &FilterCriteria<'_>) -> bool {
+        let mut matches = true;
+
+        let map = &self.map;
+        let clock_rate = self.enabled_mods.bits().clock_rate() as f32;
+
+        matches &= criteria.acc.contains(self.accuracy);
+        matches &= criteria.pp.contains_opt(self.pp);
+        matches &= criteria.combo.contains(self.max_combo as f32);
+        matches &= criteria.miss.contains(self.count_miss as f32);
+        matches &= criteria.rank.contains(self.position as f32);
+        matches &= criteria.matches_grade(self.grade);
+        matches &= criteria.matches_date(self.date);
+        matches &= criteria.mods.matches(self.enabled_mods);
+
+        matches &= criteria.stars.contains_opt(map.stars);
+        matches &= criteria.ar.contains(map.diff_ar);
+        matches &= criteria.cs.contains(map.diff_cs);
+        matches &= criteria.hp.contains(map.diff_hp);
+        matches &= criteria.od.contains(map.diff_od);
+        matches &= criteria
+            .length
+            .contains(map.seconds_drain as f32 / clock_rate);
+        matches &= criteria.bpm.contains(map.bpm * clock_rate);
+        matches &= map.mode != GameMode::Mania || criteria.keys.contains(map.diff_cs);
+
+        let artist = map.artist.cow_to_ascii_lowercase();
+        let creator = map.creator.cow_to_ascii_lowercase();
+        let title = map.title.cow_to_ascii_lowercase();
+
+        matches &= criteria.artist.matches(artist.as_ref());
+        matches &= criteria.creator.matches(creator.as_ref());
+        matches &= criteria.title.matches(title.as_ref());
+
+        if matches && criteria.has_search_terms() {
+            let terms = [artist, creator, map.version.cow_to_ascii_lowercase(), title];
+
+            matches &= criteria
+                .matches_search(|term| terms.iter().any(|searchable| searchable.contains(term)));
+        }
+
+        matches
+    }
+}
+
+impl Searchable for ScraperScore {
+    fn matches(&self, criteria: &FilterCriteria<'_>) -> bool {
+        let mut matches = true;
+
+        matches &= criteria.acc.contains(self.accuracy);
+        matches &= criteria.pp.contains_opt(self.pp);
+        matches &= criteria.combo.contains(self.max_combo as f32);
+        matches &= criteria.miss.contains(self.count_miss as f32);
+        matches &= criteria.matches_grade(self.grade);
+        matches &= criteria.matches_date(self.date);
+        matches &= criteria.mods.matches(self.mods);
+
+        if matches && criteria.has_search_terms() {
+            let username = self.username.cow_to_ascii_lowercase();
+
+            matches &= criteria.matches_search(|term| username.contains(term));
         }
 
         matches
@@ -157,6 +246,12 @@ impl Searchable for OsuTrackerCountryScore {
     fn matches(&self, criteria: &FilterCriteria<'_>) -> bool {
         let mut matches = true;
 
+        matches &= criteria.acc.contains(self.acc);
+        matches &= criteria.pp.contains(self.pp);
+        matches &= criteria.miss.contains(self.n_misses as f32);
+        matches &= criteria.matches_date(self.ended_at);
+        matches &= criteria.mods.matches(self.mods);
+
         let len = self.seconds_total as f32 / self.mods.bits().clock_rate() as f32;
         matches &= criteria.length.contains(len);
 
@@ -167,8 +262,7 @@ impl Searchable for OsuTrackerCountryScore {
             let terms = [self.name.cow_to_ascii_lowercase(), creator];
 
             matches &= criteria
-                .search_terms()
-                .all(|term| terms.iter().any(|searchable| searchable.contains(term)));
+                .matches_search(|term| terms.iter().any(|searchable| searchable.contains(term)));
         }
 
         matches